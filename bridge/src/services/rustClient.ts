@@ -110,6 +110,33 @@ class RustClient {
     }
   }
 
+  /**
+   * Get news stories produced by the simulation, newest first
+   * Endpoint: GET /api/news?limit={n}&team_slug={slug}&league_slug={slug}&news_type={type}&min_importance={level}
+   */
+  async getNews(params: {
+    limit?: number;
+    teamSlug?: string;
+    leagueSlug?: string;
+    newsType?: string;
+    minImportance?: 'minor' | 'notable' | 'major' | 'sensational';
+  } = {}) {
+    const query = new URLSearchParams();
+    if (params.limit !== undefined) query.set('limit', String(params.limit));
+    if (params.teamSlug) query.set('team_slug', params.teamSlug);
+    if (params.leagueSlug) query.set('league_slug', params.leagueSlug);
+    if (params.newsType) query.set('news_type', params.newsType);
+    if (params.minImportance) query.set('min_importance', params.minImportance);
+
+    const search = query.toString();
+
+    try {
+      return await this.fetch(`/api/news${search ? `?${search}` : ''}`);
+    } catch {
+      return { news: [] };
+    }
+  }
+
   // ============================================
   // Health Check
   // ============================================
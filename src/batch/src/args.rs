@@ -0,0 +1,209 @@
+use crate::BatchError;
+use std::path::PathBuf;
+
+pub const DEFAULT_OUTPUT_DIRECTORY: &str = "batch";
+
+pub const USAGE: &str = "usage: open_football batch (--days <n> | --seasons <n>) [--seed <n>] \
+[--format json|csv] [--output <dir>] [--database <dir>] [--load <save>]";
+
+/// How long a batch run goes on for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchDuration {
+    Days(u32),
+    /// Until this many season changes have been played through
+    Seasons(u16),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchFormat {
+    Json,
+    Csv,
+}
+
+impl BatchFormat {
+    pub const ALL: [BatchFormat; 2] = [BatchFormat::Json, BatchFormat::Csv];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BatchFormat::Json => "json",
+            BatchFormat::Csv => "csv",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchArgs {
+    pub duration: BatchDuration,
+    /// Makes the generated world, or the days simulated from a loaded one, repeatable
+    pub seed: Option<u64>,
+    pub format: BatchFormat,
+    pub output: PathBuf,
+    /// Database directory to generate the world from
+    pub database: Option<PathBuf>,
+    /// Save to start from instead of generating a new world
+    pub load: Option<PathBuf>,
+}
+
+impl BatchArgs {
+    /// Reads the arguments after the `batch` subcommand
+    pub fn parse<I, S>(args: I) -> Result<Self, BatchError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut duration = None;
+        let mut seed = None;
+        let mut format = BatchFormat::Json;
+        let mut output = PathBuf::from(DEFAULT_OUTPUT_DIRECTORY);
+        let mut database = None;
+        let mut load = None;
+
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let arg = arg.as_ref();
+
+            let value = args
+                .next()
+                .ok_or_else(|| BatchError::Usage(format!("{} needs a value", arg)))?;
+            let value = value.as_ref();
+
+            match arg {
+                "--days" | "--seasons" if duration.is_some() => {
+                    return Err(BatchError::Usage(
+                        "--days and --seasons can't be combined".to_string(),
+                    ));
+                }
+                "--days" => duration = Some(BatchDuration::Days(parse_count(arg, value)?)),
+                "--seasons" => duration = Some(BatchDuration::Seasons(parse_count(arg, value)?)),
+                "--seed" => seed = Some(parse_number(arg, value)?),
+                "--format" => {
+                    format = BatchFormat::ALL
+                        .iter()
+                        .copied()
+                        .find(|format| format.as_str() == value)
+                        .ok_or_else(|| BatchError::Usage(format!("Unknown format '{}'", value)))?
+                }
+                "--output" => output = PathBuf::from(value),
+                "--database" => database = Some(PathBuf::from(value)),
+                "--load" => load = Some(PathBuf::from(value)),
+                _ => return Err(BatchError::Usage(format!("Unknown argument '{}'", arg))),
+            }
+        }
+
+        let duration = duration
+            .ok_or_else(|| BatchError::Usage("--days or --seasons is required".to_string()))?;
+
+        if load.is_some() && database.is_some() {
+            return Err(BatchError::Usage(
+                "--database only applies to a generated world, not to --load".to_string(),
+            ));
+        }
+
+        Ok(BatchArgs {
+            duration,
+            seed,
+            format,
+            output,
+            database,
+            load,
+        })
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(arg: &str, value: &str) -> Result<T, BatchError> {
+    value
+        .parse()
+        .map_err(|_| BatchError::Usage(format!("{} expects a number, got '{}'", arg, value)))
+}
+
+fn parse_count<T: std::str::FromStr + Default + PartialEq>(
+    arg: &str,
+    value: &str,
+) -> Result<T, BatchError> {
+    let count = parse_number(arg, value)?;
+
+    if count == T::default() {
+        return Err(BatchError::Usage(format!("{} must be at least 1", arg)));
+    }
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_all_arguments() {
+        let args = BatchArgs::parse([
+            "--seasons",
+            "2",
+            "--seed",
+            "7",
+            "--format",
+            "csv",
+            "--output",
+            "out",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            args,
+            BatchArgs {
+                duration: BatchDuration::Seasons(2),
+                seed: Some(7),
+                format: BatchFormat::Csv,
+                output: PathBuf::from("out"),
+                database: None,
+                load: None,
+            }
+        );
+    }
+
+    #[test]
+    fn defaults_to_json_in_the_batch_directory() {
+        let args = BatchArgs::parse(["--days", "30"]).unwrap();
+
+        assert_eq!(args.duration, BatchDuration::Days(30));
+        assert_eq!(args.format, BatchFormat::Json);
+        assert_eq!(args.output, PathBuf::from(DEFAULT_OUTPUT_DIRECTORY));
+    }
+
+    #[test]
+    fn seeds_a_loaded_world() {
+        let args =
+            BatchArgs::parse(["--days", "1", "--load", "save.json.gz", "--seed", "1"]).unwrap();
+
+        assert_eq!(args.load, Some(PathBuf::from("save.json.gz")));
+        assert_eq!(args.seed, Some(1));
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        let invalid: [&[&str]; 7] = [
+            &[],
+            &["--seed", "1"],
+            &["--days", "0"],
+            &["--days", "ten"],
+            &["--days", "1", "--seasons", "1"],
+            &["--days", "1", "--format", "xml"],
+            &[
+                "--days",
+                "1",
+                "--load",
+                "save.json.gz",
+                "--database",
+                "data",
+            ],
+        ];
+
+        for args in invalid {
+            assert!(
+                matches!(BatchArgs::parse(args), Err(BatchError::Usage(_))),
+                "{:?}",
+                args
+            );
+        }
+    }
+}
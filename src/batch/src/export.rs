@@ -0,0 +1,97 @@
+use crate::{BatchError, BatchFormat, BatchReport};
+use serde::Serialize;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+pub struct BatchExport;
+
+impl BatchExport {
+    /// Writes the report into the directory, returns the written files
+    pub fn write(
+        report: &BatchReport,
+        format: BatchFormat,
+        directory: &Path,
+    ) -> Result<Vec<PathBuf>, BatchError> {
+        fs::create_dir_all(directory)?;
+
+        match format {
+            BatchFormat::Json => {
+                let path = directory.join("report.json");
+
+                serde_json::to_writer_pretty(BufWriter::new(File::create(&path)?), report)?;
+
+                Ok(vec![path])
+            }
+            BatchFormat::Csv => Ok(vec![
+                write_csv(directory, "league_tables.csv", &report.league_tables)?,
+                write_csv(directory, "transfers.csv", &report.transfers)?,
+                write_csv(
+                    directory,
+                    "player_statistics.csv",
+                    &report.player_statistics,
+                )?,
+            ]),
+        }
+    }
+}
+
+fn write_csv<T: Serialize>(
+    directory: &Path,
+    name: &str,
+    rows: &[T],
+) -> Result<PathBuf, BatchError> {
+    let path = directory.join(name);
+
+    let mut writer = csv::Writer::from_path(&path)?;
+
+    for row in rows {
+        writer.serialize(row)?;
+    }
+
+    writer.flush()?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::TransferReportRow;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn writes_a_csv_file_per_section() {
+        let date = NaiveDate::from_ymd_opt(2024, 8, 1).unwrap();
+
+        let mut report = BatchReport::new(Some(1), date);
+
+        report.transfers.push(TransferReportRow {
+            date,
+            player_id: 5,
+            player: "Doe, John".to_string(),
+            from_club_id: 1,
+            from_club: "First".to_string(),
+            to_club_id: 2,
+            to_club: "Second".to_string(),
+            transfer_type: "permanent",
+            fee: 1500000.0,
+        });
+
+        let directory = std::env::temp_dir().join(format!("batch-export-{}", std::process::id()));
+
+        let files = BatchExport::write(&report, BatchFormat::Csv, &directory).unwrap();
+
+        assert_eq!(files.len(), 3);
+
+        let transfers = fs::read_to_string(directory.join("transfers.csv")).unwrap();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            transfers,
+            "date,player_id,player,from_club_id,from_club,to_club_id,to_club,transfer_type,fee\n\
+             2024-08-01,5,\"Doe, John\",1,First,2,Second,permanent,1500000.0\n"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+mod args;
+mod export;
+mod report;
+mod runner;
+
+pub use args::*;
+pub use export::*;
+pub use report::*;
+pub use runner::*;
+
+use database::{DatabaseError, SaveError};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum BatchError {
+    /// Arguments that don't make a run
+    Usage(String),
+    Database(DatabaseError),
+    Save(SaveError),
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Csv(csv::Error),
+}
+
+impl Display for BatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatchError::Usage(message) => write!(f, "{}\n{}", message, USAGE),
+            BatchError::Database(err) => write!(f, "failed to load database: {}", err),
+            BatchError::Save(err) => write!(f, "failed to load save: {}", err),
+            BatchError::Io(err) => write!(f, "IO error: {}", err),
+            BatchError::Json(err) => write!(f, "failed to write json: {}", err),
+            BatchError::Csv(err) => write!(f, "failed to write csv: {}", err),
+        }
+    }
+}
+
+impl From<DatabaseError> for BatchError {
+    fn from(err: DatabaseError) -> Self {
+        BatchError::Database(err)
+    }
+}
+
+impl From<SaveError> for BatchError {
+    fn from(err: SaveError) -> Self {
+        BatchError::Save(err)
+    }
+}
+
+impl From<std::io::Error> for BatchError {
+    fn from(err: std::io::Error) -> Self {
+        BatchError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for BatchError {
+    fn from(err: serde_json::Error) -> Self {
+        BatchError::Json(err)
+    }
+}
+
+impl From<csv::Error> for BatchError {
+    fn from(err: csv::Error) -> Self {
+        BatchError::Csv(err)
+    }
+}
@@ -0,0 +1,201 @@
+use chrono::NaiveDate;
+use core::SimulatorData;
+use core::league::Season;
+use core::transfers::TransferType;
+use serde::Serialize;
+
+/// What a batch run leaves behind
+#[derive(Debug, Serialize)]
+pub struct BatchReport {
+    pub seed: Option<u64>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub days: u32,
+    pub league_tables: Vec<LeagueTableReportRow>,
+    pub transfers: Vec<TransferReportRow>,
+    pub player_statistics: Vec<PlayerSeasonReportRow>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LeagueTableReportRow {
+    pub season: String,
+    /// Day the table was taken
+    pub date: NaiveDate,
+    pub league_id: u32,
+    pub league: String,
+    pub position: u8,
+    pub team_id: u32,
+    pub team: String,
+    pub played: u8,
+    pub won: u8,
+    pub drawn: u8,
+    pub lost: u8,
+    pub goals_for: i32,
+    pub goals_against: i32,
+    pub points: u8,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransferReportRow {
+    pub date: NaiveDate,
+    pub player_id: u32,
+    pub player: String,
+    pub from_club_id: u32,
+    pub from_club: String,
+    pub to_club_id: u32,
+    pub to_club: String,
+    pub transfer_type: &'static str,
+    pub fee: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlayerSeasonReportRow {
+    pub season: String,
+    pub player_id: u32,
+    pub player: String,
+    /// Where the player is at the end of the run
+    pub team: String,
+    pub played: u16,
+    pub played_subs: u16,
+    pub goals: u16,
+    pub assists: u16,
+    pub yellow_cards: u8,
+    pub red_cards: u8,
+    pub clean_sheets: u16,
+    pub player_of_the_match: u8,
+    pub average_rating: f32,
+}
+
+impl BatchReport {
+    pub fn new(seed: Option<u64>, start_date: NaiveDate) -> Self {
+        BatchReport {
+            seed,
+            start_date,
+            end_date: start_date,
+            days: 0,
+            league_tables: Vec::new(),
+            transfers: Vec::new(),
+            player_statistics: Vec::new(),
+        }
+    }
+
+    /// Every league's table as it stands
+    pub fn add_league_tables(&mut self, season: Season, data: &SimulatorData) {
+        let date = data.date.date();
+
+        for league in data
+            .continents
+            .iter()
+            .flat_map(|continent| &continent.countries)
+            .flat_map(|country| &country.leagues.leagues)
+        {
+            for (index, row) in league.table.get().iter().enumerate() {
+                self.league_tables.push(LeagueTableReportRow {
+                    season: season.to_string(),
+                    date,
+                    league_id: league.id,
+                    league: league.name.clone(),
+                    position: index as u8 + 1,
+                    team_id: row.team_id,
+                    team: team_name(data, row.team_id),
+                    played: row.played,
+                    won: row.win,
+                    drawn: row.draft,
+                    lost: row.lost,
+                    goals_for: row.goal_scored,
+                    goals_against: row.goal_concerned,
+                    points: row.points,
+                });
+            }
+        }
+    }
+
+    /// Closes the report at the last simulated day, collecting transfers and player seasons
+    pub fn finish(&mut self, seasons: &[Season], data: &SimulatorData) {
+        self.end_date = data.date.date();
+
+        let countries = data
+            .continents
+            .iter()
+            .flat_map(|continent| &continent.countries);
+
+        for transfer in countries
+            .clone()
+            .flat_map(|country| &country.transfer_market.transfer_history)
+            .filter(|transfer| transfer.transfer_date >= self.start_date)
+        {
+            self.transfers.push(TransferReportRow {
+                date: transfer.transfer_date,
+                player_id: transfer.player_id,
+                player: data
+                    .player(transfer.player_id)
+                    .map(|player| player.full_name.to_string())
+                    .unwrap_or_default(),
+                from_club_id: transfer.from_club_id,
+                from_club: club_name(data, transfer.from_club_id),
+                to_club_id: transfer.to_club_id,
+                to_club: club_name(data, transfer.to_club_id),
+                transfer_type: match transfer.transfer_type {
+                    TransferType::Permanent => "permanent",
+                    TransferType::Loan(_) => "loan",
+                    TransferType::Free => "free",
+                },
+                fee: transfer.fee.amount,
+            });
+        }
+
+        self.transfers.sort_by_key(|transfer| transfer.date);
+
+        let current_season = Season::from_date(self.end_date);
+
+        for team in countries
+            .flat_map(|country| &country.clubs)
+            .flat_map(|club| &club.teams.teams)
+        {
+            for player in &team.players.players {
+                let archived = player
+                    .statistics_history
+                    .items
+                    .iter()
+                    .filter(|item| seasons.contains(&item.season))
+                    .map(|item| (item.season, &item.statistics));
+
+                for (season, statistics) in
+                    archived.chain(std::iter::once((current_season, &player.statistics)))
+                {
+                    if statistics.played + statistics.played_subs == 0 {
+                        continue;
+                    }
+
+                    self.player_statistics.push(PlayerSeasonReportRow {
+                        season: season.to_string(),
+                        player_id: player.id,
+                        player: player.full_name.to_string(),
+                        team: team.name.clone(),
+                        played: statistics.played,
+                        played_subs: statistics.played_subs,
+                        goals: statistics.goals,
+                        assists: statistics.assists,
+                        yellow_cards: statistics.yellow_cards,
+                        red_cards: statistics.red_cards,
+                        clean_sheets: statistics.clean_sheets,
+                        player_of_the_match: statistics.player_of_the_match,
+                        average_rating: statistics.average_rating,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn team_name(data: &SimulatorData, team_id: u32) -> String {
+    data.team(team_id)
+        .map(|team| team.name.clone())
+        .unwrap_or_default()
+}
+
+fn club_name(data: &SimulatorData, club_id: u32) -> String {
+    data.club(club_id)
+        .map(|club| club.name.clone())
+        .unwrap_or_default()
+}
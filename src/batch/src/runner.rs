@@ -0,0 +1,186 @@
+use crate::{BatchArgs, BatchDuration, BatchError, BatchExport, BatchReport};
+use core::league::Season;
+use core::utils::RandomSeed;
+use core::{FootballSimulator, SimulatorData};
+use database::{DatabaseGenerator, DatabaseLoader, SaveGame};
+use log::info;
+
+pub struct BatchRunner;
+
+impl BatchRunner {
+    /// Simulates the requested days or seasons and writes the report.
+    /// The same seed gives the same world and the same results, matches played in parallel
+    /// each get a seed forked from it
+    pub fn run(args: BatchArgs) -> Result<BatchReport, BatchError> {
+        let mut data = Self::start_world(&args)?;
+
+        let report = Self::simulate(&mut data, &args);
+
+        let files = BatchExport::write(&report, args.format, &args.output)?;
+
+        for file in files {
+            info!("batch output written: {}", file.display());
+        }
+
+        Ok(report)
+    }
+
+    fn start_world(args: &BatchArgs) -> Result<SimulatorData, BatchError> {
+        if let Some(seed) = args.seed {
+            RandomSeed::set(seed);
+        }
+
+        if let Some(save_file) = &args.load {
+            return Ok(SaveGame::load(save_file)?);
+        }
+
+        let database = DatabaseLoader::load_configured(args.database.as_deref())?;
+
+        Ok(DatabaseGenerator::generate(&database))
+    }
+
+    fn simulate(data: &mut SimulatorData, args: &BatchArgs) -> BatchReport {
+        let mut report = BatchReport::new(args.seed, data.date.date());
+
+        let mut season = Season::from_date(data.date.date());
+        let mut seasons = vec![season];
+
+        let mut seasons_played = 0;
+        let mut season_changed;
+
+        loop {
+            FootballSimulator::simulate(data);
+
+            report.days += 1;
+
+            let date_season = Season::from_date(data.date.date());
+
+            season_changed = date_season != season;
+
+            if season_changed {
+                info!(
+                    "batch season {} finished after {} days",
+                    season, report.days
+                );
+
+                report.add_league_tables(season, data);
+
+                season = date_season;
+                seasons.push(season);
+                seasons_played += 1;
+            }
+
+            let finished = match args.duration {
+                BatchDuration::Days(days) => report.days >= days,
+                BatchDuration::Seasons(count) => seasons_played >= count,
+            };
+
+            if finished {
+                break;
+            }
+        }
+
+        // A run stopping mid season still reports where the tables stand
+        if !season_changed {
+            report.add_league_tables(season, data);
+        }
+
+        report.finish(&seasons, data);
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+
+    /// A league of four Italian clubs, the first matchday is the first Saturday of July
+    fn write_database(directory: &Path) {
+        fs::create_dir_all(directory).unwrap();
+
+        let team = |id: u32, name: &str| {
+            format!(
+                r#"{{"id": {id}, "name": "{name}", "country_id": 776, "location": {{"city_id": 1}},
+                "finance": {{"balance": 1000000}},
+                "stadium": {{"name": "{name} Stadium", "capacity": 10000, "quality": 10, "ownership": "Owned"}},
+                "teams": [{{"id": {id}, "name": "{name}", "slug": "club-{id}", "team_type": "Main", "league_id": 1,
+                "reputation": {{"home": 5000, "national": 5000, "world": 5000}}}}]}}"#
+            )
+        };
+
+        let files = [
+            ("continents.json", r#"[{"id": 1, "name": "Europe"}]"#.to_string()),
+            (
+                "countries.json",
+                r#"[{"id": 776, "code": "it", "slug": "italy", "name": "Italy", "continent_id": 1, "reputation": 0}]"#
+                    .to_string(),
+            ),
+            (
+                "leagues.json",
+                r#"[{"id": 1, "slug": "test-league", "name": "Test League", "country_id": 776, "reputation": 5000,
+                "settings": {"season_starting_half": {"from_day": 1, "from_month": 7, "to_day": 5, "to_month": 12},
+                "season_ending_half": {"from_day": 1, "from_month": 3, "to_day": 31, "to_month": 5}}}]"#
+                    .to_string(),
+            ),
+            (
+                "clubs.json",
+                format!(
+                    "[{}, {}, {}, {}]",
+                    team(1, "First"),
+                    team(2, "Second"),
+                    team(3, "Third"),
+                    team(4, "Fourth")
+                ),
+            ),
+            (
+                "names/names_by_country.json",
+                r#"[{"country_id": 776, "first_names": ["Andrea", "Marco", "Luca"], "last_names": ["Rossi", "Russo", "Bianchi"]}]"#
+                    .to_string(),
+            ),
+        ];
+
+        fs::create_dir_all(directory.join("names")).unwrap();
+
+        for (file, content) in files {
+            fs::write(directory.join(file), content).unwrap();
+        }
+    }
+
+    fn league_tables(directory: &Path, run: &str) -> String {
+        let output = directory.join(run);
+
+        let args = BatchArgs::parse([
+            "--days",
+            "6",
+            "--seed",
+            "42",
+            "--database",
+            directory.to_str().unwrap(),
+            "--output",
+            output.to_str().unwrap(),
+        ])
+        .unwrap();
+
+        let report = BatchRunner::run(args).unwrap();
+
+        serde_json::to_string(&report.league_tables).unwrap()
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_league_tables() {
+        let directory = std::env::temp_dir().join(format!("batch-seed-{}", std::process::id()));
+        write_database(&directory);
+
+        let first = league_tables(&directory, "first");
+        let second = league_tables(&directory, "second");
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        // The first matchday has been played
+        assert!(first.contains(r#""played":1"#), "{}", first);
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::league::Season;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Seasons an award keeps adding to a player's value
+const AWARD_VALUE_YEARS: i64 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AwardType {
+    PlayerOfTheSeason,
+    YoungPlayerOfTheSeason,
+    CoachOfTheSeason,
+    TeamOfTheSeason,
+}
+
+impl AwardType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AwardType::PlayerOfTheSeason => "Player of the Season",
+            AwardType::YoungPlayerOfTheSeason => "Young Player of the Season",
+            AwardType::CoachOfTheSeason => "Coach of the Season",
+            AwardType::TeamOfTheSeason => "Team of the Season",
+        }
+    }
+}
+
+/// Who hands the award out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AwardScope {
+    League(u32),
+    Continent(u32),
+}
+
+/// Award in a player's or coach's record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Award {
+    pub award_type: AwardType,
+    pub scope: AwardScope,
+    pub season: Season,
+    pub date: NaiveDate,
+    pub club_id: u32,
+}
+
+impl Award {
+    pub fn new(
+        award_type: AwardType,
+        scope: AwardScope,
+        season: Season,
+        date: NaiveDate,
+        club_id: u32,
+    ) -> Self {
+        Award {
+            award_type,
+            scope,
+            season,
+            date,
+            club_id,
+        }
+    }
+
+    /// Home and world reputation the award brings, prestige is the standing of the league from 0 to 1
+    pub fn reputation_boost(&self, prestige: f32) -> (i16, i16) {
+        let (home, world) = match self.award_type {
+            AwardType::PlayerOfTheSeason => (1500.0, 1000.0),
+            AwardType::YoungPlayerOfTheSeason => (800.0, 500.0),
+            AwardType::TeamOfTheSeason => (500.0, 300.0),
+            // A coach's standing is kept by Staff::reputation
+            AwardType::CoachOfTheSeason => (0.0, 0.0),
+        };
+
+        let scale = match self.scope {
+            AwardScope::League(_) => prestige,
+            AwardScope::Continent(_) => 1.0 + prestige,
+        };
+
+        ((home * scale) as i16, (world * scale) as i16)
+    }
+
+    /// Share added to the player's value while the award is recent
+    pub fn value_bonus(&self, date: NaiveDate) -> f64 {
+        if (date - self.date).num_days() > AWARD_VALUE_YEARS * 365 {
+            return 0.0;
+        }
+
+        let bonus = match self.award_type {
+            AwardType::PlayerOfTheSeason => 0.15,
+            AwardType::YoungPlayerOfTheSeason => 0.1,
+            AwardType::TeamOfTheSeason => 0.05,
+            AwardType::CoachOfTheSeason => 0.0,
+        };
+
+        match self.scope {
+            AwardScope::League(_) => bonus,
+            AwardScope::Continent(_) => bonus * 2.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AwardWinner {
+    pub id: u32,
+    pub club_id: u32,
+}
+
+/// Winners of one season's awards in a league or continent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonAwards {
+    pub season: Season,
+    pub player_of_the_season: Option<AwardWinner>,
+    pub young_player_of_the_season: Option<AwardWinner>,
+    pub coach_of_the_season: Option<AwardWinner>,
+    /// Goalkeeper, four defenders, four midfielders and two forwards
+    pub team_of_the_season: Vec<AwardWinner>,
+}
+
+impl SeasonAwards {
+    /// Nobody qualified, as in a season without league football
+    pub fn is_empty(&self) -> bool {
+        self.player_of_the_season.is_none() && self.coach_of_the_season.is_none()
+    }
+
+    /// Players honoured this season, with the award each one receives
+    pub fn player_awards(&self) -> Vec<(AwardType, AwardWinner)> {
+        let mut awards: Vec<(AwardType, AwardWinner)> = self
+            .team_of_the_season
+            .iter()
+            .map(|&winner| (AwardType::TeamOfTheSeason, winner))
+            .collect();
+
+        if let Some(winner) = self.player_of_the_season {
+            awards.push((AwardType::PlayerOfTheSeason, winner));
+        }
+
+        if let Some(winner) = self.young_player_of_the_season {
+            awards.push((AwardType::YoungPlayerOfTheSeason, winner));
+        }
+
+        awards
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn award(award_type: AwardType, scope: AwardScope) -> Award {
+        Award::new(
+            award_type,
+            scope,
+            Season::TwoYear(2024, 2025),
+            NaiveDate::from_ymd_opt(2025, 5, 31).unwrap(),
+            1,
+        )
+    }
+
+    #[test]
+    fn continental_awards_count_for_more_and_fade_from_value() {
+        let league = award(AwardType::PlayerOfTheSeason, AwardScope::League(2));
+        let continent = award(AwardType::PlayerOfTheSeason, AwardScope::Continent(1));
+
+        assert!(continent.reputation_boost(0.8).1 > league.reputation_boost(0.8).1);
+
+        let next_summer = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
+        let years_later = NaiveDate::from_ymd_opt(2029, 8, 1).unwrap();
+
+        assert!(continent.value_bonus(next_summer) > league.value_bonus(next_summer));
+        assert_eq!(0.0, league.value_bonus(years_later));
+    }
+}
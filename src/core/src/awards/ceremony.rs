@@ -0,0 +1,85 @@
+use crate::awards::{Award, AwardScope, AwardType, SeasonAwards};
+use crate::Club;
+use chrono::NaiveDate;
+use log::info;
+
+/// Ceiling of the player reputation scales
+const MAX_REPUTATION: i16 = 10000;
+
+/// Hands the season's awards to the winners and lets them count towards their reputation
+pub struct AwardCeremony;
+
+impl AwardCeremony {
+    /// Prestige is the standing of the league or competition from 0 to 1
+    pub fn present<'c>(
+        awards: &SeasonAwards,
+        scope: AwardScope,
+        prestige: f32,
+        date: NaiveDate,
+        clubs: impl Iterator<Item = &'c mut Club>,
+    ) {
+        let player_awards = awards.player_awards();
+
+        for club in clubs {
+            for team in &mut club.teams.teams {
+                for player in &mut team.players.players {
+                    for (award_type, winner) in &player_awards {
+                        if winner.id != player.id {
+                            continue;
+                        }
+
+                        let award =
+                            Award::new(*award_type, scope, awards.season, date, winner.club_id);
+                        let (home, world) = award.reputation_boost(prestige);
+
+                        let attributes = &mut player.player_attributes;
+
+                        attributes.home_reputation = attributes
+                            .home_reputation
+                            .saturating_add(home)
+                            .min(MAX_REPUTATION);
+                        attributes.world_reputation = attributes
+                            .world_reputation
+                            .saturating_add(world)
+                            .min(MAX_REPUTATION);
+
+                        info!(
+                            "🏅 {:?} {}: {} ({})",
+                            scope,
+                            award_type.as_str(),
+                            player.full_name,
+                            awards.season
+                        );
+
+                        player.awards.push(award);
+                    }
+                }
+
+                if let Some(winner) = awards.coach_of_the_season {
+                    if let Some(staff) = team
+                        .staffs
+                        .staffs
+                        .iter_mut()
+                        .find(|staff| staff.id == winner.id)
+                    {
+                        info!(
+                            "🏅 {:?} {}: {} ({})",
+                            scope,
+                            AwardType::CoachOfTheSeason.as_str(),
+                            staff.full_name,
+                            awards.season
+                        );
+
+                        staff.awards.push(Award::new(
+                            AwardType::CoachOfTheSeason,
+                            scope,
+                            awards.season,
+                            date,
+                            winner.club_id,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod award;
+pub mod ceremony;
+pub mod nominees;
+
+pub use award::*;
+pub use ceremony::*;
+pub use nominees::*;
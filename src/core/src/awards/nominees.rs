@@ -0,0 +1,330 @@
+use crate::awards::{AwardWinner, SeasonAwards};
+use crate::league::{League, Season};
+use crate::{Person, Player, PlayerFieldPositionGroup, Team};
+use chrono::NaiveDate;
+use std::cmp::Ordering;
+
+/// Appearances needed to be considered for an award
+const MIN_APPEARANCES: u16 = 10;
+
+/// Oldest a young player of the season can be
+const YOUNG_PLAYER_MAX_AGE: u8 = 21;
+
+/// Team of the season lines up in a 4-4-2
+const TEAM_OF_THE_SEASON: [(PlayerFieldPositionGroup, usize); 4] = [
+    (PlayerFieldPositionGroup::Goalkeeper, 1),
+    (PlayerFieldPositionGroup::Defender, 4),
+    (PlayerFieldPositionGroup::Midfielder, 4),
+    (PlayerFieldPositionGroup::Forward, 2),
+];
+
+/// A player's season as the jury sees it
+#[derive(Debug, Clone)]
+pub struct AwardCandidate {
+    pub player_id: u32,
+    pub club_id: u32,
+    pub age: u8,
+    pub position: PlayerFieldPositionGroup,
+    pub played: u16,
+    pub average_rating: f32,
+    pub goals: u16,
+    pub assists: u16,
+    pub clean_sheets: u16,
+    /// 1 for the champions down to 0 for the bottom side
+    pub team_success: f32,
+    /// Standing of the league from 0 to 1
+    pub prestige: f32,
+}
+
+impl AwardCandidate {
+    pub fn new(
+        player: &Player,
+        club_id: u32,
+        team_success: f32,
+        prestige: f32,
+        date: NaiveDate,
+    ) -> Option<Self> {
+        let statistics = &player.statistics;
+
+        if statistics.played < MIN_APPEARANCES {
+            return None;
+        }
+
+        Some(AwardCandidate {
+            player_id: player.id,
+            club_id,
+            age: player.age(date),
+            position: player.position().position_group(),
+            played: statistics.played,
+            average_rating: statistics.average_rating,
+            goals: statistics.goals,
+            assists: statistics.assists,
+            clean_sheets: statistics.clean_sheets,
+            team_success,
+            prestige,
+        })
+    }
+
+    /// Ratings carry the most weight, then goals, assists and clean sheets for the back line
+    pub fn score(&self) -> f32 {
+        let clean_sheets = match self.position {
+            PlayerFieldPositionGroup::Goalkeeper | PlayerFieldPositionGroup::Defender => {
+                self.clean_sheets as f32 * 0.8
+            }
+            _ => 0.0,
+        };
+
+        let score = self.average_rating * 10.0
+            + self.goals as f32
+            + self.assists as f32 * 0.6
+            + clean_sheets
+            + self.team_success * 10.0;
+
+        score * (0.5 + self.prestige / 2.0)
+    }
+
+    fn winner(&self) -> AwardWinner {
+        AwardWinner {
+            id: self.player_id,
+            club_id: self.club_id,
+        }
+    }
+}
+
+/// A head coach judged on where the team finished against where it was expected to
+#[derive(Debug, Clone)]
+pub struct CoachCandidate {
+    pub staff_id: u32,
+    pub club_id: u32,
+    pub position: u8,
+    pub expected_position: u8,
+    pub teams_count: u8,
+    pub prestige: f32,
+}
+
+impl CoachCandidate {
+    pub fn score(&self) -> f32 {
+        let over_performance =
+            (self.expected_position as f32 - self.position as f32) / self.teams_count.max(1) as f32;
+
+        let title = if self.position == 1 { 0.25 } else { 0.0 };
+
+        (over_performance + title + 1.0) * (0.5 + self.prestige / 2.0)
+    }
+
+    fn winner(&self) -> AwardWinner {
+        AwardWinner {
+            id: self.staff_id,
+            club_id: self.club_id,
+        }
+    }
+}
+
+/// Everyone in the running for a league's or continent's awards
+#[derive(Debug, Default)]
+pub struct AwardNominees {
+    pub players: Vec<AwardCandidate>,
+    pub coaches: Vec<CoachCandidate>,
+}
+
+impl AwardNominees {
+    pub fn new() -> Self {
+        AwardNominees::default()
+    }
+
+    /// Nominates the players and managers of the given teams from the final league table
+    pub fn add_league(&mut self, league: &League, teams: &[&Team], date: NaiveDate) {
+        let rows = league.table.get();
+
+        if rows.is_empty() {
+            return;
+        }
+
+        let teams_count = rows.len();
+        let prestige = league.prestige();
+
+        // Expectations follow the pecking order of reputation
+        let mut by_reputation: Vec<&Team> = teams.to_vec();
+        by_reputation.sort_by(|a, b| b.reputation.world.cmp(&a.reputation.world));
+
+        for team in teams {
+            let position = match rows.iter().position(|row| row.team_id == team.id) {
+                Some(position) => position,
+                None => continue,
+            };
+
+            let team_success = 1.0 - position as f32 / (teams_count - 1).max(1) as f32;
+
+            self.players
+                .extend(team.players.players.iter().filter_map(|player| {
+                    AwardCandidate::new(player, team.club_id, team_success, prestige, date)
+                }));
+
+            if let Some(staff_id) = team.staffs.manager_id() {
+                let expected_position = by_reputation
+                    .iter()
+                    .position(|other| other.id == team.id)
+                    .unwrap_or(position);
+
+                self.coaches.push(CoachCandidate {
+                    staff_id,
+                    club_id: team.club_id,
+                    position: (position + 1) as u8,
+                    expected_position: (expected_position + 1) as u8,
+                    teams_count: teams_count as u8,
+                    prestige,
+                });
+            }
+        }
+    }
+
+    pub fn select(&self, season: Season) -> SeasonAwards {
+        let mut ranked: Vec<&AwardCandidate> = self.players.iter().collect();
+        ranked.sort_by(|a, b| {
+            Self::by_score(a.score(), b.score()).then(a.player_id.cmp(&b.player_id))
+        });
+
+        let team_of_the_season = TEAM_OF_THE_SEASON
+            .iter()
+            .flat_map(|&(group, count)| {
+                ranked
+                    .iter()
+                    .filter(move |candidate| candidate.position == group)
+                    .take(count)
+                    .map(|candidate| candidate.winner())
+            })
+            .collect();
+
+        let coach_of_the_season = self
+            .coaches
+            .iter()
+            .min_by(|a, b| Self::by_score(a.score(), b.score()).then(a.staff_id.cmp(&b.staff_id)))
+            .map(CoachCandidate::winner);
+
+        SeasonAwards {
+            season,
+            player_of_the_season: ranked.first().map(|candidate| candidate.winner()),
+            young_player_of_the_season: ranked
+                .iter()
+                .find(|candidate| candidate.age <= YOUNG_PLAYER_MAX_AGE)
+                .map(|candidate| candidate.winner()),
+            coach_of_the_season,
+            team_of_the_season,
+        }
+    }
+
+    /// Highest score first
+    fn by_score(a: f32, b: f32) -> Ordering {
+        b.partial_cmp(&a).unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(
+        player_id: u32,
+        age: u8,
+        position: PlayerFieldPositionGroup,
+        average_rating: f32,
+        goals: u16,
+    ) -> AwardCandidate {
+        AwardCandidate {
+            player_id,
+            club_id: 1,
+            age,
+            position,
+            played: 30,
+            average_rating,
+            goals,
+            assists: 0,
+            clean_sheets: 0,
+            team_success: 0.5,
+            prestige: 0.8,
+        }
+    }
+
+    fn coach(staff_id: u32, position: u8, expected_position: u8) -> CoachCandidate {
+        CoachCandidate {
+            staff_id,
+            club_id: staff_id,
+            position,
+            expected_position,
+            teams_count: 20,
+            prestige: 0.8,
+        }
+    }
+
+    #[test]
+    fn best_season_wins_and_team_of_the_season_is_a_four_four_two() {
+        let mut nominees = AwardNominees::new();
+
+        nominees
+            .players
+            .push(candidate(1, 29, PlayerFieldPositionGroup::Forward, 7.5, 25));
+        nominees.players.push(candidate(
+            2,
+            20,
+            PlayerFieldPositionGroup::Midfielder,
+            7.0,
+            5,
+        ));
+        nominees.players.push(candidate(
+            3,
+            27,
+            PlayerFieldPositionGroup::Goalkeeper,
+            6.8,
+            0,
+        ));
+        nominees.players.push(candidate(
+            4,
+            24,
+            PlayerFieldPositionGroup::Goalkeeper,
+            6.2,
+            0,
+        ));
+
+        for id in 10..16 {
+            nominees.players.push(candidate(
+                id,
+                25,
+                PlayerFieldPositionGroup::Defender,
+                6.5,
+                1,
+            ));
+        }
+
+        nominees.coaches.push(coach(100, 1, 1));
+        nominees.coaches.push(coach(101, 4, 15));
+
+        let awards = nominees.select(Season::TwoYear(2024, 2025));
+
+        assert_eq!(Some(1), awards.player_of_the_season.map(|winner| winner.id));
+        assert_eq!(
+            Some(2),
+            awards.young_player_of_the_season.map(|winner| winner.id)
+        );
+        assert_eq!(
+            Some(101),
+            awards.coach_of_the_season.map(|winner| winner.id)
+        );
+
+        let team: Vec<u32> = awards
+            .team_of_the_season
+            .iter()
+            .map(|winner| winner.id)
+            .collect();
+
+        assert_eq!(vec![3, 10, 11, 12, 13, 2, 1], team);
+    }
+
+    #[test]
+    fn season_without_nominees_has_no_winners() {
+        let awards = AwardNominees::new().select(Season::TwoYear(2024, 2025));
+
+        assert!(awards.player_of_the_season.is_none());
+        assert!(awards.coach_of_the_season.is_none());
+        assert!(awards.team_of_the_season.is_empty());
+    }
+}
@@ -1,19 +1,32 @@
 use crate::academy::result::ProduceYouthPlayersResult;
 use crate::club::academy::result::ClubAcademyResult;
+use crate::club::academy::review::{AcademyLoan, AcademyReview};
 use crate::club::academy::settings::AcademySettings;
+use crate::club::SquadMovement;
 use crate::context::GlobalContext;
 use crate::utils::IntegerUtils;
-use crate::{Person, Player, PlayerCollection, PlayerGenerator, PlayerPositionType, StaffCollection};
-use chrono::Datelike;
+use crate::{
+    Person, Player, PlayerCollection, PlayerGenerator, PlayerPositionType, StaffCollection,
+    TeamCollection,
+};
+use chrono::{Datelike, NaiveDate};
 use log::debug;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+/// Academy players are ready for youth team football at this age
+pub const ACADEMY_GRADUATION_AGE: u8 = 16;
+
+/// Youngsters are picked to play in their natural position, quality comes from the academy level
+const ACADEMY_POSITION_LEVEL: u8 = 20;
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ClubAcademy {
     settings: AcademySettings,
     players: PlayerCollection,
     _staff: StaffCollection,
     level: u8,
     last_production_year: Option<i32>,
+    pub loans: Vec<AcademyLoan>,
 }
 
 impl ClubAcademy {
@@ -24,15 +37,30 @@ impl ClubAcademy {
             _staff: StaffCollection::new(Vec::new()),
             level,
             last_production_year: None,
+            loans: Vec::new(),
         }
     }
 
-    pub fn simulate(&mut self, ctx: GlobalContext<'_>) -> ClubAcademyResult {
+    pub fn simulate(&mut self, ctx: GlobalContext<'_>, teams: &TeamCollection) -> ClubAcademyResult {
+        let club_id = ctx.club.as_ref().expect("no club found").id;
+        let date = ctx.simulation.date.date();
+
         // Simulate existing academy players
         let players_result = self.players.simulate(ctx.with_player(None));
 
+        let mut result = ClubAcademyResult::new(club_id, players_result);
+
+        // Graduates are reviewed before the new intake arrives
+        if SquadMovement::is_review_day(date) {
+            result.decisions = AcademyReview::review(&self.graduates(date), teams, date);
+
+            let (ended, active) = self.loans.drain(..).partition(|loan| loan.end < date);
+            result.returning_loans = ended;
+            self.loans = active;
+        }
+
         // Produce new youth players
-        let produce_result = self.produce_youth_players(ctx);
+        let produce_result = self.produce_youth_players(ctx, result.decisions.len());
 
         // Add newly produced players to the academy
         for player in produce_result.players {
@@ -40,10 +68,10 @@ impl ClubAcademy {
             self.players.add(player);
         }
 
-        ClubAcademyResult::new(players_result)
+        result
     }
 
-    fn produce_youth_players(&mut self, ctx: GlobalContext<'_>) -> ProduceYouthPlayersResult {
+    fn produce_youth_players(&mut self, ctx: GlobalContext<'_>, leaving: usize) -> ProduceYouthPlayersResult {
         let current_year = ctx.simulation.date.year();
         let current_month = ctx.simulation.date.month();
 
@@ -59,7 +87,8 @@ impl ClubAcademy {
         debug!("🎓 academy: {} starting yearly youth intake", club_name);
 
         // Determine how many players to produce based on academy level and current squad size
-        let current_size = self.players.players.len();
+        // Graduates leaving after the review make room for the intake
+        let current_size = self.players.players.len().saturating_sub(leaving);
         let min_required = self.settings.players_count_range.start as usize;
         let max_allowed = self.settings.players_count_range.end as usize;
 
@@ -103,13 +132,15 @@ impl ClubAcademy {
         for i in 0..players_to_produce {
             let position = self.select_position_for_youth_player(i, players_to_produce);
 
-            let generated_player = PlayerGenerator::generate(
+            let mut generated_player = PlayerGenerator::generate(
                 country_id,
                 ctx.simulation.date.date(),
                 position,
-                self.level, // Pass academy level to influence player quality
+                ACADEMY_POSITION_LEVEL,
             );
 
+            self.assign_abilities(&mut generated_player);
+
             debug!("👤 academy: {} generated youth player: {} ({}, age {})",
                    club_name,
                    generated_player.full_name,
@@ -128,6 +159,15 @@ impl ClubAcademy {
         ProduceYouthPlayersResult::new(generated_players)
     }
 
+    /// Better academies find youngsters with more potential
+    fn assign_abilities(&self, player: &mut Player) {
+        let level = self.level.clamp(1, 10) as i32;
+
+        player.player_attributes.current_ability = IntegerUtils::random(20, 50) as u8;
+        player.player_attributes.potential_ability =
+            IntegerUtils::random(50 + level * 5, 100 + level * 10) as u8;
+    }
+
     fn should_produce_players(&self, current_year: i32, current_month: u32) -> bool {
         // Produce players once per year, typically in July (pre-season)
         const YOUTH_INTAKE_MONTH: u32 = 7;
@@ -221,6 +261,15 @@ impl ClubAcademy {
         }
     }
 
+    /// Academy players old enough for the yearly review
+    pub fn graduates(&self, now: NaiveDate) -> Vec<&Player> {
+        self.players
+            .players
+            .iter()
+            .filter(|player| player.age(now) >= ACADEMY_GRADUATION_AGE)
+            .collect()
+    }
+
     pub fn graduate_player(&mut self, player_id: u32) -> Option<Player> {
         // Remove a player from academy (e.g., promoted to first team)
         self.players.take_player(&player_id)
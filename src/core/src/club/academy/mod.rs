@@ -1,6 +1,8 @@
 pub mod academy;
 pub mod result;
+pub mod review;
 mod settings;
 
 pub use academy::*;
 pub use result::*;
+pub use review::*;
@@ -1,17 +1,218 @@
-use crate::{Player, PlayerCollectionResult, SimulatorData};
+use crate::club::academy::review::{AcademyDecision, AcademyDecisionType, AcademyLoan};
+use crate::{
+    ContractType, Person, Player, PlayerClubContract, PlayerCollectionResult, PlayerSquadStatus,
+    SimulatorData, TeamCollection,
+};
+use chrono::{Datelike, NaiveDate};
+use log::info;
+
+/// Years of the first professional and youth contracts
+const ACADEMY_CONTRACT_YEARS: i32 = 3;
 
 pub struct ClubAcademyResult {
-    pub players: PlayerCollectionResult
+    pub club_id: u32,
+    pub players: PlayerCollectionResult,
+    pub decisions: Vec<AcademyDecision>,
+    pub returning_loans: Vec<AcademyLoan>,
 }
 
 impl ClubAcademyResult {
-    pub fn new(players: PlayerCollectionResult) -> Self {
+    pub fn new(club_id: u32, players: PlayerCollectionResult) -> Self {
         ClubAcademyResult {
-            players
+            club_id,
+            players,
+            decisions: Vec::new(),
+            returning_loans: Vec::new(),
         }
     }
 
-    pub fn process(&self, _: &mut SimulatorData) {}
+    pub fn process(&self, data: &mut SimulatorData) {
+        if self.returning_loans.is_empty() && self.decisions.is_empty() {
+            return;
+        }
+
+        for loan in &self.returning_loans {
+            self.return_from_loan(loan, data);
+        }
+
+        self.apply_decisions(data);
+
+        // Graduates and returning loans join the club's squads
+        data.refresh_player_indexes();
+    }
+
+    fn apply_decisions(&self, data: &mut SimulatorData) {
+        let date = data.date.date();
+        let mut loaned = Vec::new();
+        let mut released = Vec::new();
+
+        let club = match data.club_mut(self.club_id) {
+            Some(club) => club,
+            None => return,
+        };
+
+        for decision in &self.decisions {
+            match decision.decision {
+                AcademyDecisionType::ProfessionalContract => {
+                    if let Some(mut player) = club.academy.graduate_player(decision.player_id) {
+                        player.contract = Some(Self::contract(&player, ContractType::FullTime, date));
+
+                        info!("✍️ {}: academy graduate {} signs a first professional contract", club.name, player.full_name);
+
+                        if let Some(team) = club.teams.main_mut() {
+                            team.players.add(player);
+                        }
+                    }
+                }
+                AcademyDecisionType::Promoted => {
+                    if let Some(player) = club.academy.graduate_player(decision.player_id) {
+                        info!("⬆️ {}: academy graduate {} joins the reserves", club.name, player.full_name);
+
+                        Self::promote(&mut club.teams, player, date);
+                    }
+                }
+                AcademyDecisionType::Loaned => {
+                    if let Some(player) = club.academy.graduate_player(decision.player_id) {
+                        loaned.push(player);
+                    }
+                }
+                AcademyDecisionType::Released => {
+                    if let Some(player) = club.academy.release_player(decision.player_id) {
+                        info!("👋 {}: academy player {} released", club.name, player.full_name);
+                        released.push(player);
+                    }
+                }
+            }
+        }
+
+        for player in released {
+            data.free_agents.add(player);
+        }
+
+        for player in loaned {
+            self.loan(player, date, data);
+        }
+    }
+
+    fn contract(player: &Player, contract_type: ContractType, date: NaiveDate) -> PlayerClubContract {
+        let potential = player.player_attributes.potential_ability as u32;
+
+        let salary = match contract_type {
+            ContractType::FullTime => potential * 50,
+            _ => potential * 10,
+        };
+
+        let mut contract = PlayerClubContract::new(salary, Self::season_end(date, ACADEMY_CONTRACT_YEARS));
+
+        contract.started = Some(date);
+        contract.contract_type = contract_type;
+        contract.squad_status = PlayerSquadStatus::HotProspectForTheFuture;
+
+        contract
+    }
+
+    /// Youth contract with the first reserve side the player is young enough for
+    fn promote(teams: &mut TeamCollection, mut player: Player, date: NaiveDate) {
+        player.contract = Some(Self::contract(&player, ContractType::Youth, date));
+
+        let team_id = teams
+            .reserve_team_for(player.age(date))
+            .or_else(|| teams.main_team_id());
+
+        if let Some(team) = team_id.and_then(|team_id| teams.by_id_mut(team_id)) {
+            team.players.add(player);
+        }
+    }
+
+    /// A season at a club of the same country no more renowned than ours, the one with the least cover in the position
+    fn loan(&self, player: Player, date: NaiveDate, data: &mut SimulatorData) {
+        let group = player.position().position_group();
+
+        let loan_club_id = data
+            .indexes
+            .as_ref()
+            .and_then(|indexes| indexes.get_club_location(self.club_id))
+            .and_then(|(_, country_id)| data.country(country_id))
+            .and_then(|country| {
+                let reputation = |teams: &TeamCollection| {
+                    teams
+                        .main_team_id()
+                        .map(|id| teams.by_id(id).reputation.overall_score())
+                        .unwrap_or(0.0)
+                };
+
+                let parent = country.clubs.iter().find(|club| club.id == self.club_id)?;
+                let parent_reputation = reputation(&parent.teams);
+
+                country
+                    .clubs
+                    .iter()
+                    .filter(|club| club.id != self.club_id && reputation(&club.teams) <= parent_reputation)
+                    .min_by_key(|club| {
+                        club.teams
+                            .teams
+                            .iter()
+                            .flat_map(|team| team.players.players.iter())
+                            .filter(|p| p.position().position_group() == group)
+                            .count()
+                    })
+                    .map(|club| club.id)
+            });
+
+        let loan_club = loan_club_id.and_then(|club_id| data.club_mut(club_id));
+
+        match loan_club {
+            Some(loan_club) => {
+                info!("🔁 academy graduate {} loaned to {} for the season", player.full_name, loan_club.name);
+
+                let loan = AcademyLoan::new(player.id, loan_club.id, Self::season_end(date, 1));
+
+                Self::promote(&mut loan_club.teams, player, date);
+
+                if let Some(club) = data.club_mut(self.club_id) {
+                    club.academy.loans.push(loan);
+                }
+            }
+            None => {
+                // No club to take the player, the reserves it is
+                if let Some(club) = data.club_mut(self.club_id) {
+                    Self::promote(&mut club.teams, player, date);
+                }
+            }
+        }
+    }
+
+    fn return_from_loan(&self, loan: &AcademyLoan, data: &mut SimulatorData) {
+        let date = data.date.date();
+
+        let player = data
+            .indexes
+            .as_ref()
+            .and_then(|indexes| indexes.get_player_location(loan.player_id))
+            .filter(|&(_, _, club_id, _)| club_id == loan.club_id)
+            .and_then(|(_, _, _, team_id)| data.team_mut(team_id))
+            .and_then(|team| team.players.take_player(&loan.player_id));
+
+        if let (Some(player), Some(club)) = (player, data.club_mut(self.club_id)) {
+            info!("↩️ {}: {} returns from loan", club.name, player.full_name);
+
+            let team_id = club
+                .teams
+                .reserve_team_for(player.age(date))
+                .or_else(|| club.teams.main_team_id());
+
+            if let Some(team) = team_id.and_then(|team_id| club.teams.by_id_mut(team_id)) {
+                team.players.add(player);
+            }
+        }
+    }
+
+    /// Contracts and loans run until the end of June
+    fn season_end(date: NaiveDate, years: i32) -> NaiveDate {
+        let year = if date.month() > 6 { date.year() + years } else { date.year() + years - 1 };
+
+        NaiveDate::from_ymd_opt(year, 6, 30).unwrap_or(date)
+    }
 }
 
 pub struct ProduceYouthPlayersResult {
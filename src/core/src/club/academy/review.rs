@@ -0,0 +1,189 @@
+use crate::utils::FloatUtils;
+use crate::{Person, Player, PlayerFieldPositionGroup, ScoutJudgement, Staff, TeamCollection};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Players without a professional future are let go at this age
+pub const ACADEMY_LEAVING_AGE: u8 = 18;
+
+/// Players each reserve side wants in a position group
+pub const RESERVE_DEPTH: [(PlayerFieldPositionGroup, usize); 4] = [
+    (PlayerFieldPositionGroup::Goalkeeper, 3),
+    (PlayerFieldPositionGroup::Defender, 8),
+    (PlayerFieldPositionGroup::Midfielder, 8),
+    (PlayerFieldPositionGroup::Forward, 5),
+];
+
+/// Estimated potential a short position is worth
+const POSITIONAL_NEED_BONUS: f32 = 15.0;
+
+const PROFESSIONAL_CONTRACT_POTENTIAL: f32 = 150.0;
+const PROMOTION_POTENTIAL: f32 = 115.0;
+const LOAN_POTENTIAL: f32 = 95.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AcademyDecisionType {
+    /// First professional contract with the first team
+    ProfessionalContract,
+    /// Youth contract with the reserve side
+    Promoted,
+    /// A season at a smaller club to get game time
+    Loaned,
+    /// Released to the free-agent pool
+    Released,
+}
+
+#[derive(Debug, Clone)]
+pub struct AcademyDecision {
+    pub player_id: u32,
+    pub decision: AcademyDecisionType,
+}
+
+impl AcademyDecision {
+    pub fn new(player_id: u32, decision: AcademyDecisionType) -> Self {
+        AcademyDecision {
+            player_id,
+            decision,
+        }
+    }
+}
+
+/// Academy player out on loan, returning to the club at the end of the season
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcademyLoan {
+    pub player_id: u32,
+    pub club_id: u32,
+    pub end: NaiveDate,
+}
+
+impl AcademyLoan {
+    pub fn new(player_id: u32, club_id: u32, end: NaiveDate) -> Self {
+        AcademyLoan {
+            player_id,
+            club_id,
+            end,
+        }
+    }
+}
+
+/// Yearly meeting of the academy director and the head coach about the academy graduates
+pub struct AcademyReview;
+
+impl AcademyReview {
+    pub fn review(players: &[&Player], teams: &TeamCollection, date: NaiveDate) -> Vec<AcademyDecision> {
+        let main_id = match teams.main_team_id() {
+            Some(main_id) => main_id,
+            None => return Vec::new(),
+        };
+
+        let staffs = &teams.by_id(main_id).staffs;
+        let director = staffs.academy_director();
+        let head_coach = staffs.head_coach();
+
+        let reserve = teams.by_id(teams.reserve_team_id().unwrap_or(main_id));
+        let mut depth: Vec<(PlayerFieldPositionGroup, usize)> = RESERVE_DEPTH
+            .iter()
+            .map(|&(group, _)| {
+                let count = reserve
+                    .players
+                    .players
+                    .iter()
+                    .filter(|player| player.position().position_group() == group)
+                    .count();
+
+                (group, count)
+            })
+            .collect();
+
+        let mut decisions = Vec::new();
+
+        for player in players {
+            let group = player.position().position_group();
+
+            // The director's view counts double, the head coach has the final word on the first team
+            let estimated_potential =
+                (Self::estimate(player, director) * 2.0 + Self::estimate(player, head_coach)) / 3.0;
+
+            let count = depth.iter_mut().find(|(g, _)| *g == group).map(|(_, count)| count);
+            let need = count.as_ref().is_some_and(|count| **count < Self::required_depth(group));
+
+            if let Some(decision) = Self::decide(estimated_potential, player.age(date), need) {
+                if let (Some(count), AcademyDecisionType::ProfessionalContract | AcademyDecisionType::Promoted) =
+                    (count, decision)
+                {
+                    *count += 1;
+                }
+
+                decisions.push(AcademyDecision::new(player.id, decision));
+            }
+        }
+
+        decisions
+    }
+
+    /// None keeps the player in the academy for another year
+    pub fn decide(estimated_potential: f32, age: u8, need: bool) -> Option<AcademyDecisionType> {
+        let potential = if need {
+            estimated_potential + POSITIONAL_NEED_BONUS
+        } else {
+            estimated_potential
+        };
+
+        if potential >= PROFESSIONAL_CONTRACT_POTENTIAL {
+            return Some(AcademyDecisionType::ProfessionalContract);
+        }
+
+        if potential >= PROMOTION_POTENTIAL {
+            return Some(AcademyDecisionType::Promoted);
+        }
+
+        if age < ACADEMY_LEAVING_AGE {
+            return None;
+        }
+
+        if potential >= LOAN_POTENTIAL {
+            Some(AcademyDecisionType::Loaned)
+        } else {
+            Some(AcademyDecisionType::Released)
+        }
+    }
+
+    fn estimate(player: &Player, staff: &Staff) -> f32 {
+        let error = ScoutJudgement::from_staff(staff).potential_error(365);
+
+        player.player_attributes.potential_ability as f32 + FloatUtils::random(-error, error)
+    }
+
+    fn required_depth(group: PlayerFieldPositionGroup) -> usize {
+        RESERVE_DEPTH
+            .iter()
+            .find(|(g, _)| *g == group)
+            .map(|(_, required)| *required)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_prospects_get_a_professional_contract() {
+        assert_eq!(
+            Some(AcademyDecisionType::ProfessionalContract),
+            AcademyReview::decide(160.0, 16, false)
+        );
+        assert_eq!(
+            Some(AcademyDecisionType::ProfessionalContract),
+            AcademyReview::decide(140.0, 16, true)
+        );
+        assert_eq!(Some(AcademyDecisionType::Promoted), AcademyReview::decide(140.0, 16, false));
+    }
+
+    #[test]
+    fn young_players_stay_another_year_before_leaving() {
+        assert_eq!(None, AcademyReview::decide(100.0, 16, false));
+        assert_eq!(Some(AcademyDecisionType::Loaned), AcademyReview::decide(100.0, 18, false));
+        assert_eq!(Some(AcademyDecisionType::Released), AcademyReview::decide(80.0, 18, false));
+    }
+}
@@ -1,11 +1,33 @@
-use crate::club::{BoardMood, BoardResult, StaffClubContract};
+use crate::club::{
+    BoardDecision, BoardDecisionType, BoardMood, BoardObjectiveOutcome, BoardObjectiveType,
+    BoardResult, BoardSeasonObjective, ClubFinances, ManagerSearch, StaffClubContract,
+    StaffPosition, StaffStatus,
+};
 use crate::context::{GlobalContext, SimulationContext};
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+const SACKING_CONFIDENCE: f32 = 20.0;
+const MANAGER_GRACE_PERIOD_DAYS: i64 = 60;
+
+const DIRECTOR_CONTRACT_YEARS: i32 = 3;
+const DIRECTOR_WEEKLY_SALARY: u32 = 5000;
+const SPORT_DIRECTOR_WEEKLY_SALARY: u32 = 4000;
+
+/// Matches played before the board starts judging the league position
+const POSITION_REVIEW_MIN_PLAYED: u8 = 6;
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ClubBoard {
     pub mood: BoardMood,
     pub director: Option<StaffClubContract>,
     pub sport_director: Option<StaffClubContract>,
+
+    pub objective: Option<BoardSeasonObjective>,
+    pub objectives_history: Vec<BoardSeasonObjective>,
+    pub decisions: Vec<BoardDecision>,
+    pub manager_appointed_on: Option<NaiveDate>,
+    pub manager_search: Option<ManagerSearch>,
 }
 
 impl ClubBoard {
@@ -14,11 +36,37 @@ impl ClubBoard {
             mood: BoardMood::default(),
             director: None,
             sport_director: None,
+            objective: None,
+            objectives_history: Vec::new(),
+            decisions: Vec::new(),
+            manager_appointed_on: None,
+            manager_search: None,
         }
     }
 
     pub fn simulate(&mut self, ctx: GlobalContext<'_>) -> BoardResult {
-        let result = BoardResult::new();
+        let club_id = ctx.club.as_ref().expect("no club found").id;
+
+        let result = BoardResult::new(
+            club_id,
+            ctx.simulation.is_week_beginning(),
+            ctx.simulation.is_month_beginning(),
+        );
+
+        // A manager in post before the board kept count gets the grace period from now
+        if self.manager_appointed_on.is_none() {
+            self.manager_appointed_on = Some(ctx.simulation.date.date());
+        }
+
+        if ctx.simulation.check_contract_expiration() {
+            if self.is_director_contract_expiring(&ctx.simulation) {
+                self.director = None;
+            }
+
+            if self.is_sport_director_contract_expiring(&ctx.simulation) {
+                self.sport_director = None;
+            }
+        }
 
         if self.director.is_none() {
             self.run_director_election(&ctx.simulation);
@@ -28,13 +76,153 @@ impl ClubBoard {
             self.run_sport_director_election(&ctx.simulation);
         }
 
-        if ctx.simulation.check_contract_expiration() {
-            if self.is_director_contract_expiring(&ctx.simulation) {}
+        result
+    }
+
+    pub fn get_week_salary(&self) -> u32 {
+        self.director
+            .iter()
+            .chain(self.sport_director.iter())
+            .map(|contract| contract.salary)
+            .sum()
+    }
+
+    pub fn set_objective(&mut self, objective: BoardSeasonObjective, date: NaiveDate) {
+        self.record(date, BoardDecisionType::ObjectiveSet(objective.objective_type));
+        self.objective = Some(objective);
+    }
+
+    /// React to a single league result, judged against the season objective
+    pub fn process_match_result(&mut self, goals_for: u8, goals_against: u8) {
+        let objective_type = match &self.objective {
+            Some(objective) => objective.objective_type,
+            None => return,
+        };
+
+        let delta = match goals_for.cmp(&goals_against) {
+            std::cmp::Ordering::Greater => 2.0,
+            std::cmp::Ordering::Equal => match objective_type {
+                BoardObjectiveType::WinTitle => -1.0,
+                BoardObjectiveType::QualifyForEurope => -0.5,
+                BoardObjectiveType::MidTable => 0.5,
+                BoardObjectiveType::AvoidRelegation => 1.0,
+            },
+            std::cmp::Ordering::Less => match objective_type {
+                BoardObjectiveType::WinTitle => -3.0,
+                BoardObjectiveType::QualifyForEurope => -2.5,
+                BoardObjectiveType::MidTable => -2.0,
+                BoardObjectiveType::AvoidRelegation => -1.5,
+            },
+        };
 
-            if self.is_sport_director_contract_expiring(&ctx.simulation) {}
+        self.mood.change_confidence(delta);
+    }
+
+    /// Long losing streaks reported by the league
+    pub fn apply_manager_pressure(&mut self) {
+        self.mood.change_confidence(-5.0);
+    }
+
+    pub fn review_league_position(&mut self, position: u8, played: u8) {
+        if played < POSITION_REVIEW_MIN_PLAYED {
+            return;
         }
 
-        result
+        if let Some(objective) = &self.objective {
+            let margin = objective.position_margin(position) as f32;
+            self.mood.change_confidence((margin * 0.5).clamp(-3.0, 2.0));
+        }
+    }
+
+    pub fn review_finances(&mut self, finances: &ClubFinances) {
+        let balance = finances.balance.balance;
+
+        let mut delta = if balance < 0 { -1.5 } else { 0.5 };
+
+        if let Some((_, last_month)) = finances.history.iter().next() {
+            delta += if last_month.income < last_month.outcome { -0.5 } else { 0.5 };
+        }
+
+        self.mood.change_confidence(delta);
+    }
+
+    pub fn evaluate_objective(&mut self, position: u8, date: NaiveDate) {
+        let mut objective = match self.objective.take() {
+            Some(objective) => objective,
+            None => return,
+        };
+
+        let achieved = objective.is_met_by(position);
+
+        objective.outcome = Some(BoardObjectiveOutcome {
+            final_position: position,
+            achieved,
+            evaluated_on: date,
+        });
+
+        if achieved {
+            let bonus = objective.position_margin(position) as f32;
+            self.mood.change_confidence(15.0 + bonus);
+            self.record(date, BoardDecisionType::ObjectiveAchieved(objective.objective_type));
+        } else {
+            let shortfall = -objective.position_margin(position) as f32;
+            self.mood.change_confidence(-15.0 - shortfall * 2.0);
+            self.record(date, BoardDecisionType::ObjectiveFailed(objective.objective_type));
+        }
+
+        self.objectives_history.push(objective);
+    }
+
+    pub fn should_sack_manager(&self, date: NaiveDate) -> bool {
+        if self.mood.confidence >= SACKING_CONFIDENCE || self.manager_search.is_some() {
+            return false;
+        }
+
+        match self.manager_appointed_on {
+            Some(appointed) => (date - appointed).num_days() >= MANAGER_GRACE_PERIOD_DAYS,
+            // Not known yet, as good as just appointed
+            None => false,
+        }
+    }
+
+    /// The manager was sacked or left, a caretaker takes over while the board searches
+    pub fn open_manager_search(&mut self, departed: BoardDecisionType, search: ManagerSearch, date: NaiveDate) {
+        self.record(date, departed);
+
+        if let Some((caretaker_id, _)) = search.caretaker {
+            self.record(date, BoardDecisionType::CaretakerAppointed(caretaker_id));
+        }
+
+        self.mood.reset();
+        self.manager_search = Some(search);
+    }
+
+    /// The search ran out of time with nobody to hand the job to
+    pub fn restart_manager_search(&mut self, caretaker: Option<(u32, StaffPosition)>, date: NaiveDate) {
+        let search = match self.manager_search.as_mut() {
+            Some(search) => search,
+            None => return,
+        };
+
+        search.started = date;
+        search.caretaker = caretaker;
+
+        if let Some((caretaker_id, _)) = caretaker {
+            self.record(date, BoardDecisionType::CaretakerAppointed(caretaker_id));
+        }
+    }
+
+    pub fn record_manager_appointed(&mut self, staff_id: u32, date: NaiveDate) {
+        self.manager_search = None;
+        self.manager_appointed_on = Some(date);
+
+        self.mood.reset();
+        self.record(date, BoardDecisionType::ManagerAppointed(staff_id));
+    }
+
+    fn record(&mut self, date: NaiveDate, decision_type: BoardDecisionType) {
+        self.decisions
+            .push(BoardDecision::new(date, decision_type, self.mood.confidence));
     }
 
     fn is_director_contract_expiring(&self, simulation_ctx: &SimulationContext) -> bool {
@@ -44,14 +232,146 @@ impl ClubBoard {
         }
     }
 
-    fn run_director_election(&mut self, _: &SimulationContext) {}
+    fn run_director_election(&mut self, simulation_ctx: &SimulationContext) {
+        let date = simulation_ctx.date.date();
+
+        self.director = Some(Self::board_contract(
+            StaffPosition::Director,
+            DIRECTOR_WEEKLY_SALARY,
+            date,
+        ));
+
+        self.record(date, BoardDecisionType::DirectorAppointed);
+    }
 
     fn is_sport_director_contract_expiring(&self, simulation_ctx: &SimulationContext) -> bool {
-        match &self.director {
+        match &self.sport_director {
             Some(d) => d.is_expired(simulation_ctx),
             None => false,
         }
     }
 
-    fn run_sport_director_election(&mut self, _: &SimulationContext) {}
+    fn run_sport_director_election(&mut self, simulation_ctx: &SimulationContext) {
+        let date = simulation_ctx.date.date();
+
+        self.sport_director = Some(Self::board_contract(
+            StaffPosition::DirectorOfFootball,
+            SPORT_DIRECTOR_WEEKLY_SALARY,
+            date,
+        ));
+
+        self.record(date, BoardDecisionType::SportDirectorAppointed);
+    }
+
+    fn board_contract(position: StaffPosition, salary: u32, date: NaiveDate) -> StaffClubContract {
+        let expiration = date
+            .with_year(date.year() + DIRECTOR_CONTRACT_YEARS)
+            .unwrap_or(date);
+
+        StaffClubContract::new(salary, expiration, position, StaffStatus::Active)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::club::BoardMoodState;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    fn board_with_objective(objective_type: BoardObjectiveType) -> ClubBoard {
+        let mut board = ClubBoard::new();
+        board.set_objective(BoardSeasonObjective::new(objective_type, 1, 20, date(7, 1)), date(7, 1));
+        board
+    }
+
+    #[test]
+    fn losses_hurt_title_contenders_more() {
+        let mut title = board_with_objective(BoardObjectiveType::WinTitle);
+        let mut survival = board_with_objective(BoardObjectiveType::AvoidRelegation);
+
+        title.process_match_result(0, 1);
+        survival.process_match_result(0, 1);
+
+        assert!(title.mood.confidence < survival.mood.confidence);
+    }
+
+    #[test]
+    fn evaluate_objective_moves_it_to_history() {
+        let mut board = board_with_objective(BoardObjectiveType::QualifyForEurope);
+
+        board.evaluate_objective(2, date(5, 31));
+
+        assert!(board.objective.is_none());
+        assert_eq!(1, board.objectives_history.len());
+        assert!(board.objectives_history[0].outcome.as_ref().unwrap().achieved);
+        assert_eq!(
+            Some(&BoardDecisionType::ObjectiveAchieved(BoardObjectiveType::QualifyForEurope)),
+            board.decisions.last().map(|d| &d.decision_type)
+        );
+    }
+
+    #[test]
+    fn manager_is_sacked_only_after_grace_period() {
+        let mut board = board_with_objective(BoardObjectiveType::MidTable);
+        board.record_manager_appointed(2, date(8, 1));
+
+        board.mood.set_confidence(10.0);
+        assert_eq!(BoardMoodState::Poor, board.mood.state);
+
+        assert!(!board.should_sack_manager(date(8, 20)));
+        assert!(board.should_sack_manager(date(10, 1)));
+    }
+
+    #[test]
+    fn manager_with_unknown_appointment_is_not_sacked() {
+        let mut board = board_with_objective(BoardObjectiveType::MidTable);
+        board.mood.set_confidence(0.0);
+
+        assert!(!board.should_sack_manager(date(10, 1)));
+    }
+
+    #[test]
+    fn restarted_search_is_no_longer_overdue() {
+        let mut board = board_with_objective(BoardObjectiveType::MidTable);
+
+        board.open_manager_search(
+            BoardDecisionType::ManagerSacked(1),
+            ManagerSearch::new(date(9, 1), None, 100_000),
+            date(9, 1),
+        );
+
+        assert!(board.manager_search.as_ref().unwrap().is_overdue(date(12, 1)));
+
+        board.restart_manager_search(Some((4, StaffPosition::Coach)), date(12, 1));
+
+        let search = board.manager_search.as_ref().unwrap();
+
+        assert!(!search.is_overdue(date(12, 8)));
+        assert_eq!(Some((4, StaffPosition::Coach)), search.caretaker);
+        assert_eq!(
+            Some(&BoardDecisionType::CaretakerAppointed(4)),
+            board.decisions.last().map(|d| &d.decision_type)
+        );
+    }
+
+    #[test]
+    fn manager_is_not_sacked_during_search() {
+        let mut board = board_with_objective(BoardObjectiveType::MidTable);
+
+        board.open_manager_search(
+            BoardDecisionType::ManagerSacked(1),
+            ManagerSearch::new(date(9, 1), Some((3, StaffPosition::AssistantManager)), 100_000),
+            date(9, 1),
+        );
+        board.mood.set_confidence(0.0);
+
+        assert!(!board.should_sack_manager(date(12, 1)));
+        assert_eq!(
+            Some(&BoardDecisionType::CaretakerAppointed(3)),
+            board.decisions.last().map(|d| &d.decision_type)
+        );
+    }
 }
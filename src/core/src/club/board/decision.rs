@@ -0,0 +1,35 @@
+use crate::club::BoardObjectiveType;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BoardDecisionType {
+    ObjectiveSet(BoardObjectiveType),
+    ObjectiveAchieved(BoardObjectiveType),
+    ObjectiveFailed(BoardObjectiveType),
+    ManagerSacked(u32),
+    /// Manager left for another club
+    ManagerLeft(u32),
+    CaretakerAppointed(u32),
+    ManagerAppointed(u32),
+    DirectorAppointed,
+    SportDirectorAppointed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardDecision {
+    pub date: NaiveDate,
+    pub decision_type: BoardDecisionType,
+    /// Board confidence at the moment of the decision
+    pub confidence: f32,
+}
+
+impl BoardDecision {
+    pub fn new(date: NaiveDate, decision_type: BoardDecisionType, confidence: f32) -> Self {
+        BoardDecision {
+            date,
+            decision_type,
+            confidence,
+        }
+    }
+}
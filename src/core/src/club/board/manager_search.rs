@@ -0,0 +1,189 @@
+use crate::club::{BoardObjectiveType, CoachingStyle, Staff, StaffPosition};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Days the board looks for a new manager before keeping the caretaker
+pub const MANAGER_SEARCH_MAX_DAYS: i64 = 56;
+
+const SHORTLIST_SIZE: usize = 5;
+
+/// An open head coach vacancy
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ManagerSearch {
+    pub started: NaiveDate,
+    /// Caretaker in charge and the position to return to
+    pub caretaker: Option<(u32, StaffPosition)>,
+    pub max_salary: u32,
+    pub shortlist: Vec<ManagerCandidate>,
+    /// Candidates who turned the club down
+    pub rejected_by: Vec<u32>,
+}
+
+impl ManagerSearch {
+    pub fn new(started: NaiveDate, caretaker: Option<(u32, StaffPosition)>, max_salary: u32) -> Self {
+        ManagerSearch {
+            started,
+            caretaker,
+            max_salary,
+            shortlist: Vec::new(),
+            rejected_by: Vec::new(),
+        }
+    }
+
+    pub fn is_overdue(&self, date: NaiveDate) -> bool {
+        (date - self.started).num_days() >= MANAGER_SEARCH_MAX_DAYS
+    }
+
+    /// Keep the best scored candidates the club has not been rejected by
+    pub fn update_shortlist(&mut self, mut candidates: Vec<ManagerCandidate>) {
+        candidates.retain(|candidate| !self.rejected_by.contains(&candidate.staff_id));
+        candidates.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        candidates.truncate(SHORTLIST_SIZE);
+
+        self.shortlist = candidates;
+    }
+
+    pub fn reject(&mut self, staff_id: u32) {
+        self.rejected_by.push(staff_id);
+        self.shortlist.retain(|candidate| candidate.staff_id != staff_id);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManagerCandidate {
+    pub staff_id: u32,
+    /// Club currently employing the candidate, None for unemployed coaches
+    pub club_id: Option<u32>,
+    pub score: f32,
+}
+
+/// What the board looks for in a new manager
+#[derive(Debug)]
+pub struct ManagerRequirements {
+    pub preferred_styles: Vec<CoachingStyle>,
+    pub min_license_level: u8,
+}
+
+impl ManagerRequirements {
+    pub fn for_objective(objective: Option<BoardObjectiveType>) -> Self {
+        let (preferred_styles, min_license_level) = match objective {
+            Some(BoardObjectiveType::WinTitle) => {
+                (vec![CoachingStyle::Tactical, CoachingStyle::Transformational], 6)
+            }
+            Some(BoardObjectiveType::QualifyForEurope) => {
+                (vec![CoachingStyle::Tactical, CoachingStyle::Democratic], 5)
+            }
+            Some(BoardObjectiveType::MidTable) | None => {
+                (vec![CoachingStyle::Democratic, CoachingStyle::Transformational], 4)
+            }
+            Some(BoardObjectiveType::AvoidRelegation) => {
+                (vec![CoachingStyle::Authoritarian, CoachingStyle::Tactical], 3)
+            }
+        };
+
+        ManagerRequirements {
+            preferred_styles,
+            min_license_level,
+        }
+    }
+
+    /// None when the coach does not hold the required licence
+    pub fn score(&self, staff: &Staff) -> Option<f32> {
+        let license_level = staff.license.level();
+
+        if license_level < self.min_license_level {
+            return None;
+        }
+
+        let style_fit = if self.preferred_styles.contains(&staff.coaching_style) {
+            1.0
+        } else {
+            0.0
+        };
+
+        Some(
+            staff.reputation() as f32 / 1000.0 * 0.5
+                + license_level as f32 / 7.0 * 0.3
+                + style_fit * 0.2,
+        )
+    }
+}
+
+pub struct ManagerNegotiation;
+
+impl ManagerNegotiation {
+    /// Returns the agreed weekly salary, None if the coach turns the offer down.
+    /// `current_club_reputation` is set when the coach is employed elsewhere
+    pub fn negotiate(
+        staff: &Staff,
+        club_reputation: u16,
+        current_club_reputation: Option<u16>,
+        max_salary: u32,
+    ) -> Option<u32> {
+        let mut demand = staff.expected_salary();
+
+        if let Some(current_reputation) = current_club_reputation {
+            // Coaches only leave a job for a bigger club and a raise
+            if club_reputation <= current_reputation {
+                return None;
+            }
+
+            demand = demand * 6 / 5;
+        }
+
+        // Ambitious coaches hold out for clubs matching their standing
+        let ambition = staff.attributes.ambition / 20.0;
+        let required_reputation = staff.reputation() as f32 * (0.4 + ambition * 0.5);
+
+        if (club_reputation as f32) < required_reputation {
+            return None;
+        }
+
+        if max_salary < demand * 9 / 10 {
+            return None;
+        }
+
+        Some(demand.min(max_salary))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{StaffLicenseType, StaffStub};
+
+    #[test]
+    fn requirements_filter_by_license() {
+        let mut staff = StaffStub::default();
+
+        let requirements = ManagerRequirements::for_objective(Some(BoardObjectiveType::WinTitle));
+        assert!(requirements.score(&staff).is_none());
+
+        staff.license = StaffLicenseType::ContinentalPro;
+        assert!(requirements.score(&staff).is_some());
+    }
+
+    #[test]
+    fn employed_coach_does_not_move_to_smaller_club() {
+        let staff = StaffStub::default();
+
+        assert!(ManagerNegotiation::negotiate(&staff, 500, Some(600), u32::MAX).is_none());
+        assert!(ManagerNegotiation::negotiate(&staff, 1000, Some(600), u32::MAX).is_some());
+    }
+
+    #[test]
+    fn shortlist_skips_rejections_and_sorts_by_score() {
+        let date = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        let mut search = ManagerSearch::new(date, None, 100_000);
+
+        search.reject(2);
+        search.update_shortlist(vec![
+            ManagerCandidate { staff_id: 1, club_id: None, score: 0.3 },
+            ManagerCandidate { staff_id: 2, club_id: None, score: 0.9 },
+            ManagerCandidate { staff_id: 3, club_id: Some(5), score: 0.6 },
+        ]);
+
+        let ids: Vec<u32> = search.shortlist.iter().map(|c| c.staff_id).collect();
+        assert_eq!(vec![3, 1], ids);
+    }
+}
@@ -1,10 +1,16 @@
 pub mod board;
 pub mod context;
+mod decision;
+mod manager_search;
 mod mood;
+mod objective;
 
 mod result;
 
 pub use board::*;
 pub use context::*;
+pub use decision::*;
+pub use manager_search::*;
 pub use mood::*;
+pub use objective::*;
 pub use result::*;
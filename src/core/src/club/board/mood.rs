@@ -1,20 +1,74 @@
-#[derive(Debug)]
+use serde::{Deserialize, Serialize};
+
+const MIN_CONFIDENCE: f32 = 0.0;
+const MAX_CONFIDENCE: f32 = 100.0;
+const DEFAULT_CONFIDENCE: f32 = 50.0;
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct BoardMood {
     pub state: BoardMoodState,
+    /// Board confidence in the head coach (0-100)
+    pub confidence: f32,
 }
 
 impl BoardMood {
     pub fn default() -> Self {
         BoardMood {
             state: BoardMoodState::Normal,
+            confidence: DEFAULT_CONFIDENCE,
         }
     }
+
+    pub fn change_confidence(&mut self, delta: f32) {
+        self.set_confidence(self.confidence + delta);
+    }
+
+    pub fn set_confidence(&mut self, confidence: f32) {
+        self.confidence = confidence.clamp(MIN_CONFIDENCE, MAX_CONFIDENCE);
+        self.state = BoardMoodState::from_confidence(self.confidence);
+    }
+
+    pub fn reset(&mut self) {
+        self.set_confidence(DEFAULT_CONFIDENCE);
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum BoardMoodState {
     Poor,
     Normal,
     Good,
     Excellent,
 }
+
+impl BoardMoodState {
+    pub fn from_confidence(confidence: f32) -> Self {
+        match confidence {
+            c if c < 25.0 => BoardMoodState::Poor,
+            c if c < 60.0 => BoardMoodState::Normal,
+            c if c < 85.0 => BoardMoodState::Good,
+            _ => BoardMoodState::Excellent,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn change_confidence_is_clamped_and_updates_state() {
+        let mut mood = BoardMood::default();
+
+        mood.change_confidence(-80.0);
+        assert_eq!(0.0, mood.confidence);
+        assert_eq!(BoardMoodState::Poor, mood.state);
+
+        mood.change_confidence(150.0);
+        assert_eq!(100.0, mood.confidence);
+        assert_eq!(BoardMoodState::Excellent, mood.state);
+
+        mood.reset();
+        assert_eq!(BoardMoodState::Normal, mood.state);
+    }
+}
@@ -0,0 +1,113 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BoardObjectiveType {
+    WinTitle,
+    QualifyForEurope,
+    MidTable,
+    AvoidRelegation,
+}
+
+impl BoardObjectiveType {
+    /// Pick the objective for a club expected to finish at `expected_position` (1-based)
+    pub fn from_expected_position(expected_position: u8, teams_count: u8) -> Self {
+        if expected_position <= 1 {
+            BoardObjectiveType::WinTitle
+        } else if expected_position <= Self::europe_places(teams_count) {
+            BoardObjectiveType::QualifyForEurope
+        } else if expected_position <= teams_count.saturating_sub(Self::relegation_places(teams_count) * 2) {
+            BoardObjectiveType::MidTable
+        } else {
+            BoardObjectiveType::AvoidRelegation
+        }
+    }
+
+    /// Lowest final position (1-based) that still meets the objective
+    pub fn target_position(&self, teams_count: u8) -> u8 {
+        match self {
+            BoardObjectiveType::WinTitle => 1,
+            BoardObjectiveType::QualifyForEurope => Self::europe_places(teams_count),
+            BoardObjectiveType::MidTable => (teams_count / 2).max(1),
+            BoardObjectiveType::AvoidRelegation => {
+                teams_count.saturating_sub(Self::relegation_places(teams_count)).max(1)
+            }
+        }
+    }
+
+    fn europe_places(teams_count: u8) -> u8 {
+        (teams_count / 5).max(1)
+    }
+
+    pub fn relegation_places(teams_count: u8) -> u8 {
+        ((teams_count as u16 * 3 / 20) as u8).max(1)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardSeasonObjective {
+    pub objective_type: BoardObjectiveType,
+    pub league_id: u32,
+    pub target_position: u8,
+    pub set_on: NaiveDate,
+    pub outcome: Option<BoardObjectiveOutcome>,
+}
+
+impl BoardSeasonObjective {
+    pub fn new(objective_type: BoardObjectiveType, league_id: u32, teams_count: u8, set_on: NaiveDate) -> Self {
+        BoardSeasonObjective {
+            objective_type,
+            league_id,
+            target_position: objective_type.target_position(teams_count),
+            set_on,
+            outcome: None,
+        }
+    }
+
+    pub fn is_met_by(&self, position: u8) -> bool {
+        position <= self.target_position
+    }
+
+    /// Positive when the club is above target, negative when below
+    pub fn position_margin(&self, position: u8) -> i16 {
+        self.target_position as i16 - position as i16
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardObjectiveOutcome {
+    pub final_position: u8,
+    pub achieved: bool,
+    pub evaluated_on: NaiveDate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_from_expected_position() {
+        assert_eq!(BoardObjectiveType::WinTitle, BoardObjectiveType::from_expected_position(1, 20));
+        assert_eq!(BoardObjectiveType::QualifyForEurope, BoardObjectiveType::from_expected_position(4, 20));
+        assert_eq!(BoardObjectiveType::MidTable, BoardObjectiveType::from_expected_position(10, 20));
+        assert_eq!(BoardObjectiveType::AvoidRelegation, BoardObjectiveType::from_expected_position(16, 20));
+    }
+
+    #[test]
+    fn objective_target_positions() {
+        assert_eq!(1, BoardObjectiveType::WinTitle.target_position(20));
+        assert_eq!(4, BoardObjectiveType::QualifyForEurope.target_position(20));
+        assert_eq!(10, BoardObjectiveType::MidTable.target_position(20));
+        assert_eq!(17, BoardObjectiveType::AvoidRelegation.target_position(20));
+    }
+
+    #[test]
+    fn objective_is_met_by_position() {
+        let date = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let objective = BoardSeasonObjective::new(BoardObjectiveType::QualifyForEurope, 1, 20, date);
+
+        assert!(objective.is_met_by(3));
+        assert!(!objective.is_met_by(5));
+        assert_eq!(-1, objective.position_margin(5));
+    }
+}
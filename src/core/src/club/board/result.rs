@@ -1,11 +1,421 @@
+use crate::club::{
+    BoardDecisionType, BoardObjectiveType, BoardSeasonObjective, ManagerCandidate,
+    ManagerNegotiation, ManagerRequirements, ManagerSearch, SeasonOutcomeType, StaffClubContract,
+    StaffPosition, StaffStatus,
+};
+use crate::news::NewsReporter;
 use crate::simulator::SimulatorData;
+use crate::{Club, Staff};
+use chrono::{Datelike, NaiveDate};
+use log::info;
 
-pub struct BoardResult {}
+const MANAGER_CONTRACT_YEARS: i32 = 3;
+
+pub struct BoardResult {
+    pub club_id: u32,
+    pub is_week_beginning: bool,
+    pub is_month_beginning: bool,
+}
+
+struct LeagueStanding {
+    league_id: u32,
+    position: u8,
+    expected_position: u8,
+    teams_count: u8,
+    played: u8,
+    tier: u8,
+    is_season_end: bool,
+}
 
 impl BoardResult {
-    pub fn new() -> Self {
-        BoardResult {}
+    pub fn new(club_id: u32, is_week_beginning: bool, is_month_beginning: bool) -> Self {
+        BoardResult {
+            club_id,
+            is_week_beginning,
+            is_month_beginning,
+        }
+    }
+
+    pub fn process(&self, data: &mut SimulatorData) {
+        let date = data.date.date();
+
+        if let Some(standing) = self.league_standing(data) {
+            self.review_season(standing, date, data);
+        }
+
+        let should_sack = data
+            .club(self.club_id)
+            .is_some_and(|club| club.board.should_sack_manager(date));
+
+        if should_sack {
+            Self::sack_manager(self.club_id, date, data);
+        }
+
+        let is_searching = data
+            .club(self.club_id)
+            .is_some_and(|club| club.board.manager_search.is_some());
+
+        if is_searching && self.is_week_beginning {
+            Self::search_manager(self.club_id, date, data);
+        }
+    }
+
+    fn review_season(&self, standing: LeagueStanding, date: NaiveDate, data: &mut SimulatorData) {
+        let club = match data.club_mut(self.club_id) {
+            Some(club) => club,
+            None => return,
+        };
+
+        if club.board.objective.is_none() && !standing.is_season_end {
+            let objective_type = BoardObjectiveType::from_expected_position(
+                standing.expected_position,
+                standing.teams_count,
+            );
+
+            club.board.set_objective(
+                BoardSeasonObjective::new(objective_type, standing.league_id, standing.teams_count, date),
+                date,
+            );
+        }
+
+        if self.is_week_beginning {
+            club.board.review_league_position(standing.position, standing.played);
+        }
+
+        if self.is_month_beginning {
+            club.board.review_finances(&club.finance);
+        }
+
+        if standing.is_season_end {
+            club.board.evaluate_objective(standing.position, date);
+
+            let outcome = SeasonOutcomeType::from_position(standing.position, standing.teams_count, standing.tier);
+            club.end_season(outcome, date);
+        }
+    }
+
+    // ========== MANAGER MARKET ==========
+
+    fn sack_manager(club_id: u32, date: NaiveDate, data: &mut SimulatorData) {
+        let club = match data.club_mut(club_id) {
+            Some(club) => club,
+            None => return,
+        };
+
+        let manager = match Self::release_manager(club) {
+            Some(manager) => manager,
+            None => return,
+        };
+
+        info!(
+            "🔴 {}: board sacked manager {} (confidence {:.0})",
+            club.name, manager.0.full_name, club.board.mood.confidence
+        );
+
+        Self::open_vacancy(club, BoardDecisionType::ManagerSacked(manager.0.id), manager.1, date);
+
+        // The sacked manager is not considered again
+        if let Some(search) = club.board.manager_search.as_mut() {
+            search.rejected_by.push(manager.0.id);
+        }
+
+        let news = NewsReporter::manager_sacked(club.id, &club.name, &manager.0, date);
+
+        data.news.publish(news);
+        data.manager_market.add(manager.0);
+    }
+
+    /// Removes the manager from the first team, returns the manager with the last salary
+    fn release_manager(club: &mut Club) -> Option<(Staff, u32)> {
+        let staffs = &mut club.teams.main_mut()?.staffs;
+
+        let manager_id = staffs.manager_id()?;
+        let salary = staffs
+            .staffs
+            .iter()
+            .find(|staff| staff.id == manager_id)
+            .and_then(|staff| staff.contract.as_ref())
+            .map(|contract| contract.salary)
+            .unwrap_or(0);
+
+        staffs.release(manager_id).map(|manager| (manager, salary))
+    }
+
+    fn open_vacancy(club: &mut Club, departed: BoardDecisionType, previous_salary: u32, date: NaiveDate) {
+        let club_reputation = Self::club_reputation(club);
+
+        let caretaker = match club.teams.main_mut() {
+            Some(team) => {
+                // The caretaker picks their own tactics
+                team.tactics = None;
+                team.staffs.appoint_caretaker()
+            }
+            None => None,
+        };
+
+        let max_salary = (previous_salary * 3 / 2).max(club_reputation as u32 * 100);
+
+        club.board
+            .open_manager_search(departed, ManagerSearch::new(date, caretaker, max_salary), date);
+    }
+
+    fn search_manager(club_id: u32, date: NaiveDate, data: &mut SimulatorData) {
+        let (club_reputation, requirements, max_salary, is_overdue) = match data.club(club_id) {
+            Some(club) => match &club.board.manager_search {
+                Some(search) => (
+                    Self::club_reputation(club),
+                    ManagerRequirements::for_objective(
+                        club.board.objective.as_ref().map(|o| o.objective_type),
+                    ),
+                    search.max_salary,
+                    search.is_overdue(date),
+                ),
+                None => return,
+            },
+            None => return,
+        };
+
+        if is_overdue {
+            Self::keep_caretaker(club_id, date, data);
+            return;
+        }
+
+        let candidates = Self::manager_candidates(club_id, club_reputation, &requirements, data);
+
+        let shortlist = match data
+            .club_mut(club_id)
+            .and_then(|club| club.board.manager_search.as_mut())
+        {
+            Some(search) => {
+                search.update_shortlist(candidates);
+                search.shortlist.clone()
+            }
+            None => return,
+        };
+
+        for candidate in shortlist {
+            let offer = Self::candidate(&candidate, data).and_then(|(staff, current_reputation)| {
+                ManagerNegotiation::negotiate(staff, club_reputation, current_reputation, max_salary)
+            });
+
+            match offer {
+                Some(salary) => {
+                    Self::hire_manager(club_id, &candidate, salary, date, data);
+                    return;
+                }
+                None => {
+                    if let Some(search) = data
+                        .club_mut(club_id)
+                        .and_then(|club| club.board.manager_search.as_mut())
+                    {
+                        search.reject(candidate.staff_id);
+                    }
+                }
+            }
+        }
+    }
+
+    fn manager_candidates(
+        club_id: u32,
+        club_reputation: u16,
+        requirements: &ManagerRequirements,
+        data: &SimulatorData,
+    ) -> Vec<ManagerCandidate> {
+        let unemployed = data.manager_market.unemployed.iter().filter_map(|staff| {
+            requirements.score(staff).map(|score| ManagerCandidate {
+                staff_id: staff.id,
+                club_id: None,
+                score,
+            })
+        });
+
+        // Managers of smaller clubs may be tempted to move
+        let employed = data
+            .continents
+            .iter()
+            .flat_map(|continent| continent.countries.iter())
+            .flat_map(|country| country.clubs.iter())
+            .filter(|club| club.id != club_id && club.board.manager_search.is_none())
+            .filter(|club| Self::club_reputation(club) < club_reputation)
+            .filter_map(|club| {
+                let team = club.teams.main_team_id().map(|id| club.teams.by_id(id))?;
+                let manager_id = team.staffs.manager_id()?;
+                let manager = team.staffs.staffs.iter().find(|staff| staff.id == manager_id)?;
+
+                requirements.score(manager).map(|score| ManagerCandidate {
+                    staff_id: manager.id,
+                    club_id: Some(club.id),
+                    score,
+                })
+            });
+
+        unemployed.chain(employed).collect()
+    }
+
+    /// The candidate and the reputation of their current club
+    fn candidate<'d>(candidate: &ManagerCandidate, data: &'d SimulatorData) -> Option<(&'d Staff, Option<u16>)> {
+        match candidate.club_id {
+            None => data.manager_market.get(candidate.staff_id).map(|staff| (staff, None)),
+            Some(club_id) => {
+                let club = data.club(club_id)?;
+                let team = club.teams.main_team_id().map(|id| club.teams.by_id(id))?;
+                let staff = team.staffs.staffs.iter().find(|s| s.id == candidate.staff_id)?;
+
+                Some((staff, Some(Self::club_reputation(club))))
+            }
+        }
+    }
+
+    fn hire_manager(club_id: u32, candidate: &ManagerCandidate, salary: u32, date: NaiveDate, data: &mut SimulatorData) {
+        let staff = match candidate.club_id {
+            None => data.manager_market.take(candidate.staff_id),
+            Some(previous_club_id) => data.club_mut(previous_club_id).and_then(|previous_club| {
+                let (staff, previous_salary) = Self::release_manager(previous_club)?;
+
+                info!("{}: manager {} leaves for another club", previous_club.name, staff.full_name);
+
+                Self::open_vacancy(
+                    previous_club,
+                    BoardDecisionType::ManagerLeft(staff.id),
+                    previous_salary,
+                    date,
+                );
+
+                Some(staff)
+            }),
+        };
+
+        let staff = match staff {
+            Some(staff) => staff,
+            None => return,
+        };
+
+        let club = match data.club_mut(club_id) {
+            Some(club) => club,
+            None => return,
+        };
+
+        let caretaker = club.board.manager_search.as_ref().and_then(|search| search.caretaker);
+        let staff_id = staff.id;
+        let manager_reputation = staff.reputation();
+
+        info!("🟢 {}: appointed manager {} ({}/week)", club.name, staff.full_name, salary);
+
+        if let Some(team) = club.teams.main_mut() {
+            if let Some((caretaker_id, position)) = caretaker {
+                team.staffs.restore_position(caretaker_id, position);
+            }
+
+            team.staffs.appoint_manager(
+                staff,
+                StaffClubContract::new(
+                    salary,
+                    Self::manager_contract_expiration(date),
+                    StaffPosition::Manager,
+                    StaffStatus::Active,
+                ),
+            );
+
+            // New manager, new tactics
+            team.tactics = None;
+            team.reputation.process_manager_change(manager_reputation);
+        }
+
+        club.board.record_manager_appointed(staff_id, date);
     }
 
-    pub fn process(&self, _: &mut SimulatorData) {}
+    /// Nobody suitable accepted the job, the caretaker stays on. Without one the search starts over
+    fn keep_caretaker(club_id: u32, date: NaiveDate, data: &mut SimulatorData) {
+        let club = match data.club_mut(club_id) {
+            Some(club) => club,
+            None => return,
+        };
+
+        let caretaker = club.board.manager_search.as_ref().and_then(|search| search.caretaker);
+
+        let appointed = match (caretaker, club.teams.main_mut()) {
+            (Some((caretaker_id, _)), Some(team)) => team
+                .staffs
+                .make_permanent(caretaker_id, Self::manager_contract_expiration(date))
+                .then_some(caretaker_id),
+            _ => None,
+        };
+
+        match appointed {
+            Some(staff_id) => club.board.record_manager_appointed(staff_id, date),
+            None => {
+                // Coaches may have joined since the manager left
+                let caretaker = club.teams.main_mut().and_then(|team| team.staffs.appoint_caretaker());
+
+                info!("{}: no manager found, the search goes on", club.name);
+
+                club.board.restart_manager_search(caretaker, date);
+            }
+        }
+    }
+
+    fn manager_contract_expiration(date: NaiveDate) -> NaiveDate {
+        date.with_year(date.year() + MANAGER_CONTRACT_YEARS).unwrap_or(date)
+    }
+
+    fn club_reputation(club: &Club) -> u16 {
+        club.teams
+            .main_team_id()
+            .map(|id| (club.teams.by_id(id).reputation.overall_score() * 1000.0) as u16)
+            .unwrap_or(0)
+    }
+
+    fn league_standing(&self, data: &SimulatorData) -> Option<LeagueStanding> {
+        let team = data
+            .club(self.club_id)
+            .and_then(|club| club.teams.main_team_id())
+            .and_then(|team_id| data.team(team_id))?;
+
+        let league = data.league(team.league_id)?;
+        let rows = &league.table.rows;
+
+        let row_index = rows.iter().position(|r| r.team_id == team.id)?;
+
+        let strength = |team_id: u32| -> f32 {
+            data.team(team_id)
+                .map(|t| {
+                    let budget = data
+                        .club(t.club_id)
+                        .map(|club| Self::budget(club))
+                        .unwrap_or(0.0);
+
+                    (t.reputation.world as f32, budget)
+                })
+                .map(|(reputation, budget)| reputation + budget / 1_000_000.0)
+                .unwrap_or(0.0)
+        };
+
+        // Board expectations are based on how the club compares with the rest of the league
+        let own_strength = strength(team.id);
+        let stronger_teams = rows
+            .iter()
+            .filter(|r| r.team_id != team.id && strength(r.team_id) > own_strength)
+            .count();
+
+        let date = data.date.date();
+        let season_end = &league.settings.season_ending_half;
+
+        Some(LeagueStanding {
+            league_id: league.id,
+            position: (row_index + 1) as u8,
+            expected_position: (stronger_teams + 1) as u8,
+            teams_count: rows.len() as u8,
+            played: rows[row_index].played,
+            tier: league.tier,
+            is_season_end: date.day() == season_end.to_day as u32
+                && date.month() == season_end.to_month as u32,
+        })
+    }
+
+    fn budget(club: &Club) -> f32 {
+        match &club.finance.transfer_budget {
+            Some(budget) => budget.amount as f32,
+            None => club.finance.balance.balance.max(0) as f32,
+        }
+    }
 }
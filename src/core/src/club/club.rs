@@ -1,18 +1,58 @@
 use crate::club::academy::ClubAcademy;
 use crate::club::board::ClubBoard;
+use crate::club::scouting::ClubScouting;
+use crate::club::stadium::Stadium;
 use crate::club::status::ClubStatus;
-use crate::club::{ClubFinances, ClubResult};
+use crate::club::{
+    BoardObjectiveType, ClubExpenseType, ClubFinances, ClubResult, ContractRenewals, SquadMovement,
+};
 use crate::context::GlobalContext;
 use crate::shared::Location;
 use crate::TeamCollection;
+use chrono::NaiveDate;
+use log::info;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+/// Days after the season a relegation release clause can still be triggered, through the summer window
+const RELEGATION_RELEASE_DAYS: i64 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SeasonOutcomeType {
+    Promoted,
+    Relegated,
+}
+
+impl SeasonOutcomeType {
+    /// The bottom places go down, below the top flight as many at the top go up
+    pub fn from_position(position: u8, teams_count: u8, tier: u8) -> Option<Self> {
+        let places = BoardObjectiveType::relegation_places(teams_count);
+
+        if position > teams_count.saturating_sub(places) {
+            Some(SeasonOutcomeType::Relegated)
+        } else if tier > 1 && position <= places {
+            Some(SeasonOutcomeType::Promoted)
+        } else {
+            None
+        }
+    }
+}
+
+/// How the first team's last league season ended
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClubSeasonOutcome {
+    pub outcome_type: SeasonOutcomeType,
+    pub date: NaiveDate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Club {
     pub id: u32,
     pub name: String,
 
     pub location: Location,
 
+    pub stadium: Stadium,
+
     pub board: ClubBoard,
 
     pub finance: ClubFinances,
@@ -21,7 +61,11 @@ pub struct Club {
 
     pub academy: ClubAcademy,
 
+    pub scouting: ClubScouting,
+
     pub teams: TeamCollection,
+
+    pub season_outcome: Option<ClubSeasonOutcome>,
 }
 
 impl Club {
@@ -29,6 +73,7 @@ impl Club {
         id: u32,
         name: String,
         location: Location,
+        stadium: Stadium,
         finance: ClubFinances,
         academy: ClubAcademy,
         status: ClubStatus,
@@ -38,36 +83,130 @@ impl Club {
             id,
             name,
             location,
+            stadium,
             finance,
             status,
             academy,
+            scouting: ClubScouting::new(),
             board: ClubBoard::new(),
             teams,
+            season_outcome: None,
+        }
+    }
+
+    /// Relegation release clauses hold until the summer window after the drop closes
+    pub fn is_relegated(&self, date: NaiveDate) -> bool {
+        self.season_outcome.as_ref().is_some_and(|outcome| {
+            outcome.outcome_type == SeasonOutcomeType::Relegated
+                && (date - outcome.date).num_days() <= RELEGATION_RELEASE_DAYS
+        })
+    }
+
+    /// Records how the season ended, promotion wage rises are paid from now on
+    pub fn end_season(&mut self, outcome_type: Option<SeasonOutcomeType>, date: NaiveDate) {
+        self.season_outcome = outcome_type.map(|outcome_type| ClubSeasonOutcome { outcome_type, date });
+
+        if outcome_type != Some(SeasonOutcomeType::Promoted) {
+            return;
+        }
+
+        let wage_rises = self
+            .teams
+            .teams
+            .iter_mut()
+            .flat_map(|team| team.players.players.iter_mut())
+            .filter_map(|player| player.contract.as_mut())
+            .map(|contract| contract.apply_promotion_wage_rise())
+            .filter(|&raised| raised)
+            .count();
+
+        if wage_rises > 0 {
+            info!("📈 {}: promotion wage rise for {} players", self.name, wage_rises);
         }
     }
 
     pub fn simulate(&mut self, ctx: GlobalContext<'_>) -> ClubResult {
-        let result = ClubResult::new(
+        let date = ctx.simulation.date.date();
+
+        let mut squad_moves = 0;
+
+        if SquadMovement::is_review_day(date) {
+            squad_moves += SquadMovement::yearly(&mut self.teams, date);
+        }
+
+        if ctx.simulation.is_week_beginning() {
+            squad_moves += SquadMovement::weekly(&mut self.teams, date);
+        }
+
+        if ctx.simulation.is_month_beginning() {
+            let can_afford = self.finance.balance.balance >= 0;
+
+            if let Some(main_team) = self.teams.main_mut() {
+                ContractRenewals::monthly(main_team, can_afford, date);
+            }
+        }
+
+        let mut result = ClubResult::new(
             self.finance.simulate(ctx.with_finance()),
             self.teams.simulate(ctx.with_club(self.id, &self.name)),
             self.board.simulate(ctx.with_board()),
-            self.academy.simulate(ctx.clone()),
+            self.academy.simulate(ctx.clone(), &self.teams),
         );
 
+        result.squads_changed = squad_moves > 0;
+
         if ctx.simulation.is_week_beginning() {
-            self.process_salaries(ctx);
+            self.process_salaries(ctx.clone());
+        }
+
+        if ctx.simulation.is_month_beginning() {
+            self.process_stadium_costs(ctx.clone());
+        }
+
+        if let Some(added_capacity) = self.stadium.simulate(date) {
+            info!(
+                "🏟️ {}: {} expansion completed, +{} seats (capacity {})",
+                self.name, self.stadium.name, added_capacity, self.stadium.capacity
+            );
         }
 
         result
     }
 
+    fn process_stadium_costs(&mut self, ctx: GlobalContext<'_>) {
+        let stadium_costs = self.stadium.monthly_rent()
+            + self
+                .stadium
+                .pay_expansion_installment(ctx.simulation.date.date());
+
+        if stadium_costs > 0 {
+            self.finance
+                .balance
+                .push_outcome_of(ClubExpenseType::Stadium, stadium_costs);
+        }
+    }
+
     fn process_salaries(&mut self, ctx: GlobalContext<'_>) {
+        let club_name = ctx.club.as_ref().expect("no club found").name;
+
         for team in &self.teams.teams {
-            let weekly_salary = team.get_week_salary();
             self.finance.push_salary(
-                ctx.club.as_ref().expect("no club found").name,
-                weekly_salary as i32,
+                club_name,
+                ClubExpenseType::PlayerWages,
+                team.players.get_week_salary() as i32,
+            );
+
+            self.finance.push_salary(
+                club_name,
+                ClubExpenseType::StaffWages,
+                team.staffs.get_week_salary() as i32,
             );
         }
+
+        self.finance.push_salary(
+            club_name,
+            ClubExpenseType::StaffWages,
+            self.board.get_week_salary() as i32,
+        );
     }
 }
@@ -1,10 +1,14 @@
 use crate::context::GlobalContext;
 use crate::shared::CurrencyValue;
-use crate::{ClubFinanceResult, ClubFinancialBalanceHistory, ClubSponsorship, ClubSponsorshipContract};
+use crate::{
+    ClubExpenseType, ClubFinanceResult, ClubFinancialBalanceHistory, ClubFinancialBreakdown,
+    ClubIncomeType, ClubSponsorship, ClubSponsorshipContract,
+};
 use chrono::NaiveDate;
 use log::debug;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ClubFinances {
     pub balance: ClubFinancialBalance,
     pub history: ClubFinancialBalanceHistory,
@@ -41,20 +45,23 @@ impl ClubFinances {
     }
 
     pub fn simulate(&mut self, ctx: GlobalContext<'_>) -> ClubFinanceResult {
-        let result = ClubFinanceResult::new();
-        let club_name = ctx.club.as_ref().expect("no club found").name;
+        let club = ctx.club.as_ref().expect("no club found");
+        let club_name = club.name;
+
+        let result = ClubFinanceResult::new(club.id, ctx.simulation.is_month_beginning());
 
         if ctx.simulation.is_month_beginning() {
             debug!("club: {}, finance: start new month", club_name);
             self.start_new_month(club_name, ctx.simulation.date.date());
 
+            // Sponsors pay monthly
+            self.receive_sponsorship(club_name, ctx.simulation.date.date());
+
             // Update budgets at month beginning
             self.update_budgets();
         }
 
         if ctx.simulation.is_year_beginning() {
-            // ... sponsorship income code ...
-
             // Reset budgets for new year
             self.reset_annual_budgets();
         }
@@ -62,6 +69,25 @@ impl ClubFinances {
         result
     }
 
+    fn receive_sponsorship(&mut self, club_name: &str, date: NaiveDate) {
+        let sponsorship_income: i32 = self
+            .sponsorship
+            .get_sponsorship_incomes(date)
+            .iter()
+            .map(|contract| contract.wage)
+            .sum();
+
+        if sponsorship_income > 0 {
+            debug!(
+                "club: {}, finance: sponsorship income, amount = {}",
+                club_name, sponsorship_income
+            );
+
+            self.balance
+                .push_income_of(ClubIncomeType::Sponsorship, sponsorship_income);
+        }
+    }
+
     fn start_new_month(&mut self, club_name: &str, date: NaiveDate) {
         debug!(
         "club: {}, finance: add history, date = {}, balance = {}, income={}, outcome={}",
@@ -72,13 +98,13 @@ impl ClubFinances {
         self.balance.clear();
     }
 
-    pub fn push_salary(&mut self, club_name: &str, amount: i32) {
+    pub fn push_salary(&mut self, club_name: &str, expense_type: ClubExpenseType, amount: i32) {
         debug!(
             "club: {}, finance: push salary, amount = {}",
             club_name, amount
         );
 
-        self.balance.push_outcome(amount);
+        self.balance.push_outcome_of(expense_type, amount);
     }
 
     fn update_budgets(&mut self) {
@@ -122,7 +148,8 @@ impl ClubFinances {
         if let Some(ref mut budget) = self.transfer_budget {
             if budget.amount >= amount {
                 budget.amount -= amount;
-                self.balance.push_outcome(amount as i32);
+                self.balance
+                    .push_outcome_of(ClubExpenseType::Transfers, amount as i32);
                 return true;
             }
         }
@@ -131,7 +158,8 @@ impl ClubFinances {
 
     // Helper method to add transfer income
     pub fn add_transfer_income(&mut self, amount: f64) {
-        self.balance.push_income(amount as i32);
+        self.balance
+            .push_income_of(ClubIncomeType::Transfers, amount as i32);
 
         // Add 50% of transfer income to transfer budget
         if let Some(ref mut budget) = self.transfer_budget {
@@ -145,12 +173,13 @@ impl ClubFinances {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct ClubFinancialBalance {
     pub balance: i32,
     pub income: i32,
     pub outcome: i32,
+    pub breakdown: ClubFinancialBreakdown,
     highest_wage_paid: i32,
     latest_season_tickets: i32,
     remaining_budget: i32,
@@ -167,6 +196,7 @@ impl ClubFinancialBalance {
             balance,
             income: 0,
             outcome: 0,
+            breakdown: ClubFinancialBreakdown::new(),
             highest_wage_paid: 0,
             latest_season_tickets: 0,
             remaining_budget: 0,
@@ -179,17 +209,30 @@ impl ClubFinancialBalance {
     }
 
     pub fn push_income(&mut self, wage: i32) {
-        self.balance = self.balance + wage;
-        self.income = self.income + wage;
+        self.push_income_of(ClubIncomeType::Other, wage);
     }
 
     pub fn push_outcome(&mut self, wage: i32) {
-        self.balance = self.balance - wage;
-        self.outcome = self.outcome + wage;
+        self.push_outcome_of(ClubExpenseType::Other, wage);
+    }
+
+    pub fn push_income_of(&mut self, income_type: ClubIncomeType, amount: i32) {
+        self.balance = self.balance.saturating_add(amount);
+        self.income = self.income.saturating_add(amount);
+
+        self.breakdown.add_income(income_type, amount);
+    }
+
+    pub fn push_outcome_of(&mut self, expense_type: ClubExpenseType, amount: i32) {
+        self.balance = self.balance.saturating_sub(amount);
+        self.outcome = self.outcome.saturating_add(amount);
+
+        self.breakdown.add_expense(expense_type, amount);
     }
 
     pub fn clear(&mut self) {
         self.income = 0;
         self.outcome = 0;
+        self.breakdown = ClubFinancialBreakdown::new();
     }
 }
\ No newline at end of file
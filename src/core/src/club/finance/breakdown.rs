@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClubIncomeType {
+    Matchday,
+    TvRights,
+    Sponsorship,
+    PrizeMoney,
+    Transfers,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClubExpenseType {
+    PlayerWages,
+    StaffWages,
+    Transfers,
+    Stadium,
+    Other,
+}
+
+/// Income and expenses of a period split by source
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClubFinancialBreakdown {
+    pub matchday: i32,
+    pub tv_rights: i32,
+    pub sponsorship: i32,
+    pub prize_money: i32,
+    pub transfer_income: i32,
+    pub other_income: i32,
+
+    pub player_wages: i32,
+    pub staff_wages: i32,
+    pub transfer_expenses: i32,
+    pub stadium_expenses: i32,
+    pub other_expenses: i32,
+}
+
+impl ClubFinancialBreakdown {
+    pub fn new() -> Self {
+        ClubFinancialBreakdown::default()
+    }
+
+    pub fn add_income(&mut self, income_type: ClubIncomeType, amount: i32) {
+        let item = match income_type {
+            ClubIncomeType::Matchday => &mut self.matchday,
+            ClubIncomeType::TvRights => &mut self.tv_rights,
+            ClubIncomeType::Sponsorship => &mut self.sponsorship,
+            ClubIncomeType::PrizeMoney => &mut self.prize_money,
+            ClubIncomeType::Transfers => &mut self.transfer_income,
+            ClubIncomeType::Other => &mut self.other_income,
+        };
+
+        *item = item.saturating_add(amount);
+    }
+
+    pub fn add_expense(&mut self, expense_type: ClubExpenseType, amount: i32) {
+        let item = match expense_type {
+            ClubExpenseType::PlayerWages => &mut self.player_wages,
+            ClubExpenseType::StaffWages => &mut self.staff_wages,
+            ClubExpenseType::Transfers => &mut self.transfer_expenses,
+            ClubExpenseType::Stadium => &mut self.stadium_expenses,
+            ClubExpenseType::Other => &mut self.other_expenses,
+        };
+
+        *item = item.saturating_add(amount);
+    }
+
+    pub fn total_income(&self) -> i32 {
+        self.matchday
+            .saturating_add(self.tv_rights)
+            .saturating_add(self.sponsorship)
+            .saturating_add(self.prize_money)
+            .saturating_add(self.transfer_income)
+            .saturating_add(self.other_income)
+    }
+
+    pub fn total_expenses(&self) -> i32 {
+        self.player_wages
+            .saturating_add(self.staff_wages)
+            .saturating_add(self.transfer_expenses)
+            .saturating_add(self.stadium_expenses)
+            .saturating_add(self.other_expenses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_income_goes_to_its_category() {
+        let mut breakdown = ClubFinancialBreakdown::new();
+
+        breakdown.add_income(ClubIncomeType::Matchday, 100);
+        breakdown.add_income(ClubIncomeType::TvRights, 50);
+        breakdown.add_income(ClubIncomeType::Matchday, 10);
+
+        assert_eq!(110, breakdown.matchday);
+        assert_eq!(50, breakdown.tv_rights);
+        assert_eq!(160, breakdown.total_income());
+    }
+
+    #[test]
+    fn add_expense_goes_to_its_category() {
+        let mut breakdown = ClubFinancialBreakdown::new();
+
+        breakdown.add_expense(ClubExpenseType::PlayerWages, 300);
+        breakdown.add_expense(ClubExpenseType::StaffWages, 20);
+
+        assert_eq!(300, breakdown.player_wages);
+        assert_eq!(20, breakdown.staff_wages);
+        assert_eq!(320, breakdown.total_expenses());
+    }
+}
@@ -1,8 +1,9 @@
 use crate::club::ClubFinancialBalance;
 use chrono::NaiveDate;
 use std::collections::LinkedList;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ClubFinancialBalanceHistory {
     history: LinkedList<(NaiveDate, ClubFinancialBalance)>,
 }
@@ -27,6 +28,11 @@ impl ClubFinancialBalanceHistory {
     pub fn add(&mut self, date: NaiveDate, balance: ClubFinancialBalance) {
         self.history.push_front((date, balance))
     }
+
+    /// Most recent records first
+    pub fn iter(&self) -> impl Iterator<Item = &(NaiveDate, ClubFinancialBalance)> {
+        self.history.iter()
+    }
 }
 
 #[cfg(test)]
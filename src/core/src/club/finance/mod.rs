@@ -1,11 +1,15 @@
 mod balance;
+mod breakdown;
 mod context;
 mod history;
 mod result;
+mod revenue;
 mod sponsorship;
 
 pub use balance::*;
+pub use breakdown::*;
 pub use context::*;
 pub use history::*;
 pub use result::*;
+pub use revenue::*;
 pub use sponsorship::*;
@@ -1,11 +1,78 @@
 use crate::simulator::SimulatorData;
+use crate::{ClubIncomeType, ClubRevenueCalculator};
+use chrono::Datelike;
+use log::debug;
 
-pub struct ClubFinanceResult {}
+pub struct ClubFinanceResult {
+    pub club_id: u32,
+    pub is_month_beginning: bool,
+}
 
 impl ClubFinanceResult {
-    pub fn new() -> Self {
-        ClubFinanceResult {}
+    pub fn new(club_id: u32, is_month_beginning: bool) -> Self {
+        ClubFinanceResult {
+            club_id,
+            is_month_beginning,
+        }
     }
 
-    pub fn process(&self, _: &mut SimulatorData) {}
+    pub fn process(&self, data: &mut SimulatorData) {
+        // League money depends on the table, so it is paid here and not in ClubFinances
+        let payments = self.league_payments(data);
+
+        if let Some(club) = data.club_mut(self.club_id) {
+            for (income_type, amount) in payments {
+                debug!(
+                    "club: {}, finance: {:?} income, amount = {}",
+                    club.name, income_type, amount
+                );
+
+                club.finance.balance.push_income_of(income_type, amount);
+            }
+        }
+    }
+
+    fn league_payments(&self, data: &SimulatorData) -> Vec<(ClubIncomeType, i32)> {
+        let mut payments = Vec::new();
+
+        let team = match data
+            .club(self.club_id)
+            .and_then(|club| club.teams.main_team_id())
+            .and_then(|team_id| data.team(team_id))
+        {
+            Some(team) => team,
+            None => return payments,
+        };
+
+        let league = match data.league(team.league_id) {
+            Some(league) => league,
+            None => return payments,
+        };
+
+        let teams_count = league.table.rows.len();
+
+        let position = match league.table.rows.iter().position(|r| r.team_id == team.id) {
+            Some(position) => position,
+            None => return payments,
+        };
+
+        if self.is_month_beginning {
+            payments.push((
+                ClubIncomeType::TvRights,
+                ClubRevenueCalculator::monthly_tv_rights(league.reputation, position, teams_count),
+            ));
+        }
+
+        let date = data.date.date();
+        let season_end = &league.settings.season_ending_half;
+
+        if date.day() == season_end.to_day as u32 && date.month() == season_end.to_month as u32 {
+            payments.push((
+                ClubIncomeType::PrizeMoney,
+                ClubRevenueCalculator::season_prize_money(league.reputation, position, teams_count),
+            ));
+        }
+
+        payments
+    }
 }
@@ -0,0 +1,80 @@
+/// Estimates of the money a club earns from matches, broadcasting and competitions
+pub struct ClubRevenueCalculator;
+
+impl ClubRevenueCalculator {
+    /// Average ticket price in USD, top leagues charge more
+    pub fn ticket_price(league_reputation: u16) -> f64 {
+        15.0 + league_reputation as f64 / 200.0
+    }
+
+    pub fn matchday_revenue(attendance: u32, ticket_price: f64) -> i32 {
+        (attendance as f64 * ticket_price) as i32
+    }
+
+    /// Yearly broadcasting pool of a league
+    pub fn tv_rights_pool(league_reputation: u16) -> f64 {
+        league_reputation as f64 * 100_000.0
+    }
+
+    /// Half of the pool is shared equally, the other half is paid on merit
+    pub fn monthly_tv_rights(league_reputation: u16, position: usize, teams_count: usize) -> i32 {
+        if teams_count == 0 {
+            return 0;
+        }
+
+        let monthly_pool = Self::tv_rights_pool(league_reputation) / 12.0;
+
+        let equal_share = monthly_pool * 0.5 / teams_count as f64;
+        let merit_share = monthly_pool * 0.5 * Self::merit_weight(position, teams_count);
+
+        (equal_share + merit_share) as i32
+    }
+
+    pub fn season_prize_money(league_reputation: u16, position: usize, teams_count: usize) -> i32 {
+        if teams_count == 0 {
+            return 0;
+        }
+
+        let prize_pool = Self::tv_rights_pool(league_reputation) * 0.2;
+
+        (prize_pool * Self::merit_weight(position, teams_count)) as i32
+    }
+
+    /// Share of a merit pool for the given table position (0 - top), all shares sum up to 1
+    fn merit_weight(position: usize, teams_count: usize) -> f64 {
+        let position = position.min(teams_count - 1);
+        let total_points = (teams_count * (teams_count + 1) / 2) as f64;
+
+        (teams_count - position) as f64 / total_points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn higher_position_earns_more_tv_money() {
+        let first = ClubRevenueCalculator::monthly_tv_rights(8000, 0, 20);
+        let last = ClubRevenueCalculator::monthly_tv_rights(8000, 19, 20);
+
+        assert!(first > last);
+        assert!(last > 0);
+    }
+
+    #[test]
+    fn tv_money_shares_sum_to_monthly_pool() {
+        let total: i64 = (0..20)
+            .map(|position| ClubRevenueCalculator::monthly_tv_rights(8000, position, 20) as i64)
+            .sum();
+
+        let pool = ClubRevenueCalculator::tv_rights_pool(8000) / 12.0;
+
+        assert!((total as f64 - pool).abs() < 20.0);
+    }
+
+    #[test]
+    fn prize_money_empty_league_is_zero() {
+        assert_eq!(0, ClubRevenueCalculator::season_prize_money(8000, 0, 0));
+    }
+}
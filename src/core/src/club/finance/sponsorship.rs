@@ -1,6 +1,7 @@
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ClubSponsorship {
     pub sponsorship_contracts: Vec<ClubSponsorshipContract>,
 }
@@ -24,9 +25,10 @@ impl ClubSponsorship {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ClubSponsorshipContract {
     pub sponsor_name: String,
+    /// Paid every month
     pub wage: i32,
     expiration: NaiveDate,
 }
@@ -41,6 +43,6 @@ impl ClubSponsorshipContract {
     }
 
     pub fn is_expired(&self, date: NaiveDate) -> bool {
-        self.expiration >= date
+        self.expiration < date
     }
 }
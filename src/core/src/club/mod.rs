@@ -8,6 +8,8 @@ pub mod person;
 pub mod player;
 pub mod relations;
 pub mod result;
+pub mod scouting;
+pub mod stadium;
 pub mod staff;
 pub mod status;
 pub mod team;
@@ -20,6 +22,7 @@ pub use context::*;
 pub use mood::*;
 pub use person::*;
 pub use result::*;
+pub use stadium::*;
 pub use status::*;
 
 // Finance exports
@@ -27,6 +30,7 @@ pub use finance::{
     ClubFinances, ClubFinancialBalance, ClubFinancialBalanceHistory,
     ClubSponsorship, ClubSponsorshipContract,
     ClubFinanceContext, ClubFinanceResult,
+    ClubFinancialBreakdown, ClubIncomeType, ClubExpenseType, ClubRevenueCalculator,
 };
 
 // Relations exports
@@ -41,18 +45,28 @@ pub use transfers::{
     ClubTransferStrategy,
 };
 
+// Scouting exports
+pub use scouting::{
+    ClubScouting, ScoutAssignment, ScoutJudgement, ScoutingRegion, ScoutingReport,
+    SCOUTING_PLAYERS_PER_WEEK,
+};
+
 // Player exports (except conflicting modules)
 pub use player::{
     Player, PlayerCollection, PlayerBuilder,
     PlayerAttributes, PlayerContext,
     PlayerPreferredFoot, PlayerPositionType, PlayerFieldPositionGroup, PlayerStatusType,
     PlayerSkills, Technical, Mental, Physical,
-    PlayerPositions, PlayerPosition, PlayerStatus, StatusData,
+    PlayerPositions, PlayerPosition, REQUIRED_POSITION_LEVEL, PlayerStatus, StatusData,
     PlayerStatistics, PlayerStatisticsHistory, PlayerStatisticsHistoryItem,
-    PlayerHappiness, PositiveHappiness, NegativeHappiness,
+    PlayerMorale, PlayerMoraleState, MoraleFactors, MoraleSituation,
+    PlayerInteractions, PlayerInteraction, PlayerInteractionType, PlayerPromise, PlayerPromiseType,
+    PlayerRequestType,
     PlayerClubContract, ContractType, PlayerSquadStatus, PlayerTransferStatus,
     ContractBonusType, ContractBonus, ContractClauseType, ContractClause,
-    PlayerMailbox, PlayerMessage, PlayerMessageType, PlayerContractProposal, PlayerMailboxResult,
+    ContractNegotiation, ContractDemands, ContractOffer, ContractRenewal,
+    PlayerMailbox, PlayerMessage, PlayerMessageType, PlayerContractProposal, PlayerRequestDismissal,
+    PlayerMailboxResult,
     PlayerTraining, PlayerTrainingHistory, TrainingRecord,
     PlayerResult, PlayerCollectionResult, PlayerContractResult,
     PlayerValueCalculator, PlayerGenerator, PlayerUtils,
@@ -60,7 +74,9 @@ pub use player::{
 };
 // Also export the missing types
 pub use player::training::result::PlayerTrainingResult;
-pub use player::mailbox::handlers::{AcceptContractHandler, ProcessContractHandler};
+pub use player::mailbox::handlers::{
+    AcceptContractHandler, DismissedRequestHandler, ProcessContractHandler, PromiseHandler,
+};
 // Also export context module for those who want to import from it
 pub use player::context as player_context;
 // Also keep module aliases for those who want to import from the module
@@ -110,6 +126,7 @@ pub use team::{
     TrainingFacilities, FacilityQuality, TrainingLoadManager, PlayerTrainingLoad,
     Transfers, TransferItem,
     MatchHistory, MatchHistoryItem,
+    SquadMovement, TeamMoraleReview, ContractRenewals,
     TeamReputation, ReputationLevel, ReputationTrend, Achievement, AchievementType,
     MatchResultInfo, MatchOutcome, ReputationRequirements,
     TACTICS_POSITIONS,
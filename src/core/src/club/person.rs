@@ -2,6 +2,7 @@ use crate::shared::FullName;
 use crate::utils::DateUtils;
 use crate::Relations;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
 pub trait Person {
     fn id(&self) -> u32;
@@ -18,7 +19,7 @@ pub trait Person {
     fn relations(&self) -> &Relations;
 }
 
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
 pub struct PersonAttributes {
     pub adaptability: f32,
     pub ambition: f32,
@@ -30,7 +31,7 @@ pub struct PersonAttributes {
     pub temperament: f32,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct PersonBehaviour {
     pub state: PersonBehaviourState,
 }
@@ -57,7 +58,7 @@ impl PersonBehaviour {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[derive(Default)]
 pub enum PersonBehaviourState {
     Poor,
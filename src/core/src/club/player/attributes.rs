@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 pub const CONDITION_MAX_VALUE: i16 = 10000;
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct PlayerAttributes {
     pub is_banned: bool,
     pub is_injured: bool,
@@ -39,6 +41,11 @@ impl PlayerAttributes {
         }
     }
 
+    pub fn tire(&mut self, condition: u16, jadedness: u16) {
+        self.condition = self.condition.saturating_sub(condition as i16).max(0);
+        self.jadedness = self.jadedness.saturating_add(jadedness as i16);
+    }
+
     pub fn condition_percentage(&self) -> u32 {
         (self.condition as f32 * 100.0 / CONDITION_MAX_VALUE as f32).floor() as u32
     }
@@ -100,6 +107,19 @@ mod tests {
         assert_eq!(player_attributes.condition, CONDITION_MAX_VALUE);
     }
 
+    #[test]
+    fn test_tire_does_not_go_below_zero() {
+        let mut player_attributes = PlayerAttributes {
+            condition: 1000,
+            jadedness: 2000,
+            ..Default::default()
+        };
+
+        player_attributes.tire(1500, 300);
+        assert_eq!(player_attributes.condition, 0);
+        assert_eq!(player_attributes.jadedness, 2300);
+    }
+
     #[test]
     fn test_condition_percentage() {
         let player_attributes = PlayerAttributes {
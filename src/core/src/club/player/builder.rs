@@ -2,8 +2,9 @@ use crate::club::{
     PersonBehaviour, PlayerAttributes, PlayerClubContract, PlayerMailbox,
     PlayerSkills, PlayerTraining,
 };
+use crate::awards::Award;
 use crate::shared::fullname::FullName;
-use crate::{PersonAttributes, Player, PlayerHappiness, PlayerPositions, PlayerPreferredFoot, PlayerStatistics, PlayerStatisticsHistory, PlayerStatus, PlayerTrainingHistory, Relations};
+use crate::{PersonAttributes, Player, PlayerInteractions, PlayerMorale, PlayerPositions, PlayerPreferredFoot, PlayerStatistics, PlayerStatisticsHistory, PlayerStatus, PlayerTrainingHistory, Relations};
 use chrono::NaiveDate;
 
 // Builder for Player
@@ -15,7 +16,8 @@ pub struct PlayerBuilder {
     country_id: Option<u32>,
     behaviour: Option<PersonBehaviour>,
     attributes: Option<PersonAttributes>,
-    happiness: Option<PlayerHappiness>,
+    morale: Option<PlayerMorale>,
+    interactions: Option<PlayerInteractions>,
     statuses: Option<PlayerStatus>,
     skills: Option<PlayerSkills>,
     contract: Option<Option<PlayerClubContract>>,
@@ -28,6 +30,7 @@ pub struct PlayerBuilder {
     relations: Option<Relations>,
     statistics: Option<PlayerStatistics>,
     statistics_history: Option<PlayerStatisticsHistory>,
+    awards: Option<Vec<Award>>,
 }
 
 impl PlayerBuilder {
@@ -65,8 +68,13 @@ impl PlayerBuilder {
         self
     }
 
-    pub fn happiness(mut self, happiness: PlayerHappiness) -> Self {
-        self.happiness = Some(happiness);
+    pub fn morale(mut self, morale: PlayerMorale) -> Self {
+        self.morale = Some(morale);
+        self
+    }
+
+    pub fn interactions(mut self, interactions: PlayerInteractions) -> Self {
+        self.interactions = Some(interactions);
         self
     }
 
@@ -130,6 +138,11 @@ impl PlayerBuilder {
         self
     }
 
+    pub fn awards(mut self, awards: Vec<Award>) -> Self {
+        self.awards = Some(awards);
+        self
+    }
+
     pub fn build(self) -> Result<Player, String> {
         Ok(Player {
             id: self.id.ok_or("id is required")?,
@@ -138,7 +151,8 @@ impl PlayerBuilder {
             country_id: self.country_id.ok_or("country_id is required")?,
             behaviour: self.behaviour.unwrap_or_default(),
             attributes: self.attributes.ok_or("attributes is required")?,
-            happiness: self.happiness.unwrap_or_else(PlayerHappiness::new),
+            morale: self.morale.unwrap_or_else(PlayerMorale::new),
+            interactions: self.interactions.unwrap_or_else(PlayerInteractions::new),
             statuses: self.statuses.unwrap_or_else(PlayerStatus::new),
             skills: self.skills.ok_or("skills is required")?,
             contract: self.contract.unwrap_or(None),
@@ -151,6 +165,7 @@ impl PlayerBuilder {
             relations: self.relations.unwrap_or_else(Relations::new),
             statistics: self.statistics.unwrap_or_default(),
             statistics_history: self.statistics_history.unwrap_or_else(PlayerStatisticsHistory::new),
+            awards: self.awards.unwrap_or_default(),
         })
     }
 }
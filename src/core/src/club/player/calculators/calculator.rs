@@ -14,12 +14,16 @@ impl PlayerValueCalculator {
 
         let country_factor = determine_country_factor(player);
 
+        let reputation_factor = determine_reputation_factor(player);
+
         let form_factor = determine_form_factor(player);
 
         let match_appearance_factor = determine_match_appearance_factor(player);
 
         let statistics_factor = determine_statistics_factor(player);
 
+        let awards_factor = determine_awards_factor(player, now);
+
         let other_factors = determine_other_factors(player);
 
         let value = base_value
@@ -27,9 +31,11 @@ impl PlayerValueCalculator {
             * status_factor
             * contract_factor
             * country_factor
+            * reputation_factor
             * form_factor
             * match_appearance_factor
             * statistics_factor
+            * awards_factor
             * other_factors;
 
         value
@@ -101,6 +107,13 @@ fn determine_country_factor(_player: &Player) -> f64 {
     country_factor
 }
 
+/// Players known around the world, through international football for one, sell for more
+fn determine_reputation_factor(player: &Player) -> f64 {
+    let world_reputation = player.player_attributes.world_reputation.clamp(0, 10000) as f64;
+
+    1.0 + world_reputation / 10000.0
+}
+
 fn determine_form_factor(_player: &Player) -> f64 {
     let form_factor = 1.0;
 
@@ -126,7 +139,16 @@ fn determine_match_appearance_factor(_player: &Player) -> f64 {
 }
 
 fn determine_statistics_factor(player: &Player) -> f64 {
-    let match_appearance_factor = match player.statistics.played {
+    // Last season's appearances count until this season's catch up
+    let played = player.statistics.played.max(
+        player
+            .statistics_history
+            .last()
+            .map(|statistics| statistics.played)
+            .unwrap_or(0),
+    );
+
+    let match_appearance_factor = match played {
         match_appearances if match_appearances > 20 => 1.1,
         match_appearances if match_appearances > 10 => 1.0,
         match_appearances if match_appearances > 5 => 0.9,
@@ -137,6 +159,17 @@ fn determine_statistics_factor(player: &Player) -> f64 {
     match_appearance_factor
 }
 
+/// Recent awards put a premium on the player
+fn determine_awards_factor(player: &Player, now: NaiveDate) -> f64 {
+    let bonus: f64 = player
+        .awards
+        .iter()
+        .map(|award| award.value_bonus(now))
+        .sum();
+
+    1.0 + bonus.min(0.5)
+}
+
 fn determine_other_factors(player: &Player) -> f64 {
     let mut other_factors = 1.0;
 
@@ -144,7 +177,7 @@ fn determine_other_factors(player: &Player) -> f64 {
         other_factors *= 1.2;
     }
 
-    if player.happiness.is_happy() {
+    if player.morale.is_happy() {
         other_factors *= 1.1;
     }
 
@@ -1,8 +1,9 @@
 use crate::context::SimulationContext;
 pub use chrono::prelude::{DateTime, Datelike, NaiveDate, Utc};
 use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum ContractType {
     PartTime,
     FullTime,
@@ -11,7 +12,7 @@ pub enum ContractType {
     NonContract,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum PlayerSquadStatus {
     Invalid,
     NotYetSet,
@@ -25,14 +26,14 @@ pub enum PlayerSquadStatus {
     SquadStatusCount,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum PlayerTransferStatus {
     TransferListed,
     LoadListed,
     TransferAndLoadListed,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerClubContract {
     pub shirt_number: Option<u8>,
 
@@ -75,13 +76,53 @@ impl PlayerClubContract {
         diff.num_days().abs()
     }
 
+    pub fn bonus(&self, bonus_type: ContractBonusType) -> Option<i32> {
+        self.bonuses
+            .iter()
+            .find(|bonus| bonus.bonus_type == bonus_type)
+            .map(|bonus| bonus.value)
+    }
+
+    pub fn clause(&self, clause_type: ContractClauseType) -> Option<i32> {
+        self.clauses
+            .iter()
+            .find(|clause| clause.bonus_type == clause_type)
+            .map(|clause| clause.value)
+    }
+
+    /// Fee that lets a buying club bypass us, the lowest release clause that applies to it
+    pub fn release_fee(&self, foreign_buyer: bool, relegated: bool) -> Option<u32> {
+        self.clauses
+            .iter()
+            .filter(|clause| match clause.bonus_type {
+                ContractClauseType::MinimumFeeRelease => true,
+                ContractClauseType::MinimumFeeReleaseToForeignClubs => foreign_buyer,
+                ContractClauseType::MinimumFeeReleaseToDomesticClubs => !foreign_buyer,
+                ContractClauseType::RelegationFeeRelease => relegated,
+                _ => false,
+            })
+            .map(|clause| clause.value.max(0) as u32)
+            .min()
+    }
+
+    /// Raises the salary by the percentage the promotion clause promises
+    pub fn apply_promotion_wage_rise(&mut self) -> bool {
+        match self.clause(ContractClauseType::PromotionWageIncrease) {
+            Some(percent) if percent > 0 => {
+                self.salary = (self.salary as f32 * (1.0 + percent as f32 / 100.0)) as u32;
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub fn simulate(&mut self, context: &mut SimulationContext) {
         if context.check_contract_expiration() && self.is_expired(context.date) {}
     }
 }
 
 // Bonuses
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum ContractBonusType {
     AppearanceFee,
     GoalFee,
@@ -94,7 +135,7 @@ pub enum ContractBonusType {
     UnusedSubstitutionFee,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ContractBonus {
     pub value: i32,
     pub bonus_type: ContractBonusType,
@@ -107,7 +148,7 @@ impl ContractBonus {
 }
 
 // Clauses
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum ContractClauseType {
     MinimumFeeRelease,
     RelegationFeeRelease,
@@ -131,7 +172,7 @@ pub enum ContractClauseType {
     OptionalContractExtensionByClub,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ContractClause {
     pub value: i32,
     pub bonus_type: ContractClauseType,
@@ -142,3 +183,48 @@ impl ContractClause {
         ContractClause { value, bonus_type }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract() -> PlayerClubContract {
+        let mut contract = PlayerClubContract::new(1000, NaiveDate::from_ymd_opt(2027, 6, 30).unwrap());
+
+        contract.clauses.push(ContractClause::new(
+            30_000_000,
+            ContractClauseType::MinimumFeeReleaseToDomesticClubs,
+        ));
+        contract.clauses.push(ContractClause::new(
+            20_000_000,
+            ContractClauseType::MinimumFeeReleaseToForeignClubs,
+        ));
+        contract.clauses.push(ContractClause::new(
+            8_000_000,
+            ContractClauseType::RelegationFeeRelease,
+        ));
+
+        contract
+    }
+
+    #[test]
+    fn release_fee_depends_on_buyer_and_relegation() {
+        let contract = contract();
+
+        assert_eq!(Some(30_000_000), contract.release_fee(false, false));
+        assert_eq!(Some(20_000_000), contract.release_fee(true, false));
+        assert_eq!(Some(8_000_000), contract.release_fee(false, true));
+        assert_eq!(None, PlayerClubContract::new(1000, contract.expiration).release_fee(true, true));
+    }
+
+    #[test]
+    fn promotion_raises_wage_only_with_clause() {
+        let mut contract = contract();
+        assert!(!contract.apply_promotion_wage_rise());
+
+        contract.clauses.push(ContractClause::new(20, ContractClauseType::PromotionWageIncrease));
+
+        assert!(contract.apply_promotion_wage_rise());
+        assert_eq!(1200, contract.salary);
+    }
+}
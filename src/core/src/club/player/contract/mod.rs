@@ -1,3 +1,5 @@
 pub mod contract;
+pub mod renewal;
 
 pub use contract::*;
+pub use renewal::*;
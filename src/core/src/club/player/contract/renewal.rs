@@ -0,0 +1,405 @@
+use crate::{
+    ContractBonus, ContractBonusType, ContractClause, ContractClauseType, Person, Player,
+    PlayerClubContract, PlayerSquadStatus, Staff,
+};
+use chrono::{Datelike, NaiveDate};
+
+/// Rounds of talks before either side walks away
+const NEGOTIATION_ROUNDS: u8 = 3;
+
+/// Ambitious players want a way out to a bigger league written into the contract
+const RELEASE_CLAUSE_AMBITION: f32 = 14.0;
+
+/// The most ambitious want it to work for any club
+const OPEN_RELEASE_CLAUSE_AMBITION: f32 = 17.0;
+
+/// Regulars with some ambition won't follow the club down a division
+const RELEGATION_RELEASE_AMBITION: f32 = 10.0;
+
+/// What the player and their agent ask for
+#[derive(Debug, Clone)]
+pub struct ContractDemands {
+    pub salary: u32,
+    /// The least the player will sign for
+    pub min_salary: u32,
+    pub years: u8,
+    pub squad_status: PlayerSquadStatus,
+    pub release_clause: Option<(ContractClauseType, u32)>,
+    pub relegation_release: Option<u32>,
+    pub appearance_fee: u32,
+    pub goal_fee: u32,
+}
+
+/// How far the club is prepared to go
+#[derive(Debug, Clone)]
+pub struct ContractOffer {
+    pub salary: u32,
+    pub max_salary: u32,
+    /// Release clauses below this value are refused
+    pub min_release_clause: u32,
+}
+
+/// Terms both sides agreed on
+#[derive(Debug, Clone)]
+pub struct ContractRenewal {
+    pub salary: u32,
+    pub years: u8,
+    pub squad_status: PlayerSquadStatus,
+    pub release_clause: Option<(ContractClauseType, u32)>,
+    pub relegation_release: Option<u32>,
+    pub appearance_fee: u32,
+    pub goal_fee: u32,
+}
+
+pub struct ContractNegotiation;
+
+impl ContractNegotiation {
+    /// The going rate is what teammates of similar ability earn, ambition raises the ask and loyalty lowers the floor
+    pub fn demands(player: &Player, peer_salary: Option<u32>, date: NaiveDate) -> ContractDemands {
+        let (current_salary, squad_status) = player
+            .contract
+            .as_ref()
+            .map(|contract| (contract.salary, contract.squad_status))
+            .unwrap_or((0, PlayerSquadStatus::NotYetSet));
+
+        let base = current_salary.max(peer_salary.unwrap_or(0)) as f32;
+
+        let status_premium = match squad_status {
+            PlayerSquadStatus::KeyPlayer => 1.25,
+            PlayerSquadStatus::FirstTeamRegular => 1.15,
+            _ => 1.05,
+        };
+
+        let ambition = player.attributes.ambition;
+        let ambition_premium = 1.0 + (ambition - 10.0) / 50.0;
+
+        let salary = (base * status_premium * ambition_premium) as u32;
+        let min_salary = (salary as f32 * (0.95 - player.attributes.loyalty / 20.0 * 0.15)) as u32;
+
+        let age = player.age(date);
+        let mut years = match age {
+            0..=23 => 5,
+            24..=27 => 4,
+            28..=30 => 3,
+            31..=32 => 2,
+            _ => 1,
+        };
+
+        let value = player.value(date);
+
+        let release_clause = if ambition >= RELEASE_CLAUSE_AMBITION {
+            // Young and ambitious players keep their options open
+            if age <= 25 && years > 2 {
+                years -= 1;
+            }
+
+            let clause_type = if ambition >= OPEN_RELEASE_CLAUSE_AMBITION {
+                ContractClauseType::MinimumFeeRelease
+            } else {
+                ContractClauseType::MinimumFeeReleaseToForeignClubs
+            };
+
+            Some((clause_type, (value * (2.5 - ambition as f64 / 20.0)) as u32))
+        } else {
+            None
+        };
+
+        let regular = matches!(
+            squad_status,
+            PlayerSquadStatus::KeyPlayer | PlayerSquadStatus::FirstTeamRegular
+        );
+
+        let relegation_release = if regular && ambition >= RELEGATION_RELEASE_AMBITION {
+            Some((value * 0.75) as u32)
+        } else {
+            None
+        };
+
+        let position = player.position();
+        let scores_goals = position.is_forward() || position.is_midfielder();
+
+        ContractDemands {
+            salary,
+            min_salary,
+            years,
+            squad_status,
+            release_clause,
+            relegation_release,
+            appearance_fee: if regular { salary / 10 } else { salary / 20 },
+            goal_fee: if scores_goals { salary / 5 } else { 0 },
+        }
+    }
+
+    /// The club opens above the current wage and goes furthest for the players it can't do without
+    pub fn offer(
+        player: &Player,
+        peer_salary: Option<u32>,
+        can_afford: bool,
+        date: NaiveDate,
+    ) -> ContractOffer {
+        let (current_salary, squad_status) = player
+            .contract
+            .as_ref()
+            .map(|contract| (contract.salary, contract.squad_status))
+            .unwrap_or((0, PlayerSquadStatus::NotYetSet));
+
+        let importance = match squad_status {
+            PlayerSquadStatus::KeyPlayer => 1.5,
+            PlayerSquadStatus::FirstTeamRegular => 1.3,
+            _ => 1.1,
+        };
+
+        let salary = (current_salary as f32 * 1.05) as u32;
+
+        let max_salary = if can_afford {
+            (current_salary.max(peer_salary.unwrap_or(0)) as f32 * importance) as u32
+        } else {
+            salary
+        };
+
+        let value = player.value(date) as u32;
+
+        ContractOffer {
+            salary: salary.min(max_salary),
+            max_salary,
+            min_release_clause: if squad_status == PlayerSquadStatus::KeyPlayer {
+                value.saturating_mul(2)
+            } else {
+                value
+            },
+        }
+    }
+
+    /// Both sides move towards each other, a persuasive negotiator gets more out of each round
+    pub fn negotiate(
+        demands: &ContractDemands,
+        offer: &ContractOffer,
+        negotiator: &Staff,
+    ) -> Option<ContractRenewal> {
+        let skill = (negotiator.staff_attributes.mental.man_management as f32
+            + negotiator.staff_attributes.mental.motivating as f32)
+            / 40.0;
+
+        let mut ask = demands.salary as f32;
+        let mut bid = offer.salary as f32;
+
+        for _ in 0..NEGOTIATION_ROUNDS {
+            if bid >= ask {
+                break;
+            }
+
+            bid += (offer.max_salary as f32 - bid).max(0.0) * (0.3 + 0.2 * skill);
+            ask -= (ask - demands.min_salary as f32).max(0.0) * (0.3 + 0.4 * skill);
+        }
+
+        if bid < demands.min_salary as f32 {
+            return None;
+        }
+
+        let salary = bid.min(ask) as u32;
+
+        Some(ContractRenewal {
+            salary,
+            years: demands.years,
+            squad_status: demands.squad_status,
+            release_clause: demands
+                .release_clause
+                .map(|(clause_type, fee)| (clause_type, fee.max(offer.min_release_clause))),
+            relegation_release: demands
+                .relegation_release
+                .map(|fee| fee.max(offer.min_release_clause / 2)),
+            appearance_fee: demands.appearance_fee.min(salary / 10),
+            goal_fee: demands.goal_fee.min(salary / 5),
+        })
+    }
+
+    /// New contract running to the end of June of its final season. A renewed contract keeps its
+    /// terms, only the salary, expiry, squad status, bonuses and the clauses agreed are changed
+    pub fn contract(
+        renewal: &ContractRenewal,
+        previous: Option<PlayerClubContract>,
+        date: NaiveDate,
+    ) -> PlayerClubContract {
+        let end_year = if date.month() > 6 {
+            date.year() + renewal.years as i32
+        } else {
+            date.year() + renewal.years as i32 - 1
+        };
+        let expiration = NaiveDate::from_ymd_opt(end_year, 6, 30).unwrap_or(date);
+
+        let mut contract = previous.unwrap_or_else(|| {
+            let mut contract = PlayerClubContract::new(renewal.salary, expiration);
+            contract.started = Some(date);
+            contract
+        });
+
+        contract.salary = renewal.salary;
+        contract.expiration = expiration;
+        contract.squad_status = renewal.squad_status;
+
+        contract.bonuses.retain(|bonus| {
+            !matches!(
+                bonus.bonus_type,
+                ContractBonusType::AppearanceFee | ContractBonusType::GoalFee
+            )
+        });
+
+        if renewal.appearance_fee > 0 {
+            contract.bonuses.push(ContractBonus::new(
+                renewal.appearance_fee as i32,
+                ContractBonusType::AppearanceFee,
+            ));
+        }
+
+        if renewal.goal_fee > 0 {
+            contract.bonuses.push(ContractBonus::new(
+                renewal.goal_fee as i32,
+                ContractBonusType::GoalFee,
+            ));
+        }
+
+        if let Some((clause_type, fee)) = renewal.release_clause {
+            Self::set_clause(&mut contract, clause_type, fee);
+        }
+
+        if let Some(fee) = renewal.relegation_release {
+            Self::set_clause(&mut contract, ContractClauseType::RelegationFeeRelease, fee);
+        }
+
+        contract
+    }
+
+    fn set_clause(contract: &mut PlayerClubContract, clause_type: ContractClauseType, fee: u32) {
+        contract
+            .clauses
+            .retain(|clause| clause.bonus_type != clause_type);
+
+        contract.clauses.push(ContractClause::new(
+            fee.min(i32::MAX as u32) as i32,
+            clause_type,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContractType, StaffStub};
+
+    fn demands() -> ContractDemands {
+        ContractDemands {
+            salary: 1200,
+            min_salary: 1000,
+            years: 3,
+            squad_status: PlayerSquadStatus::KeyPlayer,
+            release_clause: Some((ContractClauseType::MinimumFeeReleaseToForeignClubs, 500_000)),
+            relegation_release: Some(100_000),
+            appearance_fee: 150,
+            goal_fee: 300,
+        }
+    }
+
+    #[test]
+    fn talks_settle_above_the_players_floor_or_break_down() {
+        let negotiator = StaffStub::default();
+
+        let generous = ContractOffer {
+            salary: 900,
+            max_salary: 1300,
+            min_release_clause: 800_000,
+        };
+
+        let renewal = ContractNegotiation::negotiate(&demands(), &generous, &negotiator).unwrap();
+
+        assert!(renewal.salary >= 1000 && renewal.salary <= 1200);
+        assert_eq!(
+            Some((ContractClauseType::MinimumFeeReleaseToForeignClubs, 800_000)),
+            renewal.release_clause
+        );
+        assert_eq!(Some(400_000), renewal.relegation_release);
+        assert!(renewal.appearance_fee <= renewal.salary / 10);
+
+        let tight = ContractOffer {
+            salary: 900,
+            max_salary: 950,
+            min_release_clause: 0,
+        };
+
+        assert!(ContractNegotiation::negotiate(&demands(), &tight, &negotiator).is_none());
+    }
+
+    #[test]
+    fn new_contract_runs_to_the_end_of_june_with_bonuses_and_clause() {
+        let date = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+
+        let renewal = ContractRenewal {
+            salary: 1100,
+            years: 3,
+            squad_status: PlayerSquadStatus::KeyPlayer,
+            release_clause: Some((ContractClauseType::MinimumFeeRelease, 600_000)),
+            relegation_release: Some(300_000),
+            appearance_fee: 100,
+            goal_fee: 0,
+        };
+
+        let contract = ContractNegotiation::contract(&renewal, None, date);
+
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2027, 6, 30).unwrap(),
+            contract.expiration
+        );
+        assert_eq!(Some(100), contract.bonus(ContractBonusType::AppearanceFee));
+        assert_eq!(None, contract.bonus(ContractBonusType::GoalFee));
+        assert_eq!(
+            Some(600_000),
+            contract.clause(ContractClauseType::MinimumFeeRelease)
+        );
+        assert_eq!(
+            Some(300_000),
+            contract.clause(ContractClauseType::RelegationFeeRelease)
+        );
+    }
+
+    #[test]
+    fn renewed_contract_keeps_the_terms_not_negotiated() {
+        let date = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+        let started = NaiveDate::from_ymd_opt(2021, 7, 1).unwrap();
+
+        let mut previous = PlayerClubContract::new(800, NaiveDate::from_ymd_opt(2025, 6, 30).unwrap());
+        previous.started = Some(started);
+        previous.shirt_number = Some(9);
+        previous.contract_type = ContractType::PartTime;
+        previous.bonuses.push(ContractBonus::new(50, ContractBonusType::AppearanceFee));
+        previous.bonuses.push(ContractBonus::new(5_000, ContractBonusType::TopGoalscorer));
+        previous.clauses.push(ContractClause::new(100_000, ContractClauseType::MinimumFeeRelease));
+        previous.clauses.push(ContractClause::new(20, ContractClauseType::PromotionWageIncrease));
+
+        let renewal = ContractRenewal {
+            salary: 1100,
+            years: 3,
+            squad_status: PlayerSquadStatus::KeyPlayer,
+            release_clause: Some((ContractClauseType::MinimumFeeRelease, 600_000)),
+            relegation_release: None,
+            appearance_fee: 100,
+            goal_fee: 0,
+        };
+
+        let contract = ContractNegotiation::contract(&renewal, Some(previous), date);
+
+        assert_eq!(1100, contract.salary);
+        assert_eq!(NaiveDate::from_ymd_opt(2027, 6, 30).unwrap(), contract.expiration);
+        assert_eq!(PlayerSquadStatus::KeyPlayer, contract.squad_status);
+
+        assert_eq!(Some(started), contract.started);
+        assert_eq!(Some(9), contract.shirt_number);
+        assert!(matches!(contract.contract_type, ContractType::PartTime));
+
+        assert_eq!(Some(100), contract.bonus(ContractBonusType::AppearanceFee));
+        assert_eq!(Some(5_000), contract.bonus(ContractBonusType::TopGoalscorer));
+        assert_eq!(2, contract.bonuses.len());
+
+        assert_eq!(Some(600_000), contract.clause(ContractClauseType::MinimumFeeRelease));
+        assert_eq!(Some(20), contract.clause(ContractClauseType::PromotionWageIncrease));
+        assert_eq!(2, contract.clauses.len());
+    }
+}
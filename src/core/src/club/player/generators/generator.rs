@@ -2,7 +2,7 @@ use crate::shared::FullName;
 use crate::utils::IntegerUtils;
 use crate::{
     Mental, PersonAttributes, PersonBehaviour, PersonBehaviourState, Physical, Player,
-    PlayerAttributes, PlayerHappiness, PlayerMailbox, PlayerPosition, PlayerPositionType,
+    PlayerAttributes, PlayerInteractions, PlayerMorale, PlayerMailbox, PlayerPosition, PlayerPositionType,
     PlayerPositions, PlayerPreferredFoot, PlayerSkills, PlayerStatistics, PlayerStatisticsHistory,
     PlayerStatus, PlayerTraining, PlayerTrainingHistory, Relations, Technical,
 };
@@ -31,7 +31,7 @@ impl PlayerGenerator {
             birth_date: NaiveDate::from_ymd_opt(year as i32, month, day).unwrap(),
             country_id,
             behaviour: PersonBehaviour {
-                state: PersonBehaviourState::Poor,
+                state: PersonBehaviourState::Normal,
             },
             attributes: PersonAttributes {
                 adaptability: 10.0,
@@ -43,7 +43,8 @@ impl PlayerGenerator {
                 sportsmanship: 10.0,
                 temperament: 10.0,
             },
-            happiness: PlayerHappiness::new(),
+            morale: PlayerMorale::new(),
+            interactions: PlayerInteractions::new(),
             statuses: PlayerStatus { statuses: vec![] },
             skills: PlayerSkills {
                 technical: Technical {
@@ -118,6 +119,7 @@ impl PlayerGenerator {
             relations: Relations::new(),
             statistics: PlayerStatistics::default(),
             statistics_history: PlayerStatisticsHistory::new(),
+            awards: Vec::new(),
         }
     }
 }
@@ -0,0 +1,231 @@
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Interactions kept in a player's history
+const INTERACTION_HISTORY_SIZE: usize = 50;
+
+const PLAYING_TIME_PROMISE_WEEKS: i64 = 6;
+const NEW_CONTRACT_PROMISE_WEEKS: i64 = 16;
+
+/// What a player asks the club for
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PlayerRequestType {
+    PlayingTime,
+    NewContract,
+    Transfer,
+}
+
+impl PlayerRequestType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlayerRequestType::PlayingTime => "playing_time",
+            PlayerRequestType::NewContract => "new_contract",
+            PlayerRequestType::Transfer => "transfer",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PlayerPromiseType {
+    PlayingTime,
+    NewContract,
+}
+
+impl PlayerPromiseType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlayerPromiseType::PlayingTime => "playing_time",
+            PlayerPromiseType::NewContract => "new_contract",
+        }
+    }
+}
+
+/// Promise the coach made to a player, judged when the deadline passes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerPromise {
+    pub promise_type: PlayerPromiseType,
+    pub staff_id: u32,
+    pub made: NaiveDate,
+    pub deadline: NaiveDate,
+    /// Contract expiry when the promise was made, a new deal runs past it
+    pub contract_expiration: Option<NaiveDate>,
+}
+
+impl PlayerPromise {
+    pub fn playing_time(staff_id: u32, date: NaiveDate) -> Self {
+        PlayerPromise {
+            promise_type: PlayerPromiseType::PlayingTime,
+            staff_id,
+            made: date,
+            deadline: date + Duration::weeks(PLAYING_TIME_PROMISE_WEEKS),
+            contract_expiration: None,
+        }
+    }
+
+    pub fn new_contract(staff_id: u32, date: NaiveDate, contract_expiration: NaiveDate) -> Self {
+        PlayerPromise {
+            promise_type: PlayerPromiseType::NewContract,
+            staff_id,
+            made: date,
+            deadline: date + Duration::weeks(NEW_CONTRACT_PROMISE_WEEKS),
+            contract_expiration: Some(contract_expiration),
+        }
+    }
+
+    pub fn is_due(&self, date: NaiveDate) -> bool {
+        date >= self.deadline
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PlayerInteractionType {
+    Requested(PlayerRequestType),
+    Promised(PlayerPromiseType),
+    Dismissed(PlayerRequestType),
+    TransferListed,
+    ContractRenewed,
+    ContractTalksFailed,
+    PromiseKept(PlayerPromiseType),
+    PromiseBroken(PlayerPromiseType),
+}
+
+impl PlayerInteractionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlayerInteractionType::Requested(_) => "requested",
+            PlayerInteractionType::Promised(_) => "promised",
+            PlayerInteractionType::Dismissed(_) => "dismissed",
+            PlayerInteractionType::TransferListed => "transfer_listed",
+            PlayerInteractionType::ContractRenewed => "contract_renewed",
+            PlayerInteractionType::ContractTalksFailed => "contract_talks_failed",
+            PlayerInteractionType::PromiseKept(_) => "promise_kept",
+            PlayerInteractionType::PromiseBroken(_) => "promise_broken",
+        }
+    }
+
+    /// What the interaction was about
+    pub fn subject(&self) -> &'static str {
+        match self {
+            PlayerInteractionType::Requested(request) | PlayerInteractionType::Dismissed(request) => {
+                request.as_str()
+            }
+            PlayerInteractionType::Promised(promise)
+            | PlayerInteractionType::PromiseKept(promise)
+            | PlayerInteractionType::PromiseBroken(promise) => promise.as_str(),
+            PlayerInteractionType::TransferListed => PlayerRequestType::Transfer.as_str(),
+            PlayerInteractionType::ContractRenewed | PlayerInteractionType::ContractTalksFailed => {
+                PlayerRequestType::NewContract.as_str()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerInteraction {
+    pub date: NaiveDate,
+    pub interaction_type: PlayerInteractionType,
+    /// Staff member on the club's side, none for the player's own requests
+    pub staff_id: Option<u32>,
+}
+
+/// Requests the player made, what the club answered and the promises still open
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PlayerInteractions {
+    pub history: Vec<PlayerInteraction>,
+    pub promises: Vec<PlayerPromise>,
+}
+
+impl PlayerInteractions {
+    pub fn new() -> Self {
+        PlayerInteractions {
+            history: Vec::with_capacity(INTERACTION_HISTORY_SIZE),
+            promises: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, date: NaiveDate, interaction_type: PlayerInteractionType, staff_id: Option<u32>) {
+        if self.history.len() >= INTERACTION_HISTORY_SIZE {
+            self.history.remove(0);
+        }
+
+        self.history.push(PlayerInteraction {
+            date,
+            interaction_type,
+            staff_id,
+        });
+    }
+
+    pub fn promise(&mut self, promise: PlayerPromise) {
+        self.record(promise.made, PlayerInteractionType::Promised(promise.promise_type), Some(promise.staff_id));
+        self.promises.push(promise);
+    }
+
+    pub fn has_promise(&self, promise_type: PlayerPromiseType) -> bool {
+        self.promises.iter().any(|promise| promise.promise_type == promise_type)
+    }
+
+    /// Removes and returns the promises the player is ready to judge
+    pub fn take_promises(&mut self, ready: impl Fn(&PlayerPromise) -> bool) -> Vec<PlayerPromise> {
+        let (taken, open) = self.promises.drain(..).partition(|promise| ready(promise));
+        self.promises = open;
+        taken
+    }
+
+    /// Days since the last interaction of this type
+    pub fn days_since(&self, interaction_type: PlayerInteractionType, date: NaiveDate) -> Option<i64> {
+        self.history
+            .iter()
+            .rev()
+            .find(|interaction| interaction.interaction_type == interaction_type)
+            .map(|interaction| (date - interaction.date).num_days())
+    }
+
+    /// Days since the player last made this request
+    pub fn days_since_request(&self, request: PlayerRequestType, date: NaiveDate) -> Option<i64> {
+        self.days_since(PlayerInteractionType::Requested(request), date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 9, 2).unwrap()
+    }
+
+    #[test]
+    fn due_promises_are_taken_and_the_rest_stay_open() {
+        let mut interactions = PlayerInteractions::new();
+
+        interactions.promise(PlayerPromise::playing_time(1, date()));
+        interactions.promise(PlayerPromise::new_contract(1, date(), date() + Duration::days(300)));
+
+        let later = date() + Duration::weeks(PLAYING_TIME_PROMISE_WEEKS);
+        let due = interactions.take_promises(|promise| promise.is_due(later));
+
+        assert_eq!(1, due.len());
+        assert_eq!(PlayerPromiseType::PlayingTime, due[0].promise_type);
+        assert!(interactions.has_promise(PlayerPromiseType::NewContract));
+        assert!(!interactions.has_promise(PlayerPromiseType::PlayingTime));
+    }
+
+    #[test]
+    fn history_is_capped_and_remembers_the_last_request() {
+        let mut interactions = PlayerInteractions::new();
+
+        for day in 0..(INTERACTION_HISTORY_SIZE as i64 + 10) {
+            interactions.record(
+                date() + Duration::days(day),
+                PlayerInteractionType::Requested(PlayerRequestType::PlayingTime),
+                None,
+            );
+        }
+
+        assert_eq!(INTERACTION_HISTORY_SIZE, interactions.history.len());
+
+        let now = date() + Duration::days(INTERACTION_HISTORY_SIZE as i64 + 20);
+        assert_eq!(Some(11), interactions.days_since_request(PlayerRequestType::PlayingTime, now));
+        assert_eq!(None, interactions.days_since_request(PlayerRequestType::Transfer, now));
+    }
+}
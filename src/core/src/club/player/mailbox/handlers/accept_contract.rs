@@ -1,10 +1,14 @@
 use crate::{ContractType, Player, PlayerClubContract, PlayerContractProposal, PlayerSquadStatus};
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
 
 pub struct AcceptContractHandler;
 
 impl AcceptContractHandler {
     pub fn process(player: &mut Player, proposal: PlayerContractProposal, now: NaiveDate) {
+        let expiration = now
+            .with_year(now.year() + proposal.years.max(1) as i32)
+            .unwrap_or(now);
+
         player.contract = Some(PlayerClubContract {
             shirt_number: None,
             salary: proposal.salary,
@@ -12,8 +16,8 @@ impl AcceptContractHandler {
             squad_status: PlayerSquadStatus::FirstTeamRegular,
             is_transfer_listed: false,
             transfer_status: Option::None,
-            started: Option::None,
-            expiration: now, //TODO ADD YEARS
+            started: Some(now),
+            expiration,
             bonuses: vec![],
             clauses: vec![],
         });
@@ -0,0 +1,31 @@
+use crate::{Player, PlayerInteractionType, PlayerRequestDismissal, PlayerRequestType, PlayerStatusType};
+use chrono::NaiveDate;
+
+/// Morale a player loses when the coach brushes off a complaint
+const DISMISSED_REQUEST_MORALE: f32 = 5.0;
+
+pub struct DismissedRequestHandler;
+
+impl DismissedRequestHandler {
+    pub fn process(player: &mut Player, dismissal: PlayerRequestDismissal, now: NaiveDate) {
+        match dismissal.request {
+            PlayerRequestType::Transfer => {
+                player.morale.reject_transfer_request(now);
+                player.statuses.remove(PlayerStatusType::Req);
+            }
+            _ => {
+                player.morale.change(-DISMISSED_REQUEST_MORALE);
+
+                if !player.statuses.get().contains(&PlayerStatusType::Unh) {
+                    player.statuses.add(now, PlayerStatusType::Unh);
+                }
+            }
+        }
+
+        player.interactions.record(
+            now,
+            PlayerInteractionType::Dismissed(dismissal.request),
+            Some(dismissal.staff_id),
+        );
+    }
+}
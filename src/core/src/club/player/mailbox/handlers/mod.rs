@@ -1,5 +1,9 @@
 mod accept_contract;
 mod contract_proposal;
+mod dismissed_request;
+mod promise;
 
 pub use accept_contract::*;
 pub use contract_proposal::*;
+pub use dismissed_request::*;
+pub use promise::*;
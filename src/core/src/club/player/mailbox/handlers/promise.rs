@@ -0,0 +1,15 @@
+use crate::{Player, PlayerPromise, PlayerStatusType};
+
+/// Morale a player gains from being promised what they asked for
+const PROMISE_MORALE: f32 = 5.0;
+
+pub struct PromiseHandler;
+
+impl PromiseHandler {
+    pub fn process(player: &mut Player, promise: PlayerPromise) {
+        player.morale.change(PROMISE_MORALE);
+        player.statuses.remove(PlayerStatusType::Unh);
+
+        player.interactions.promise(promise);
+    }
+}
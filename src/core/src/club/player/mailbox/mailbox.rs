@@ -1,27 +1,37 @@
-use crate::handlers::ProcessContractHandler;
-use crate::{Player, PlayerMailboxResult, PlayerResult};
+use crate::handlers::{DismissedRequestHandler, ProcessContractHandler, PromiseHandler};
+use crate::{Player, PlayerMailboxResult, PlayerPromise, PlayerRequestType, PlayerResult};
 use chrono::NaiveDate;
 use std::collections::VecDeque;
 use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerMessage {
     pub message_type: PlayerMessageType,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum PlayerMessageType {
     Greeting,
     ContractProposal(PlayerContractProposal),
+    Promise(PlayerPromise),
+    RequestDismissed(PlayerRequestDismissal),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerContractProposal {
     pub salary: u32,
     pub years: u8,
 }
 
-#[derive(Debug)]
+/// The coach turning down what the player asked for
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlayerRequestDismissal {
+    pub request: PlayerRequestType,
+    pub staff_id: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerMailbox {
     messages: Mutex<VecDeque<PlayerMessage>>,
 }
@@ -46,6 +56,12 @@ impl PlayerMailbox {
                 PlayerMessageType::ContractProposal(proposal) => {
                     ProcessContractHandler::process(player, proposal, now, player_result);
                 }
+                PlayerMessageType::Promise(promise) => {
+                    PromiseHandler::process(player, promise);
+                }
+                PlayerMessageType::RequestDismissed(dismissal) => {
+                    DismissedRequestHandler::process(player, dismissal, now);
+                }
             }
         }
 
@@ -3,8 +3,9 @@ pub mod calculators;
 pub mod context;
 pub mod contract;
 pub mod generators;
-pub mod happiness;
+pub mod interaction;
 pub mod mailbox;
+pub mod morale;
 pub mod player;
 pub mod position;
 pub mod result;
@@ -21,8 +22,9 @@ pub use calculators::*;
 pub use context::*;
 pub use contract::*;
 pub use generators::*;
-pub use happiness::*;
+pub use interaction::*;
 pub use mailbox::*;
+pub use morale::*;
 pub use player::*;
 pub use position::*;
 pub use result::*;
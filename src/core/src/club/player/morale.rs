@@ -0,0 +1,355 @@
+use crate::{PersonAttributes, PlayerSquadStatus};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+const MIN_MORALE: f32 = 0.0;
+const MAX_MORALE: f32 = 100.0;
+const DEFAULT_MORALE: f32 = 55.0;
+
+/// Weeks of appearances a player looks back on when judging their playing time
+const PLAYING_TIME_WEEKS: usize = 4;
+
+/// Weeks a rejected transfer request keeps hurting, and before the player asks again
+const REJECTED_REQUEST_WEEKS: i64 = 12;
+const REJECTED_REQUEST_PENALTY: f32 = 15.0;
+
+/// Neutral coaching multiplier of a fresh relationship with the coach
+const NEUTRAL_COACH_RECEPTIVENESS: f32 = 1.4;
+
+/// What the player makes of their situation at the weekly review
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MoraleSituation {
+    /// League starts made this season
+    pub appearances: u16,
+    /// Matches the team played since the last review
+    pub team_matches: u16,
+    pub is_injured: bool,
+    /// Salary compared to teammates of similar ability, none without a contract
+    pub wage_ratio: Option<f32>,
+    /// Points per game in the team's recent matches
+    pub points_per_game: Option<f32>,
+    /// Coaching multiplier of the relationship with the head coach
+    pub coach_receptiveness: Option<f32>,
+}
+
+/// Contribution of each concern to the player's morale, positive or negative
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct MoraleFactors {
+    pub playing_time: f32,
+    pub wage: f32,
+    pub team_results: f32,
+    pub coach: f32,
+    pub transfer_request: f32,
+    pub personality: f32,
+}
+
+impl MoraleFactors {
+    pub fn total(&self) -> f32 {
+        self.playing_time
+            + self.wage
+            + self.team_results
+            + self.coach
+            + self.transfer_request
+            + self.personality
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlayerMorale {
+    /// Morale from 0 (wants out) to 100 (couldn't be happier)
+    pub value: f32,
+    pub state: PlayerMoraleState,
+    pub factors: MoraleFactors,
+    pub transfer_requested: bool,
+    pub transfer_request_rejected: Option<NaiveDate>,
+    last_appearances: u16,
+    recent_playing_time: Vec<(u16, u16)>,
+}
+
+impl Default for PlayerMorale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlayerMorale {
+    pub fn new() -> Self {
+        PlayerMorale {
+            value: DEFAULT_MORALE,
+            state: PlayerMoraleState::from_value(DEFAULT_MORALE),
+            factors: MoraleFactors::default(),
+            transfer_requested: false,
+            transfer_request_rejected: None,
+            last_appearances: 0,
+            recent_playing_time: Vec::with_capacity(PLAYING_TIME_WEEKS),
+        }
+    }
+
+    pub fn is_happy(&self) -> bool {
+        matches!(self.state, PlayerMoraleState::Good | PlayerMoraleState::Superb)
+    }
+
+    pub fn change(&mut self, delta: f32) {
+        self.set_value(self.value + delta);
+    }
+
+    fn set_value(&mut self, value: f32) {
+        self.value = value.clamp(MIN_MORALE, MAX_MORALE);
+        self.state = PlayerMoraleState::from_value(self.value);
+    }
+
+    /// Weekly review, morale moves towards what the player's situation justifies
+    pub fn review(
+        &mut self,
+        situation: &MoraleSituation,
+        squad_status: Option<&PlayerSquadStatus>,
+        attributes: &PersonAttributes,
+        date: NaiveDate,
+    ) {
+        let played = situation.appearances.saturating_sub(self.last_appearances);
+        self.last_appearances = situation.appearances;
+
+        // Injured players don't expect to play
+        if !situation.is_injured && situation.team_matches > 0 {
+            if self.recent_playing_time.len() >= PLAYING_TIME_WEEKS {
+                self.recent_playing_time.remove(0);
+            }
+            self.recent_playing_time.push((played.min(situation.team_matches), situation.team_matches));
+        }
+
+        // Ambitious players care more about playing and being paid, pressure handlers shrug off results
+        let ambition = 0.5 + attributes.ambition / 20.0;
+        let pressure = 1.5 - attributes.pressure / 20.0;
+
+        self.factors = MoraleFactors {
+            playing_time: self.playing_time_factor(squad_status) * ambition,
+            wage: situation.wage_ratio.map_or(0.0, Self::wage_factor) * ambition,
+            team_results: situation.points_per_game.map_or(0.0, Self::results_factor) * pressure,
+            coach: situation.coach_receptiveness.map_or(0.0, Self::coach_factor),
+            transfer_request: self.rejected_request_factor(date),
+            personality: Self::personality_factor(attributes),
+        };
+
+        let target = (DEFAULT_MORALE + self.factors.total()).clamp(MIN_MORALE, MAX_MORALE);
+
+        // Even-tempered players take things in their stride
+        let rate = 0.4 - attributes.temperament / 20.0 * 0.25;
+
+        self.set_value(self.value + (target - self.value) * rate);
+    }
+
+    /// Players on a poor run of morale play below themselves, confident ones slightly above
+    pub fn match_factor(&self) -> f32 {
+        0.92 + 0.13 * self.value / MAX_MORALE
+    }
+
+    pub fn training_factor(&self) -> f32 {
+        0.8 + 0.3 * self.value / MAX_MORALE
+    }
+
+    /// Share of the team's recent matches the player started, none before the first match
+    pub fn playing_time_share(&self) -> Option<f32> {
+        let (played, matches) = self.recent_playing_time();
+
+        if matches == 0 {
+            return None;
+        }
+
+        Some(played as f32 / matches as f32)
+    }
+
+    /// Loyal players put up with a lot more before asking to leave
+    pub fn wants_to_leave(&self, attributes: &PersonAttributes, date: NaiveDate) -> bool {
+        if self.transfer_requested || self.is_rejection_recent(date) {
+            return false;
+        }
+
+        self.value < 25.0 - attributes.loyalty
+    }
+
+    pub fn request_transfer(&mut self) {
+        self.transfer_requested = true;
+    }
+
+    pub fn reject_transfer_request(&mut self, date: NaiveDate) {
+        self.transfer_requested = false;
+        self.transfer_request_rejected = Some(date);
+        self.change(-REJECTED_REQUEST_PENALTY);
+    }
+
+    fn is_rejection_recent(&self, date: NaiveDate) -> bool {
+        self.transfer_request_rejected
+            .is_some_and(|rejected| (date - rejected).num_weeks() < REJECTED_REQUEST_WEEKS)
+    }
+
+    fn playing_time_factor(&self, squad_status: Option<&PlayerSquadStatus>) -> f32 {
+        let expected = match squad_status.and_then(Self::expected_playing_time) {
+            Some(expected) => expected,
+            None => return 0.0,
+        };
+
+        let share = match self.playing_time_share() {
+            Some(share) => share,
+            None => return 0.0,
+        };
+
+        // Falling short of what was promised hurts more than playing more than expected pleases
+        if share < expected {
+            (share - expected) * 40.0
+        } else {
+            (share - expected) * 10.0
+        }
+    }
+
+    fn recent_playing_time(&self) -> (u16, u16) {
+        self.recent_playing_time
+            .iter()
+            .fold((0, 0), |(played, matches), (p, m)| (played + p, matches + m))
+    }
+
+    /// Share of matches the squad status promises
+    fn expected_playing_time(status: &PlayerSquadStatus) -> Option<f32> {
+        match status {
+            PlayerSquadStatus::KeyPlayer => Some(0.8),
+            PlayerSquadStatus::FirstTeamRegular => Some(0.6),
+            PlayerSquadStatus::FirstTeamSquadRotation => Some(0.35),
+            PlayerSquadStatus::MainBackupPlayer => Some(0.15),
+            PlayerSquadStatus::HotProspectForTheFuture => Some(0.1),
+            PlayerSquadStatus::DecentYoungster | PlayerSquadStatus::NotNeeded => Some(0.0),
+            _ => None,
+        }
+    }
+
+    fn wage_factor(wage_ratio: f32) -> f32 {
+        ((wage_ratio - 1.0) * 15.0).clamp(-12.0, 5.0)
+    }
+
+    fn results_factor(points_per_game: f32) -> f32 {
+        (points_per_game - 1.4) * 6.0
+    }
+
+    fn coach_factor(receptiveness: f32) -> f32 {
+        ((receptiveness - NEUTRAL_COACH_RECEPTIVENESS) * 25.0).clamp(-10.0, 10.0)
+    }
+
+    fn rejected_request_factor(&self, date: NaiveDate) -> f32 {
+        match self.transfer_request_rejected {
+            Some(rejected) if self.is_rejection_recent(date) => {
+                let weeks = (date - rejected).num_weeks() as f32;
+                -REJECTED_REQUEST_PENALTY * (1.0 - weeks / REJECTED_REQUEST_WEEKS as f32)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Professionals keep their spirits up, controversial characters are rarely content
+    fn personality_factor(attributes: &PersonAttributes) -> f32 {
+        (attributes.professionalism - 10.0) * 0.5 - (attributes.controversy - 10.0) * 0.5
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PlayerMoraleState {
+    VeryPoor,
+    Poor,
+    Okay,
+    Good,
+    Superb,
+}
+
+impl PlayerMoraleState {
+    pub fn from_value(value: f32) -> Self {
+        match value {
+            v if v < 20.0 => PlayerMoraleState::VeryPoor,
+            v if v < 40.0 => PlayerMoraleState::Poor,
+            v if v < 60.0 => PlayerMoraleState::Okay,
+            v if v < 80.0 => PlayerMoraleState::Good,
+            _ => PlayerMoraleState::Superb,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlayerMoraleState::VeryPoor => "Very Poor",
+            PlayerMoraleState::Poor => "Poor",
+            PlayerMoraleState::Okay => "Okay",
+            PlayerMoraleState::Good => "Good",
+            PlayerMoraleState::Superb => "Superb",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attributes() -> PersonAttributes {
+        PersonAttributes {
+            adaptability: 10.0,
+            ambition: 10.0,
+            controversy: 10.0,
+            loyalty: 10.0,
+            pressure: 10.0,
+            professionalism: 10.0,
+            sportsmanship: 10.0,
+            temperament: 10.0,
+        }
+    }
+
+    fn date(week: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 9, 2).unwrap() + chrono::Duration::weeks(week as i64)
+    }
+
+    #[test]
+    fn key_player_left_out_loses_morale() {
+        let mut morale = PlayerMorale::new();
+
+        for week in 0..6 {
+            let situation = MoraleSituation {
+                team_matches: 1,
+                ..Default::default()
+            };
+
+            morale.review(&situation, Some(&PlayerSquadStatus::KeyPlayer), &attributes(), date(week));
+        }
+
+        assert!(morale.factors.playing_time < -20.0);
+        assert!(morale.value < 40.0);
+    }
+
+    #[test]
+    fn winning_and_playing_lifts_morale() {
+        let mut morale = PlayerMorale::new();
+
+        for week in 0..6 {
+            let situation = MoraleSituation {
+                appearances: week as u16 + 1,
+                team_matches: 1,
+                wage_ratio: Some(1.2),
+                points_per_game: Some(2.6),
+                ..Default::default()
+            };
+
+            morale.review(&situation, Some(&PlayerSquadStatus::FirstTeamRegular), &attributes(), date(week));
+        }
+
+        assert!(morale.is_happy());
+        assert!(morale.match_factor() > 1.0);
+    }
+
+    #[test]
+    fn rejected_request_hurts_and_delays_the_next_one() {
+        let mut morale = PlayerMorale::new();
+        morale.change(-50.0);
+
+        assert!(morale.wants_to_leave(&attributes(), date(0)));
+
+        morale.request_transfer();
+        assert!(!morale.wants_to_leave(&attributes(), date(0)));
+
+        morale.reject_transfer_request(date(1));
+        assert!(!morale.wants_to_leave(&attributes(), date(5)));
+        assert!(morale.rejected_request_factor(date(5)) < 0.0);
+        assert!(morale.wants_to_leave(&attributes(), date(14)));
+    }
+}
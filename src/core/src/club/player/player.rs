@@ -1,3 +1,4 @@
+use crate::awards::Award;
 use crate::club::player::utils::PlayerUtils;
 use crate::club::{
     PersonBehaviour, PlayerAttributes, PlayerClubContract, PlayerCollectionResult, PlayerMailbox,
@@ -7,16 +8,35 @@ use crate::context::GlobalContext;
 use crate::shared::fullname::FullName;
 use crate::utils::{DateUtils, Logging};
 use crate::{
-    Person, PersonAttributes, PlayerHappiness, PlayerPositionType, PlayerPositions,
-    PlayerStatistics, PlayerStatisticsHistory, PlayerStatus, PlayerTrainingHistory,
-    PlayerValueCalculator, Relations,
+    ChangeType, Person, PersonAttributes, PlayerInteractionType, PlayerInteractions, PlayerMorale,
+    PlayerPositionType, PlayerPositions, PlayerPromise, PlayerPromiseType, PlayerRequestType,
+    PlayerSquadStatus, PlayerStatistics, PlayerStatisticsHistory, PlayerStatus, PlayerStatusType,
+    PlayerTrainingHistory, PlayerValueCalculator, RelationshipChange, Relations,
 };
 use chrono::{NaiveDate, NaiveDateTime};
 use std::fmt::{Display, Formatter, Result};
 use std::ops::Index;
 use crate::club::player::builder::PlayerBuilder;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+/// Days before a player raises the same request again
+const REQUEST_INTERVAL_DAYS: i64 = 28;
+
+/// Morale factors bad enough for the player to go and see the coach
+const PLAYING_TIME_COMPLAINT: f32 = -8.0;
+const WAGE_COMPLAINT: f32 = -10.0;
+
+/// Key players want to talk about a new deal with this much of the contract left
+const NEW_CONTRACT_REQUEST_DAYS: i64 = 365;
+
+const PROMISED_PLAYING_TIME_SHARE: f32 = 0.5;
+const PROMISE_KEPT_MORALE: f32 = 5.0;
+const PROMISE_BROKEN_MORALE: f32 = 20.0;
+
+/// Teammates within this much current ability are who a player compares their wage with
+const WAGE_PEER_ABILITY_RANGE: u8 = 15;
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Player {
     //person data
     pub id: u32,
@@ -27,7 +47,8 @@ pub struct Player {
     pub attributes: PersonAttributes,
 
     //player data
-    pub happiness: PlayerHappiness,
+    pub morale: PlayerMorale,
+    pub interactions: PlayerInteractions,
     pub statuses: PlayerStatus,
     pub skills: PlayerSkills,
     pub contract: Option<PlayerClubContract>,
@@ -41,6 +62,7 @@ pub struct Player {
 
     pub statistics: PlayerStatistics,
     pub statistics_history: PlayerStatisticsHistory,
+    pub awards: Vec<Award>,
 }
 
 impl Player {
@@ -60,13 +82,114 @@ impl Player {
         self.process_contract(&mut result, now);
         self.process_mailbox(&mut result, now.date());
 
-        if self.behaviour.is_poor() {
-            result.request_transfer(self.id);
+        if ctx.simulation.is_week_beginning() {
+            self.process_promises(now.date());
+            self.process_requests(&mut result, now);
+        }
+
+        if !self.is_transfer_listed() && self.morale.wants_to_leave(&self.attributes, now.date()) {
+            self.morale.request_transfer();
+            self.statuses.add(now.date(), PlayerStatusType::Req);
+            self.request(&mut result, PlayerRequestType::Transfer, now.date());
         }
 
         result
     }
 
+    /// Unhappy players knock on the coach's door, though not every week about the same thing
+    fn process_requests(&mut self, result: &mut PlayerResult, now: NaiveDateTime) {
+        let date = now.date();
+
+        let can_ask = |interactions: &PlayerInteractions, request: PlayerRequestType| {
+            interactions
+                .days_since_request(request, date)
+                .is_none_or(|days| days >= REQUEST_INTERVAL_DAYS)
+        };
+
+        if self.morale.factors.playing_time <= PLAYING_TIME_COMPLAINT
+            && !self.interactions.has_promise(PlayerPromiseType::PlayingTime)
+            && can_ask(&self.interactions, PlayerRequestType::PlayingTime)
+        {
+            self.request(result, PlayerRequestType::PlayingTime, date);
+        }
+
+        let wants_new_contract = self.contract.as_ref().is_some_and(|contract| {
+            let is_important = matches!(
+                contract.squad_status,
+                PlayerSquadStatus::KeyPlayer | PlayerSquadStatus::FirstTeamRegular
+            );
+
+            !contract.is_transfer_listed
+                && (self.morale.factors.wage <= WAGE_COMPLAINT && !self.morale.is_happy()
+                    || is_important && contract.days_to_expiration(now) < NEW_CONTRACT_REQUEST_DAYS)
+        });
+
+        if wants_new_contract
+            && !self.interactions.has_promise(PlayerPromiseType::NewContract)
+            && can_ask(&self.interactions, PlayerRequestType::NewContract)
+        {
+            self.request(result, PlayerRequestType::NewContract, date);
+        }
+    }
+
+    fn request(&mut self, result: &mut PlayerResult, request: PlayerRequestType, date: NaiveDate) {
+        self.interactions
+            .record(date, PlayerInteractionType::Requested(request), None);
+
+        result.request(request);
+    }
+
+    /// Promises are judged at their deadline, a new contract counts as soon as it is signed
+    fn process_promises(&mut self, date: NaiveDate) {
+        let playing_time_share = self.morale.playing_time_share();
+        let contract_expiration = self.contract.as_ref().map(|contract| contract.expiration);
+
+        let is_kept = |promise: &PlayerPromise| match promise.promise_type {
+            PlayerPromiseType::PlayingTime => {
+                playing_time_share.is_some_and(|share| share >= PROMISED_PLAYING_TIME_SHARE)
+            }
+            PlayerPromiseType::NewContract => contract_expiration > promise.contract_expiration,
+        };
+
+        let judged = self
+            .interactions
+            .take_promises(|promise| promise.is_due(date) || is_kept(promise));
+
+        for promise in judged {
+            if is_kept(&promise) {
+                self.morale.change(PROMISE_KEPT_MORALE);
+                self.relations.update_staff_relationship(
+                    promise.staff_id,
+                    RelationshipChange::positive(ChangeType::PersonalSupport, 0.5),
+                    date,
+                );
+
+                self.interactions.record(
+                    date,
+                    PlayerInteractionType::PromiseKept(promise.promise_type),
+                    Some(promise.staff_id),
+                );
+            } else {
+                self.morale.change(-PROMISE_BROKEN_MORALE);
+                self.relations.update_staff_relationship(
+                    promise.staff_id,
+                    RelationshipChange::negative(ChangeType::BrokenPromise, 2.0),
+                    date,
+                );
+
+                if !self.statuses.get().contains(&PlayerStatusType::Unh) {
+                    self.statuses.add(date, PlayerStatusType::Unh);
+                }
+
+                self.interactions.record(
+                    date,
+                    PlayerInteractionType::PromiseBroken(promise.promise_type),
+                    Some(promise.staff_id),
+                );
+            }
+        }
+    }
+
     fn process_contract(&mut self, result: &mut PlayerResult, now: NaiveDateTime) {
         if let Some(ref mut contract) = self.contract {
             const HALF_YEAR_DAYS: i64 = 30 * 6;
@@ -109,6 +232,10 @@ impl Player {
             .expect("no position found")
     }
 
+    pub fn is_transfer_listed(&self) -> bool {
+        self.contract.as_ref().is_some_and(|contract| contract.is_transfer_listed)
+    }
+
     pub fn preferred_foot_str(&self) -> &'static str {
         match self.preferred_foot {
             PlayerPreferredFoot::Left => "Left",
@@ -125,6 +252,10 @@ impl Player {
         }
     }
 
+    pub fn is_on_international_duty(&self) -> bool {
+        self.statuses.get().contains(&PlayerStatusType::Int)
+    }
+
     pub fn growth_potential(&self, now: NaiveDate) -> u8 {
         PlayerUtils::growth_potential(self, now)
     }
@@ -156,7 +287,7 @@ impl Person for Player {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum PlayerPreferredFoot {
     Left,
     Right,
@@ -170,9 +301,8 @@ impl Display for Player {
     }
 }
 
-const DEFAULT_PLAYER_TRANSFER_BUFFER_SIZE: usize = 10;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerCollection {
     pub players: Vec<Player>,
 }
@@ -195,15 +325,7 @@ impl PlayerCollection {
             })
             .collect();
 
-        let mut outgoing_players = Vec::with_capacity(DEFAULT_PLAYER_TRANSFER_BUFFER_SIZE);
-
-        for transfer_request_player_id in player_results.iter().flat_map(|p| &p.transfer_requests) {
-            if let Some(player) = self.take_player(transfer_request_player_id) {
-                outgoing_players.push(player)
-            }
-        }
-
-        PlayerCollectionResult::new(player_results, outgoing_players)
+        PlayerCollectionResult::new(player_results)
     }
 
     pub fn by_position(&self, position: &PlayerPositionType) -> Vec<&Player> {
@@ -231,6 +353,25 @@ impl PlayerCollection {
             .sum::<u32>()
     }
 
+    /// Average salary of teammates of similar ability, what a player measures their own wage against
+    pub fn peer_salary(&self, player: &Player) -> Option<u32> {
+        let ability = player.player_attributes.current_ability;
+
+        let (total, count) = self
+            .players
+            .iter()
+            .filter(|peer| peer.id != player.id)
+            .filter(|peer| peer.player_attributes.current_ability.abs_diff(ability) <= WAGE_PEER_ABILITY_RANGE)
+            .filter_map(|peer| peer.contract.as_ref())
+            .fold((0u64, 0u64), |(total, count), contract| (total + contract.salary as u64, count + 1));
+
+        if count == 0 || total == 0 {
+            return None;
+        }
+
+        Some((total / count) as u32)
+    }
+
     pub fn players(&self) -> Vec<&Player> {
         self.players.iter().map(|player| player).collect()
     }
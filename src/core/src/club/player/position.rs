@@ -1,7 +1,7 @@
-use serde::Serialize;
 use std::fmt::{Display, Formatter, Result};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Serialize)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Serialize, Deserialize)]
 pub enum PlayerPositionType {
     Goalkeeper,
     Sweeper,
@@ -62,6 +62,35 @@ impl PlayerPositionType {
         }
     }
 
+    /// Reverse of `get_short_name`
+    pub fn from_short_name(short_name: &str) -> Option<Self> {
+        match short_name {
+            "GK" => Some(PlayerPositionType::Goalkeeper),
+            "SW" => Some(PlayerPositionType::Sweeper),
+            "DL" => Some(PlayerPositionType::DefenderLeft),
+            "DCL" => Some(PlayerPositionType::DefenderCenterLeft),
+            "DC" => Some(PlayerPositionType::DefenderCenter),
+            "DCR" => Some(PlayerPositionType::DefenderCenterRight),
+            "DR" => Some(PlayerPositionType::DefenderRight),
+            "DM" => Some(PlayerPositionType::DefensiveMidfielder),
+            "ML" => Some(PlayerPositionType::MidfielderLeft),
+            "MCL" => Some(PlayerPositionType::MidfielderCenterLeft),
+            "MC" => Some(PlayerPositionType::MidfielderCenter),
+            "MCR" => Some(PlayerPositionType::MidfielderCenterRight),
+            "MR" => Some(PlayerPositionType::MidfielderRight),
+            "AML" => Some(PlayerPositionType::AttackingMidfielderLeft),
+            "AMC" => Some(PlayerPositionType::AttackingMidfielderCenter),
+            "AMR" => Some(PlayerPositionType::AttackingMidfielderRight),
+            "WL" => Some(PlayerPositionType::WingbackLeft),
+            "WR" => Some(PlayerPositionType::WingbackRight),
+            "FL" => Some(PlayerPositionType::ForwardLeft),
+            "FC" => Some(PlayerPositionType::ForwardCenter),
+            "FR" => Some(PlayerPositionType::ForwardRight),
+            "ST" => Some(PlayerPositionType::Striker),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn is_goalkeeper(&self) -> bool {
         self.position_group() == PlayerFieldPositionGroup::Goalkeeper
@@ -111,12 +140,13 @@ impl PlayerPositionType {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerPositions {
     pub positions: Vec<PlayerPosition>,
 }
 
-const REQUIRED_POSITION_LEVEL: u8 = 15;
+/// Level from which a player can play the position
+pub const REQUIRED_POSITION_LEVEL: u8 = 15;
 
 impl PlayerPositions {
     pub fn positions(&self) -> Vec<PlayerPositionType> {
@@ -150,7 +180,7 @@ impl PlayerPositions {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerPosition {
     pub position: PlayerPositionType,
     pub level: u8,
@@ -191,6 +221,23 @@ mod tests {
         assert_eq!("WR", PlayerPositionType::WingbackRight.get_short_name());
     }
 
+    #[test]
+    fn short_position_names_read_back() {
+        for position in [
+            PlayerPositionType::Goalkeeper,
+            PlayerPositionType::DefenderCenterLeft,
+            PlayerPositionType::AttackingMidfielderCenter,
+            PlayerPositionType::Striker,
+        ] {
+            assert_eq!(
+                Some(position),
+                PlayerPositionType::from_short_name(position.get_short_name())
+            );
+        }
+
+        assert_eq!(None, PlayerPositionType::from_short_name("XX"));
+    }
+
     #[test]
     fn display_positions_return_with_over_15_level() {
         let positions = PlayerPositions {
@@ -224,7 +271,7 @@ mod tests {
     }
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum PlayerFieldPositionGroup {
     Goalkeeper,
     Defender,
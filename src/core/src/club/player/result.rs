@@ -1,17 +1,13 @@
-use crate::club::Player;
 use crate::simulator::SimulatorData;
+use crate::PlayerRequestType;
 
 pub struct PlayerCollectionResult {
     pub players: Vec<PlayerResult>,
-    pub outgoing_players: Vec<Player>,
 }
 
 impl PlayerCollectionResult {
-    pub fn new(players: Vec<PlayerResult>, outgoing_players: Vec<Player>) -> Self {
-        PlayerCollectionResult {
-            players,
-            outgoing_players,
-        }
+    pub fn new(players: Vec<PlayerResult>) -> Self {
+        PlayerCollectionResult { players }
     }
 
     pub fn process(&self, data: &mut SimulatorData) {
@@ -25,7 +21,7 @@ pub struct PlayerResult {
     pub player_id: u32,
     pub contract: PlayerContractResult,
     pub is_transfer_requested: bool,
-    pub transfer_requests: Vec<u32>,
+    pub requests: Vec<PlayerRequestType>,
 }
 
 pub struct PlayerContractResult {
@@ -46,14 +42,14 @@ impl PlayerResult {
                 want_extend_contract: false,
             },
             is_transfer_requested: false,
-            transfer_requests: Vec::new(),
+            requests: Vec::new(),
         }
     }
 
     pub fn process(&self, _: &mut SimulatorData) {}
 
-    pub fn request_transfer(&mut self, player_id: u32) {
-        self.transfer_requests.push(player_id);
+    pub fn request(&mut self, request: PlayerRequestType) {
+        self.requests.push(request);
     }
 
     pub fn has_contract_actions(&self) -> bool {
@@ -1,4 +1,6 @@
-#[derive(Debug, Copy, Clone, Default)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
 pub struct PlayerSkills {
     pub technical: Technical,
     pub mental: Mental,
@@ -47,9 +49,55 @@ impl PlayerSkills {
 
         base_max_speed * condition_factor
     }
+
+    /// A skill by its field name, as in `finishing` or `work_rate`
+    pub fn skill_mut(&mut self, name: &str) -> Option<&mut f32> {
+        let skill = match name {
+            "corners" => &mut self.technical.corners,
+            "crossing" => &mut self.technical.crossing,
+            "dribbling" => &mut self.technical.dribbling,
+            "finishing" => &mut self.technical.finishing,
+            "first_touch" => &mut self.technical.first_touch,
+            "free_kicks" => &mut self.technical.free_kicks,
+            "heading" => &mut self.technical.heading,
+            "long_shots" => &mut self.technical.long_shots,
+            "long_throws" => &mut self.technical.long_throws,
+            "marking" => &mut self.technical.marking,
+            "passing" => &mut self.technical.passing,
+            "penalty_taking" => &mut self.technical.penalty_taking,
+            "tackling" => &mut self.technical.tackling,
+            "technique" => &mut self.technical.technique,
+            "aggression" => &mut self.mental.aggression,
+            "anticipation" => &mut self.mental.anticipation,
+            "bravery" => &mut self.mental.bravery,
+            "composure" => &mut self.mental.composure,
+            "concentration" => &mut self.mental.concentration,
+            "decisions" => &mut self.mental.decisions,
+            "determination" => &mut self.mental.determination,
+            "flair" => &mut self.mental.flair,
+            "leadership" => &mut self.mental.leadership,
+            "off_the_ball" => &mut self.mental.off_the_ball,
+            "positioning" => &mut self.mental.positioning,
+            "teamwork" => &mut self.mental.teamwork,
+            "vision" => &mut self.mental.vision,
+            "work_rate" => &mut self.mental.work_rate,
+            "acceleration" => &mut self.physical.acceleration,
+            "agility" => &mut self.physical.agility,
+            "balance" => &mut self.physical.balance,
+            "jumping" => &mut self.physical.jumping,
+            "natural_fitness" => &mut self.physical.natural_fitness,
+            "pace" => &mut self.physical.pace,
+            "stamina" => &mut self.physical.stamina,
+            "strength" => &mut self.physical.strength,
+            "match_readiness" => &mut self.physical.match_readiness,
+            _ => return None,
+        };
+
+        Some(skill)
+    }
 }
 
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
 pub struct Technical {
     pub corners: f32,
     pub crossing: f32,
@@ -89,7 +137,7 @@ impl Technical {
     pub fn rest(&mut self) {}
 }
 
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
 pub struct Mental {
     pub aggression: f32,
     pub anticipation: f32,
@@ -126,10 +174,23 @@ impl Mental {
             / 14.0
     }
 
+    /// Morale shows in the attributes a player's head decides on the day
+    pub fn apply_morale(&mut self, factor: f32) {
+        for attribute in [
+            &mut self.composure,
+            &mut self.concentration,
+            &mut self.decisions,
+            &mut self.determination,
+            &mut self.work_rate,
+        ] {
+            *attribute = (*attribute * factor).clamp(1.0, 20.0);
+        }
+    }
+
     pub fn rest(&mut self) {}
 }
 
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
 pub struct Physical {
     pub acceleration: f32,
     pub agility: f32,
@@ -252,6 +313,24 @@ mod tests {
         assert!(true);
     }
 
+    #[test]
+    fn test_mental_apply_morale() {
+        let mut mental = Mental {
+            aggression: 10.0,
+            composure: 10.0,
+            concentration: 19.5,
+            decisions: 10.0,
+            determination: 10.0,
+            work_rate: 10.0,
+            ..Default::default()
+        };
+        mental.apply_morale(1.05);
+
+        assert_eq!(mental.aggression, 10.0);
+        assert_eq!(mental.composure, 10.5);
+        assert_eq!(mental.concentration, 20.0);
+    }
+
     #[test]
     fn test_physical_average() {
         let physical = Physical {
@@ -1,6 +1,7 @@
 use crate::league::Season;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct PlayerStatistics {
     pub played: u16,
     pub played_subs: u16,
@@ -11,6 +12,7 @@ pub struct PlayerStatistics {
     pub player_of_the_match: u8,
     pub yellow_cards: u8,
     pub red_cards: u8,
+    pub clean_sheets: u16,
 
     pub shots_on_target: f32,
     pub tackling: f32,
@@ -19,12 +21,21 @@ pub struct PlayerStatistics {
     pub average_rating: f32,
 }
 
-#[derive(Debug)]
+impl PlayerStatistics {
+    /// Folds a match rating into the running average over the appearances so far
+    pub fn add_rating(&mut self, rating: f32) {
+        let played = self.played.max(1) as f32;
+
+        self.average_rating = (self.average_rating * (played - 1.0) + rating) / played;
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerStatisticsHistory {
     pub items: Vec<PlayerStatisticsHistoryItem>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerStatisticsHistoryItem {
     pub season: Season,
     pub statistics: PlayerStatistics,
@@ -40,4 +51,40 @@ impl PlayerStatisticsHistory {
     pub fn new() -> Self {
         PlayerStatisticsHistory { items: Vec::new() }
     }
+
+    /// Closes the season, the running statistics start again from zero
+    pub fn archive(&mut self, season: Season, statistics: &mut PlayerStatistics) {
+        self.items.push(PlayerStatisticsHistoryItem {
+            season,
+            statistics: std::mem::take(statistics),
+        });
+    }
+
+    pub fn last(&self) -> Option<&PlayerStatistics> {
+        self.items.last().map(|item| &item.statistics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rating_is_averaged_over_appearances_and_archived_at_season_end() {
+        let mut statistics = PlayerStatistics::default();
+
+        for rating in [6.0, 8.0, 7.0] {
+            statistics.played += 1;
+            statistics.add_rating(rating);
+        }
+
+        assert!((statistics.average_rating - 7.0).abs() < 0.001);
+
+        let mut history = PlayerStatisticsHistory::new();
+        history.archive(Season::TwoYear(2024, 2025), &mut statistics);
+
+        assert_eq!(0, statistics.played);
+        assert_eq!(Some(3), history.last().map(|last| last.played));
+        assert_eq!(Season::TwoYear(2024, 2025), history.items[0].season);
+    }
 }
@@ -1,7 +1,8 @@
 use crate::PlayerSkills;
 use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerTrainingHistory {
     records: Vec<TrainingRecord>,
 }
@@ -33,7 +34,7 @@ impl PlayerTrainingHistory {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TrainingRecord {
     date: NaiveDateTime,
     _skills: PlayerSkills,
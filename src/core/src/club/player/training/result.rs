@@ -1,4 +1,9 @@
-﻿use crate::{MentalGains, PhysicalGains, SimulatorData, TechnicalGains, TrainingEffects};
+﻿use crate::news::NewsReporter;
+use crate::{MentalGains, PhysicalGains, SimulatorData, TechnicalGains, TrainingEffects};
+use crate::utils::RandomSeed;
+
+/// Morale points a full unit of training morale change is worth
+const MORALE_CHANGE_SCALE: f32 = 10.0;
 
 pub struct PlayerTrainingResult {
     pub player_id: u32,
@@ -30,6 +35,8 @@ impl PlayerTrainingResult {
     /// Apply the training effects to the player
     /// This is where the actual skill updates happen with mutable references
     pub fn process(&self, data: &mut SimulatorData) {
+        let mut injured = false;
+
         // Get mutable reference to the player
         if let Some(player) = data.player_mut(self.player_id) {
             // Apply physical gains
@@ -65,7 +72,8 @@ impl PlayerTrainingResult {
             player.player_attributes.condition = new_condition.clamp(0.0, 10000.0) as i16;
 
             // Apply injury risk (simplified)
-            if rand::random::<f32>() < self.effects.injury_risk {
+            if RandomSeed::random::<f32>() < self.effects.injury_risk {
+                injured = !player.player_attributes.is_injured;
                 player.player_attributes.is_injured = true;
                 // You might want to add more injury details here
             }
@@ -79,14 +87,8 @@ impl PlayerTrainingResult {
                 player.skills.physical.match_readiness = (player.skills.physical.match_readiness - 1.0).max(0.0);
             }
 
-            // Apply morale changes to happiness (simplified)
-            // You might want to integrate this with your happiness system
-            if self.effects.morale_change > 0.0 {
-                // Positive morale change could improve behavior
-                if rand::random::<f32>() < self.effects.morale_change {
-                    player.behaviour.try_increase();
-                }
-            }
+            // Team activities and recovery sessions lift the mood
+            player.morale.change(self.effects.morale_change * MORALE_CHANGE_SCALE);
 
             // Record training in history (if you implement this)
             // player.training_history.add_record(TrainingRecord {
@@ -94,5 +96,11 @@ impl PlayerTrainingResult {
             //     skills: player.skills.clone(),
             // });
         }
+
+        if injured {
+            if let Some(news) = NewsReporter::injury(self.player_id, false, data.date.date(), data) {
+                data.news.publish(news);
+            }
+        }
     }
 }
\ No newline at end of file
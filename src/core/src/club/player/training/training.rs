@@ -1,8 +1,9 @@
 use crate::club::player::training::result::PlayerTrainingResult;
 use crate::{MentalGains, Person, PhysicalGains, Player, Staff, TechnicalGains, TrainingEffects, TrainingIntensity, TrainingSession, TrainingType};
 use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerTraining {}
 
 impl Default for PlayerTraining {
@@ -221,7 +222,7 @@ impl PlayerTraining {
             _ => -0.1,
         };
 
-        (base + relationship_bonus + age_bonus).clamp(0.1, 1.5)
+        ((base + relationship_bonus + age_bonus) * player.morale.training_factor()).clamp(0.1, 1.5)
     }
 
     fn calculate_age_training_factor(age: u8) -> f32 {
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Enhanced Relations system with complex relationship dynamics
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Relations {
     /// Player relationships
     players: RelationStore<PlayerRelation>,
@@ -288,7 +288,7 @@ impl Relations {
 }
 
 /// Store for relationships of a specific type
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct RelationStore<T: Relationship> {
     relations: HashMap<u32, T>,
 }
@@ -343,7 +343,7 @@ trait Relationship {
 }
 
 /// Player relationship details
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerRelation {
     /// Relationship level (-100 to 100)
     pub level: f32,
@@ -473,7 +473,7 @@ impl Relationship for PlayerRelation {
 }
 
 /// Staff relationship details
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffRelation {
     /// Relationship level (-100 to 100)
     pub level: f32,
@@ -555,6 +555,11 @@ impl Relationship for StaffRelation {
                 self.loyalty += magnitude * 3.0;
                 self.level += magnitude * 2.0;
             }
+            ChangeType::BrokenPromise => {
+                self.trust_in_abilities -= magnitude.abs() * 2.0;
+                self.loyalty -= magnitude.abs() * 3.0;
+                self.level -= magnitude.abs() * 2.0;
+            }
             ChangeType::DisciplinaryAction => {
                 self.authority_respect += magnitude;  // Can increase if fair
                 self.personal_bond -= magnitude * 2.0;
@@ -576,7 +581,7 @@ impl Relationship for StaffRelation {
 }
 
 /// Group dynamics and cliques
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct GroupDynamics {
     groups: HashMap<GroupId, Group>,
     entity_groups: HashMap<u32, HashSet<GroupId>>,
@@ -645,7 +650,7 @@ impl GroupDynamics {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Group {
     id: GroupId,
     members: HashSet<u32>,
@@ -664,7 +669,7 @@ impl Group {
 
 type GroupId = u32;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 enum GroupType {
     Nationality,
     AgeGroup,
@@ -674,7 +679,7 @@ enum GroupType {
 }
 
 /// Team chemistry calculator
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct TeamChemistry {
     overall: f32,
     factors: ChemistryFactors,
@@ -745,7 +750,7 @@ impl TeamChemistry {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChemistryFactors {
     pub player_harmony: f32,
     pub leadership_quality: f32,
@@ -755,7 +760,7 @@ pub struct ChemistryFactors {
 }
 
 /// Relationship history tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct RelationshipHistory {
     events: VecDeque<RelationshipEvent>,
     max_events: usize,
@@ -782,7 +787,7 @@ impl RelationshipHistory {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct RelationshipEvent {
     date: NaiveDate,
     subject_id: u32,
@@ -792,7 +797,7 @@ struct RelationshipEvent {
     new_value: f32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 enum SubjectType {
     Player,
     Staff,
@@ -817,6 +822,7 @@ pub enum ChangeType {
     TacticalDisagreement,
     DisciplinaryAction,
     TeamFailure,
+    BrokenPromise,
 
     // Neutral
     NaturalProgression,
@@ -849,7 +855,7 @@ impl RelationshipChange {
 }
 
 /// Mentorship types
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MentorshipType {
     Mentor,
     Mentee,
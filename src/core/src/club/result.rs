@@ -2,15 +2,22 @@ use crate::club::academy::result::ClubAcademyResult;
 use crate::club::{BoardResult, ClubFinanceResult};
 use crate::simulator::SimulatorData;
 use crate::{
-    PlayerContractProposal, PlayerMessage, PlayerMessageType, PlayerResult, SimulationResult,
-    TeamResult,
+    PlayerContractProposal, PlayerInteractionType, PlayerMessage, PlayerMessageType, PlayerPromise,
+    PlayerRequestDismissal, PlayerRequestType, PlayerResult, PlayerSquadStatus, PlayerStatusType,
+    SimulationResult, TeamResult,
 };
+use log::info;
+
+/// Players the coach counts on, the ones they can promise football to
+const PLAYING_TIME_PROMISE_RANK: usize = 18;
 
 pub struct ClubResult {
     pub finance: ClubFinanceResult,
     pub teams: Vec<TeamResult>,
     pub board: BoardResult,
     pub academy: ClubAcademyResult,
+    /// Players moved between the club's teams during the day
+    pub squads_changed: bool,
 }
 
 impl ClubResult {
@@ -25,6 +32,7 @@ impl ClubResult {
             teams,
             board,
             academy,
+            squads_changed: false,
         }
     }
 
@@ -36,6 +44,10 @@ impl ClubResult {
                 if player_result.has_contract_actions() {
                     Self::process_player_contract_interaction(player_result, data);
                 }
+
+                for request in &player_result.requests {
+                    Self::process_player_request(team_result.team_id, player_result.player_id, *request, data);
+                }
             }
 
             team_result.process(data);
@@ -45,6 +57,91 @@ impl ClubResult {
         self.academy.process(data);
     }
 
+    /// The head coach answers the player, with a promise or a refusal
+    fn process_player_request(team_id: u32, player_id: u32, request: PlayerRequestType, data: &mut SimulatorData) {
+        let date = data.date.date();
+
+        let (coach_id, rank) = match data.team(team_id) {
+            Some(team) => {
+                let ability = match team.players.players.iter().find(|player| player.id == player_id) {
+                    Some(player) => player.player_attributes.current_ability,
+                    None => return,
+                };
+
+                let rank = team
+                    .players
+                    .players
+                    .iter()
+                    .filter(|player| player.player_attributes.current_ability > ability)
+                    .count();
+
+                (team.staffs.head_coach().id, rank)
+            }
+            None => return,
+        };
+
+        let player = match data.player_mut(player_id) {
+            Some(player) => player,
+            None => return,
+        };
+
+        let is_important = player.contract.as_ref().is_some_and(|contract| {
+            matches!(
+                contract.squad_status,
+                PlayerSquadStatus::KeyPlayer | PlayerSquadStatus::FirstTeamRegular
+            )
+        });
+
+        let dismiss = PlayerMessage {
+            message_type: PlayerMessageType::RequestDismissed(PlayerRequestDismissal {
+                request,
+                staff_id: coach_id,
+            }),
+        };
+
+        match request {
+            PlayerRequestType::PlayingTime => {
+                if rank < PLAYING_TIME_PROMISE_RANK {
+                    player.mailbox.push(PlayerMessage {
+                        message_type: PlayerMessageType::Promise(PlayerPromise::playing_time(coach_id, date)),
+                    });
+                } else {
+                    player.mailbox.push(dismiss);
+                }
+            }
+            PlayerRequestType::NewContract => match player.contract.as_ref() {
+                Some(contract) if is_important => {
+                    player.mailbox.push(PlayerMessage {
+                        message_type: PlayerMessageType::Promise(PlayerPromise::new_contract(
+                            coach_id,
+                            date,
+                            contract.expiration,
+                        )),
+                    });
+                }
+                _ => player.mailbox.push(dismiss),
+            },
+            // The club holds on to the players it builds the team around and lists the rest
+            PlayerRequestType::Transfer => {
+                if is_important {
+                    info!("🚫 transfer request of {} rejected", player.full_name);
+
+                    player.mailbox.push(dismiss);
+                } else if let Some(contract) = player.contract.as_mut() {
+                    info!("📋 {} transfer listed at their own request", player.full_name);
+
+                    contract.is_transfer_listed = true;
+
+                    player.statuses.remove(PlayerStatusType::Req);
+                    player.statuses.add(date, PlayerStatusType::Lst);
+                    player
+                        .interactions
+                        .record(date, PlayerInteractionType::TransferListed, Some(coach_id));
+                }
+            }
+        }
+    }
+
     fn process_player_contract_interaction(result: &PlayerResult, data: &mut SimulatorData) {
         if result.contract.no_contract || result.contract.want_improve_contract {
             let player = data.player(result.player_id).expect(&format!("player {} not found", result.player_id));
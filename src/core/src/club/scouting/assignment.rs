@@ -0,0 +1,75 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ScoutingRegion {
+    Country(u32),
+    League(u32),
+    Continent(u32),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoutAssignment {
+    pub staff_id: u32,
+    pub region: ScoutingRegion,
+    pub started: NaiveDate,
+}
+
+impl ScoutAssignment {
+    pub fn new(staff_id: u32, region: ScoutingRegion, started: NaiveDate) -> Self {
+        ScoutAssignment {
+            staff_id,
+            region,
+            started,
+        }
+    }
+
+    pub fn days_in_region(&self, date: NaiveDate) -> i64 {
+        (date - self.started).num_days().max(0)
+    }
+}
+
+/// How well a scout reads players, derived from knowledge and data analysis attributes (0-20)
+#[derive(Debug, Clone, Copy)]
+pub struct ScoutJudgement {
+    pub ability: u8,
+    pub potential: u8,
+}
+
+impl ScoutJudgement {
+    pub fn new(ability: u8, potential: u8) -> Self {
+        ScoutJudgement {
+            ability: ability.min(20),
+            potential: potential.min(20),
+        }
+    }
+
+    pub fn from_staff(staff: &crate::Staff) -> Self {
+        let knowledge = &staff.staff_attributes.knowledge;
+        let data = staff.staff_attributes.data_analysis.judging_player_data as u16;
+
+        ScoutJudgement::new(
+            ((knowledge.judging_player_ability as u16 * 2 + data) / 3) as u8,
+            ((knowledge.judging_player_potential as u16 * 2 + data) / 3) as u8,
+        )
+    }
+
+    /// Maximum error of a single look at a player's current ability
+    pub fn ability_error(&self, days_in_region: i64) -> f32 {
+        Self::error(self.ability, days_in_region, 30.0)
+    }
+
+    /// Potential is harder to read than current ability
+    pub fn potential_error(&self, days_in_region: i64) -> f32 {
+        Self::error(self.potential, days_in_region, 45.0)
+    }
+
+    fn error(skill: u8, days_in_region: i64, max_error: f32) -> f32 {
+        let skill_factor = 1.0 - skill as f32 / 20.0;
+
+        // Scouts who know the region well read players better, up to a year of experience
+        let familiarity = (days_in_region as f32 / 365.0).min(1.0) * 0.3;
+
+        3.0 + max_error * skill_factor * (1.0 - familiarity)
+    }
+}
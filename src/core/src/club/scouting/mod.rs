@@ -0,0 +1,7 @@
+pub mod assignment;
+pub mod report;
+pub mod scouting;
+
+pub use assignment::*;
+pub use report::*;
+pub use scouting::*;
@@ -0,0 +1,160 @@
+use crate::club::scouting::ScoutJudgement;
+use crate::utils::FloatUtils;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoutingReport {
+    pub player_id: u32,
+    pub scout_id: u32,
+
+    pub estimated_current_ability: u8,
+    pub estimated_potential_ability: u8,
+
+    pub observations: u16,
+    pub first_observed: NaiveDate,
+    pub last_observed: NaiveDate,
+
+    current_ability_sum: f32,
+    potential_ability_sum: f32,
+    error_sum: f32,
+}
+
+impl ScoutingReport {
+    pub fn new(player_id: u32, scout_id: u32, date: NaiveDate) -> Self {
+        ScoutingReport {
+            player_id,
+            scout_id,
+            estimated_current_ability: 0,
+            estimated_potential_ability: 0,
+            observations: 0,
+            first_observed: date,
+            last_observed: date,
+            current_ability_sum: 0.0,
+            potential_ability_sum: 0.0,
+            error_sum: 0.0,
+        }
+    }
+
+    /// Scout watches the player once more. Every look is noisy, estimates are the
+    /// average of all looks, so the picture gets sharper the longer a player is followed.
+    pub fn observe(
+        &mut self,
+        scout_id: u32,
+        current_ability: u8,
+        potential_ability: u8,
+        judgement: &ScoutJudgement,
+        days_in_region: i64,
+        date: NaiveDate,
+    ) {
+        let ability_error = judgement.ability_error(days_in_region);
+        let potential_error = judgement.potential_error(days_in_region);
+
+        let seen_current =
+            current_ability as f32 + FloatUtils::random(-ability_error, ability_error);
+
+        // Potential can never look lower than what the scout believes the player already is
+        let seen_potential = (potential_ability as f32
+            + FloatUtils::random(-potential_error, potential_error))
+        .max(seen_current);
+
+        self.add_observation(scout_id, seen_current, seen_potential, ability_error, date);
+    }
+
+    fn add_observation(
+        &mut self,
+        scout_id: u32,
+        seen_current: f32,
+        seen_potential: f32,
+        error: f32,
+        date: NaiveDate,
+    ) {
+        self.scout_id = scout_id;
+        self.observations += 1;
+        self.last_observed = date;
+
+        self.current_ability_sum += seen_current.clamp(0.0, u8::MAX as f32);
+        self.potential_ability_sum += seen_potential.clamp(0.0, u8::MAX as f32);
+        self.error_sum += error;
+
+        let count = self.observations as f32;
+
+        self.estimated_current_ability = (self.current_ability_sum / count).round() as u8;
+        self.estimated_potential_ability = (self.potential_ability_sum / count).round() as u8;
+    }
+
+    /// Expected error of the current ability estimate
+    pub fn uncertainty(&self) -> f32 {
+        if self.observations == 0 {
+            return f32::MAX;
+        }
+
+        let count = self.observations as f32;
+
+        (self.error_sum / count) / count.sqrt()
+    }
+
+    pub fn is_outdated(&self, date: NaiveDate) -> bool {
+        (date - self.last_observed).num_days() > 365
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 7, day).unwrap()
+    }
+
+    #[test]
+    fn report_averages_observations() {
+        let mut report = ScoutingReport::new(1, 10, date(1));
+
+        report.add_observation(10, 60.0, 90.0, 10.0, date(1));
+        report.add_observation(11, 70.0, 100.0, 10.0, date(8));
+
+        assert_eq!(report.estimated_current_ability, 65);
+        assert_eq!(report.estimated_potential_ability, 95);
+        assert_eq!(report.observations, 2);
+        assert_eq!(report.scout_id, 11);
+        assert_eq!(report.last_observed, date(8));
+    }
+
+    #[test]
+    fn uncertainty_decreases_with_observations() {
+        let mut report = ScoutingReport::new(1, 10, date(1));
+
+        report.add_observation(10, 60.0, 90.0, 10.0, date(1));
+        let first = report.uncertainty();
+
+        for day in 2..10 {
+            report.add_observation(10, 60.0, 90.0, 10.0, date(day));
+        }
+
+        assert!(report.uncertainty() < first);
+    }
+
+    #[test]
+    fn skilled_scout_has_smaller_error() {
+        let poor = ScoutJudgement::new(2, 2);
+        let excellent = ScoutJudgement::new(19, 19);
+
+        assert!(excellent.ability_error(0) < poor.ability_error(0));
+        assert!(excellent.potential_error(0) < poor.potential_error(0));
+        assert!(poor.ability_error(365) < poor.ability_error(0));
+    }
+
+    #[test]
+    fn observe_stays_within_error_bounds() {
+        let judgement = ScoutJudgement::new(20, 20);
+        let mut report = ScoutingReport::new(1, 10, date(1));
+
+        report.observe(10, 80, 120, &judgement, 0, date(1));
+
+        let error = judgement.ability_error(0);
+
+        assert!((report.estimated_current_ability as f32 - 80.0).abs() <= error + 1.0);
+        assert!(report.estimated_potential_ability >= report.estimated_current_ability);
+    }
+}
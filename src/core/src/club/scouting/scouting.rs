@@ -0,0 +1,151 @@
+use crate::club::scouting::{ScoutAssignment, ScoutJudgement, ScoutingRegion, ScoutingReport};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// Players every scout watches per week
+pub const SCOUTING_PLAYERS_PER_WEEK: usize = 3;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ClubScouting {
+    pub assignments: Vec<ScoutAssignment>,
+    pub reports: HashMap<u32, ScoutingReport>,
+    pub shortlist: Vec<u32>,
+}
+
+impl ClubScouting {
+    pub fn new() -> Self {
+        ClubScouting::default()
+    }
+
+    pub fn assign(&mut self, staff_id: u32, region: ScoutingRegion, date: NaiveDate) {
+        self.assignments.retain(|a| a.staff_id != staff_id);
+        self.assignments
+            .push(ScoutAssignment::new(staff_id, region, date));
+    }
+
+    pub fn assignment(&self, staff_id: u32) -> Option<&ScoutAssignment> {
+        self.assignments.iter().find(|a| a.staff_id == staff_id)
+    }
+
+    /// Drop assignments of scouts who left and give every idle scout a region,
+    /// spreading them over the regions in order of priority
+    pub fn sync_assignments(
+        &mut self,
+        scout_ids: &[u32],
+        regions: &[ScoutingRegion],
+        date: NaiveDate,
+    ) {
+        self.assignments.retain(|a| scout_ids.contains(&a.staff_id));
+
+        if regions.is_empty() {
+            return;
+        }
+
+        for &scout_id in scout_ids {
+            if self.assignment(scout_id).is_some() {
+                continue;
+            }
+
+            let region = regions
+                .iter()
+                .min_by_key(|region| {
+                    self.assignments
+                        .iter()
+                        .filter(|a| a.region == **region)
+                        .count()
+                })
+                .copied()
+                .unwrap_or(regions[0]);
+
+            self.assign(scout_id, region, date);
+        }
+    }
+
+    pub fn report(&self, player_id: u32) -> Option<&ScoutingReport> {
+        self.reports.get(&player_id)
+    }
+
+    pub fn add_to_shortlist(&mut self, player_id: u32) {
+        if !self.shortlist.contains(&player_id) {
+            self.shortlist.push(player_id);
+        }
+    }
+
+    pub fn record_observation(
+        &mut self,
+        scout_id: u32,
+        player_id: u32,
+        current_ability: u8,
+        potential_ability: u8,
+        judgement: &ScoutJudgement,
+        date: NaiveDate,
+    ) {
+        let days_in_region = self
+            .assignment(scout_id)
+            .map(|a| a.days_in_region(date))
+            .unwrap_or(0);
+
+        self.reports
+            .entry(player_id)
+            .or_insert_with(|| ScoutingReport::new(player_id, scout_id, date))
+            .observe(
+                scout_id,
+                current_ability,
+                potential_ability,
+                judgement,
+                days_in_region,
+                date,
+            );
+
+        self.shortlist.retain(|id| *id != player_id);
+    }
+
+    pub fn remove_outdated_reports(&mut self, date: NaiveDate) {
+        self.reports.retain(|_, report| !report.is_outdated(date));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 7, 1).unwrap()
+    }
+
+    #[test]
+    fn sync_assignments_spreads_scouts_over_regions() {
+        let mut scouting = ClubScouting::new();
+
+        let regions = [ScoutingRegion::Country(1), ScoutingRegion::Continent(2)];
+
+        scouting.sync_assignments(&[10, 11, 12], &regions, date());
+
+        assert_eq!(scouting.assignment(10).unwrap().region, ScoutingRegion::Country(1));
+        assert_eq!(scouting.assignment(11).unwrap().region, ScoutingRegion::Continent(2));
+        assert_eq!(scouting.assignment(12).unwrap().region, ScoutingRegion::Country(1));
+    }
+
+    #[test]
+    fn sync_assignments_removes_departed_scouts() {
+        let mut scouting = ClubScouting::new();
+
+        scouting.assign(10, ScoutingRegion::League(5), date());
+        scouting.sync_assignments(&[11], &[ScoutingRegion::Country(1)], date());
+
+        assert!(scouting.assignment(10).is_none());
+        assert!(scouting.assignment(11).is_some());
+    }
+
+    #[test]
+    fn record_observation_creates_report_and_clears_shortlist() {
+        let mut scouting = ClubScouting::new();
+
+        scouting.add_to_shortlist(7);
+        scouting.record_observation(10, 7, 80, 100, &ScoutJudgement::new(15, 15), date());
+
+        assert!(scouting.report(7).is_some());
+        assert!(scouting.shortlist.is_empty());
+    }
+}
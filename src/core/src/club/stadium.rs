@@ -0,0 +1,266 @@
+use crate::utils::FloatUtils;
+use crate::ClubRevenueCalculator;
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum StadiumOwnership {
+    Owned,
+    Rented,
+    Shared,
+}
+
+impl FromStr for StadiumOwnership {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Owned" => Ok(StadiumOwnership::Owned),
+            "Rented" => Ok(StadiumOwnership::Rented),
+            "Shared" => Ok(StadiumOwnership::Shared),
+            _ => Err(format!("'{}' is not a valid value for StadiumOwnership", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StadiumExpansion {
+    pub additional_capacity: u32,
+    pub remaining_cost: i32,
+    pub completion_date: NaiveDate,
+}
+
+impl StadiumExpansion {
+    pub fn new(additional_capacity: u32, cost: i32, completion_date: NaiveDate) -> Self {
+        StadiumExpansion {
+            additional_capacity,
+            remaining_cost: cost,
+            completion_date,
+        }
+    }
+
+    /// Building costs are paid in equal monthly installments until the completion date
+    pub fn monthly_installment(&self, date: NaiveDate) -> i32 {
+        let months_left = (self.completion_date.year() - date.year()) * 12
+            + self.completion_date.month() as i32
+            - date.month() as i32;
+
+        self.remaining_cost / months_left.max(1)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Stadium {
+    pub name: String,
+    pub capacity: u32,
+    /// Facilities quality (0-20)
+    pub quality: u8,
+    pub ownership: StadiumOwnership,
+    pub expansion: Option<StadiumExpansion>,
+}
+
+/// Everything that makes people come to a match
+#[derive(Debug, Clone)]
+pub struct MatchAttendanceFactors {
+    pub home_reputation: u16,
+    pub away_reputation: u16,
+    /// Recent form of the home team (0.0-1.0)
+    pub home_momentum: f32,
+    pub is_derby: bool,
+    /// Weekday, season period and table situation from league dynamics
+    pub league_multiplier: f32,
+    pub league_reputation: u16,
+    pub date: NaiveDate,
+}
+
+impl Stadium {
+    pub fn new(name: String, capacity: u32, quality: u8, ownership: StadiumOwnership) -> Self {
+        Stadium {
+            name,
+            capacity,
+            quality: quality.min(20),
+            ownership,
+            expansion: None,
+        }
+    }
+
+    pub fn with_expansion(mut self, expansion: StadiumExpansion) -> Self {
+        self.expansion = Some(expansion);
+        self
+    }
+
+    /// Finish the expansion project once its date is reached, returns added capacity
+    pub fn simulate(&mut self, date: NaiveDate) -> Option<u32> {
+        let is_completed = self
+            .expansion
+            .as_ref()
+            .is_some_and(|expansion| expansion.completion_date <= date);
+
+        if !is_completed {
+            return None;
+        }
+
+        let expansion = self.expansion.take()?;
+        self.capacity += expansion.additional_capacity;
+
+        Some(expansion.additional_capacity)
+    }
+
+    /// Monthly payment towards the running expansion project
+    pub fn pay_expansion_installment(&mut self, date: NaiveDate) -> i32 {
+        match self.expansion.as_mut() {
+            Some(expansion) => {
+                let installment = expansion.monthly_installment(date);
+                expansion.remaining_cost -= installment;
+                installment
+            }
+            None => 0,
+        }
+    }
+
+    /// Rent for grounds the club does not own
+    pub fn monthly_rent(&self) -> i32 {
+        match self.ownership {
+            StadiumOwnership::Owned => 0,
+            StadiumOwnership::Rented => self.capacity as i32 * 10,
+            StadiumOwnership::Shared => self.capacity as i32 * 5,
+        }
+    }
+
+    /// Better facilities allow higher prices
+    pub fn ticket_price(&self, league_reputation: u16) -> f64 {
+        ClubRevenueCalculator::ticket_price(league_reputation)
+            * (0.8 + self.quality as f64 / 20.0 * 0.4)
+    }
+
+    pub fn attendance(&self, factors: &MatchAttendanceFactors) -> u32 {
+        // Popular clubs fill their grounds, small ones only a part of it
+        let mut demand = 0.4 + (factors.home_reputation as f32 / 10000.0).min(1.0) * 0.45;
+
+        // Fans follow a team in form
+        demand *= 0.85 + factors.home_momentum.clamp(0.0, 1.0) * 0.3;
+
+        // Famous visitors draw a crowd
+        if factors.away_reputation > factors.home_reputation {
+            demand *= 1.1;
+        }
+
+        if factors.is_derby {
+            demand *= 1.25;
+        }
+
+        demand *= factors.league_multiplier;
+
+        // Expensive tickets keep some people away
+        let league_price = ClubRevenueCalculator::ticket_price(factors.league_reputation);
+        let price_ratio = league_price / self.ticket_price(factors.league_reputation);
+
+        demand *= price_ratio.powf(0.3) as f32;
+
+        demand *= Self::weather_factor(factors.date);
+
+        ((self.capacity as f32 * demand) as u32).min(self.capacity)
+    }
+
+    /// Winter cold and autumn rain hurt walk-up sales, every day has its own weather
+    fn weather_factor(date: NaiveDate) -> f32 {
+        let season = match date.month() {
+            12 | 1 | 2 => 0.9,
+            11 | 3 => 0.95,
+            _ => 1.0,
+        };
+
+        let midweek = match date.weekday() {
+            Weekday::Sat | Weekday::Sun => 1.0,
+            _ => 0.97,
+        };
+
+        season * midweek * FloatUtils::random(0.95, 1.05)
+    }
+
+    /// Extra strength the home side draws from its crowd (0.0-0.1)
+    pub fn home_advantage(&self, attendance: u32) -> f32 {
+        if self.capacity == 0 {
+            return 0.0;
+        }
+
+        let fill_rate = (attendance as f32 / self.capacity as f32).min(1.0);
+
+        // Big and full grounds are louder
+        let size_factor = (attendance as f32 / 60000.0).min(1.0);
+
+        0.02 + fill_rate * 0.05 + size_factor * 0.03
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn factors(home_reputation: u16, is_derby: bool) -> MatchAttendanceFactors {
+        MatchAttendanceFactors {
+            home_reputation,
+            away_reputation: 5000,
+            home_momentum: 0.5,
+            is_derby,
+            league_multiplier: 1.0,
+            league_reputation: 8000,
+            date: NaiveDate::from_ymd_opt(2024, 9, 14).unwrap(),
+        }
+    }
+
+    #[test]
+    fn attendance_never_exceeds_capacity() {
+        let stadium = Stadium::new(String::from("Arena"), 20000, 10, StadiumOwnership::Owned);
+
+        let mut attendance_factors = factors(10000, true);
+        attendance_factors.league_multiplier = 2.0;
+
+        assert_eq!(20000, stadium.attendance(&attendance_factors));
+    }
+
+    #[test]
+    fn popular_club_draws_bigger_crowd() {
+        let stadium = Stadium::new(String::from("Arena"), 80000, 10, StadiumOwnership::Owned);
+
+        let small = stadium.attendance(&factors(1000, false));
+        let big = stadium.attendance(&factors(9000, false));
+
+        assert!(big > small);
+    }
+
+    #[test]
+    fn full_stadium_gives_bigger_home_advantage() {
+        let stadium = Stadium::new(String::from("Arena"), 60000, 10, StadiumOwnership::Owned);
+
+        assert!(stadium.home_advantage(60000) > stadium.home_advantage(20000));
+        assert!(stadium.home_advantage(60000) <= 0.1);
+    }
+
+    #[test]
+    fn expansion_adds_capacity_on_completion() {
+        let completion_date = NaiveDate::from_ymd_opt(2025, 7, 31).unwrap();
+
+        let mut stadium = Stadium::new(String::from("Arena"), 20000, 10, StadiumOwnership::Owned)
+            .with_expansion(StadiumExpansion::new(5000, 12_000_000, completion_date));
+
+        assert_eq!(None, stadium.simulate(NaiveDate::from_ymd_opt(2025, 7, 1).unwrap()));
+        assert_eq!(Some(5000), stadium.simulate(completion_date));
+        assert_eq!(25000, stadium.capacity);
+        assert!(stadium.expansion.is_none());
+    }
+
+    #[test]
+    fn expansion_installment_spreads_cost() {
+        let expansion = StadiumExpansion::new(
+            5000,
+            12_000_000,
+            NaiveDate::from_ymd_opt(2025, 7, 31).unwrap(),
+        );
+
+        let installment = expansion.monthly_installment(NaiveDate::from_ymd_opt(2024, 7, 1).unwrap());
+
+        assert_eq!(1_000_000, installment);
+    }
+}
@@ -1,4 +1,6 @@
-#[derive(Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct StaffAttributes {
     pub coaching: StaffCoaching,
     pub goalkeeping: StaffGoalkeeperCoaching,
@@ -8,7 +10,7 @@ pub struct StaffAttributes {
     pub medical: StaffMedical,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct StaffCoaching {
     pub attacking: u8,
     pub defending: u8,
@@ -19,14 +21,14 @@ pub struct StaffCoaching {
     pub working_with_youngsters: u8,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct StaffGoalkeeperCoaching {
     pub distribution: u8,
     pub handling: u8,
     pub shot_stopping: u8,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct StaffMental {
     pub adaptability: u8,
     pub determination: u8,
@@ -35,25 +37,58 @@ pub struct StaffMental {
     pub motivating: u8,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct StaffKnowledge {
     pub judging_player_ability: u8,
     pub judging_player_potential: u8,
     pub tactical_knowledge: u8,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct StaffDataAnalysis {
     pub judging_player_data: u8,
     pub judging_team_data: u8,
     pub presenting_data: u8,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct StaffMedical {
     pub physiotherapy: u8,
     pub sports_science: u8,
     pub non_player_tendencies: u8,
 }
 
-impl StaffAttributes {}
+impl StaffAttributes {
+    /// An attribute by its field name, as in `tactical` or `man_management`
+    pub fn attribute_mut(&mut self, name: &str) -> Option<&mut u8> {
+        let attribute = match name {
+            "attacking" => &mut self.coaching.attacking,
+            "defending" => &mut self.coaching.defending,
+            "fitness" => &mut self.coaching.fitness,
+            "mental" => &mut self.coaching.mental,
+            "tactical" => &mut self.coaching.tactical,
+            "technical" => &mut self.coaching.technical,
+            "working_with_youngsters" => &mut self.coaching.working_with_youngsters,
+            "distribution" => &mut self.goalkeeping.distribution,
+            "handling" => &mut self.goalkeeping.handling,
+            "shot_stopping" => &mut self.goalkeeping.shot_stopping,
+            "adaptability" => &mut self.mental.adaptability,
+            "determination" => &mut self.mental.determination,
+            "discipline" => &mut self.mental.discipline,
+            "man_management" => &mut self.mental.man_management,
+            "motivating" => &mut self.mental.motivating,
+            "judging_player_ability" => &mut self.knowledge.judging_player_ability,
+            "judging_player_potential" => &mut self.knowledge.judging_player_potential,
+            "tactical_knowledge" => &mut self.knowledge.tactical_knowledge,
+            "judging_player_data" => &mut self.data_analysis.judging_player_data,
+            "judging_team_data" => &mut self.data_analysis.judging_team_data,
+            "presenting_data" => &mut self.data_analysis.presenting_data,
+            "physiotherapy" => &mut self.medical.physiotherapy,
+            "sports_science" => &mut self.medical.sports_science,
+            "non_player_tendencies" => &mut self.medical.non_player_tendencies,
+            _ => return None,
+        };
+
+        Some(attribute)
+    }
+}
@@ -1,4 +1,6 @@
-#[derive(Debug, Default)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct StaffResponsibility {
     pub board: BoardResponsibility,
     pub recruitment: RecruitmentResponsibility,
@@ -9,19 +11,19 @@ pub struct StaffResponsibility {
     pub training: TrainingResponsibility,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct BoardResponsibility {
     pub hire_fire_director: Option<u32>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct RecruitmentResponsibility {
     pub hire_fire_head_of_youth_development: Option<u32>,
     pub hire_fire_chief_scout: Option<u32>,
     pub hire_fire_other_staff: Option<u32>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct IncomingTransfersResponsibility {
     pub find_and_make_offers_first_team: Option<u32>,
     pub finalize_first_team_signings: Option<u32>,
@@ -30,13 +32,13 @@ pub struct IncomingTransfersResponsibility {
     pub finalize_youth_team_signings: Option<u32>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct OutgoingTransfersResponsibility {
     pub find_clubs_for_transfers_and_loans_listed_first_team: Option<u32>,
     pub find_clubs_for_transfers_and_loans_listed_youth_team: Option<u32>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct ContractRenewalResponsibility {
     pub handle_first_team_contracts: Option<u32>,
     pub handle_youth_team_contracts: Option<u32>,
@@ -44,13 +46,13 @@ pub struct ContractRenewalResponsibility {
     pub handle_other_staff_contracts: Option<u32>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct ScoutingResponsibility {
     pub handle_scouting_tasks: Option<u32>,
     pub updates_you_on_players_found: Option<u32>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct TrainingResponsibility {
     pub training_first_team: Option<u32>,
     pub training_youth_team: Option<u32>,
@@ -63,3 +65,58 @@ pub struct TrainingResponsibility {
 
     pub youth_development: Option<u32>,
 }
+
+impl StaffResponsibility {
+    /// Clear every responsibility held by a staff member who left the club
+    pub fn unassign(&mut self, staff_id: u32) {
+        let assignments = [
+            &mut self.board.hire_fire_director,
+            &mut self.recruitment.hire_fire_head_of_youth_development,
+            &mut self.recruitment.hire_fire_chief_scout,
+            &mut self.recruitment.hire_fire_other_staff,
+            &mut self.incoming_transfers.find_and_make_offers_first_team,
+            &mut self.incoming_transfers.finalize_first_team_signings,
+            &mut self.incoming_transfers.find_and_make_offers_youth_team,
+            &mut self.incoming_transfers.finalize_youth_team_signings,
+            &mut self.outgoing_transfers.find_clubs_for_transfers_and_loans_listed_first_team,
+            &mut self.outgoing_transfers.find_clubs_for_transfers_and_loans_listed_youth_team,
+            &mut self.contract_renewal.handle_first_team_contracts,
+            &mut self.contract_renewal.handle_youth_team_contracts,
+            &mut self.contract_renewal.handle_director_of_football_contract,
+            &mut self.contract_renewal.handle_other_staff_contracts,
+            &mut self.scouting.handle_scouting_tasks,
+            &mut self.scouting.updates_you_on_players_found,
+            &mut self.training.training_first_team,
+            &mut self.training.training_youth_team,
+            &mut self.training.individual_training_first_team,
+            &mut self.training.individual_training_youth_team,
+            &mut self.training.match_training_first_team,
+            &mut self.training.match_training_reserve_team,
+            &mut self.training.match_training_youth_team,
+            &mut self.training.youth_development,
+        ];
+
+        for assignment in assignments {
+            if *assignment == Some(staff_id) {
+                *assignment = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unassign_clears_only_given_staff() {
+        let mut responsibility = StaffResponsibility::default();
+        responsibility.training.training_first_team = Some(1);
+        responsibility.scouting.handle_scouting_tasks = Some(2);
+
+        responsibility.unassign(1);
+
+        assert_eq!(None, responsibility.training.training_first_team);
+        assert_eq!(Some(2), responsibility.scouting.handle_scouting_tasks);
+    }
+}
@@ -1,15 +1,18 @@
+use serde::{Deserialize, Serialize};
+
 // Assuming rand is available
 extern crate rand;
+use crate::awards::{Award, AwardScope};
 use crate::club::{
     PersonBehaviour, StaffClubContract, StaffPosition, StaffStatus
 };
 use crate::context::GlobalContext;
 use crate::shared::fullname::FullName;
-use crate::utils::DateUtils;
+use crate::utils::{DateUtils, RandomSeed};
 use crate::{CoachFocus, Logging, PersonAttributes, PersonBehaviourState, Relations, StaffAttributes, StaffCollectionResult, StaffResponsibility, StaffResult, StaffStub, TeamType, TrainingIntensity, TrainingType};
 use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Staff {
     pub id: u32,
     pub full_name: FullName,
@@ -29,9 +32,10 @@ pub struct Staff {
     pub recent_performance: StaffPerformance,
     pub coaching_style: CoachingStyle,
     pub training_schedule: Vec<StaffTrainingSession>,
+    pub awards: Vec<Award>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct StaffCollection {
     pub staffs: Vec<Staff>,
 
@@ -97,10 +101,102 @@ impl StaffCollection {
     pub fn head_coach(&self) -> &Staff {
         match self.manager() {
             Some(ref head_coach) => head_coach,
-            None => self.get_by_position(StaffPosition::AssistantManager),
+            None => match self.find_by_position(StaffPosition::CaretakerManager) {
+                Some(caretaker) => caretaker,
+                None => self.get_by_position(StaffPosition::AssistantManager),
+            },
+        }
+    }
+
+    /// Staff member who judges the academy players, the head coach at clubs without a youth chief
+    pub fn academy_director(&self) -> &Staff {
+        self.find_by_position(StaffPosition::HeadOfYouthDevelopment)
+            .or_else(|| self.find_by_position(StaffPosition::DirectorOfFootball))
+            .unwrap_or_else(|| self.head_coach())
+    }
+
+    pub fn manager_id(&self) -> Option<u32> {
+        self.manager().map(|manager| manager.id)
+    }
+
+    /// Remove a staff member from the club, e.g. after being sacked
+    pub fn release(&mut self, staff_id: u32) -> Option<Staff> {
+        let index = self.staffs.iter().position(|staff| staff.id == staff_id)?;
+        let mut staff = self.staffs.remove(index);
+
+        staff.contract = None;
+        self.responsibility.unassign(staff_id);
+
+        Some(staff)
+    }
+
+    /// Put the best internal coach in temporary charge of the team.
+    /// Returns the caretaker id and the position to restore afterwards
+    pub fn appoint_caretaker(&mut self) -> Option<(u32, StaffPosition)> {
+        let candidate = self
+            .staffs
+            .iter_mut()
+            .filter(|staff| {
+                staff.contract.as_ref().is_some_and(|contract| {
+                    matches!(
+                        contract.position,
+                        StaffPosition::AssistantManager
+                            | StaffPosition::FirstTeamCoach
+                            | StaffPosition::Coach
+                    )
+                })
+            })
+            .max_by_key(|staff| staff.manager_rating())?;
+
+        let contract = candidate.contract.as_mut()?;
+        let previous_position = contract.position;
+
+        contract.position = StaffPosition::CaretakerManager;
+
+        Some((candidate.id, previous_position))
+    }
+
+    pub fn restore_position(&mut self, staff_id: u32, position: StaffPosition) {
+        if let Some(contract) = self
+            .staffs
+            .iter_mut()
+            .find(|staff| staff.id == staff_id)
+            .and_then(|staff| staff.contract.as_mut())
+        {
+            contract.position = position;
         }
     }
 
+    /// Give the caretaker the job permanently
+    pub fn make_permanent(&mut self, staff_id: u32, expiration: NaiveDate) -> bool {
+        let contract = match self
+            .staffs
+            .iter_mut()
+            .find(|staff| staff.id == staff_id)
+            .and_then(|staff| staff.contract.as_mut())
+        {
+            Some(contract) => contract,
+            None => return false,
+        };
+
+        contract.position = StaffPosition::Manager;
+        contract.expired = expiration;
+
+        self.responsibility.training.training_first_team = Some(staff_id);
+
+        true
+    }
+
+    pub fn appoint_manager(&mut self, mut staff: Staff, contract: StaffClubContract) {
+        self.responsibility.training.training_first_team = Some(staff.id);
+
+        staff.contract = Some(contract);
+        staff.job_satisfaction = 75.0;
+
+        self.staffs.push(staff);
+    }
+
+    /// Staff member who negotiates player contracts, the director of football or head coach when nobody is assigned
     pub fn contract_resolver(&self, team_type: TeamType) -> &Staff {
         let staff_id = match team_type {
             TeamType::Main => {
@@ -120,7 +216,42 @@ impl StaffCollection {
             }
         };
 
-        self.get_by_id(staff_id.unwrap())
+        staff_id
+            .and_then(|staff_id| self.staffs.iter().find(|staff| staff.id == staff_id))
+            .or_else(|| self.find_by_position(StaffPosition::DirectorOfFootball))
+            .unwrap_or_else(|| self.head_coach())
+    }
+
+    pub fn get_week_salary(&self) -> u32 {
+        self.staffs
+            .iter()
+            .filter_map(|staff| staff.contract.as_ref())
+            .map(|contract| contract.salary)
+            .sum()
+    }
+
+    pub fn scouts(&self) -> Vec<&Staff> {
+        let scouting_lead = self.responsibility.scouting.handle_scouting_tasks;
+
+        self.staffs
+            .iter()
+            .filter(|staff| {
+                Some(staff.id) == scouting_lead
+                    || staff.contract.as_ref().is_some_and(|contract| {
+                        contract.position == StaffPosition::Scout
+                            || contract.position == StaffPosition::ChiefScout
+                    })
+            })
+            .collect()
+    }
+
+    fn find_by_position(&self, position: StaffPosition) -> Option<&Staff> {
+        self.staffs.iter().find(|staff| {
+            staff
+                .contract
+                .as_ref()
+                .is_some_and(|contract| contract.position == position)
+        })
     }
 
     fn get_by_position(&self, position: StaffPosition) -> &Staff {
@@ -146,7 +277,7 @@ impl StaffCollection {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffPerformance {
     pub training_effectiveness: f32,  // 0-1 multiplier
     pub player_development_rate: f32,  // 0-1 multiplier
@@ -155,7 +286,7 @@ pub struct StaffPerformance {
     pub last_evaluation_date: Option<NaiveDate>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum CoachingStyle {
     Authoritarian,    // Strict discipline, high demands
     Democratic,       // Collaborative, player input
@@ -193,9 +324,42 @@ impl Staff {
             recent_performance: StaffPerformance::default(),
             coaching_style: CoachingStyle::default(),
             training_schedule: Vec::new(),
+            awards: Vec::new(),
         }
     }
 
+    /// How suitable the staff member is to lead the first team
+    pub fn manager_rating(&self) -> u16 {
+        let coaching = &self.staff_attributes.coaching;
+        let mental = &self.staff_attributes.mental;
+
+        coaching.tactical as u16
+            + coaching.mental as u16
+            + mental.man_management as u16
+            + mental.motivating as u16
+            + self.staff_attributes.knowledge.tactical_knowledge as u16
+    }
+
+    /// Coaching reputation (0-1000), the scale of TeamReputation
+    pub fn reputation(&self) -> u16 {
+        // Coach of the season awards stay on the record
+        let awards: u16 = self
+            .awards
+            .iter()
+            .map(|award| match award.scope {
+                AwardScope::League(_) => 30,
+                AwardScope::Continent(_) => 80,
+            })
+            .sum();
+
+        (self.license.level() as u16 * 70 + self.manager_rating() * 5 + awards).min(1000)
+    }
+
+    /// Weekly wage the staff member expects from a new contract
+    pub fn expected_salary(&self) -> u32 {
+        self.calculate_market_value() as u32
+    }
+
     pub fn simulate(&mut self, ctx: GlobalContext<'_>) -> StaffResult {
         let now = ctx.simulation.date;
         let mut result = StaffResult::new();
@@ -316,7 +480,7 @@ impl Staff {
         // Extreme fatigue can lead to health issues
         if self.fatigue > 95.0 {
             result.add_warning(StaffWarning::BurnoutRisk);
-            if rand::random::<f32>() < 0.05 {
+            if RandomSeed::random::<f32>() < 0.05 {
                 result.health_issue = Some(HealthIssue::StressRelated);
             }
         }
@@ -405,7 +569,7 @@ impl Staff {
         let resignation_probability = self.calculate_resignation_probability();
 
         if resignation_probability > 0.0 {
-            if rand::random::<f32>() < resignation_probability {
+            if RandomSeed::random::<f32>() < resignation_probability {
                 result.resignation_risk = true;
 
                 if resignation_probability > 0.5 {
@@ -423,7 +587,7 @@ impl Staff {
 
         if ctx.simulation.date.hour() == 12 {  // Midday check
             // Small random relationship events
-            if rand::random::<f32>() < 0.1 {
+            if RandomSeed::random::<f32>() < 0.1 {
                 // Positive interaction with random player
                 result.relationship_event = Some(RelationshipEvent::PositiveInteraction);
 
@@ -431,7 +595,7 @@ impl Staff {
                 // self.relations.update_simple(player_id, 0.5);
             }
 
-            if rand::random::<f32>() < 0.05 && self.job_satisfaction < 40.0 {
+            if RandomSeed::random::<f32>() < 0.05 && self.job_satisfaction < 40.0 {
                 // Conflict when satisfaction is low
                 result.relationship_event = Some(RelationshipEvent::Conflict);
             }
@@ -465,7 +629,7 @@ impl Staff {
     fn process_professional_development(&mut self, ctx: &GlobalContext<'_>, result: &mut StaffResult) {
         // Check for license upgrade opportunities
         if self.should_upgrade_license() {
-            if rand::random::<f32>() < 0.01 {  // Small daily chance
+            if RandomSeed::random::<f32>() < 0.01 {  // Small daily chance
                 result.license_upgrade_available = true;
 
                 if self.attributes.ambition > 15.0 {
@@ -720,12 +884,12 @@ impl Staff {
 
     fn improve_attributes_from_experience(&mut self) {
         // Slow improvement over time
-        if rand::random::<f32>() < 0.3 {
+        if RandomSeed::random::<f32>() < 0.3 {
             // Small chance of improvement each month
             let improvement = 1;
 
             // Improve a random coaching attribute
-            match rand::random::<u8>() % 6 {
+            match RandomSeed::random::<u8>() % 6 {
                 0 => self.staff_attributes.coaching.attacking =
                     (self.staff_attributes.coaching.attacking + improvement).min(20),
                 1 => self.staff_attributes.coaching.defending =
@@ -810,7 +974,7 @@ pub enum RelationshipEvent {
     TrustBuilt,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum StaffLicenseType {
     ContinentalPro,
     ContinentalA,
@@ -821,6 +985,21 @@ pub enum StaffLicenseType {
     NationalC
 }
 
+impl StaffLicenseType {
+    /// 1 (NationalC) to 7 (ContinentalPro)
+    pub fn level(&self) -> u8 {
+        match self {
+            StaffLicenseType::ContinentalPro => 7,
+            StaffLicenseType::ContinentalA => 6,
+            StaffLicenseType::ContinentalB => 5,
+            StaffLicenseType::ContinentalC => 4,
+            StaffLicenseType::NationalA => 3,
+            StaffLicenseType::NationalB => 2,
+            StaffLicenseType::NationalC => 1,
+        }
+    }
+}
+
 // Default implementations
 impl Default for StaffPerformance {
     fn default() -> Self {
@@ -840,7 +1019,7 @@ impl Default for CoachingStyle {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffTrainingSession {
     pub session_type: TrainingType,
     pub intensity: TrainingIntensity,
@@ -4,8 +4,9 @@ use crate::utils::FloatUtils;
 use crate::{Person, PersonBehaviourState, Player, PlayerCollection, PlayerPositionType, PlayerRelation, StaffCollection};
 use chrono::{Datelike, NaiveDateTime};
 use log::{debug, info};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TeamBehaviour {
     last_full_update: Option<NaiveDateTime>,
     last_minor_update: Option<NaiveDateTime>,
@@ -525,45 +526,9 @@ impl TeamBehaviour {
     // (calculate_competition_factor, calculate_synergy_factor, etc.)
     // ... [Previous helper functions would go here] ...
 
+    /// Player's mood from -1 (miserable) to 1 (delighted), driven by their morale
     fn calculate_player_happiness(player: &Player) -> f32 {
-        let mut happiness = 0.0;
-
-        // Contract satisfaction
-        happiness += player
-            .contract
-            .as_ref()
-            .map(|c| (c.salary as f32 / 10000.0).min(1.0))
-            .unwrap_or(-0.5);
-
-        // Playing time satisfaction
-        if player.statistics.played > 20 {
-            happiness += 0.3;
-        } else if player.statistics.played > 10 {
-            happiness += 0.1;
-        } else {
-            happiness -= 0.2;
-        }
-
-        // Performance satisfaction
-        let goals_ratio = player.statistics.goals as f32 / player.statistics.played.max(1) as f32;
-        if player.position().is_forward() && goals_ratio > 0.5 {
-            happiness += 0.2;
-        } else if !player.position().is_forward() && goals_ratio > 0.3 {
-            happiness += 0.15;
-        }
-
-        // Personality factors
-        happiness += (player.attributes.professionalism - 10.0) / 100.0;
-        happiness -= (player.attributes.controversy - 10.0) / 50.0;
-
-        // Behavior state affects happiness
-        match player.behaviour.state {
-            PersonBehaviourState::Good => happiness += 0.2,
-            PersonBehaviourState::Poor => happiness -= 0.3,
-            PersonBehaviourState::Normal => {}
-        }
-
-        happiness.clamp(-1.0, 1.0)
+        ((player.morale.value - 50.0) / 50.0).clamp(-1.0, 1.0)
     }
 
     // pub fn simulate(
@@ -1,15 +1,22 @@
 use crate::context::GlobalContext;
 use crate::utils::Logging;
 use crate::{Team, TeamResult, TeamType};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TeamCollection {
     pub teams: Vec<Team>,
+    /// Reserves covering first team injuries, with the side they go back to
+    #[serde(default)]
+    pub injury_cover: Vec<(u32, u32)>,
 }
 
 impl TeamCollection {
     pub fn new(teams: Vec<Team>) -> Self {
-        TeamCollection { teams }
+        TeamCollection {
+            teams,
+            injury_cover: Vec::new(),
+        }
     }
 
     pub fn simulate(&mut self, ctx: GlobalContext<'_>) -> Vec<TeamResult> {
@@ -29,6 +36,10 @@ impl TeamCollection {
             .expect(format!("no team with id = {}", id).as_str())
     }
 
+    pub fn by_id_mut(&mut self, id: u32) -> Option<&mut Team> {
+        self.teams.iter_mut().find(|t| t.id == id)
+    }
+
     pub fn main_team_id(&self) -> Option<u32> {
         self.teams
             .iter()
@@ -36,6 +47,34 @@ impl TeamCollection {
             .map(|t| t.id)
     }
 
+    pub fn main_mut(&mut self) -> Option<&mut Team> {
+        self.teams.iter_mut().find(|t| t.team_type == TeamType::Main)
+    }
+
+    /// The side that takes first team players short of fitness and covers for injuries
+    pub fn reserve_team_id(&self) -> Option<u32> {
+        TeamType::RESERVES.iter().find_map(|team_type| {
+            self.teams
+                .iter()
+                .find(|t| t.team_type == *team_type)
+                .map(|t| t.id)
+        })
+    }
+
+    /// The first reserve side a player of this age can play for
+    pub fn reserve_team_for(&self, age: u8) -> Option<u32> {
+        TeamType::RESERVES.iter().find_map(|team_type| {
+            if team_type.max_age().is_some_and(|max_age| age > max_age) {
+                return None;
+            }
+
+            self.teams
+                .iter()
+                .find(|t| t.team_type == *team_type)
+                .map(|t| t.id)
+        })
+    }
+
     pub fn with_league(&self, league_id: u32) -> Vec<u32> {
         self.teams
             .iter()
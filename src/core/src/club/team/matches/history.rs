@@ -1,9 +1,10 @@
 use crate::r#match::TeamScore;
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
 
 const DEFAULT_MATCH_LIST_SIZE: usize = 10;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MatchHistory {
     items: Vec<MatchHistoryItem>,
 }
@@ -24,9 +25,26 @@ impl MatchHistory {
     pub fn add(&mut self, item: MatchHistoryItem) {
         self.items.push(item);
     }
+
+    pub fn matches_since(&self, date: NaiveDate) -> u16 {
+        self.items.iter().filter(|item| item.date.date() >= date).count() as u16
+    }
+
+    /// Points per game over the last `count` matches, none before the first match
+    pub fn points_per_game(&self, count: usize) -> Option<f32> {
+        let recent = &self.items[self.items.len().saturating_sub(count)..];
+
+        if recent.is_empty() {
+            return None;
+        }
+
+        let points: u32 = recent.iter().map(|item| item.points() as u32).sum();
+
+        Some(points as f32 / recent.len() as f32)
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct MatchHistoryItem {
     date: NaiveDateTime,
@@ -42,4 +60,18 @@ impl MatchHistoryItem {
             score,
         }
     }
+
+    pub fn date(&self) -> NaiveDateTime {
+        self.date
+    }
+
+    pub fn points(&self) -> u8 {
+        let (own, rival) = (self.score.0.get(), self.score.1.get());
+
+        match own.cmp(&rival) {
+            std::cmp::Ordering::Greater => 3,
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Less => 0,
+        }
+    }
 }
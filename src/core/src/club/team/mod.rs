@@ -3,6 +3,9 @@ pub mod builder;
 pub mod collection;
 pub mod context;
 pub mod matches;
+pub mod morale;
+pub mod movement;
+pub mod renewals;
 pub mod reputation;
 pub mod result;
 pub mod tactics;
@@ -15,6 +18,9 @@ pub use builder::*;
 pub use collection::*;
 pub use context::*;
 pub use matches::*;
+pub use morale::*;
+pub use movement::*;
+pub use renewals::*;
 pub use reputation::*;
 pub use result::*;
 pub use tactics::*;
@@ -0,0 +1,121 @@
+use crate::{MoraleSituation, Player, PlayerCollection, Team, TeamType};
+use chrono::{Duration, NaiveDate};
+
+/// Recent matches the squad judges the team's form on
+const FORM_MATCHES: usize = 5;
+
+/// Weekly look at how each player in the squad feels about their situation
+pub struct TeamMoraleReview;
+
+impl TeamMoraleReview {
+    pub fn weekly(team: &mut Team, date: NaiveDate) {
+        // Only first team games count as appearances, the rest of the club isn't judged on playing time
+        let is_first_team = team.team_type == TeamType::Main;
+
+        let team_matches = if is_first_team {
+            team.match_history.matches_since(date - Duration::days(7))
+        } else {
+            0
+        };
+        let points_per_game = team.match_history.points_per_game(FORM_MATCHES);
+        let manager_id = team.staffs.manager_id();
+
+        let wage_ratios: Vec<Option<f32>> = team
+            .players
+            .players
+            .iter()
+            .map(|player| Self::wage_ratio(player, &team.players))
+            .collect();
+
+        for (player, wage_ratio) in team.players.players.iter_mut().zip(wage_ratios) {
+            let situation = MoraleSituation {
+                appearances: player.statistics.played,
+                team_matches,
+                is_injured: player.player_attributes.is_injured,
+                wage_ratio,
+                points_per_game,
+                coach_receptiveness: manager_id
+                    .filter(|&manager_id| player.relations.get_staff(manager_id).is_some())
+                    .map(|manager_id| player.relations.get_coaching_receptiveness(manager_id)),
+            };
+
+            let squad_status = player
+                .contract
+                .as_ref()
+                .map(|contract| &contract.squad_status)
+                .filter(|_| is_first_team);
+
+            player
+                .morale
+                .review(&situation, squad_status, &player.attributes, date);
+        }
+    }
+
+    /// Salary against the average of teammates of similar ability
+    fn wage_ratio(player: &Player, squad: &PlayerCollection) -> Option<f32> {
+        let salary = player.contract.as_ref()?.salary;
+
+        squad.peer_salary(player).map(|peer_salary| salary as f32 / peer_salary as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r#match::TeamScore;
+    use crate::{
+        MatchHistoryItem, PlayerClubContract, PlayerGenerator, PlayerPositionType, PlayerSquadStatus,
+        StaffCollection, TeamReputation, TrainingSchedule,
+    };
+    use chrono::NaiveTime;
+
+    fn date(week: i64) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 9, 2).unwrap() + Duration::weeks(week)
+    }
+
+    fn team(team_type: TeamType) -> Team {
+        let mut player = PlayerGenerator::generate(1, date(0), PlayerPositionType::Striker, 18);
+
+        let mut contract = PlayerClubContract::new(1_000, date(100));
+        contract.squad_status = PlayerSquadStatus::KeyPlayer;
+        player.contract = Some(contract);
+
+        Team::builder()
+            .id(1)
+            .league_id(1)
+            .club_id(1)
+            .name("Team".to_string())
+            .slug("team".to_string())
+            .team_type(team_type)
+            .training_schedule(TrainingSchedule::new(
+                NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            ))
+            .reputation(TeamReputation::new(100, 100, 100))
+            .players(PlayerCollection::new(vec![player]))
+            .staffs(StaffCollection::new(Vec::new()))
+            .build()
+            .unwrap()
+    }
+
+    /// A few weeks of matches the player doesn't appear in
+    fn review_weeks(team: &mut Team) -> f32 {
+        for week in 1..=4 {
+            team.match_history.add(MatchHistoryItem::new(
+                (date(week) - Duration::days(2)).and_hms_opt(15, 0, 0).unwrap(),
+                2,
+                (TeamScore::new_with_score(1, 1), TeamScore::new_with_score(2, 1)),
+            ));
+
+            TeamMoraleReview::weekly(team, date(week));
+        }
+
+        team.players.players[0].morale.factors.playing_time
+    }
+
+    #[test]
+    fn only_first_team_players_miss_their_playing_time() {
+        assert!(review_weeks(&mut team(TeamType::Main)) < 0.0);
+        assert_eq!(0.0, review_weeks(&mut team(TeamType::B)));
+    }
+}
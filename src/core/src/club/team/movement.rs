@@ -0,0 +1,431 @@
+use crate::{Person, Player, PlayerFieldPositionGroup, PlayerStatusType, Team, TeamCollection, TeamType};
+use chrono::{Datelike, NaiveDate};
+use log::info;
+use std::cmp::Reverse;
+
+/// Fit players the first team keeps in each position group before calling on the reserves
+const FIRST_TEAM_COVER: [(PlayerFieldPositionGroup, usize); 4] = [
+    (PlayerFieldPositionGroup::Goalkeeper, 2),
+    (PlayerFieldPositionGroup::Defender, 6),
+    (PlayerFieldPositionGroup::Midfielder, 6),
+    (PlayerFieldPositionGroup::Forward, 3),
+];
+
+/// First team players sent down in a week to play their way back to fitness
+const MAX_FITNESS_DEMOTIONS: usize = 3;
+
+/// Over-age youth players move on before the new season
+const SQUAD_REVIEW_DAY: (u32, u32) = (7, 1);
+
+/// Moves players between the first team and the reserve and youth sides
+pub struct SquadMovement;
+
+impl SquadMovement {
+    pub fn is_review_day(date: NaiveDate) -> bool {
+        (date.month(), date.day()) == SQUAD_REVIEW_DAY
+    }
+
+    /// Match fit players come back, those short of fitness go down and injuries are covered from the reserves
+    /// until the first team has enough fit players again. Returns the number of players moved
+    pub fn weekly(teams: &mut TeamCollection, date: NaiveDate) -> usize {
+        let (main_id, reserve_id) = match (teams.main_team_id(), teams.reserve_team_id()) {
+            (Some(main_id), Some(reserve_id)) => (main_id, reserve_id),
+            _ => return 0,
+        };
+
+        Self::return_match_fit(teams, main_id)
+            + Self::end_injury_cover(teams, main_id, date)
+            + Self::demote_for_fitness(teams, main_id, reserve_id, date)
+            + Self::cover_injuries(teams, main_id)
+    }
+
+    /// Over-age youth players move up a side. Returns the number of players moved
+    pub fn yearly(teams: &mut TeamCollection, date: NaiveDate) -> usize {
+        let main_id = match teams.main_team_id() {
+            Some(main_id) => main_id,
+            None => return 0,
+        };
+
+        let senior_id = teams
+            .teams
+            .iter()
+            .find(|team| team.team_type == TeamType::B)
+            .map(|team| team.id)
+            .unwrap_or(main_id);
+
+        let youth_ids = Self::youth_teams(teams);
+        let mut moved = 0;
+
+        // Youngest sides first, so a player can move through several in one review
+        for (index, &(team_id, max_age)) in youth_ids.iter().enumerate() {
+            let next_id = youth_ids
+                .get(index + 1)
+                .map(|&(id, _)| id)
+                .unwrap_or(senior_id);
+
+            let over_age: Vec<u32> = teams
+                .by_id(team_id)
+                .players
+                .players
+                .iter()
+                .filter(|player| player.age(date) > max_age && !Self::is_short_of_fitness(player))
+                .map(|player| player.id)
+                .collect();
+
+            for player_id in over_age {
+                if Self::transfer(teams, player_id, team_id, next_id).is_some() {
+                    moved += 1;
+                }
+            }
+        }
+
+        moved
+    }
+
+    fn return_match_fit(teams: &mut TeamCollection, main_id: u32) -> usize {
+        let returning: Vec<(u32, u32)> = teams
+            .teams
+            .iter()
+            .filter(|team| team.id != main_id)
+            .flat_map(|team| {
+                team.players
+                    .players
+                    .iter()
+                    .filter(|player| Self::is_short_of_fitness(player) && player.is_ready_for_match())
+                    .map(move |player| (team.id, player.id))
+            })
+            .collect();
+
+        let mut moved = 0;
+
+        for (team_id, player_id) in returning {
+            if let Some(player) = Self::transfer(teams, player_id, team_id, main_id) {
+                player.statuses.remove(PlayerStatusType::Lmp);
+                moved += 1;
+            }
+        }
+
+        moved
+    }
+
+    fn demote_for_fitness(teams: &mut TeamCollection, main_id: u32, reserve_id: u32, date: NaiveDate) -> usize {
+        let main = teams.by_id(main_id);
+
+        let mut candidates: Vec<&Player> = main
+            .players
+            .players
+            .iter()
+            .filter(|player| Self::is_fit(player) && !player.is_on_international_duty())
+            .filter(|player| !player.is_ready_for_match())
+            .collect();
+
+        candidates.sort_by(|a, b| {
+            a.skills
+                .physical
+                .match_readiness
+                .total_cmp(&b.skills.physical.match_readiness)
+        });
+
+        let mut demoted: Vec<(u32, PlayerFieldPositionGroup)> = Vec::new();
+
+        for player in candidates {
+            if demoted.len() >= MAX_FITNESS_DEMOTIONS {
+                break;
+            }
+
+            let group = player.position().position_group();
+            let already_demoted = demoted.iter().filter(|(_, g)| *g == group).count();
+
+            // The first team keeps enough players to pick from
+            if Self::fit_count(main, group) - already_demoted > Self::required_cover(group) {
+                demoted.push((player.id, group));
+            }
+        }
+
+        let mut moved = 0;
+
+        for (player_id, _) in demoted {
+            if let Some(player) = Self::transfer(teams, player_id, main_id, reserve_id) {
+                player.statuses.add(date, PlayerStatusType::Lmp);
+                moved += 1;
+            }
+        }
+
+        moved
+    }
+
+    fn cover_injuries(teams: &mut TeamCollection, main_id: u32) -> usize {
+        let mut moved = 0;
+
+        for (group, required) in FIRST_TEAM_COVER {
+            let available = Self::fit_count(teams.by_id(main_id), group);
+
+            if available >= required {
+                continue;
+            }
+
+            // The best fit players of the B team first, then the youth sides
+            let called_up: Vec<(u32, u32)> = TeamType::RESERVES
+                .iter()
+                .filter_map(|team_type| teams.teams.iter().find(|team| team.team_type == *team_type))
+                .flat_map(|team| {
+                    let mut players: Vec<&Player> = team
+                        .players
+                        .players
+                        .iter()
+                        .filter(|player| Self::is_fit(player) && player.position().position_group() == group)
+                        .collect();
+
+                    players.sort_by_key(|player| Reverse(player.player_attributes.current_ability));
+                    players.into_iter().map(move |player| (team.id, player.id))
+                })
+                .take(required - available)
+                .collect();
+
+            for (team_id, player_id) in called_up {
+                if let Some(player) = Self::transfer(teams, player_id, team_id, main_id) {
+                    player.statuses.remove(PlayerStatusType::Lmp);
+                    info!("⬆️ {} called up to the first team to cover injuries", player.full_name);
+
+                    teams.injury_cover.push((player_id, team_id));
+                    moved += 1;
+                }
+            }
+        }
+
+        moved
+    }
+
+    /// Called up reserves go back to their side once the first team has more fit players than it needs
+    fn end_injury_cover(teams: &mut TeamCollection, main_id: u32, date: NaiveDate) -> usize {
+        // Players who left the first team some other way are no longer covering
+        let main = teams.by_id(main_id);
+        let covering: Vec<(u32, u32)> = teams
+            .injury_cover
+            .iter()
+            .filter(|(player_id, _)| main.players.contains(*player_id))
+            .copied()
+            .collect();
+
+        let mut released: Vec<(u32, u32)> = Vec::new();
+
+        for &(player_id, from_id) in &covering {
+            let group = match main.players.players.iter().find(|player| player.id == player_id) {
+                Some(player) => player.position().position_group(),
+                None => continue,
+            };
+
+            let already_released = released
+                .iter()
+                .filter_map(|(id, _)| main.players.players.iter().find(|player| player.id == *id))
+                .filter(|player| Self::is_fit(player) && player.position().position_group() == group)
+                .count();
+
+            if Self::fit_count(main, group) - already_released > Self::required_cover(group) {
+                released.push((player_id, from_id));
+            }
+        }
+
+        teams.injury_cover = covering;
+        teams.injury_cover.retain(|cover| !released.contains(cover));
+
+        let mut moved = 0;
+
+        for (player_id, from_id) in released {
+            // The side may have been closed, the first reserve side the player can play for takes them then
+            let to_id = match teams.teams.iter().any(|team| team.id == from_id) {
+                true => Some(from_id),
+                false => teams
+                    .by_id(main_id)
+                    .players
+                    .players
+                    .iter()
+                    .find(|player| player.id == player_id)
+                    .and_then(|player| teams.reserve_team_for(player.age(date))),
+            };
+
+            if let Some(player) = to_id.and_then(|to_id| Self::transfer(teams, player_id, main_id, to_id)) {
+                info!("⬇️ {} goes back to the reserves, the first team has its injured players back", player.full_name);
+                moved += 1;
+            }
+        }
+
+        moved
+    }
+
+    /// Youth sides with their age limits, youngest first
+    fn youth_teams(teams: &TeamCollection) -> Vec<(u32, u8)> {
+        let mut youth: Vec<(u32, u8)> = teams
+            .teams
+            .iter()
+            .filter_map(|team| team.team_type.max_age().map(|max_age| (team.id, max_age)))
+            .collect();
+
+        youth.sort_by_key(|&(_, max_age)| max_age);
+        youth
+    }
+
+    fn transfer(teams: &mut TeamCollection, player_id: u32, from_id: u32, to_id: u32) -> Option<&mut Player> {
+        teams.by_id_mut(to_id)?;
+
+        let player = teams.by_id_mut(from_id)?.players.take_player(&player_id)?;
+
+        let players = &mut teams.by_id_mut(to_id)?.players.players;
+        players.push(player);
+        players.last_mut()
+    }
+
+    /// Away players still count, they are back after the international break
+    fn is_fit(player: &Player) -> bool {
+        !player.player_attributes.is_injured && !player.player_attributes.is_banned
+    }
+
+    fn is_short_of_fitness(player: &Player) -> bool {
+        player.statuses.get().contains(&PlayerStatusType::Lmp)
+    }
+
+    fn fit_count(team: &Team, group: PlayerFieldPositionGroup) -> usize {
+        team.players
+            .players
+            .iter()
+            .filter(|player| Self::is_fit(player) && player.position().position_group() == group)
+            .count()
+    }
+
+    fn required_cover(group: PlayerFieldPositionGroup) -> usize {
+        FIRST_TEAM_COVER
+            .iter()
+            .find(|(g, _)| *g == group)
+            .map(|(_, required)| *required)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PlayerCollection, PlayerGenerator, PlayerPositionType, StaffCollection, TeamReputation, TrainingSchedule};
+    use chrono::NaiveTime;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 9, 2).unwrap()
+    }
+
+    fn player(id: u32, position: PlayerPositionType) -> Player {
+        let mut player = PlayerGenerator::generate(1, date(), position, 20);
+        player.id = id;
+        player.skills.physical.match_readiness = 15.0;
+        player
+    }
+
+    fn team(id: u32, team_type: TeamType, players: Vec<Player>) -> Team {
+        Team::builder()
+            .id(id)
+            .league_id(id)
+            .club_id(1)
+            .name(format!("Team {}", id))
+            .slug(format!("team-{}", id))
+            .team_type(team_type)
+            .training_schedule(TrainingSchedule::new(
+                NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            ))
+            .reputation(TeamReputation::new(100, 100, 100))
+            .players(PlayerCollection::new(players))
+            .staffs(StaffCollection::new(Vec::new()))
+            .build()
+            .unwrap()
+    }
+
+    fn goalkeepers(from_id: u32, count: u32) -> Vec<Player> {
+        (from_id..from_id + count)
+            .map(|id| player(id, PlayerPositionType::Goalkeeper))
+            .collect()
+    }
+
+    #[test]
+    fn injuries_are_covered_from_the_b_team_first() {
+        let mut main_players = goalkeepers(1, 3);
+        main_players[0].player_attributes.is_injured = true;
+        main_players[1].player_attributes.is_injured = true;
+
+        let mut teams = TeamCollection::new(vec![
+            team(1, TeamType::Main, main_players),
+            team(2, TeamType::U19, goalkeepers(20, 2)),
+            team(3, TeamType::B, goalkeepers(10, 2)),
+        ]);
+
+        SquadMovement::weekly(&mut teams, date());
+
+        let main = teams.by_id(1);
+        assert_eq!(2, SquadMovement::fit_count(main, PlayerFieldPositionGroup::Goalkeeper));
+        assert!(main.players.players.iter().any(|p| p.id == 10 || p.id == 11));
+        assert_eq!(2, teams.by_id(2).players.players.len());
+    }
+
+    #[test]
+    fn injury_cover_goes_back_once_the_first_team_is_fit() {
+        let mut main_players = goalkeepers(1, 2);
+        main_players[0].player_attributes.is_injured = true;
+
+        let mut teams = TeamCollection::new(vec![
+            team(1, TeamType::Main, main_players),
+            team(2, TeamType::U19, goalkeepers(20, 1)),
+        ]);
+
+        assert_eq!(1, SquadMovement::weekly(&mut teams, date()));
+        assert!(teams.by_id(1).players.contains(20));
+
+        // Still needed while the injured keeper is out
+        assert_eq!(0, SquadMovement::weekly(&mut teams, date()));
+
+        teams.teams[0].players.players[0].player_attributes.is_injured = false;
+        assert_eq!(1, SquadMovement::weekly(&mut teams, date()));
+
+        assert!(teams.by_id(2).players.contains(20));
+        assert_eq!(2, teams.by_id(1).players.players.len());
+        assert!(teams.injury_cover.is_empty());
+    }
+
+    #[test]
+    fn players_short_of_fitness_play_for_the_reserves_until_ready() {
+        let mut main_players = goalkeepers(1, 4);
+        main_players[3].skills.physical.match_readiness = 4.0;
+
+        let mut teams = TeamCollection::new(vec![
+            team(1, TeamType::Main, main_players),
+            team(2, TeamType::B, Vec::new()),
+        ]);
+
+        assert_eq!(1, SquadMovement::weekly(&mut teams, date()));
+
+        let demoted = &teams.by_id(2).players.players;
+        assert_eq!(1, demoted.len());
+        assert!(demoted[0].statuses.get().contains(&PlayerStatusType::Lmp));
+
+        teams.teams[1].players.players[0].skills.physical.match_readiness = 12.0;
+        SquadMovement::weekly(&mut teams, date());
+
+        assert!(teams.by_id(2).players.players.is_empty());
+        assert_eq!(4, teams.by_id(1).players.players.len());
+    }
+
+    #[test]
+    fn over_age_youth_players_move_up() {
+        let mut youngster = player(2, PlayerPositionType::Striker);
+        youngster.birth_date = NaiveDate::from_ymd_opt(2008, 1, 1).unwrap();
+
+        let mut veteran = player(3, PlayerPositionType::Striker);
+        veteran.birth_date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+
+        let mut teams = TeamCollection::new(vec![
+            team(1, TeamType::Main, Vec::new()),
+            team(2, TeamType::U19, vec![youngster, veteran]),
+        ]);
+
+        assert_eq!(1, SquadMovement::yearly(&mut teams, date()));
+
+        assert!(teams.by_id(2).players.contains(2));
+        assert!(teams.by_id(1).players.contains(3));
+    }
+}
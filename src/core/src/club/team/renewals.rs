@@ -0,0 +1,217 @@
+use crate::{
+    ContractNegotiation, Player, PlayerInteractionType, PlayerPromiseType, PlayerSquadStatus, Team,
+    TeamType,
+};
+use chrono::NaiveDate;
+use log::debug;
+
+/// Window before expiry in which the club opens talks
+const RENEWAL_WINDOW_DAYS: (i64, i64) = (365, 548);
+
+/// Days before the club tries again after talks broke down
+const FAILED_TALKS_COOLDOWN_DAYS: i64 = 90;
+
+/// Morale a player gains from a new deal
+const RENEWAL_MORALE: f32 = 10.0;
+
+/// Monthly round of contract talks with the players the club wants to keep
+pub struct ContractRenewals;
+
+impl ContractRenewals {
+    pub fn monthly(team: &mut Team, can_afford: bool, date: NaiveDate) {
+        let negotiator = team.staffs.contract_resolver(TeamType::Main);
+        let negotiator_id = negotiator.id;
+
+        let candidates: Vec<(usize, Option<u32>)> = team
+            .players
+            .players
+            .iter()
+            .enumerate()
+            .filter(|(_, player)| Self::is_candidate(player, date))
+            .map(|(index, player)| (index, team.players.peer_salary(player)))
+            .collect();
+
+        let outcomes: Vec<_> = candidates
+            .into_iter()
+            .map(|(index, peer_salary)| {
+                let player = &team.players.players[index];
+
+                let demands = ContractNegotiation::demands(player, peer_salary, date);
+                let offer = ContractNegotiation::offer(player, peer_salary, can_afford, date);
+
+                (
+                    index,
+                    ContractNegotiation::negotiate(&demands, &offer, negotiator),
+                )
+            })
+            .collect();
+
+        for (index, renewal) in outcomes {
+            let player = &mut team.players.players[index];
+
+            match renewal {
+                Some(renewal) => {
+                    let contract =
+                        ContractNegotiation::contract(&renewal, player.contract.take(), date);
+
+                    debug!(
+                        "contract renewed: player {} salary {} until {}",
+                        player.id, contract.salary, contract.expiration
+                    );
+
+                    player.contract = Some(contract);
+                    player.morale.change(RENEWAL_MORALE);
+                    player.interactions.record(
+                        date,
+                        PlayerInteractionType::ContractRenewed,
+                        Some(negotiator_id),
+                    );
+                }
+                None => {
+                    player.interactions.record(
+                        date,
+                        PlayerInteractionType::ContractTalksFailed,
+                        Some(negotiator_id),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Valued players near the end of their deal, or anyone the coach promised a new one
+    fn is_candidate(player: &Player, date: NaiveDate) -> bool {
+        let contract = match &player.contract {
+            Some(contract) => contract,
+            None => return false,
+        };
+
+        if contract.is_transfer_listed {
+            return false;
+        }
+
+        let recently_failed = player
+            .interactions
+            .days_since(PlayerInteractionType::ContractTalksFailed, date)
+            .is_some_and(|days| days < FAILED_TALKS_COOLDOWN_DAYS);
+
+        if recently_failed {
+            return false;
+        }
+
+        if player
+            .interactions
+            .has_promise(PlayerPromiseType::NewContract)
+        {
+            return true;
+        }
+
+        let valued = matches!(
+            contract.squad_status,
+            PlayerSquadStatus::KeyPlayer
+                | PlayerSquadStatus::FirstTeamRegular
+                | PlayerSquadStatus::HotProspectForTheFuture
+        );
+
+        let days_left = (contract.expiration - date).num_days();
+
+        valued && days_left >= RENEWAL_WINDOW_DAYS.0 && days_left <= RENEWAL_WINDOW_DAYS.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        PlayerClubContract, PlayerCollection, PlayerGenerator, PlayerPositionType, PlayerPromise,
+        StaffCollection, TeamReputation, TrainingSchedule,
+    };
+    use chrono::{Duration, NaiveTime};
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 9, 2).unwrap()
+    }
+
+    fn player(id: u32, days_left: i64, squad_status: PlayerSquadStatus) -> Player {
+        let mut player = PlayerGenerator::generate(1, date(), PlayerPositionType::Striker, 18);
+        player.id = id;
+
+        let mut contract = PlayerClubContract::new(1_000, date() + Duration::days(days_left));
+        contract.squad_status = squad_status;
+        player.contract = Some(contract);
+
+        player
+    }
+
+    fn team(players: Vec<Player>) -> Team {
+        Team::builder()
+            .id(1)
+            .league_id(1)
+            .club_id(1)
+            .name("Team".to_string())
+            .slug("team".to_string())
+            .team_type(TeamType::Main)
+            .training_schedule(TrainingSchedule::new(
+                NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            ))
+            .reputation(TeamReputation::new(100, 100, 100))
+            .players(PlayerCollection::new(players))
+            .staffs(StaffCollection::new(Vec::new()))
+            .build()
+            .unwrap()
+    }
+
+    /// Players the club sat down with, whether they signed or not
+    fn talked_to(team: &Team) -> Vec<u32> {
+        team.players
+            .players
+            .iter()
+            .filter(|player| {
+                [
+                    PlayerInteractionType::ContractRenewed,
+                    PlayerInteractionType::ContractTalksFailed,
+                ]
+                .into_iter()
+                .any(|talks| player.interactions.days_since(talks, date()) == Some(0))
+            })
+            .map(|player| player.id)
+            .collect()
+    }
+
+    #[test]
+    fn talks_open_between_a_year_and_eighteen_months_before_expiry() {
+        let mut team = team(vec![
+            player(1, 364, PlayerSquadStatus::KeyPlayer),
+            player(2, 365, PlayerSquadStatus::KeyPlayer),
+            player(3, 548, PlayerSquadStatus::FirstTeamRegular),
+            player(4, 549, PlayerSquadStatus::KeyPlayer),
+            player(5, 400, PlayerSquadStatus::NotNeeded),
+        ]);
+
+        ContractRenewals::monthly(&mut team, true, date());
+
+        assert_eq!(talked_to(&team), vec![2, 3]);
+    }
+
+    #[test]
+    fn promised_players_are_talked_to_outside_the_window() {
+        let mut promised = player(1, 900, PlayerSquadStatus::MainBackupPlayer);
+        let expiration = promised.contract.as_ref().unwrap().expiration;
+        promised
+            .interactions
+            .promise(PlayerPromise::new_contract(1, date(), expiration));
+
+        let mut recently_failed = player(2, 400, PlayerSquadStatus::KeyPlayer);
+        recently_failed.interactions.record(
+            date() - Duration::days(30),
+            PlayerInteractionType::ContractTalksFailed,
+            None,
+        );
+
+        let mut team = team(vec![promised, recently_failed]);
+
+        ContractRenewals::monthly(&mut team, true, date());
+
+        assert_eq!(talked_to(&team), vec![1]);
+    }
+}
@@ -1,8 +1,9 @@
 use chrono::NaiveDate;
 use std::collections::VecDeque;
+use serde::{Deserialize, Serialize};
 
 /// Enhanced TeamReputation with dynamic updates and history tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamReputation {
     /// Local/regional reputation (0-1000)
     pub home: u16,
@@ -260,7 +261,7 @@ impl TeamReputation {
 }
 
 /// Reputation momentum tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct ReputationMomentum {
     current: f32,
     history: VecDeque<f32>,
@@ -305,7 +306,7 @@ impl ReputationMomentum {
 }
 
 /// Historical reputation tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct ReputationHistory {
     snapshots: VecDeque<(NaiveDate, ReputationSnapshot)>,
     max_snapshots: usize,
@@ -354,7 +355,7 @@ impl ReputationHistory {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct ReputationSnapshot {
     home: u16,
     national: u16,
@@ -363,7 +364,7 @@ struct ReputationSnapshot {
 }
 
 /// Factors affecting reputation
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct ReputationFactors {
     achievements: Vec<Achievement>,
     star_players_signed: u8,
@@ -406,7 +407,7 @@ pub enum ReputationTrend {
 }
 
 /// Achievement that affects reputation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Achievement {
     achievement_type: AchievementType,
     date: NaiveDate,
@@ -449,7 +450,7 @@ impl Achievement {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AchievementType {
     LeagueTitle,
     CupWin,
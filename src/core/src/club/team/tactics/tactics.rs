@@ -1,14 +1,15 @@
 use crate::club::{PersonBehaviourState, Player, PlayerPositionType, Staff};
 use crate::Team;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tactics {
     pub tactic_type: MatchTacticType,
     pub selected_reason: TacticSelectionReason,
     pub formation_strength: f32, // 0.0 to 1.0 indicating how well this formation suits the team
 }
 
-#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub enum TacticSelectionReason {
     CoachPreference,
     TeamComposition,
@@ -241,7 +242,7 @@ pub const TACTICS_POSITIONS: &[(MatchTacticType, [PlayerPositionType; 11])] = &[
     // Add more formations as needed...
 ];
 
-#[derive(Copy, Debug, Eq, PartialEq, PartialOrd, Clone, Hash)]
+#[derive(Copy, Debug, Eq, PartialEq, PartialOrd, Clone, Hash, Serialize, Deserialize)]
 pub enum MatchTacticType {
     T442,
     T433,
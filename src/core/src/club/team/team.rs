@@ -4,13 +4,16 @@ use crate::r#match::{
     EnhancedTacticsSelector, MatchPlayer, MatchSquad, SquadSelector,
 };
 use crate::shared::CurrencyValue;
-use crate::{MatchHistory, MatchTacticType, Player, PlayerCollection, RecommendationPriority, StaffCollection, Tactics, TacticsSelector, TeamReputation, TeamResult, TeamTraining, TrainingSchedule, TransferItem, Transfers};
+use crate::{InternationalBreak, MatchHistory, MatchTacticType, Player, PlayerCollection, RecommendationPriority, StaffCollection, Tactics, TacticsSelector, TeamReputation, TeamResult, TeamTraining, TrainingSchedule, TransferItem, Transfers};
+use chrono::NaiveDate;
 use log::{debug, info};
 use std::borrow::Cow;
 use std::str::FromStr;
 use crate::club::team::builder::TeamBuilder;
+use crate::club::TeamMoraleReview;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum TeamType {
     Main = 0,
     B = 1,
@@ -20,7 +23,7 @@ pub enum TeamType {
     U23 = 5,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Team {
     pub id: u32,
     pub league_id: u32,
@@ -48,6 +51,10 @@ impl Team {
     }
 
     pub fn simulate(&mut self, ctx: GlobalContext<'_>) -> TeamResult {
+        if ctx.simulation.is_week_beginning() {
+            TeamMoraleReview::weekly(self, ctx.simulation.date.date());
+        }
+
         let result = TeamResult::new(
             self.id,
             self.players.simulate(ctx.with_player(None)),
@@ -72,6 +79,12 @@ impl Team {
         self.players.players()
     }
 
+    /// Players away with a national team outside the international breaks, at a summer finals
+    pub fn is_missing_players_to_finals(&self, date: NaiveDate) -> bool {
+        InternationalBreak::on(date).is_none()
+            && self.players.players().iter().any(|player| player.is_on_international_duty())
+    }
+
     pub fn add_player_to_transfer_list(&mut self, player_id: u32, value: CurrencyValue) {
         self.transfer_list.add(TransferItem {
             player_id,
@@ -369,6 +382,28 @@ impl Team {
     }
 }
 
+impl TeamType {
+    /// Reserve sides in the order they supply players to the first team
+    pub const RESERVES: [TeamType; 5] = [
+        TeamType::B,
+        TeamType::U23,
+        TeamType::U21,
+        TeamType::U19,
+        TeamType::U18,
+    ];
+
+    /// Oldest age a player can be to play for the side, none for senior teams
+    pub fn max_age(&self) -> Option<u8> {
+        match self {
+            TeamType::Main | TeamType::B => None,
+            TeamType::U18 => Some(18),
+            TeamType::U19 => Some(19),
+            TeamType::U21 => Some(21),
+            TeamType::U23 => Some(23),
+        }
+    }
+}
+
 impl FromStr for TeamType {
     type Err = String;
 
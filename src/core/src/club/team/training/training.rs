@@ -1,7 +1,8 @@
 use crate::club::player::training::result::PlayerTrainingResult;
-use crate::{Player, PlayerPositionType, PlayerTraining, Staff, Team, TeamTrainingResult};
+use crate::{CoachingStyle, Player, PlayerPositionType, PlayerTraining, Staff, Team, TeamTrainingResult};
 use chrono::{Datelike, NaiveDateTime, Weekday};
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug)]
 pub struct TeamTraining;
@@ -110,6 +111,7 @@ impl TeamTraining {
     fn can_participate(player: &Player) -> bool {
         !player.player_attributes.is_injured &&
             !player.player_attributes.is_banned &&
+            !player.is_on_international_duty() &&
             player.player_attributes.condition_percentage() > 30
     }
 
@@ -171,25 +173,36 @@ impl TeamTraining {
             TacticalFocus::Balanced
         };
 
-        let training_intensity = if coach.staff_attributes.coaching.fitness > 15 {
+        let training_intensity = if coach.staff_attributes.coaching.fitness > 15
+            || coach.coaching_style == CoachingStyle::Authoritarian
+        {
             TrainingIntensityPreference::High
-        } else if coach.staff_attributes.coaching.fitness < 10 {
+        } else if coach.staff_attributes.coaching.fitness < 10
+            || coach.coaching_style == CoachingStyle::LaissezFaire
+        {
             TrainingIntensityPreference::Low
         } else {
             TrainingIntensityPreference::Medium
         };
 
+        let rotation_preference = match coach.coaching_style {
+            CoachingStyle::Authoritarian | CoachingStyle::Tactical => RotationPreference::Minimal,
+            CoachingStyle::Democratic | CoachingStyle::Transformational => RotationPreference::Moderate,
+            CoachingStyle::LaissezFaire => RotationPreference::Heavy,
+        };
+
         CoachingPhilosophy {
             tactical_focus,
             training_intensity,
-            youth_focus: coach.staff_attributes.coaching.working_with_youngsters > 12,
-            rotation_preference: RotationPreference::Moderate,
+            youth_focus: coach.staff_attributes.coaching.working_with_youngsters > 12
+                || coach.coaching_style == CoachingStyle::Transformational,
+            rotation_preference,
         }
     }
 }
 
 
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub enum TrainingType {
     // Physical Training
     #[default]
@@ -238,7 +251,7 @@ pub struct TrainingSession {
     pub participants: Vec<u32>, // Player IDs
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TrainingIntensity {
     VeryLight,  // 20-40% max effort - recovery sessions
     Light,      // 40-60% max effort - technical work
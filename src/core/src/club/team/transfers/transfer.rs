@@ -1,8 +1,9 @@
 use crate::shared::CurrencyValue;
+use serde::{Deserialize, Serialize};
 
 const DEFAULT_TRANSFER_LIST_SIZE: usize = 10;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Transfers {
     items: Vec<TransferItem>,
 }
@@ -19,7 +20,7 @@ impl Transfers {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TransferItem {
     pub player_id: u32,
     pub amount: CurrencyValue,
@@ -1,7 +1,6 @@
 use crate::shared::CurrencyValue;
 use crate::transfers::offer::{TransferClause, TransferOffer};
-use crate::transfers::window::PlayerValuationCalculator;
-use crate::{Person, Player, PlayerPositionType};
+use crate::{Person, Player, PlayerPositionType, ScoutingReport};
 use chrono::NaiveDate;
 
 pub struct ClubTransferStrategy {
@@ -25,8 +24,13 @@ impl ClubTransferStrategy {
         }
     }
 
-    pub fn decide_player_interest(&self, player: &Player) -> bool {
-        // Decide if the club should be interested in this player
+    pub fn decide_player_interest(&self, player: &Player, report: Option<&ScoutingReport>) -> bool {
+        // Decide if the club should be interested in this player.
+        // The club only knows what its scouts have seen, never the true ability
+        let report = match report {
+            Some(report) => report,
+            None => return false,
+        };
 
         // Position need
         let position_needed = self.target_positions.contains(&player.position());
@@ -42,7 +46,7 @@ impl ClubTransferStrategy {
         }
 
         // Quality check
-        let player_ability = player.player_attributes.current_ability as u16;
+        let player_ability = report.estimated_current_ability as u16;
 
         // Is player good enough for the club?
         if player_ability < self.reputation_level / 2 {
@@ -58,9 +62,11 @@ impl ClubTransferStrategy {
         true
     }
 
+    /// The offer follows what the scouts believe the player is worth, not the player's true ability
     pub fn calculate_initial_offer(
         &self,
         player: &Player,
+        report: &ScoutingReport,
         asking_price: &CurrencyValue,
         current_date: NaiveDate
     ) -> TransferOffer {
@@ -70,16 +76,22 @@ impl ClubTransferStrategy {
             None => f64::MAX, // No budget constraint
         };
 
-        // Calculate base valuation
-        let player_value = PlayerValuationCalculator::calculate_value(player, current_date);
+        let age = player.age(current_date);
+
+        // Calculate base valuation from the scouting report
+        let player_value = Self::scouted_value(report, age);
 
         // Adjust based on asking price
         let mut offer_amount = if asking_price.amount > 0.0 {
-            // Start with 60-90% of asking price depending on aggressiveness
-            asking_price.amount * (0.6 + (self.buying_aggressiveness as f64 * 0.3))
+            // Start with 60-90% of asking price depending on aggressiveness,
+            // but above our own valuation only as far as the opening 60%
+            let opening = asking_price.amount * 0.6;
+            let offer = asking_price.amount * (0.6 + (self.buying_aggressiveness as f64 * 0.3));
+
+            offer.min(player_value.max(opening))
         } else {
             // No asking price - use our valuation but discount it
-            player_value.amount * (0.7 + (self.buying_aggressiveness as f64 * 0.2f64))
+            player_value * (0.7 + (self.buying_aggressiveness as f64 * 0.2f64))
         };
 
         // Cap by budget - never offer more than 80% of available budget
@@ -100,10 +112,9 @@ impl ClubTransferStrategy {
 
         // Add clauses based on player profile and club strategy
 
-        // 1. Add sell-on clause for young players with potential
-        let age = player.age(current_date);
-        let potential_gap = player.player_attributes.potential_ability as i16 -
-            player.player_attributes.current_ability as i16;
+        // 1. Add sell-on clause for young players the scouts see potential in
+        let potential_gap = report.estimated_potential_ability as i16 -
+            report.estimated_current_ability as i16;
 
         if age < 23 && potential_gap > 10 {
             // Add sell-on clause for promising youngsters
@@ -159,4 +170,19 @@ impl ClubTransferStrategy {
 
         offer.with_contract_length(contract_years)
     }
+
+    /// Estimated ability priced like the skills based player value, young players add their estimated potential
+    fn scouted_value(report: &ScoutingReport, age: u8) -> f64 {
+        const PRICE_PER_ABILITY_POINT: f64 = 100_000.0;
+
+        let value = report.estimated_current_ability as f64 * PRICE_PER_ABILITY_POINT;
+
+        if age < 23 {
+            let potential_gap = report.estimated_potential_ability.saturating_sub(report.estimated_current_ability);
+
+            value * (1.0 + potential_gap as f64 / 100.0)
+        } else {
+            value
+        }
+    }
 }
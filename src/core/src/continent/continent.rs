@@ -1,12 +1,16 @@
+use crate::awards::SeasonAwards;
 use crate::context::GlobalContext;
-use crate::continent::ContinentResult;
+use crate::continent::{ContinentResult, ContinentalGroupStage};
 use crate::country::CountryResult;
 use crate::utils::Logging;
-use crate::{Club, Country};
-use chrono::NaiveDate;
-use log::{debug, info};
+use crate::country::TournamentGroup;
+use crate::{Club, Country, TeamType};
+use chrono::{Datelike, NaiveDate, Weekday};
+use log::info;
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize)]
 pub struct Continent {
     pub id: u32,
     pub name: String,
@@ -16,6 +20,7 @@ pub struct Continent {
     pub continental_rankings: ContinentalRankings,
     pub regulations: ContinentalRegulations,
     pub economic_zone: EconomicZone,
+    pub awards: Vec<SeasonAwards>,
 }
 
 impl Continent {
@@ -28,6 +33,7 @@ impl Continent {
             continental_rankings: ContinentalRankings::new(),
             regulations: ContinentalRegulations::new(),
             economic_zone: EconomicZone::new(),
+            awards: Vec::new(),
         }
     }
 
@@ -45,7 +51,7 @@ impl Continent {
 
         info!("✅ Continent {} simulation complete", continent_name);
 
-        ContinentResult::new(country_results)
+        ContinentResult::new(self.id, country_results)
     }
 
     fn simulate_countries(&mut self, ctx: &GlobalContext<'_>) -> Vec<CountryResult> {
@@ -64,7 +70,7 @@ impl Continent {
 
 // Supporting structures for continental simulation
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContinentalCompetitions {
     pub champions_league: ChampionsLeague,
     pub europa_league: EuropaLeague,
@@ -106,12 +112,16 @@ impl ContinentalCompetitions {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChampionsLeague {
     pub participating_clubs: Vec<u32>,
     pub current_stage: CompetitionStage,
     pub matches: Vec<ContinentalMatch>,
     pub prize_pool: f64,
+    #[serde(default)]
+    pub groups: Vec<TournamentGroup>,
+    #[serde(default)]
+    pub season: Option<i32>,
 }
 
 impl Default for ChampionsLeague {
@@ -127,12 +137,26 @@ impl ChampionsLeague {
             current_stage: CompetitionStage::NotStarted,
             matches: Vec::new(),
             prize_pool: 2_000_000_000.0, // 2 billion euros
+            groups: Vec::new(),
+            season: None,
         }
     }
 
-    pub fn conduct_draw(&mut self, clubs: &[u32], _rankings: &ContinentalRankings, _date: NaiveDate) {
-        // Implement draw logic with seeding based on rankings
-        debug!("Champions League draw conducted with {} clubs", clubs.len());
+    /// Draws the qualified clubs into groups once a season, later draw dates keep the groups
+    pub fn conduct_draw(&mut self, clubs: &[u32], rankings: &ContinentalRankings, date: NaiveDate) {
+        if clubs.len() < 2 || self.season == Some(date.year()) {
+            return;
+        }
+
+        let (groups, matches) = ContinentalGroupStage::draw(clubs, rankings, Weekday::Tue, date);
+
+        info!("Champions League draw: {} clubs in {} groups", clubs.len(), groups.len());
+
+        self.participating_clubs = clubs.to_vec();
+        self.current_stage = CompetitionStage::GroupStage;
+        self.groups = groups;
+        self.matches = matches;
+        self.season = Some(date.year());
     }
 
     pub fn has_matches_today(&self, date: NaiveDate) -> bool {
@@ -141,25 +165,10 @@ impl ChampionsLeague {
 
     pub fn simulate_round(
         &mut self,
-        _clubs: &HashMap<u32, &Club>,
+        clubs: &HashMap<u32, &Club>,
         date: NaiveDate,
     ) -> Vec<ContinentalMatchResult> {
-        let mut results = Vec::new();
-
-        for match_to_play in self.matches.iter_mut().filter(|m| m.date == date) {
-            // Simulate match (simplified)
-            let result = ContinentalMatchResult {
-                home_team: match_to_play.home_team,
-                away_team: match_to_play.away_team,
-                home_score: 0,
-                away_score: 0,
-                competition: CompetitionTier::ChampionsLeague,
-            };
-
-            results.push(result);
-        }
-
-        results
+        ContinentalGroupStage::play(&mut self.groups, &self.matches, clubs, date, CompetitionTier::ChampionsLeague)
     }
 
     pub fn get_club_points(&self, club_id: u32) -> f32 {
@@ -173,12 +182,16 @@ impl ChampionsLeague {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EuropaLeague {
     pub participating_clubs: Vec<u32>,
     pub current_stage: CompetitionStage,
     pub matches: Vec<ContinentalMatch>,
     pub prize_pool: f64,
+    #[serde(default)]
+    pub groups: Vec<TournamentGroup>,
+    #[serde(default)]
+    pub season: Option<i32>,
 }
 
 impl EuropaLeague {
@@ -188,11 +201,26 @@ impl EuropaLeague {
             current_stage: CompetitionStage::NotStarted,
             matches: Vec::new(),
             prize_pool: 500_000_000.0, // 500 million euros
+            groups: Vec::new(),
+            season: None,
         }
     }
 
-    pub fn conduct_draw(&mut self, clubs: &[u32], _rankings: &ContinentalRankings, _date: NaiveDate) {
-        debug!("Europa League draw conducted with {} clubs", clubs.len());
+    /// Draws the qualified clubs into groups once a season, later draw dates keep the groups
+    pub fn conduct_draw(&mut self, clubs: &[u32], rankings: &ContinentalRankings, date: NaiveDate) {
+        if clubs.len() < 2 || self.season == Some(date.year()) {
+            return;
+        }
+
+        let (groups, matches) = ContinentalGroupStage::draw(clubs, rankings, Weekday::Thu, date);
+
+        info!("Europa League draw: {} clubs in {} groups", clubs.len(), groups.len());
+
+        self.participating_clubs = clubs.to_vec();
+        self.current_stage = CompetitionStage::GroupStage;
+        self.groups = groups;
+        self.matches = matches;
+        self.season = Some(date.year());
     }
 
     pub fn has_matches_today(&self, date: NaiveDate) -> bool {
@@ -201,10 +229,10 @@ impl EuropaLeague {
 
     pub fn simulate_round(
         &mut self,
-        _clubs: &HashMap<u32, &Club>,
-        _date: NaiveDate,
+        clubs: &HashMap<u32, &Club>,
+        date: NaiveDate,
     ) -> Vec<ContinentalMatchResult> {
-        Vec::new() // Simplified
+        ContinentalGroupStage::play(&mut self.groups, &self.matches, clubs, date, CompetitionTier::EuropaLeague)
     }
 
     pub fn get_club_points(&self, club_id: u32) -> f32 {
@@ -215,12 +243,16 @@ impl EuropaLeague {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConferenceLeague {
     pub participating_clubs: Vec<u32>,
     pub current_stage: CompetitionStage,
     pub matches: Vec<ContinentalMatch>,
     pub prize_pool: f64,
+    #[serde(default)]
+    pub groups: Vec<TournamentGroup>,
+    #[serde(default)]
+    pub season: Option<i32>,
 }
 
 impl ConferenceLeague {
@@ -230,26 +262,38 @@ impl ConferenceLeague {
             current_stage: CompetitionStage::NotStarted,
             matches: Vec::new(),
             prize_pool: 250_000_000.0, // 250 million euros
+            groups: Vec::new(),
+            season: None,
         }
     }
 
-    pub fn conduct_draw(&mut self, clubs: &[u32], _rankings: &ContinentalRankings, _date: NaiveDate) {
-        debug!(
-            "Conference League draw conducted with {} clubs",
-            clubs.len()
-        );
+    /// Draws the qualified clubs into groups once a season, later draw dates keep the groups
+    pub fn conduct_draw(&mut self, clubs: &[u32], rankings: &ContinentalRankings, date: NaiveDate) {
+        if clubs.len() < 2 || self.season == Some(date.year()) {
+            return;
+        }
+
+        let (groups, matches) = ContinentalGroupStage::draw(clubs, rankings, Weekday::Thu, date);
+
+        info!("Conference League draw: {} clubs in {} groups", clubs.len(), groups.len());
+
+        self.participating_clubs = clubs.to_vec();
+        self.current_stage = CompetitionStage::GroupStage;
+        self.groups = groups;
+        self.matches = matches;
+        self.season = Some(date.year());
     }
 
-    pub fn has_matches_today(&self, _date: NaiveDate) -> bool {
-        false // Simplified
+    pub fn has_matches_today(&self, date: NaiveDate) -> bool {
+        self.matches.iter().any(|m| m.date == date)
     }
 
     pub fn simulate_round(
         &mut self,
-        _clubs: &HashMap<u32, &Club>,
-        _date: NaiveDate,
+        clubs: &HashMap<u32, &Club>,
+        date: NaiveDate,
     ) -> Vec<ContinentalMatchResult> {
-        Vec::new() // Simplified
+        ContinentalGroupStage::play(&mut self.groups, &self.matches, clubs, date, CompetitionTier::ConferenceLeague)
     }
 
     pub fn get_club_points(&self, club_id: u32) -> f32 {
@@ -269,7 +313,7 @@ pub struct ContinentalMatchResult {
     pub competition: CompetitionTier, // Which competition (CL/EL/Conference)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SuperCup {
     pub prize_pool: f64,
 }
@@ -282,7 +326,7 @@ impl SuperCup {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CompetitionStage {
     NotStarted,
     Qualifying,
@@ -294,7 +338,7 @@ pub enum CompetitionStage {
     Final,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContinentalMatch {
     pub home_team: u32,
     pub away_team: u32,
@@ -302,7 +346,7 @@ pub struct ContinentalMatch {
     pub stage: CompetitionStage,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContinentalRankings {
     pub country_rankings: Vec<(u32, f32)>, // country_id, coefficient
     pub club_rankings: Vec<(u32, f32)>,    // club_id, points
@@ -353,9 +397,51 @@ impl ContinentalRankings {
         &self.country_rankings
     }
 
-    pub fn get_qualified_clubs(&self) -> HashMap<CompetitionTier, Vec<u32>> {
-        // Logic to determine which clubs qualify for each competition
-        HashMap::new() // Simplified
+    /// Clubs that finished the last season high enough in their top flight, by country ranking
+    pub fn get_qualified_clubs(&self, countries: &[Country]) -> HashMap<CompetitionTier, Vec<u32>> {
+        let mut qualified_clubs: HashMap<CompetitionTier, Vec<u32>> = HashMap::new();
+
+        for (country_id, _) in &self.country_rankings {
+            let (Some(spots), Some(country)) = (
+                self.qualification_spots.get(country_id),
+                countries.iter().find(|c| c.id == *country_id),
+            ) else {
+                continue;
+            };
+
+            let finishing_clubs: Vec<u32> = country
+                .leagues
+                .leagues
+                .iter()
+                .filter(|league| league.tier == 1)
+                .flat_map(|league| &league.final_standings)
+                .filter_map(|team_id| {
+                    country
+                        .clubs
+                        .iter()
+                        .flat_map(|club| &club.teams.teams)
+                        .find(|team| team.id == *team_id && team.team_type == TeamType::Main)
+                        .map(|team| team.club_id)
+                })
+                .collect();
+
+            let tiers = [
+                (CompetitionTier::ChampionsLeague, spots.champions_league),
+                (CompetitionTier::EuropaLeague, spots.europa_league),
+                (CompetitionTier::ConferenceLeague, spots.conference_league),
+            ];
+
+            let mut clubs = finishing_clubs.into_iter();
+
+            for (tier, spots) in tiers {
+                qualified_clubs
+                    .entry(tier)
+                    .or_default()
+                    .extend(clubs.by_ref().take(spots as usize));
+            }
+        }
+
+        qualified_clubs
     }
 
     pub fn set_qualification_spots(&mut self, country_id: u32, cl_spots: u8, el_spots: u8) {
@@ -370,14 +456,14 @@ impl ContinentalRankings {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QualificationSpots {
     pub champions_league: u8,
     pub europa_league: u8,
     pub conference_league: u8,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContinentalRegulations {
     pub ffp_rules: FinancialFairPlayRules,
     pub foreign_player_limits: ForeignPlayerLimits,
@@ -408,7 +494,7 @@ impl ContinentalRegulations {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FinancialFairPlayRules {
     pub max_deficit: f64,
     pub monitoring_period_years: u8,
@@ -434,7 +520,7 @@ impl FinancialFairPlayRules {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForeignPlayerLimits {
     pub max_non_eu_players: Option<u8>,
     pub homegrown_minimum: u8,
@@ -449,7 +535,7 @@ impl ForeignPlayerLimits {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct YouthRequirements {
     pub minimum_academy_investment: f64,
     pub minimum_youth_squad_size: u8,
@@ -464,7 +550,7 @@ impl YouthRequirements {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EconomicZone {
     pub tv_rights_pool: f64,
     pub sponsorship_value: f64,
@@ -524,9 +610,134 @@ pub struct TransferNegotiation {
     pub current_offer: f64,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CompetitionTier {
     ChampionsLeague,
     EuropaLeague,
     ConferenceLeague,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::club::academy::ClubAcademy;
+    use crate::league::{DayMonthPeriod, League, LeagueCollection, LeagueSettings};
+    use crate::shared::Location;
+    use crate::{
+        ClubFinances, ClubStatus, PlayerCollection, StaffCollection, Stadium, StadiumOwnership,
+        TeamBuilder, TeamCollection, TeamReputation, TrainingSchedule,
+    };
+    use chrono::NaiveTime;
+
+    fn club(id: u32, team_id: u32) -> Club {
+        let team = TeamBuilder::new()
+            .id(team_id)
+            .league_id(1)
+            .club_id(id)
+            .name(format!("Team {}", team_id))
+            .slug(format!("team-{}", team_id))
+            .team_type(TeamType::Main)
+            .training_schedule(TrainingSchedule::new(
+                NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            ))
+            .reputation(TeamReputation::new(100, 100, 100))
+            .players(PlayerCollection::new(Vec::new()))
+            .staffs(StaffCollection::new(Vec::new()))
+            .build()
+            .unwrap();
+
+        Club::new(
+            id,
+            format!("Club {}", id),
+            Location::new(1),
+            Stadium::new(format!("Stadium {}", id), 10000, 10, StadiumOwnership::Owned),
+            ClubFinances::new(0, Vec::new()),
+            ClubAcademy::new(1),
+            ClubStatus::Professional,
+            TeamCollection::new(vec![team]),
+        )
+    }
+
+    #[test]
+    fn qualified_clubs_follow_the_final_standings() {
+        let mut league = League::new(
+            1,
+            "League".to_string(),
+            "league".to_string(),
+            1,
+            5000,
+            LeagueSettings {
+                season_starting_half: DayMonthPeriod::new(1, 7, 5, 12),
+                season_ending_half: DayMonthPeriod::new(1, 3, 31, 5),
+            },
+        );
+        league.final_standings = vec![14, 12, 10, 13, 11];
+
+        let country = Country::builder()
+            .id(1)
+            .code("it".to_string())
+            .slug("italy".to_string())
+            .name("Italy".to_string())
+            .continent_id(1)
+            .leagues(LeagueCollection::new(vec![league]))
+            .clubs((1..=5).map(|id| club(id, id + 9)).collect())
+            .build()
+            .unwrap();
+
+        let mut rankings = ContinentalRankings::new();
+        rankings.update_country_ranking(1, 1.0);
+        rankings.set_qualification_spots(1, 2, 1);
+
+        let qualified_clubs = rankings.get_qualified_clubs(&[country]);
+
+        assert_eq!(Some(&vec![5, 3]), qualified_clubs.get(&CompetitionTier::ChampionsLeague));
+        assert_eq!(Some(&vec![1]), qualified_clubs.get(&CompetitionTier::EuropaLeague));
+        assert_eq!(Some(&vec![4]), qualified_clubs.get(&CompetitionTier::ConferenceLeague));
+    }
+
+    #[test]
+    fn draw_seeds_clubs_into_groups_with_home_and_away_fixtures() {
+        let mut rankings = ContinentalRankings::new();
+        for club_id in 1..=8 {
+            rankings.update_club_ranking(club_id, club_id as f32);
+        }
+
+        let mut champions_league = ChampionsLeague::new();
+        let draw_date = NaiveDate::from_ymd_opt(2024, 8, 15).unwrap();
+        champions_league.conduct_draw(&[1, 2, 3, 4, 5, 6, 7, 8], &rankings, draw_date);
+
+        assert_eq!(2, champions_league.groups.len());
+        assert!(champions_league.groups[0].teams.contains(&8));
+        assert!(champions_league.groups[1].teams.contains(&7));
+        assert_eq!(24, champions_league.matches.len());
+        assert!(champions_league
+            .matches
+            .iter()
+            .all(|m| m.date.weekday() == Weekday::Tue && m.date.month() >= 9));
+
+        // The December draw date keeps this season's groups
+        champions_league.conduct_draw(&[1, 2], &rankings, NaiveDate::from_ymd_opt(2024, 12, 15).unwrap());
+        assert_eq!(8, champions_league.participating_clubs.len());
+    }
+
+    #[test]
+    fn matchday_results_are_recorded_in_the_groups() {
+        let clubs: Vec<Club> = (1..=4).map(|id| club(id, id + 9)).collect();
+        let clubs_map: HashMap<u32, &Club> = clubs.iter().map(|club| (club.id, club)).collect();
+
+        let mut europa_league = EuropaLeague::new();
+        let draw_date = NaiveDate::from_ymd_opt(2024, 8, 20).unwrap();
+        europa_league.conduct_draw(&[1, 2, 3, 4], &ContinentalRankings::new(), draw_date);
+
+        let first_matchday = europa_league.matches.iter().map(|m| m.date).min().unwrap();
+        assert_eq!(Weekday::Thu, first_matchday.weekday());
+        assert!(europa_league.has_matches_today(first_matchday));
+
+        // Clubs without players forfeit, both sides here
+        let results = europa_league.simulate_round(&clubs_map, first_matchday);
+
+        assert_eq!(2, results.len());
+        assert!(europa_league.groups[0].table.iter().all(|row| row.played == 1));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::continent::{
+    CompetitionStage, CompetitionTier, ContinentalMatch, ContinentalMatchResult, ContinentalRankings,
+};
+use crate::country::{NationalTournament, TournamentGroup};
+use crate::r#match::{Match, MatchSquad};
+use crate::utils::RandomSeed;
+use crate::{Club, TeamType};
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use std::collections::HashMap;
+
+const GROUP_SIZE: usize = 4;
+const MIN_PLAYERS_TO_PLAY: usize = 11;
+const FORFEIT_GOALS: u8 = 3;
+
+/// Autumn weeks of the group stage, each matchday is played on the competition's weekday of that week
+const MATCHDAYS: [(u32, u32); 6] = [(9, 16), (9, 30), (10, 21), (11, 4), (11, 25), (12, 9)];
+
+/// Groups of four played home and away between the clubs a competition drew
+pub struct ContinentalGroupStage;
+
+impl ContinentalGroupStage {
+    /// Clubs are seeded by their continental ranking into pots, then every group gets its fixtures
+    pub fn draw(
+        clubs: &[u32],
+        rankings: &ContinentalRankings,
+        weekday: Weekday,
+        date: NaiveDate,
+    ) -> (Vec<TournamentGroup>, Vec<ContinentalMatch>) {
+        let points = |club_id: &u32| {
+            rankings
+                .club_rankings
+                .iter()
+                .find(|(id, _)| id == club_id)
+                .map(|(_, points)| *points)
+                .unwrap_or(0.0)
+        };
+
+        let mut seeded = clubs.to_vec();
+        seeded.sort_by(|a, b| points(b).total_cmp(&points(a)));
+
+        let groups_count = seeded.len().div_ceil(GROUP_SIZE).max(1);
+
+        let groups: Vec<TournamentGroup> = NationalTournament::seed_groups(&seeded, groups_count)
+            .into_iter()
+            .map(|teams| TournamentGroup::new(teams, true))
+            .collect();
+
+        let matches = groups
+            .iter()
+            .flat_map(|group| &group.fixtures)
+            .filter_map(|fixture| {
+                Self::matchday(fixture.round, weekday, date.year()).map(|date| ContinentalMatch {
+                    home_team: fixture.home,
+                    away_team: fixture.away,
+                    date,
+                    stage: CompetitionStage::GroupStage,
+                })
+            })
+            .collect();
+
+        (groups, matches)
+    }
+
+    /// Plays the fixtures of the day and records them in their group tables
+    pub fn play(
+        groups: &mut [TournamentGroup],
+        matches: &[ContinentalMatch],
+        clubs: &HashMap<u32, &Club>,
+        date: NaiveDate,
+        competition: CompetitionTier,
+    ) -> Vec<ContinentalMatchResult> {
+        use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+        let todays_matches: Vec<&ContinentalMatch> = matches.iter().filter(|m| m.date == date).collect();
+        let seeds: Vec<Option<u64>> = todays_matches.iter().map(|_| RandomSeed::fork()).collect();
+
+        let results: Vec<ContinentalMatchResult> = todays_matches
+            .into_par_iter()
+            .zip(seeds)
+            .map(|(continental_match, seed)| {
+                RandomSeed::scoped(seed, || Self::play_match(continental_match, clubs, competition))
+            })
+            .collect();
+
+        for result in &results {
+            if let Some(group) = groups.iter_mut().find(|group| group.teams.contains(&result.home_team)) {
+                group.record(result.home_team, result.away_team, result.home_score, result.away_score);
+            }
+        }
+
+        results
+    }
+
+    /// A club that cannot field a first team eleven forfeits the match
+    fn play_match(
+        continental_match: &ContinentalMatch,
+        clubs: &HashMap<u32, &Club>,
+        competition: CompetitionTier,
+    ) -> ContinentalMatchResult {
+        let home_squad = Self::squad(continental_match.home_team, clubs);
+        let away_squad = Self::squad(continental_match.away_team, clubs);
+
+        let (home_score, away_score) = match (home_squad, away_squad) {
+            (Some(home_squad), Some(away_squad)) => {
+                let match_id = format!(
+                    "cont_{}_{}_{}",
+                    continental_match.date.format("%Y%m%d"),
+                    continental_match.home_team,
+                    continental_match.away_team
+                );
+
+                let result = Match::make(match_id, 0, "", home_squad, away_squad).play();
+
+                (result.score.home_team.get(), result.score.away_team.get())
+            }
+            (Some(_), None) => (FORFEIT_GOALS, 0),
+            (None, Some(_)) => (0, FORFEIT_GOALS),
+            (None, None) => (0, 0),
+        };
+
+        ContinentalMatchResult {
+            home_team: continental_match.home_team,
+            away_team: continental_match.away_team,
+            home_score,
+            away_score,
+            competition,
+        }
+    }
+
+    fn squad(club_id: u32, clubs: &HashMap<u32, &Club>) -> Option<MatchSquad> {
+        let team = clubs
+            .get(&club_id)?
+            .teams
+            .teams
+            .iter()
+            .find(|team| team.team_type == TeamType::Main)?;
+
+        if team.players.players.len() < MIN_PLAYERS_TO_PLAY {
+            return None;
+        }
+
+        Some(team.get_enhanced_match_squad())
+    }
+
+    /// First `weekday` on or after the start of the matchday's week
+    fn matchday(round: usize, weekday: Weekday, year: i32) -> Option<NaiveDate> {
+        let (month, day) = MATCHDAYS.get(round)?;
+        let start = NaiveDate::from_ymd_opt(year, *month, *day)?;
+
+        let offset = (7 + weekday.num_days_from_monday() - start.weekday().num_days_from_monday()) % 7;
+
+        start.checked_add_days(Days::new(offset as u64))
+    }
+}
@@ -1,9 +1,11 @@
 pub mod context;
 mod continent;
+mod group_stage;
 mod result;
 mod tournaments;
 
 pub use context::*;
 pub use continent::*;
+pub use group_stage::*;
 pub use result::*;
 pub use tournaments::*;
@@ -1,17 +1,25 @@
+use crate::awards::{AwardCeremony, AwardNominees, AwardScope, SeasonAwards};
 use crate::continent::{
     CompetitionTier, Continent, ContinentalCompetitions, ContinentalMatchResult,
     ContinentalRankings,
 };
-use crate::country::CountryResult;
+use crate::country::{
+    CountryResult, InternationalBreak, InternationalDuty, NationalTeam, NationalTeamFixtureType,
+};
 use crate::simulator::SimulatorData;
 use crate::transfers::CompletedTransfer;
-use crate::{Club, Country, SimulationResult};
+use crate::league::Season;
+use crate::{Club, ClubIncomeType, Country, SimulationResult, Team, TeamType};
 use chrono::Datelike;
 use chrono::NaiveDate;
 use log::{debug, info};
 use std::collections::HashMap;
 
+/// Continental awards weigh more than any league's
+const CONTINENTAL_AWARD_PRESTIGE: f32 = 1.0;
+
 pub struct ContinentResult {
+    pub continent_id: u32,
     pub countries: Vec<CountryResult>,
 
     // New fields for continental-level results
@@ -22,8 +30,9 @@ pub struct ContinentResult {
 }
 
 impl ContinentResult {
-    pub fn new(countries: Vec<CountryResult>) -> Self {
+    pub fn new(continent_id: u32, countries: Vec<CountryResult>) -> Self {
         ContinentResult {
+            continent_id,
             countries,
             competition_results: None,
             rankings_update: None,
@@ -32,6 +41,10 @@ impl ContinentResult {
         }
     }
 
+    pub fn squads_changed(&self) -> bool {
+        self.countries.iter().any(CountryResult::squads_changed)
+    }
+
     pub fn process(self, data: &mut SimulatorData, result: &mut SimulationResult) {
         let current_date = data.date.date(); // Assuming SimulationResult has date
 
@@ -60,11 +73,14 @@ impl ContinentResult {
             self.update_continental_regulations(data, current_date);
         }
 
-        // Phase 6: Continental Awards & Recognition (yearly)
-        if current_date.month() == 12 && current_date.day() == 31 {
-            self.process_continental_awards(data, &self.countries);
+        // Phase 6: Continental Awards & Recognition (end of season, before the countries archive their statistics)
+        if current_date.month() == 5 && current_date.day() == 31 {
+            self.process_continental_awards(data, current_date);
         }
 
+        // Phase 7: National teams during international breaks
+        self.process_international_break(data, current_date);
+
         for country_result in self.countries {
             country_result.process(data, result);
         }
@@ -115,7 +131,7 @@ impl ContinentResult {
         let continent_id = self.get_continent_id(data);
 
         if let Some(continent) = data.continent_mut(continent_id) {
-            let qualified_clubs = continent.continental_rankings.get_qualified_clubs();
+            let qualified_clubs = continent.continental_rankings.get_qualified_clubs(&continent.countries);
 
             // Champions League draw
             if let Some(cl_clubs) = qualified_clubs.get(&CompetitionTier::ChampionsLeague) {
@@ -278,12 +294,16 @@ impl ContinentResult {
 
             // Update finances with match revenue
             let match_revenue = self.calculate_match_revenue(match_result);
-            club.finance.balance.push_income(match_revenue as i32);
+            club.finance
+                .balance
+                .push_income_of(ClubIncomeType::Matchday, match_revenue as i32);
 
             // Win bonus
             if won {
                 let win_bonus = self.calculate_win_bonus(match_result);
-                club.finance.balance.push_income(win_bonus as i32);
+                club.finance
+                    .balance
+                    .push_income_of(ClubIncomeType::PrizeMoney, win_bonus as i32);
             }
 
             // Update club reputation based on result
@@ -414,7 +434,9 @@ impl ContinentResult {
 
         for &club_id in participating_clubs {
             if let Some(club) = data.club_mut(club_id) {
-                club.finance.balance.push_income(participation_bonus as i32);
+                club.finance
+                    .balance
+                    .push_income_of(ClubIncomeType::PrizeMoney, participation_bonus as i32);
 
                 debug!(
                     "Club {} received participation bonus: €{:.2}M",
@@ -474,23 +496,27 @@ impl ContinentResult {
         }
     }
 
-    fn process_continental_awards(&self, data: &mut SimulatorData, _country_results: &[CountryResult]) {
+    fn process_continental_awards(&self, data: &mut SimulatorData, date: NaiveDate) {
         info!("🏆 Processing continental awards");
 
         let continent_id = self.get_continent_id(data);
 
-        if let Some(continent) = data.continent(continent_id) {
-            // Player of the Year
-            let _player_of_year = Self::determine_player_of_year(continent);
+        if let Some(continent) = data.continent_mut(continent_id) {
+            let awards = Self::determine_season_awards(continent, date);
 
-            // Team of the Year
-            let _team_of_year = Self::determine_team_of_year(continent);
+            if awards.is_empty() {
+                return;
+            }
 
-            // Coach of the Year
-            let _coach_of_year = Self::determine_coach_of_year(continent);
+            AwardCeremony::present(
+                &awards,
+                AwardScope::Continent(continent_id),
+                CONTINENTAL_AWARD_PRESTIGE,
+                date,
+                continent.countries.iter_mut().flat_map(|country| country.clubs.iter_mut()),
+            );
 
-            // Young Player Award
-            let _young_player = Self::determine_young_player_award(continent);
+            continent.awards.push(awards);
 
             debug!("Continental awards distributed");
         }
@@ -499,15 +525,7 @@ impl ContinentResult {
     // Helper methods
 
     fn get_continent_id(&self, _data: &SimulatorData) -> u32 {
-        // Assuming we can get continent ID from the first country
-        // You might want to store this in ContinentResult
-        if let Some(_first_country) = self.countries.first() {
-            // Get country from data and return its continent_id
-            // This is a placeholder - adjust based on your actual data structure
-            0 // Replace with actual logic
-        } else {
-            0
-        }
+        self.continent_id
     }
 
     fn calculate_country_coefficient(country: &Country, competitions: &ContinentalCompetitions) -> f32 {
@@ -564,20 +582,72 @@ impl ContinentResult {
             .collect()
     }
 
-    fn determine_player_of_year(_continent: &Continent) -> Option<u32> {
-        None
-    }
+    /// Player, young player, coach and team of the season across the first teams of every top flight
+    fn determine_season_awards(continent: &Continent, date: NaiveDate) -> SeasonAwards {
+        let mut nominees = AwardNominees::new();
+
+        for country in &continent.countries {
+            for league in country.leagues.leagues.iter().filter(|league| league.tier == 1) {
+                let teams: Vec<&Team> = country
+                    .clubs
+                    .iter()
+                    .flat_map(|club| &club.teams.teams)
+                    .filter(|team| team.league_id == league.id && team.team_type == TeamType::Main)
+                    .collect();
 
-    fn determine_team_of_year(_continent: &Continent) -> Option<Vec<u32>> {
-        None
+                nominees.add_league(league, &teams, date);
+            }
+        }
+
+        nominees.select(Season::from_date(date))
     }
 
-    fn determine_coach_of_year(_continent: &Continent) -> Option<u32> {
-        None
+    // ========== INTERNATIONAL DUTY ==========
+
+    fn process_international_break(&self, data: &mut SimulatorData, date: NaiveDate) {
+        let window = match InternationalBreak::on(date) {
+            Some(window) => window,
+            None => return,
+        };
+
+        let country_ids: Vec<u32> = self.countries.iter().map(|c| c.country_id).collect();
+
+        if window.is_call_up_day(date) {
+            InternationalDuty::call_up(&country_ids, date, data);
+        }
+
+        if let Some(matchday) = window.matchday(date) {
+            Self::play_friendlies(&country_ids, matchday, date, data);
+        }
+
+        if window.is_release_day(date) {
+            InternationalDuty::release(&country_ids, data);
+        }
     }
 
-    fn determine_young_player_award(_continent: &Continent) -> Option<u32> {
-        None
+    /// National teams without a qualifier to play meet in friendlies
+    fn play_friendlies(country_ids: &[u32], matchday: usize, date: NaiveDate, data: &mut SimulatorData) {
+        // Seeded by reputation so the circle method pairs neighbours first
+        let mut teams: Vec<(u32, u16)> = country_ids
+            .iter()
+            .filter_map(|&id| data.country(id))
+            .filter(|country| country.national_team.can_play())
+            .filter(|country| {
+                !data
+                    .national_tournaments
+                    .iter()
+                    .any(|tournament| tournament.is_qualifying(country.id))
+            })
+            .map(|country| (country.id, country.reputation))
+            .collect();
+
+        teams.sort_by(|a, b| b.1.cmp(&a.1));
+
+        let ids: Vec<u32> = teams.iter().map(|(id, _)| *id).collect();
+
+        for (home_id, away_id) in NationalTeam::pair_opponents(&ids, matchday) {
+            InternationalDuty::play_match(home_id, away_id, NationalTeamFixtureType::Friendly, date, data);
+        }
     }
 }
 
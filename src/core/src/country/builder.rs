@@ -1,6 +1,6 @@
 use crate::league::LeagueCollection;
 use crate::transfers::market::TransferMarket;
-use crate::{Club, Country, CountryEconomicFactors, CountryGeneratorData, CountryRegulations, InternationalCompetition, MediaCoverage};
+use crate::{Club, Country, CountryEconomicFactors, CountryGeneratorData, CountryRegulations, InternationalCompetition, MediaCoverage, NationalTeam};
 
 #[derive(Default)]
 pub struct CountryBuilder {
@@ -18,6 +18,7 @@ pub struct CountryBuilder {
     international_competitions: Option<Vec<InternationalCompetition>>,
     media_coverage: Option<MediaCoverage>,
     regulations: Option<CountryRegulations>,
+    national_team: Option<NationalTeam>,
 }
 
 impl CountryBuilder {
@@ -95,12 +96,23 @@ impl CountryBuilder {
         self
     }
 
+    pub fn national_team(mut self, national_team: NationalTeam) -> Self {
+        self.national_team = Some(national_team);
+        self
+    }
+
     pub fn build(self) -> Result<Country, String> {
+        let id = self.id.ok_or("id is required")?;
+        let name = self.name.ok_or("name is required")?;
+
         Ok(Country {
-            id: self.id.ok_or("id is required")?,
+            id,
             code: self.code.ok_or("code is required")?,
             slug: self.slug.ok_or("slug is required")?,
-            name: self.name.ok_or("name is required")?,
+            national_team: self
+                .national_team
+                .unwrap_or_else(|| NationalTeam::new(id, name.clone(), None)),
+            name,
             continent_id: self.continent_id.ok_or("continent_id is required")?,
             leagues: self.leagues.ok_or("leagues is required")?,
             clubs: self.clubs.ok_or("clubs is required")?,
@@ -1,6 +1,7 @@
 use crate::context::GlobalContext;
-use crate::country::CountryResult;
+use crate::country::{CountryResult, NationalTeam};
 use crate::league::LeagueCollection;
+use crate::news::{NewsEntity, NewsEntityType, NewsImportance, NewsItem, NewsType};
 use crate::transfers::market::{TransferMarket};
 use crate::utils::Logging;
 use crate::{Club, ClubResult};
@@ -8,7 +9,9 @@ use chrono::{NaiveDate};
 use log::{debug, info};
 use std::collections::HashMap;
 use crate::country::builder::CountryBuilder;
+use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize)]
 pub struct Country {
     pub id: u32,
     pub code: String,
@@ -25,6 +28,7 @@ pub struct Country {
     pub international_competitions: Vec<InternationalCompetition>,
     pub media_coverage: MediaCoverage,
     pub regulations: CountryRegulations,
+    pub national_team: NationalTeam,
 }
 
 impl Country {
@@ -45,7 +49,7 @@ impl Country {
 
         info!("✅ Country {} simulation complete", country_name);
 
-        CountryResult::new(league_results, clubs_results)
+        CountryResult::new(self.id, league_results, clubs_results)
     }
 
     fn simulate_leagues(&mut self, ctx: &GlobalContext<'_>) -> Vec<crate::league::LeagueResult> {
@@ -68,7 +72,7 @@ impl Country {
 
 // Supporting structures
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CountryEconomicFactors {
     pub gdp_growth: f32,
     pub inflation_rate: f32,
@@ -113,7 +117,7 @@ impl CountryEconomicFactors {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct InternationalCompetition {
     pub name: String,
     pub competition_type: CompetitionType,
@@ -128,7 +132,7 @@ impl InternationalCompetition {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum CompetitionType {
     ChampionsLeague,
     EuropaLeague,
@@ -136,7 +140,7 @@ pub enum CompetitionType {
     SuperCup,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MediaCoverage {
     pub intensity: f32,
     pub trending_stories: Vec<MediaStory>,
@@ -167,32 +171,81 @@ impl MediaCoverage {
         self.intensity = (self.intensity * 0.9 + 0.1).min(1.0);
     }
 
-    pub fn generate_weekly_stories(&mut self, clubs: &[Club]) {
+    /// Stories about the country's clubs out of the news of the past week
+    pub fn generate_weekly_stories(&mut self, clubs: &[Club], news: &[NewsItem]) {
         self.trending_stories.clear();
 
-        // Generate stories based on club performance, transfers, etc.
-        use crate::utils::IntegerUtils;
-
-        for club in clubs {
-            if IntegerUtils::random(0, 100) > 80 {
-                self.trending_stories.push(MediaStory {
-                    club_id: club.id,
-                    story_type: StoryType::TransferRumor,
-                    intensity: 0.5,
-                });
-            }
+        for item in news {
+            let story_type = match Self::story_type(item.news_type) {
+                Some(story_type) => story_type,
+                None => continue,
+            };
+
+            let club_id = match item
+                .entities
+                .iter()
+                .find_map(|entity| Self::club_of(clubs, entity))
+            {
+                Some(club_id) => club_id,
+                None => continue,
+            };
+
+            self.trending_stories.push(MediaStory {
+                club_id,
+                story_type,
+                intensity: Self::story_intensity(item.importance),
+            });
+        }
+    }
+
+    fn story_type(news_type: NewsType) -> Option<StoryType> {
+        match news_type {
+            NewsType::BigWin
+            | NewsType::Upset
+            | NewsType::WinningStreak
+            | NewsType::LeagueMilestone => Some(StoryType::SuccessStory),
+            NewsType::LosingStreak => Some(StoryType::CrisisStory),
+            NewsType::ManagerSacked => Some(StoryType::ManagerPressure),
+            NewsType::RecordTransfer => Some(StoryType::TransferRumor),
+            NewsType::Injury => None,
+        }
+    }
+
+    fn story_intensity(importance: NewsImportance) -> f32 {
+        match importance {
+            NewsImportance::Minor => 0.25,
+            NewsImportance::Notable => 0.5,
+            NewsImportance::Major => 0.75,
+            NewsImportance::Sensational => 1.0,
         }
     }
+
+    /// The club of this country the story is about, if any
+    fn club_of(clubs: &[Club], entity: &NewsEntity) -> Option<u32> {
+        clubs
+            .iter()
+            .find(|club| match entity.entity_type {
+                NewsEntityType::Club => club.id == entity.id,
+                NewsEntityType::Team => club.teams.teams.iter().any(|team| team.id == entity.id),
+                NewsEntityType::Player => club
+                    .teams
+                    .teams
+                    .iter()
+                    .any(|team| team.players.contains(entity.id)),
+                NewsEntityType::League | NewsEntityType::Staff => false,
+            })
+            .map(|club| club.id)
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MediaStory {
     pub club_id: u32,
     pub story_type: StoryType,
     pub intensity: f32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum StoryType {
     TransferRumor,
     ManagerPressure,
@@ -201,7 +254,7 @@ pub enum StoryType {
     CrisisStory,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CountryRegulations {
     pub foreign_player_limit: Option<u8>,
     pub salary_cap: Option<f64>,
@@ -261,6 +314,7 @@ struct CountrySimulationContext {
 }
 
 // Update CountryGeneratorData and PeopleNameGeneratorData as per original
+#[derive(Serialize, Deserialize)]
 pub struct CountryGeneratorData {
     pub people_names: PeopleNameGeneratorData,
 }
@@ -285,6 +339,7 @@ impl CountryGeneratorData {
     }
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct PeopleNameGeneratorData {
     pub first_names: Vec<String>,
     pub last_names: Vec<String>,
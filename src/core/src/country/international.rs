@@ -0,0 +1,213 @@
+use crate::country::{NationalTeam, NationalTeamFixtureType, NationalTeamMatch, MIN_CALL_UPS_TO_PLAY};
+use crate::r#match::player::statistics::MatchStatisticType;
+use crate::r#match::{Match, MatchSquad};
+use crate::news::NewsReporter;
+use crate::simulator::SimulatorData;
+use crate::{Player, PlayerStatusType};
+use crate::utils::RandomSeed;
+use chrono::NaiveDate;
+use log::info;
+use std::collections::HashMap;
+
+const INTERNATIONAL_MATCH_CONDITION_LOSS: u16 = 1500;
+const INTERNATIONAL_MATCH_JADEDNESS: u16 = 300;
+const INTERNATIONAL_TRAVEL_CONDITION_LOSS: u16 = 500;
+const INTERNATIONAL_INJURY_RISK: f32 = 0.02;
+
+/// What happened in a national team match, for competitions to build on
+#[derive(Debug)]
+pub struct InternationalMatchReport {
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub home_starters: Vec<u32>,
+    pub away_starters: Vec<u32>,
+    pub scorers: Vec<u32>,
+}
+
+/// Call-ups, national team matches and the return of players to their clubs
+pub struct InternationalDuty;
+
+impl InternationalDuty {
+    /// National team coaches pick from every club in the world by nationality
+    pub fn call_up(country_ids: &[u32], date: NaiveDate, data: &mut SimulatorData) {
+        let call_ups: Vec<(u32, Vec<u32>)> = {
+            let mut candidates: HashMap<u32, Vec<&Player>> = HashMap::new();
+
+            for player in data
+                .continents
+                .iter()
+                .flat_map(|continent| &continent.countries)
+                .flat_map(|country| &country.clubs)
+                .flat_map(|club| &club.teams.teams)
+                .flat_map(|team| team.players.players.iter())
+                .filter(|player| country_ids.contains(&player.country_id))
+                .filter(|player| !player.is_on_international_duty())
+            {
+                candidates.entry(player.country_id).or_default().push(player);
+            }
+
+            candidates
+                .iter()
+                .map(|(&country_id, players)| (country_id, NationalTeam::select_call_ups(players)))
+                .filter(|(_, call_ups)| call_ups.len() >= MIN_CALL_UPS_TO_PLAY)
+                .collect()
+        };
+
+        for (country_id, call_ups) in call_ups {
+            for &player_id in &call_ups {
+                if let Some(player) = data.player_mut(player_id) {
+                    player.statuses.add(date, PlayerStatusType::Int);
+                }
+            }
+
+            if let Some(country) = data.country_mut(country_id) {
+                info!("🌐 {}: {} players called up", country.national_team.name, call_ups.len());
+                country.national_team.call_ups = call_ups;
+            }
+        }
+    }
+
+    /// None when either side cannot field a starting eleven
+    pub fn play_match(
+        home_id: u32,
+        away_id: u32,
+        fixture_type: NationalTeamFixtureType,
+        date: NaiveDate,
+        data: &mut SimulatorData,
+    ) -> Option<InternationalMatchReport> {
+        let home_squad = Self::squad(home_id, data)?;
+        let away_squad = Self::squad(away_id, data)?;
+
+        let home_starters: Vec<u32> = home_squad.main_squad.iter().map(|p| p.id).collect();
+        let away_starters: Vec<u32> = away_squad.main_squad.iter().map(|p| p.id).collect();
+
+        let match_id = format!("int_{}_{}_{}", date.format("%Y%m%d"), home_id, away_id);
+        let result = Match::make(match_id, 0, "", home_squad, away_squad).play();
+
+        let home_goals = result.score.home_team.get();
+        let away_goals = result.score.away_team.get();
+
+        let scorers: Vec<u32> = result
+            .score
+            .detail()
+            .iter()
+            .filter(|goal| goal.stat_type == MatchStatisticType::Goal && !goal.is_auto_goal)
+            .map(|goal| goal.player_id)
+            .collect();
+
+        for player_id in home_starters.iter().chain(away_starters.iter()) {
+            let injured = match data.player_mut(*player_id) {
+                Some(player) => Self::record_appearance(player, date),
+                None => false,
+            };
+
+            if injured {
+                if let Some(news) = NewsReporter::injury(*player_id, true, date, data) {
+                    data.news.publish(news);
+                }
+            }
+        }
+
+        for &player_id in &scorers {
+            if let Some(player) = data.player_mut(player_id) {
+                player.player_attributes.international_goals += 1;
+            }
+        }
+
+        let home_match = NationalTeamMatch {
+            date,
+            opponent_country_id: away_id,
+            is_home: true,
+            fixture_type,
+            goals_for: home_goals,
+            goals_against: away_goals,
+        };
+
+        let away_match = NationalTeamMatch {
+            opponent_country_id: home_id,
+            is_home: false,
+            goals_for: away_goals,
+            goals_against: home_goals,
+            ..home_match.clone()
+        };
+
+        Self::record_match(home_id, home_match, data);
+        Self::record_match(away_id, away_match, data);
+
+        Some(InternationalMatchReport {
+            home_goals,
+            away_goals,
+            home_starters,
+            away_starters,
+            scorers,
+        })
+    }
+
+    /// Players return to their clubs after the travel
+    pub fn release(country_ids: &[u32], data: &mut SimulatorData) {
+        for &country_id in country_ids {
+            let call_ups = match data.country_mut(country_id) {
+                Some(country) => std::mem::take(&mut country.national_team.call_ups),
+                None => continue,
+            };
+
+            for player_id in call_ups {
+                if let Some(player) = data.player_mut(player_id) {
+                    player.statuses.remove(PlayerStatusType::Int);
+                    player.player_attributes.tire(INTERNATIONAL_TRAVEL_CONDITION_LOSS, 0);
+                }
+            }
+        }
+    }
+
+    pub fn can_field_team(country_id: u32, data: &SimulatorData) -> bool {
+        Self::fit_players(country_id, data).len() >= MIN_CALL_UPS_TO_PLAY
+    }
+
+    fn squad(country_id: u32, data: &SimulatorData) -> Option<MatchSquad> {
+        let players = Self::fit_players(country_id, data);
+
+        if players.len() < MIN_CALL_UPS_TO_PLAY {
+            return None;
+        }
+
+        data.country(country_id)
+            .map(|country| country.national_team.match_squad(&players))
+    }
+
+    fn fit_players(country_id: u32, data: &SimulatorData) -> Vec<&Player> {
+        match data.country(country_id) {
+            Some(country) => country
+                .national_team
+                .call_ups
+                .iter()
+                .filter_map(|&player_id| data.player(player_id))
+                .filter(|player| !player.player_attributes.is_injured)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// A cap, the physical toll of the match and a chance of coming back injured, true when injured
+    fn record_appearance(player: &mut Player, date: NaiveDate) -> bool {
+        player.player_attributes.international_apps += 1;
+        player
+            .player_attributes
+            .tire(INTERNATIONAL_MATCH_CONDITION_LOSS, INTERNATIONAL_MATCH_JADEDNESS);
+
+        if RandomSeed::random::<f32>() < INTERNATIONAL_INJURY_RISK {
+            player.player_attributes.is_injured = true;
+            player.statuses.add(date, PlayerStatusType::Inj);
+
+            return true;
+        }
+
+        false
+    }
+
+    fn record_match(country_id: u32, national_match: NationalTeamMatch, data: &mut SimulatorData) {
+        if let Some(country) = data.country_mut(country_id) {
+            country.national_team.matches.push(national_match);
+        }
+    }
+}
@@ -2,7 +2,13 @@ mod context;
 pub mod country;
 mod result;
 mod builder;
+mod international;
+mod national_team;
+mod tournament;
 
 pub use context::*;
 pub use country::*;
+pub use international::*;
+pub use national_team::*;
+pub use tournament::*;
 pub use result::*;
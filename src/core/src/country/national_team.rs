@@ -0,0 +1,291 @@
+use crate::club::{PlayerFieldPositionGroup, Staff, StaffStub};
+use crate::r#match::{MatchPlayer, MatchSquad, SquadSelector};
+use crate::{MatchTacticType, Player, Tactics};
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// National team ids live apart from club team ids so both can meet in the match engine
+pub const NATIONAL_TEAM_ID_OFFSET: u32 = 1_000_000;
+
+/// Goalkeepers, defenders, midfielders and forwards in a call-up
+const CALL_UP_QUOTAS: [(PlayerFieldPositionGroup, usize); 4] = [
+    (PlayerFieldPositionGroup::Goalkeeper, 3),
+    (PlayerFieldPositionGroup::Defender, 8),
+    (PlayerFieldPositionGroup::Midfielder, 8),
+    (PlayerFieldPositionGroup::Forward, 4),
+];
+
+pub const CALL_UP_SQUAD_SIZE: usize = 23;
+
+/// A national team can only play with a full starting eleven
+pub const MIN_CALL_UPS_TO_PLAY: usize = 11;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum NationalTeamFixtureType {
+    Friendly,
+    Qualifier,
+    Finals,
+}
+
+/// Fixed windows when clubs release their players to national teams
+#[derive(Debug)]
+pub struct InternationalBreak {
+    pub month: u32,
+    pub from_day: u32,
+    pub to_day: u32,
+}
+
+/// In season order, September to March
+const INTERNATIONAL_BREAKS: [InternationalBreak; 4] = [
+    InternationalBreak { month: 9, from_day: 4, to_day: 12 },
+    InternationalBreak { month: 10, from_day: 9, to_day: 17 },
+    InternationalBreak { month: 11, from_day: 13, to_day: 21 },
+    InternationalBreak { month: 3, from_day: 20, to_day: 28 },
+];
+
+/// Days after the call-up when the national teams play
+const MATCHDAY_OFFSETS: [u32; 2] = [2, 5];
+
+/// International matchdays in a season
+pub const INTERNATIONAL_MATCHDAYS: usize = INTERNATIONAL_BREAKS.len() * MATCHDAY_OFFSETS.len();
+
+impl InternationalBreak {
+    pub fn on(date: NaiveDate) -> Option<&'static InternationalBreak> {
+        INTERNATIONAL_BREAKS.iter().find(|window| {
+            date.month() == window.month && date.day() >= window.from_day && date.day() <= window.to_day
+        })
+    }
+
+    pub fn is_call_up_day(&self, date: NaiveDate) -> bool {
+        date.day() == self.from_day
+    }
+
+    pub fn is_release_day(&self, date: NaiveDate) -> bool {
+        date.day() == self.to_day
+    }
+
+    /// Matchday number across the season, from 0 to `INTERNATIONAL_MATCHDAYS`
+    pub fn matchday(&self, date: NaiveDate) -> Option<usize> {
+        let window_index = INTERNATIONAL_BREAKS
+            .iter()
+            .position(|window| window.month == self.month)?;
+
+        MATCHDAY_OFFSETS
+            .iter()
+            .position(|offset| self.from_day + offset == date.day())
+            .map(|matchday| window_index * MATCHDAY_OFFSETS.len() + matchday)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NationalTeamMatch {
+    pub date: NaiveDate,
+    pub opponent_country_id: u32,
+    pub is_home: bool,
+    pub fixture_type: NationalTeamFixtureType,
+    pub goals_for: u8,
+    pub goals_against: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NationalTeam {
+    pub id: u32,
+    pub country_id: u32,
+    pub name: String,
+    pub head_coach: Option<Staff>,
+    pub tactics: Tactics,
+
+    /// Players currently away with the national team
+    pub call_ups: Vec<u32>,
+    pub matches: Vec<NationalTeamMatch>,
+}
+
+impl NationalTeam {
+    pub fn new(country_id: u32, name: String, head_coach: Option<Staff>) -> Self {
+        NationalTeam {
+            id: NATIONAL_TEAM_ID_OFFSET + country_id,
+            country_id,
+            name,
+            head_coach,
+            tactics: Tactics::new(MatchTacticType::T442),
+            call_ups: Vec::new(),
+            matches: Vec::new(),
+        }
+    }
+
+    pub fn can_play(&self) -> bool {
+        self.call_ups.len() >= MIN_CALL_UPS_TO_PLAY
+    }
+
+    /// Best eligible players by position, topped up with the best of the rest
+    pub fn select_call_ups(candidates: &[&Player]) -> Vec<u32> {
+        let mut available: Vec<&Player> = candidates
+            .iter()
+            .filter(|player| !player.player_attributes.is_injured && !player.player_attributes.is_banned)
+            .copied()
+            .collect();
+
+        available.sort_by(|a, b| {
+            b.player_attributes
+                .current_ability
+                .cmp(&a.player_attributes.current_ability)
+        });
+
+        let mut selected: Vec<u32> = Vec::with_capacity(CALL_UP_SQUAD_SIZE);
+
+        for (group, quota) in CALL_UP_QUOTAS {
+            selected.extend(
+                available
+                    .iter()
+                    .filter(|player| player.position().position_group() == group)
+                    .take(quota)
+                    .map(|player| player.id),
+            );
+        }
+
+        // Short positions are covered by outfield players, never by extra goalkeepers
+        for player in &available {
+            if selected.len() >= CALL_UP_SQUAD_SIZE {
+                break;
+            }
+
+            let is_goalkeeper =
+                player.position().position_group() == PlayerFieldPositionGroup::Goalkeeper;
+
+            if !is_goalkeeper && !selected.contains(&player.id) {
+                selected.push(player.id);
+            }
+        }
+
+        selected
+    }
+
+    pub fn match_squad(&self, players: &[&Player]) -> MatchSquad {
+        let stub;
+        let coach = match &self.head_coach {
+            Some(coach) => coach,
+            None => {
+                stub = StaffStub::default();
+                &stub
+            }
+        };
+
+        let mut available: Vec<&Player> = players.to_vec();
+
+        let main_squad = SquadSelector::select_main_squad(self.id, &mut available, coach, &self.tactics);
+
+        let mut remaining: Vec<&Player> = available
+            .into_iter()
+            .filter(|player| !main_squad.iter().any(|mp| mp.id == player.id))
+            .collect();
+
+        let substitutes = SquadSelector::select_substitutes(self.id, &mut remaining, coach, &self.tactics);
+
+        let captain = players
+            .iter()
+            .filter(|player| main_squad.iter().any(|mp| mp.id == player.id))
+            .max_by_key(|player| player.player_attributes.international_apps)
+            .map(|player| MatchPlayer::from_player(self.id, player, player.position(), false));
+
+        MatchSquad {
+            team_id: self.id,
+            team_name: self.name.clone(),
+            tactics: self.tactics.clone(),
+            main_squad,
+            substitutes,
+            captain_id: captain,
+            vice_captain_id: None,
+            penalty_taker_id: None,
+            free_kick_taker_id: None,
+        }
+    }
+
+    /// Circle method round robin, the same pairs never meet in consecutive rounds
+    pub fn pair_opponents(country_ids: &[u32], round: usize) -> Vec<(u32, u32)> {
+        let mut teams: Vec<Option<u32>> = country_ids.iter().copied().map(Some).collect();
+
+        if teams.len() % 2 == 1 {
+            teams.push(None);
+        }
+
+        let count = teams.len();
+        if count < 2 {
+            return Vec::new();
+        }
+
+        let rotation = round % (count - 1);
+        teams[1..].rotate_right(rotation);
+
+        (0..count / 2)
+            .filter_map(|i| match (teams[i], teams[count - 1 - i]) {
+                (Some(home), Some(away)) if round % 2 == 0 => Some((home, away)),
+                (Some(home), Some(away)) => Some((away, home)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PlayerGenerator, PlayerPositionType};
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn international_break_calendar() {
+        assert!(InternationalBreak::on(date(8, 20)).is_none());
+
+        let window = InternationalBreak::on(date(10, 9)).unwrap();
+
+        assert!(window.is_call_up_day(date(10, 9)));
+        assert!(window.is_release_day(date(10, 17)));
+        assert_eq!(Some(2), window.matchday(date(10, 11)));
+        assert_eq!(Some(3), window.matchday(date(10, 14)));
+        assert_eq!(None, window.matchday(date(10, 12)));
+    }
+
+    #[test]
+    fn call_ups_respect_quotas_and_skip_injured() {
+        let mut players: Vec<Player> = (0..30)
+            .map(|i| {
+                let position = if i < 5 {
+                    PlayerPositionType::Goalkeeper
+                } else {
+                    PlayerPositionType::Striker
+                };
+
+                let mut player = PlayerGenerator::generate(1, date(9, 1), position, 20);
+                player.id = i;
+                player
+            })
+            .collect();
+
+        players[0].player_attributes.is_injured = true;
+
+        let candidates: Vec<&Player> = players.iter().collect();
+        let call_ups = NationalTeam::select_call_ups(&candidates);
+
+        assert_eq!(CALL_UP_SQUAD_SIZE, call_ups.len());
+        assert!(!call_ups.contains(&0));
+        assert_eq!(3, call_ups.iter().filter(|&&id| id < 5).count());
+    }
+
+    #[test]
+    fn pair_opponents_rotates_and_skips_bye() {
+        let first = NationalTeam::pair_opponents(&[1, 2, 3], 0);
+        let second = NationalTeam::pair_opponents(&[1, 2, 3], 1);
+
+        assert_eq!(1, first.len());
+        assert_eq!(1, second.len());
+        assert_ne!(first, second);
+
+        let all = NationalTeam::pair_opponents(&[1, 2, 3, 4], 0);
+        let mut ids: Vec<u32> = all.iter().flat_map(|&(h, a)| [h, a]).collect();
+        ids.sort();
+        assert_eq!(vec![1, 2, 3, 4], ids);
+    }
+}
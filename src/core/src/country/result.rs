@@ -1,34 +1,72 @@
 use chrono::Datelike;
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Weekday};
 use log::{debug, info};
-use crate::league::LeagueResult;
+use crate::club::academy::RESERVE_DEPTH;
+use crate::awards::{AwardCeremony, AwardNominees, AwardScope, SeasonAwards};
+use crate::league::{LeagueResult, Season};
+use crate::news::{NewsItem, NewsReporter};
 use crate::simulator::SimulatorData;
-use crate::{Club, ClubResult, ClubTransferStrategy, Country, SimulationResult};
+use crate::{
+    Club, ClubExpenseType, ClubResult, ClubTransferStrategy, ContractNegotiation, ContractRenewal,
+    ContractType, Country, Player, PlayerClubContract, PlayerSquadStatus, PlayerStatusType, ScoutJudgement,
+    ScoutingRegion, SimulationResult, Team, TeamType, SCOUTING_PLAYERS_PER_WEEK,
+};
+use crate::utils::IntegerUtils;
 use crate::shared::{Currency, CurrencyValue};
 use crate::transfers::{TransferListing, TransferListingType, TransferWindowManager};
 
+/// A release clause target has to be better than the buyer's third best player
+const RELEASE_CLAUSE_SQUAD_RANK: usize = 2;
+
+/// Days of news the media keeps talking about
+const MEDIA_STORY_DAYS: i64 = 7;
+
+/// Fees up to this are too small to be called a record, however empty the history
+const RECORD_TRANSFER_MIN_FEE: f64 = 1_000_000.0;
+
 pub struct CountryResult {
+    pub country_id: u32,
     pub leagues: Vec<LeagueResult>,
     pub clubs: Vec<ClubResult>,
 }
 
 impl CountryResult {
-    pub fn new(leagues: Vec<LeagueResult>, clubs: Vec<ClubResult>) -> Self {
-        CountryResult { leagues, clubs }
+    pub fn new(country_id: u32, leagues: Vec<LeagueResult>, clubs: Vec<ClubResult>) -> Self {
+        CountryResult {
+            country_id,
+            leagues,
+            clubs,
+        }
+    }
+
+    pub fn squads_changed(&self) -> bool {
+        self.clubs.iter().any(|club| club.squads_changed)
     }
 
     pub fn process(self, data: &mut SimulatorData, result: &mut SimulationResult) {
         let current_date = data.date.date();
-        let country_id = self.get_country_id(data);
+        let country_id = self.country_id;
 
         // Phase 3: Pre-season activities (if applicable)
         if Self::is_preseason(current_date) {
             self.simulate_preseason_activities(data, country_id, current_date);
         }
 
+        // Phase 3.5: Scouting - clubs learn about players only through their scouts
+        if current_date.weekday() == Weekday::Mon {
+            self.simulate_scouting(data, country_id, current_date);
+        }
+
         // Phase 4: Transfer Market Activities
+        let transfers_before = data
+            .country(country_id)
+            .map(|country| country.transfer_market.transfer_history.len())
+            .unwrap_or(0);
+
         let _transfer_activities = self.simulate_transfer_market(data, country_id, current_date);
 
+        Self::report_record_transfers(data, country_id, transfers_before);
+
         // Phase 5: International Competitions
         self.simulate_international_competitions(data, country_id, current_date);
 
@@ -36,7 +74,7 @@ impl CountryResult {
         self.update_economic_factors(data, country_id, current_date);
 
         // Phase 7: Media and Public Interest
-        self.simulate_media_coverage(data, country_id, &self.leagues);
+        self.simulate_media_coverage(data, country_id, current_date, &self.leagues);
 
         // Phase 8: End of Period Processing
         self.process_end_of_period(data, country_id, current_date, &self.clubs);
@@ -57,18 +95,6 @@ impl CountryResult {
 
     // Helper methods
 
-    fn get_country_id(&self, _data: &SimulatorData) -> u32 {
-        // Get country ID from first club or league result
-        // Adjust based on your actual data structure
-        if let Some(_club_result) = self.clubs.first() {
-            // Get club from data and return its country_id
-            // This is a placeholder
-            0
-        } else {
-            0
-        }
-    }
-
     fn is_preseason(date: NaiveDate) -> bool {
         let month = date.month();
         // Preseason typically June-July in Europe
@@ -90,6 +116,178 @@ impl CountryResult {
         }
     }
 
+    fn simulate_scouting(&self, data: &mut SimulatorData, country_id: u32, date: NaiveDate) {
+        let regions = match data.country(country_id) {
+            Some(country) => {
+                // Home country first, then its leagues, then the rest of the continent
+                let mut regions = vec![ScoutingRegion::Country(country.id)];
+
+                regions.extend(
+                    country
+                        .leagues
+                        .leagues
+                        .iter()
+                        .map(|league| ScoutingRegion::League(league.id)),
+                );
+
+                regions.push(ScoutingRegion::Continent(country.continent_id));
+                regions
+            }
+            None => return,
+        };
+
+        // Phase 1: keep scout assignments in line with current staff
+        let mut scouts_by_club: Vec<(u32, Vec<(u32, ScoutJudgement)>)> = Vec::new();
+
+        if let Some(country) = data.country_mut(country_id) {
+            for club in &mut country.clubs {
+                let scouts: Vec<(u32, ScoutJudgement)> = club
+                    .teams
+                    .teams
+                    .iter()
+                    .flat_map(|team| team.staffs.scouts())
+                    .map(|staff| (staff.id, ScoutJudgement::from_staff(staff)))
+                    .collect();
+
+                let scout_ids: Vec<u32> = scouts.iter().map(|(id, _)| *id).collect();
+
+                club.scouting.sync_assignments(&scout_ids, &regions, date);
+                club.scouting.remove_outdated_reports(date);
+
+                scouts_by_club.push((club.id, scouts));
+            }
+        }
+
+        // Phase 2: every scout watches a few players - shortlisted first, then from the assigned region
+        let mut observations = Vec::new();
+
+        if let Some(country) = data.country(country_id) {
+            for (club_id, scouts) in &scouts_by_club {
+                let club = match country.clubs.iter().find(|c| c.id == *club_id) {
+                    Some(club) => club,
+                    None => continue,
+                };
+
+                let mut shortlist = club.scouting.shortlist.iter();
+
+                for (scout_id, judgement) in scouts {
+                    let assignment = match club.scouting.assignment(*scout_id) {
+                        Some(assignment) => assignment,
+                        None => continue,
+                    };
+
+                    let mut watched: Vec<(u32, u8, u8)> = shortlist
+                        .by_ref()
+                        .take(SCOUTING_PLAYERS_PER_WEEK)
+                        .filter_map(|player_id| data.player(*player_id))
+                        .map(|player| {
+                            (
+                                player.id,
+                                player.player_attributes.current_ability,
+                                player.player_attributes.potential_ability,
+                            )
+                        })
+                        .collect();
+
+                    let candidates = Self::scouting_candidates(data, club.id, assignment.region);
+
+                    let mut attempts = 0;
+
+                    while watched.len() < SCOUTING_PLAYERS_PER_WEEK
+                        && !candidates.is_empty()
+                        && attempts < SCOUTING_PLAYERS_PER_WEEK * 3
+                    {
+                        let candidate = candidates
+                            [IntegerUtils::random(0, candidates.len() as i32) as usize];
+
+                        if !watched.iter().any(|(id, _, _)| *id == candidate.0) {
+                            watched.push(candidate);
+                        }
+
+                        attempts += 1;
+                    }
+
+                    for (player_id, current_ability, potential_ability) in watched {
+                        observations.push((
+                            club.id,
+                            *scout_id,
+                            *judgement,
+                            player_id,
+                            current_ability,
+                            potential_ability,
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Phase 3: write reports
+        if let Some(country) = data.country_mut(country_id) {
+            for (club_id, scout_id, judgement, player_id, current_ability, potential_ability) in
+                observations
+            {
+                if let Some(club) = country.clubs.iter_mut().find(|c| c.id == club_id) {
+                    club.scouting.record_observation(
+                        scout_id,
+                        player_id,
+                        current_ability,
+                        potential_ability,
+                        &judgement,
+                        date,
+                    );
+                }
+            }
+
+            debug!("🔭 Scouting reports updated for country {}", country.name);
+        }
+    }
+
+    fn scouting_candidates(
+        data: &SimulatorData,
+        scouting_club_id: u32,
+        region: ScoutingRegion,
+    ) -> Vec<(u32, u8, u8)> {
+        let clubs: Vec<&Club> = match region {
+            ScoutingRegion::Country(id) => data
+                .country(id)
+                .map(|country| country.clubs.iter().collect())
+                .unwrap_or_default(),
+            ScoutingRegion::League(id) => data
+                .league(id)
+                .and_then(|league| data.country(league.country_id))
+                .map(|country| country.clubs.iter().collect())
+                .unwrap_or_default(),
+            ScoutingRegion::Continent(id) => data
+                .continent(id)
+                .map(|continent| {
+                    continent
+                        .countries
+                        .iter()
+                        .flat_map(|country| &country.clubs)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        };
+
+        clubs
+            .into_iter()
+            .filter(|club| club.id != scouting_club_id)
+            .flat_map(|club| &club.teams.teams)
+            .filter(|team: &&Team| match region {
+                ScoutingRegion::League(id) => team.league_id == id,
+                _ => true,
+            })
+            .flat_map(|team| &team.players.players)
+            .map(|player| {
+                (
+                    player.id,
+                    player.player_attributes.current_ability,
+                    player.player_attributes.potential_ability,
+                )
+            })
+            .collect()
+    }
+
     fn simulate_transfer_market(
         &self,
         data: &mut SimulatorData,
@@ -117,21 +315,276 @@ impl CountryResult {
             // Phase 3: Process loan deals
             Self::process_loan_deals(country, current_date, &mut summary);
 
-            // Phase 4: Free agents and contract expirations
-            Self::handle_free_agents(country, current_date, &mut summary);
-
-            // Phase 5: Update market based on completed deals
+            // Phase 4: Update market based on completed deals
             country.transfer_market.update(current_date);
+        }
 
-            debug!(
-                "Transfer Activity - Listings: {}, Negotiations: {}, Completed: {}",
-                summary.total_listings, summary.active_negotiations, summary.completed_transfers
-            );
+        // Phase 5: Clubs short of players sign free agents
+        Self::handle_free_agents(data, country_id, current_date, &mut summary);
+
+        // Phase 6: Clubs at home and abroad pay release clauses
+        if current_date.weekday() == Weekday::Mon {
+            Self::trigger_release_clauses(data, country_id, current_date, &mut summary);
         }
 
+        debug!(
+            "Transfer Activity - Listings: {}, Negotiations: {}, Completed: {}",
+            summary.total_listings, summary.active_negotiations, summary.completed_transfers
+        );
+
         summary
     }
 
+    /// Deals that beat every fee paid in the country before make the news
+    fn report_record_transfers(data: &mut SimulatorData, country_id: u32, transfers_before: usize) {
+        let (mut record, transfers) = match data.country(country_id) {
+            Some(country) => {
+                let history = &country.transfer_market.transfer_history;
+                let transfers_before = transfers_before.min(history.len());
+
+                let record = history[..transfers_before]
+                    .iter()
+                    .map(|transfer| transfer.fee.amount)
+                    .fold(RECORD_TRANSFER_MIN_FEE, f64::max);
+
+                (record, history[transfers_before..].to_vec())
+            }
+            None => return,
+        };
+
+        for transfer in transfers {
+            if transfer.fee.amount <= record {
+                continue;
+            }
+
+            record = transfer.fee.amount;
+
+            if let Some(news) = NewsReporter::record_transfer(&transfer, data) {
+                data.news.publish(news);
+            }
+        }
+    }
+
+    fn trigger_release_clauses(
+        data: &mut SimulatorData,
+        country_id: u32,
+        date: NaiveDate,
+        summary: &mut TransferActivitySummary,
+    ) {
+        let mut moved = false;
+
+        for buyout in Self::release_clause_buyouts(data, country_id, date) {
+            moved |= Self::complete_buyout(data, country_id, buyout, date, summary);
+        }
+
+        if moved {
+            data.refresh_player_indexes();
+        }
+    }
+
+    /// Each club goes for the best scouted player of this country it can free with a clause and who would walk into its first team
+    fn release_clause_buyouts(data: &SimulatorData, country_id: u32, date: NaiveDate) -> Vec<ReleaseClauseBuyout> {
+        let indexes = match data.indexes.as_ref() {
+            Some(indexes) => indexes,
+            None => return Vec::new(),
+        };
+
+        let mut buyouts: Vec<ReleaseClauseBuyout> = Vec::new();
+
+        // Clubs abroad can only sign while their own window is open too
+        let window_manager = TransferWindowManager::new();
+
+        let buyers = data
+            .continents
+            .iter()
+            .flat_map(|continent| continent.countries.iter())
+            .filter(|country| window_manager.is_window_open(country.id, date))
+            .flat_map(|country| country.clubs.iter().map(move |club| (country.id, club)));
+
+        for (buyer_country_id, buyer) in buyers {
+            let squad = match buyer.teams.main_team_id() {
+                Some(team_id) => &buyer.teams.by_id(team_id).players,
+                None => continue,
+            };
+
+            let mut abilities: Vec<u8> = squad
+                .players
+                .iter()
+                .map(|player| player.player_attributes.current_ability)
+                .collect();
+            abilities.sort_unstable_by(|a, b| b.cmp(a));
+
+            let required_ability = abilities
+                .get(RELEASE_CLAUSE_SQUAD_RANK)
+                .copied()
+                .unwrap_or(0);
+
+            let budget = match &buyer.finance.transfer_budget {
+                Some(budget) => budget.amount,
+                None => buyer.finance.balance.balance.max(0) as f64,
+            };
+
+            let target = buyer
+                .scouting
+                .reports
+                .values()
+                .filter(|report| report.estimated_current_ability > required_ability)
+                .filter_map(|report| {
+                    let (_, player_country_id, club_id, team_id) =
+                        indexes.get_player_location(report.player_id)?;
+
+                    if player_country_id != country_id || club_id == buyer.id {
+                        return None;
+                    }
+
+                    let relegated = data.club(club_id)?.is_relegated(date);
+                    let fee = data
+                        .player(report.player_id)?
+                        .contract
+                        .as_ref()?
+                        .release_fee(buyer_country_id != country_id, relegated)?;
+
+                    (fee as f64 <= budget).then_some((
+                        report.estimated_current_ability,
+                        ReleaseClauseBuyout {
+                            player_id: report.player_id,
+                            selling_club_id: club_id,
+                            selling_team_id: team_id,
+                            buying_club_id: buyer.id,
+                            fee,
+                        },
+                    ))
+                })
+                .max_by_key(|(ability, _)| *ability)
+                .map(|(_, buyout)| buyout);
+
+            if let Some(buyout) = target {
+                if !buyouts.iter().any(|other| other.player_id == buyout.player_id) {
+                    buyouts.push(buyout);
+                }
+            }
+        }
+
+        buyouts
+    }
+
+    /// The selling club has no say, but the player still has to agree terms with the buyer
+    fn complete_buyout(
+        data: &mut SimulatorData,
+        country_id: u32,
+        buyout: ReleaseClauseBuyout,
+        date: NaiveDate,
+        summary: &mut TransferActivitySummary,
+    ) -> bool {
+        let renewal = match Self::personal_terms(data, &buyout, date) {
+            Some(renewal) => renewal,
+            None => {
+                debug!(
+                    "release clause of player {} met by club {}, no agreement on personal terms",
+                    buyout.player_id, buyout.buying_club_id
+                );
+                return false;
+            }
+        };
+
+        let mut player = match data
+            .club_mut(buyout.selling_club_id)
+            .and_then(|club| club.teams.by_id_mut(buyout.selling_team_id))
+            .and_then(|team| team.players.take_player(&buyout.player_id))
+        {
+            Some(player) => player,
+            None => return false,
+        };
+
+        player.contract = Some(ContractNegotiation::contract(&renewal, None, date));
+        player.statuses.remove(PlayerStatusType::Lst);
+        player.statuses.remove(PlayerStatusType::Req);
+        player.morale.transfer_requested = false;
+        player.interactions.promises.clear();
+
+        let player_name = player.full_name.to_string();
+
+        if let Some(club) = data.club_mut(buyout.selling_club_id) {
+            club.finance.add_transfer_income(buyout.fee as f64);
+        }
+
+        let buyer_name = match data.club_mut(buyout.buying_club_id) {
+            Some(club) => {
+                if !club.finance.spend_from_transfer_budget(buyout.fee as f64) {
+                    club.finance
+                        .balance
+                        .push_outcome_of(ClubExpenseType::Transfers, buyout.fee as i32);
+                }
+
+                if let Some(team) = club.teams.main_mut() {
+                    team.players.add(player);
+                }
+
+                club.name.clone()
+            }
+            None => return false,
+        };
+
+        info!(
+            "💥 {} trigger the release clause of {} for {}",
+            buyer_name, player_name, buyout.fee
+        );
+
+        if let Some(country) = data.country_mut(country_id) {
+            country.transfer_market.trigger_release_clause(
+                buyout.player_id,
+                buyout.selling_club_id,
+                buyout.selling_team_id,
+                buyout.buying_club_id,
+                CurrencyValue {
+                    amount: buyout.fee as f64,
+                    currency: Currency::Usd,
+                },
+                date,
+            );
+        }
+
+        summary.completed_transfers += 1;
+        summary.total_fees_exchanged += buyout.fee as f64;
+
+        true
+    }
+
+    /// Same talks as over a new deal at the player's own club, against the wages of the buyer's squad
+    fn personal_terms(data: &SimulatorData, buyout: &ReleaseClauseBuyout, date: NaiveDate) -> Option<ContractRenewal> {
+        let player = data.player(buyout.player_id)?;
+        let buyer = data.club(buyout.buying_club_id)?;
+        let team = buyer.teams.by_id(buyer.teams.main_team_id()?);
+
+        let peer_salary = team.players.peer_salary(player);
+        let can_afford = buyer.finance.balance.balance as i64 >= buyout.fee as i64;
+
+        let mut demands = ContractNegotiation::demands(player, peer_salary, date);
+        let offer = ContractNegotiation::offer(player, peer_salary, can_afford, date);
+
+        // The player's standing is the buyer's to give, not what the old club thought of them
+        demands.squad_status = Self::squad_status(team, player);
+
+        ContractNegotiation::negotiate(&demands, &offer, team.staffs.contract_resolver(TeamType::Main))
+    }
+
+    /// Status by how many of the squad are better than the player
+    fn squad_status(team: &Team, player: &Player) -> PlayerSquadStatus {
+        let better = team
+            .players
+            .players
+            .iter()
+            .filter(|other| other.player_attributes.current_ability > player.player_attributes.current_ability)
+            .count();
+
+        match better {
+            0..=2 => PlayerSquadStatus::KeyPlayer,
+            3..=10 => PlayerSquadStatus::FirstTeamRegular,
+            11..=17 => PlayerSquadStatus::FirstTeamSquadRotation,
+            _ => PlayerSquadStatus::MainBackupPlayer,
+        }
+    }
+
     fn list_players_for_transfer(
         country: &mut Country,
         date: NaiveDate,
@@ -183,6 +636,9 @@ impl CountryResult {
         // Collect negotiations to process (avoid borrow conflicts)
         let mut negotiations_to_process = Vec::new();
 
+        // Listed players a club knows nothing about yet get sent to its scouts
+        let mut scouting_requests = Vec::new();
+
         // First pass: collect all potential negotiations
         for buying_club in &country.clubs {
             let budget = CurrencyValue {
@@ -213,9 +669,15 @@ impl CountryResult {
             for listing in available_listings {
                 // Find the player
                 if let Some(player) = Self::find_player_in_country(country, listing.player_id) {
-                    if strategy.decide_player_interest(player) {
+                    let Some(report) = buying_club.scouting.report(listing.player_id) else {
+                        scouting_requests.push((buying_club.id, listing.player_id));
+                        continue;
+                    };
+
+                    if strategy.decide_player_interest(player, Some(report)) {
                         let offer = strategy.calculate_initial_offer(
                             player,
+                            report,
                             &listing.asking_price,
                             date,
                         );
@@ -231,6 +693,12 @@ impl CountryResult {
             }
         }
 
+        for (club_id, player_id) in scouting_requests {
+            if let Some(club) = country.clubs.iter_mut().find(|c| c.id == club_id) {
+                club.scouting.add_to_shortlist(player_id);
+            }
+        }
+
         // Second pass: process all negotiations
         for (player_id, buying_club_id, selling_club_id, offer) in negotiations_to_process {
             if let Some(neg_id) = country.transfer_market.start_negotiation(
@@ -285,11 +753,18 @@ impl CountryResult {
         &self,
         data: &mut SimulatorData,
         country_id: u32,
+        date: NaiveDate,
         league_results: &[LeagueResult],
     ) {
+        let news: Vec<NewsItem> = data
+            .news
+            .since(date - chrono::Duration::days(MEDIA_STORY_DAYS))
+            .cloned()
+            .collect();
+
         if let Some(country) = data.country_mut(country_id) {
             country.media_coverage.update_from_results(league_results);
-            country.media_coverage.generate_weekly_stories(&country.clubs);
+            country.media_coverage.generate_weekly_stories(&country.clubs, &news);
         }
     }
 
@@ -298,7 +773,7 @@ impl CountryResult {
         data: &mut SimulatorData,
         country_id: u32,
         date: NaiveDate,
-        club_results: &[ClubResult],
+        _club_results: &[ClubResult],
     ) {
         // End of season processing
         if date.month() == 5 && date.day() == 31 {
@@ -306,7 +781,7 @@ impl CountryResult {
 
             if let Some(country) = data.country_mut(country_id) {
                 // Award ceremonies
-                Self::process_season_awards(country, club_results);
+                Self::process_season_awards(country, date);
 
                 // Contract expirations
                 Self::process_contract_expirations(country);
@@ -453,8 +928,84 @@ impl CountryResult {
         debug!("Processing loan deals");
     }
 
-    fn handle_free_agents(_country: &mut Country, _date: NaiveDate, _summary: &mut TransferActivitySummary) {
-        debug!("Handling free agents");
+    /// A club short of players in a position signs the free agent of the country with the most potential there,
+    /// on a youth contract with its reserve side
+    fn handle_free_agents(
+        data: &mut SimulatorData,
+        country_id: u32,
+        date: NaiveDate,
+        summary: &mut TransferActivitySummary,
+    ) {
+        if data.free_agents.players.is_empty() {
+            return;
+        }
+
+        let club_ids: Vec<u32> = match data.country(country_id) {
+            Some(country) => country.clubs.iter().map(|club| club.id).collect(),
+            None => return,
+        };
+
+        let mut signed = false;
+
+        for club_id in club_ids {
+            let signing = data.club(club_id).and_then(|club| {
+                let team_id = club.teams.reserve_team_id().or_else(|| club.teams.main_team_id())?;
+                let team = club.teams.by_id(team_id);
+
+                RESERVE_DEPTH.iter().find_map(|&(group, required)| {
+                    let count = team
+                        .players
+                        .players
+                        .iter()
+                        .filter(|player| player.position().position_group() == group)
+                        .count();
+
+                    if count >= required {
+                        return None;
+                    }
+
+                    data.free_agents
+                        .best(country_id, group, team.team_type.max_age(), date)
+                        .map(|player_id| (player_id, team_id))
+                })
+            });
+
+            let (player_id, team_id) = match signing {
+                Some(signing) => signing,
+                None => continue,
+            };
+
+            let mut player = match data.free_agents.take(player_id) {
+                Some(player) => player,
+                None => continue,
+            };
+
+            let mut contract = PlayerClubContract::new(
+                player.player_attributes.potential_ability as u32 * 10,
+                NaiveDate::from_ymd_opt(date.year() + 1, 6, 30).unwrap_or(date),
+            );
+
+            contract.started = Some(date);
+            contract.contract_type = ContractType::Youth;
+            contract.squad_status = PlayerSquadStatus::DecentYoungster;
+
+            player.contract = Some(contract);
+
+            if let Some(club) = data.club_mut(club_id) {
+                info!("🆓 {} sign free agent {}", club.name, player.full_name);
+
+                if let Some(team) = club.teams.by_id_mut(team_id) {
+                    team.players.add(player);
+                }
+            }
+
+            summary.completed_transfers += 1;
+            signed = true;
+        }
+
+        if signed {
+            data.refresh_player_indexes();
+        }
     }
 
     fn calculate_league_competitiveness(_league: &crate::league::League) -> f32 {
@@ -469,8 +1020,50 @@ impl CountryResult {
         0 // Simplified
     }
 
-    fn process_season_awards(_country: &mut Country, _club_results: &[ClubResult]) {
+    /// Each league honours its best, then every player's season goes into the record books
+    fn process_season_awards(country: &mut Country, date: NaiveDate) {
         debug!("Processing season awards");
+
+        let season = Season::from_date(date);
+
+        let league_awards: Vec<(u32, f32, SeasonAwards)> = country
+            .leagues
+            .leagues
+            .iter()
+            .map(|league| {
+                let teams: Vec<&Team> = country
+                    .clubs
+                    .iter()
+                    .flat_map(|club| &club.teams.teams)
+                    .filter(|team| team.league_id == league.id)
+                    .collect();
+
+                let mut nominees = AwardNominees::new();
+                nominees.add_league(league, &teams, date);
+
+                (league.id, league.prestige(), nominees.select(season))
+            })
+            .collect();
+
+        for (league_id, prestige, awards) in league_awards.into_iter().filter(|(_, _, awards)| !awards.is_empty()) {
+            AwardCeremony::present(
+                &awards,
+                AwardScope::League(league_id),
+                prestige,
+                date,
+                country.clubs.iter_mut(),
+            );
+
+            if let Some(league) = country.leagues.leagues.iter_mut().find(|league| league.id == league_id) {
+                league.awards.push(awards);
+            }
+        }
+
+        for team in country.clubs.iter_mut().flat_map(|club| club.teams.teams.iter_mut()) {
+            for player in &mut team.players.players {
+                player.statistics_history.archive(season, &mut player.statistics);
+            }
+        }
     }
 
     fn process_contract_expirations(_country: &mut Country) {
@@ -488,6 +1081,16 @@ impl CountryResult {
 
 // Supporting structures (keep these in country.rs)
 
+/// Release clause a club is ready to pay
+#[derive(Debug)]
+struct ReleaseClauseBuyout {
+    player_id: u32,
+    selling_club_id: u32,
+    selling_team_id: u32,
+    buying_club_id: u32,
+    fee: u32,
+}
+
 #[derive(Debug)]
 struct TransferActivitySummary {
     total_listings: u32,
@@ -0,0 +1,981 @@
+use crate::country::{
+    InternationalBreak, InternationalDuty, InternationalMatchReport, NationalTeam,
+    NationalTeamFixtureType, INTERNATIONAL_MATCHDAYS, MIN_CALL_UPS_TO_PLAY,
+};
+use crate::simulator::SimulatorData;
+use crate::utils::RandomSeed;
+use chrono::{Datelike, NaiveDate};
+use log::info;
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+const WORLD_CUP_FINALS_TEAMS: usize = 16;
+const CONTINENTAL_FINALS_TEAMS: usize = 8;
+const FINALS_GROUP_SIZE: usize = 4;
+const MAX_QUALIFYING_GROUP_SIZE: usize = 8;
+
+/// Draw for the next summer's finals, before the September break
+const DRAW_DAY: (u32, u32) = (9, 1);
+/// Qualifying groups are closed after the March break
+const QUALIFICATION_END: (u32, u32) = (4, 1);
+
+const FINALS_CALL_UP_DAY: (u32, u32) = (6, 10);
+const FINALS_GROUP_DAYS: [(u32, u32); 3] = [(6, 14), (6, 18), (6, 22)];
+const FINALS_KNOCKOUT_DAYS: [(u32, u32); 4] = [(6, 27), (7, 2), (7, 7), (7, 12)];
+
+const FINALS_APPEARANCE_REPUTATION: i16 = 10;
+const FINALS_WIN_REPUTATION: i16 = 15;
+const FINALS_DRAW_REPUTATION: i16 = 5;
+const FINALS_GOAL_REPUTATION: i16 = 25;
+const CHAMPION_REPUTATION: i16 = 150;
+const RUNNER_UP_REPUTATION: i16 = 75;
+const MAX_WORLD_REPUTATION: i16 = 10000;
+
+const EXTRA_TIME_HALVES: usize = 2;
+const EXTRA_TIME_HALF_GOAL_CHANCE: f32 = 0.2;
+const PENALTY_CONVERSION: f32 = 0.75;
+const PENALTIES_PER_SIDE: u8 = 5;
+const MAX_PENALTY_ROUNDS: u8 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum NationalTournamentKind {
+    WorldCup,
+    ContinentalChampionship,
+}
+
+impl NationalTournamentKind {
+    /// A finals tournament every summer: the World Cup every four years,
+    /// continental championships in between
+    pub fn for_year(year: i32) -> Self {
+        if year % 4 == 2 {
+            NationalTournamentKind::WorldCup
+        } else {
+            NationalTournamentKind::ContinentalChampionship
+        }
+    }
+
+    fn max_finals_teams(&self) -> usize {
+        match self {
+            NationalTournamentKind::WorldCup => WORLD_CUP_FINALS_TEAMS,
+            NationalTournamentKind::ContinentalChampionship => CONTINENTAL_FINALS_TEAMS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum NationalTournamentStage {
+    Qualification,
+    Finals,
+    Finished,
+}
+
+/// A country entering the draw
+#[derive(Debug, Clone)]
+pub struct TournamentEntrant {
+    pub country_id: u32,
+    pub continent_id: u32,
+    pub reputation: u16,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NationalTournament {
+    pub name: String,
+    pub kind: NationalTournamentKind,
+    /// None for the World Cup
+    pub continent_id: Option<u32>,
+    /// Year of the finals
+    pub year: i32,
+    pub stage: NationalTournamentStage,
+
+    pub qualifying_groups: Vec<TournamentGroup>,
+    pub qualified: Vec<u32>,
+    pub finals_groups: Vec<TournamentGroup>,
+    pub knockout_rounds: Vec<Vec<KnockoutTie>>,
+    pub winner: Option<u32>,
+
+    reputations: HashMap<u32, u16>,
+}
+
+impl NationalTournament {
+    /// Seeds the qualifying groups, None when too few countries enter
+    pub fn draw(
+        name: String,
+        kind: NationalTournamentKind,
+        continent_id: Option<u32>,
+        year: i32,
+        entrants: &[TournamentEntrant],
+    ) -> Option<Self> {
+        let finals_teams = [16, 8, 4]
+            .into_iter()
+            .find(|&size| size <= kind.max_finals_teams() && size <= entrants.len())?;
+
+        let mut tournament = NationalTournament {
+            name,
+            kind,
+            continent_id,
+            year,
+            stage: NationalTournamentStage::Qualification,
+            qualifying_groups: Vec::new(),
+            qualified: Vec::new(),
+            finals_groups: Vec::new(),
+            knockout_rounds: Vec::new(),
+            winner: None,
+            reputations: entrants.iter().map(|e| (e.country_id, e.reputation)).collect(),
+        };
+
+        let mut regions: Vec<(u32, Vec<&TournamentEntrant>)> = Vec::new();
+        for entrant in entrants {
+            match regions.iter_mut().find(|(id, _)| *id == entrant.continent_id) {
+                Some((_, region)) => region.push(entrant),
+                None => regions.push((entrant.continent_id, vec![entrant])),
+            }
+        }
+
+        let sizes: Vec<usize> = regions.iter().map(|(_, region)| region.len()).collect();
+
+        for ((_, mut region), slots) in regions.into_iter().zip(Self::allocate_slots(&sizes, finals_teams)) {
+            region.sort_by_key(|entrant| std::cmp::Reverse(entrant.reputation));
+            let ids: Vec<u32> = region.iter().map(|e| e.country_id).collect();
+
+            // Top seeds go straight through when there are too few teams for a group each
+            let groups_count = slots.min(ids.len().saturating_sub(slots));
+            let direct = slots - groups_count;
+
+            tournament.qualified.extend(ids.iter().take(direct));
+
+            if groups_count == 0 {
+                continue;
+            }
+
+            // Only the strongest teams fit into the qualifying calendar
+            let contenders: Vec<u32> = ids
+                .into_iter()
+                .skip(direct)
+                .take(groups_count * MAX_QUALIFYING_GROUP_SIZE)
+                .collect();
+
+            tournament.qualifying_groups.extend(
+                Self::seed_groups(&contenders, groups_count)
+                    .into_iter()
+                    .map(TournamentGroup::for_qualifying),
+            );
+        }
+
+        Some(tournament)
+    }
+
+    pub fn is_qualifying(&self, country_id: u32) -> bool {
+        self.stage == NationalTournamentStage::Qualification
+            && self
+                .qualifying_groups
+                .iter()
+                .any(|group| group.teams.contains(&country_id))
+    }
+
+    pub fn simulate(&mut self, date: NaiveDate, data: &mut SimulatorData) {
+        match self.stage {
+            NationalTournamentStage::Qualification => self.simulate_qualification(date, data),
+            NationalTournamentStage::Finals => self.simulate_finals(date, data),
+            NationalTournamentStage::Finished => {}
+        }
+    }
+
+    fn simulate_qualification(&mut self, date: NaiveDate, data: &mut SimulatorData) {
+        if let Some(matchday) = InternationalBreak::on(date).and_then(|window| window.matchday(date)) {
+            for group in &mut self.qualifying_groups {
+                for (home, away) in group.fixtures_for(matchday) {
+                    let (home_goals, away_goals, _) =
+                        Self::play(home, away, NationalTeamFixtureType::Qualifier, date, data);
+
+                    group.record(home, away, home_goals, away_goals);
+                }
+            }
+        }
+
+        if date == self.date(QUALIFICATION_END) {
+            self.complete_qualification();
+        }
+    }
+
+    /// Group winners join the direct qualifiers, the finals are drawn in pots
+    fn complete_qualification(&mut self) {
+        let winners: Vec<u32> = self
+            .qualifying_groups
+            .iter()
+            .filter_map(|group| group.standings().first().copied())
+            .collect();
+
+        self.qualified.extend(winners);
+
+        let reputations = &self.reputations;
+        let reputation = |country_id: &u32| reputations.get(country_id).copied().unwrap_or(0);
+
+        self.qualified.sort_by_key(|country_id| std::cmp::Reverse(reputation(country_id)));
+
+        let groups_count = Self::finals_groups_count(self.qualified.len());
+
+        self.finals_groups = Self::seed_groups(&self.qualified, groups_count)
+            .into_iter()
+            .map(|teams| TournamentGroup::new(teams, false))
+            .collect();
+
+        info!("🏆 {}: {} teams qualified for the finals", self.name, self.qualified.len());
+
+        self.stage = NationalTournamentStage::Finals;
+    }
+
+    fn simulate_finals(&mut self, date: NaiveDate, data: &mut SimulatorData) {
+        if date == self.date(FINALS_CALL_UP_DAY) {
+            InternationalDuty::call_up(&self.qualified, date, data);
+        }
+
+        if let Some(round) = FINALS_GROUP_DAYS.iter().position(|&day| self.date(day) == date) {
+            for group in &mut self.finals_groups {
+                for (home, away) in group.fixtures_for(round) {
+                    let (home_goals, away_goals, report) =
+                        Self::play(home, away, NationalTeamFixtureType::Finals, date, data);
+
+                    if let Some(report) = report {
+                        Self::reward_players(&report, data);
+                    }
+
+                    group.record(home, away, home_goals, away_goals);
+                }
+            }
+
+            if round == FINALS_GROUP_DAYS.len() - 1 {
+                self.start_knockout(data);
+            }
+        }
+
+        if let Some(round) = FINALS_KNOCKOUT_DAYS
+            .iter()
+            .position(|&day| self.date(day) == date)
+            .filter(|&round| round < self.knockout_rounds.len())
+        {
+            self.play_knockout_round(round, date, data);
+        }
+    }
+
+    /// A power of two so the knockout halves down to a final, at least two teams a group
+    /// and at most `FINALS_GROUP_SIZE` to fit the group days
+    fn finals_groups_count(qualified: usize) -> usize {
+        let max_groups = 1 << (FINALS_KNOCKOUT_DAYS.len() - 1);
+
+        let mut groups_count = qualified
+            .div_ceil(FINALS_GROUP_SIZE)
+            .next_power_of_two()
+            .min(max_groups);
+
+        while groups_count > 1 && groups_count * 2 > qualified {
+            groups_count /= 2;
+        }
+
+        groups_count
+    }
+
+    /// Top two of each group go through, group winners meet runners-up
+    fn start_knockout(&mut self, data: &mut SimulatorData) {
+        let standings: Vec<Vec<u32>> = self.finals_groups.iter().map(|group| group.standings()).collect();
+
+        let ties: Vec<KnockoutTie> = if standings.len() == 1 {
+            match standings[0][..] {
+                [home, away, ..] => vec![KnockoutTie::new(home, away)],
+                // Nobody to play, a lone finalist takes the title
+                [champion] => return self.finish(champion, None, data),
+                [] => {
+                    self.stage = NationalTournamentStage::Finished;
+                    return;
+                }
+            }
+        } else {
+            standings
+                .chunks(2)
+                .flat_map(|pair| {
+                    [
+                        KnockoutTie::new(pair[0][0], pair[1][1]),
+                        KnockoutTie::new(pair[1][0], pair[0][1]),
+                    ]
+                })
+                .collect()
+        };
+
+        let eliminated: Vec<u32> = self
+            .qualified
+            .iter()
+            .copied()
+            .filter(|id| !ties.iter().any(|tie| tie.home == *id || tie.away == *id))
+            .collect();
+
+        InternationalDuty::release(&eliminated, data);
+
+        self.knockout_rounds.push(ties);
+    }
+
+    fn play_knockout_round(&mut self, round: usize, date: NaiveDate, data: &mut SimulatorData) {
+        let mut winners = Vec::new();
+        let mut losers = Vec::new();
+
+        for tie in &mut self.knockout_rounds[round] {
+            let (home_goals, away_goals, report) =
+                Self::play(tie.home, tie.away, NationalTeamFixtureType::Finals, date, data);
+
+            let home_strength = match &report {
+                Some(report) => {
+                    Self::reward_players(report, data);
+                    Self::strength_share(report, data)
+                }
+                None => 0.5,
+            };
+
+            let result = KnockoutResult::decide(
+                tie.home,
+                tie.away,
+                home_goals,
+                away_goals,
+                home_strength,
+                RandomSeed::random::<f32>,
+            );
+
+            winners.push(result.winner);
+            losers.push(if result.winner == tie.home { tie.away } else { tie.home });
+
+            tie.result = Some(result);
+        }
+
+        if winners.len() == 1 {
+            self.finish(winners[0], Some(losers[0]), data);
+            return;
+        }
+
+        InternationalDuty::release(&losers, data);
+
+        self.knockout_rounds.push(
+            winners
+                .chunks(2)
+                .map(|pair| KnockoutTie::new(pair[0], pair[1]))
+                .collect(),
+        );
+    }
+
+    fn finish(&mut self, champion: u32, runner_up: Option<u32>, data: &mut SimulatorData) {
+        let finalists: Vec<(u32, i16)> = std::iter::once((champion, CHAMPION_REPUTATION))
+            .chain(runner_up.map(|runner_up| (runner_up, RUNNER_UP_REPUTATION)))
+            .collect();
+
+        // Both finalists' squads become known around the world
+        for &(country_id, reputation) in &finalists {
+            let squad = data
+                .country(country_id)
+                .map(|country| country.national_team.call_ups.clone())
+                .unwrap_or_default();
+
+            for player_id in squad {
+                Self::raise_reputation(player_id, reputation, data);
+            }
+        }
+
+        let finalists: Vec<u32> = finalists.iter().map(|&(country_id, _)| country_id).collect();
+        InternationalDuty::release(&finalists, data);
+
+        info!(
+            "🏆 {} won by {}",
+            self.name,
+            data.country(champion).map(|c| c.name.as_str()).unwrap_or("unknown")
+        );
+
+        self.winner = Some(champion);
+        self.stage = NationalTournamentStage::Finished;
+    }
+
+    /// Forfeits count as 3-0 for the side able to field a team
+    fn play(
+        home: u32,
+        away: u32,
+        fixture_type: NationalTeamFixtureType,
+        date: NaiveDate,
+        data: &mut SimulatorData,
+    ) -> (u8, u8, Option<InternationalMatchReport>) {
+        match InternationalDuty::play_match(home, away, fixture_type, date, data) {
+            Some(report) => (report.home_goals, report.away_goals, Some(report)),
+            None => {
+                if InternationalDuty::can_field_team(home, data) {
+                    (3, 0, None)
+                } else {
+                    (0, 3, None)
+                }
+            }
+        }
+    }
+
+    fn reward_players(report: &InternationalMatchReport, data: &mut SimulatorData) {
+        let result_bonus = |goals_for: u8, goals_against: u8| match goals_for.cmp(&goals_against) {
+            std::cmp::Ordering::Greater => FINALS_WIN_REPUTATION,
+            std::cmp::Ordering::Equal => FINALS_DRAW_REPUTATION,
+            std::cmp::Ordering::Less => 0,
+        };
+
+        let home_bonus = FINALS_APPEARANCE_REPUTATION + result_bonus(report.home_goals, report.away_goals);
+        let away_bonus = FINALS_APPEARANCE_REPUTATION + result_bonus(report.away_goals, report.home_goals);
+
+        for &player_id in &report.home_starters {
+            Self::raise_reputation(player_id, home_bonus, data);
+        }
+
+        for &player_id in &report.away_starters {
+            Self::raise_reputation(player_id, away_bonus, data);
+        }
+
+        for &player_id in &report.scorers {
+            Self::raise_reputation(player_id, FINALS_GOAL_REPUTATION, data);
+        }
+    }
+
+    fn raise_reputation(player_id: u32, amount: i16, data: &mut SimulatorData) {
+        if let Some(player) = data.player_mut(player_id) {
+            let reputation = &mut player.player_attributes.world_reputation;
+            *reputation = reputation.saturating_add(amount).min(MAX_WORLD_REPUTATION);
+        }
+    }
+
+    /// Home side's share of the combined ability of both starting elevens
+    fn strength_share(report: &InternationalMatchReport, data: &SimulatorData) -> f32 {
+        let ability = |players: &[u32]| -> f32 {
+            players
+                .iter()
+                .filter_map(|&id| data.player(id))
+                .map(|player| player.player_attributes.current_ability as f32)
+                .sum()
+        };
+
+        let home = ability(&report.home_starters);
+        let away = ability(&report.away_starters);
+
+        if home + away > 0.0 {
+            home / (home + away)
+        } else {
+            0.5
+        }
+    }
+
+    /// Spreads the seeded teams over the groups, one pot at a time
+    pub(crate) fn seed_groups(seeded: &[u32], groups_count: usize) -> Vec<Vec<u32>> {
+        let mut groups = vec![Vec::new(); groups_count];
+
+        for (index, &team) in seeded.iter().enumerate() {
+            let pot = index / groups_count;
+            let position = index % groups_count;
+
+            // Alternate direction so the best team of a pot meets the weakest of the next
+            let group = if pot.is_multiple_of(2) { position } else { groups_count - 1 - position };
+
+            groups[group].push(team);
+        }
+
+        groups
+    }
+
+    /// Largest remainder share of the finals places by number of entrants
+    fn allocate_slots(sizes: &[usize], finals_teams: usize) -> Vec<usize> {
+        let total: usize = sizes.iter().sum();
+        if total == 0 {
+            return vec![0; sizes.len()];
+        }
+
+        let mut slots: Vec<usize> = sizes.iter().map(|size| finals_teams * size / total).collect();
+
+        let mut remainders: Vec<(usize, usize)> = sizes
+            .iter()
+            .enumerate()
+            .map(|(index, size)| (index, finals_teams * size % total))
+            .collect();
+
+        remainders.sort_by_key(|&(_, remainder)| std::cmp::Reverse(remainder));
+
+        let allocated: usize = slots.iter().sum();
+        for (index, _) in remainders.into_iter().take(finals_teams - allocated) {
+            slots[index] += 1;
+        }
+
+        slots
+    }
+
+    fn date(&self, (month, day): (u32, u32)) -> NaiveDate {
+        // The qualifying campaign starts the autumn before the finals
+        let year = if month >= DRAW_DAY.0 { self.year - 1 } else { self.year };
+
+        NaiveDate::from_ymd_opt(year, month, day).expect("invalid tournament date")
+    }
+}
+
+/// Draws next summer's tournaments and plays the current ones
+pub struct InternationalTournaments;
+
+impl InternationalTournaments {
+    pub fn process(data: &mut SimulatorData) {
+        let date = data.date.date();
+
+        if (date.month(), date.day()) == DRAW_DAY {
+            Self::draw(date.year() + 1, data);
+        }
+
+        let mut tournaments = std::mem::take(&mut data.national_tournaments);
+
+        for tournament in tournaments.iter_mut() {
+            tournament.simulate(date, data);
+        }
+
+        data.national_tournaments = tournaments;
+    }
+
+    fn draw(year: i32, data: &mut SimulatorData) {
+        let kind = NationalTournamentKind::for_year(year);
+        let entrants = Self::entrants(data);
+
+        let tournaments: Vec<NationalTournament> = match kind {
+            NationalTournamentKind::WorldCup => {
+                NationalTournament::draw(format!("World Cup {}", year), kind, None, year, &entrants)
+                    .into_iter()
+                    .collect()
+            }
+            NationalTournamentKind::ContinentalChampionship => data
+                .continents
+                .iter()
+                .filter_map(|continent| {
+                    let continent_entrants: Vec<TournamentEntrant> = entrants
+                        .iter()
+                        .filter(|entrant| entrant.continent_id == continent.id)
+                        .cloned()
+                        .collect();
+
+                    NationalTournament::draw(
+                        format!("{} Championship {}", continent.name, year),
+                        kind,
+                        Some(continent.id),
+                        year,
+                        &continent_entrants,
+                    )
+                })
+                .collect(),
+        };
+
+        for tournament in &tournaments {
+            info!(
+                "🏆 {} draw: {} qualifying groups, {} direct qualifiers",
+                tournament.name,
+                tournament.qualifying_groups.len(),
+                tournament.qualified.len()
+            );
+        }
+
+        data.national_tournaments.extend(tournaments);
+    }
+
+    /// Countries with enough players of their nationality to field a team
+    fn entrants(data: &SimulatorData) -> Vec<TournamentEntrant> {
+        let mut players_by_country: HashMap<u32, usize> = HashMap::new();
+
+        for player in data
+            .continents
+            .iter()
+            .flat_map(|continent| &continent.countries)
+            .flat_map(|country| &country.clubs)
+            .flat_map(|club| &club.teams.teams)
+            .flat_map(|team| team.players.players.iter())
+        {
+            *players_by_country.entry(player.country_id).or_default() += 1;
+        }
+
+        data.continents
+            .iter()
+            .flat_map(|continent| &continent.countries)
+            .filter(|country| {
+                players_by_country.get(&country.id).copied().unwrap_or(0) >= MIN_CALL_UPS_TO_PLAY
+            })
+            .map(|country| TournamentEntrant {
+                country_id: country.id,
+                continent_id: country.continent_id,
+                reputation: country.reputation,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentGroup {
+    pub teams: Vec<u32>,
+    pub fixtures: Vec<GroupFixture>,
+    pub table: Vec<GroupTableRow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupFixture {
+    pub round: usize,
+    pub home: u32,
+    pub away: u32,
+    pub result: Option<(u8, u8)>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GroupTableRow {
+    #[serde(alias = "country_id")]
+    pub team_id: u32,
+    pub played: u8,
+    pub won: u8,
+    pub drawn: u8,
+    pub lost: u8,
+    pub goals_for: u16,
+    pub goals_against: u16,
+    pub points: u8,
+}
+
+impl TournamentGroup {
+    pub fn new(teams: Vec<u32>, double_round_robin: bool) -> Self {
+        let single_rounds = if teams.len().is_multiple_of(2) { teams.len().saturating_sub(1) } else { teams.len() };
+
+        let mut fixtures: Vec<GroupFixture> = (0..single_rounds)
+            .flat_map(|round| {
+                NationalTeam::pair_opponents(&teams, round)
+                    .into_iter()
+                    .map(move |(home, away)| GroupFixture { round, home, away, result: None })
+            })
+            .collect();
+
+        if double_round_robin {
+            let return_legs: Vec<GroupFixture> = fixtures
+                .iter()
+                .map(|fixture| GroupFixture {
+                    round: fixture.round + single_rounds,
+                    home: fixture.away,
+                    away: fixture.home,
+                    result: None,
+                })
+                .collect();
+
+            fixtures.extend(return_legs);
+        }
+
+        let table = teams
+            .iter()
+            .map(|&team_id| GroupTableRow { team_id, ..Default::default() })
+            .collect();
+
+        TournamentGroup { teams, fixtures, table }
+    }
+
+    /// Home and away when the calendar has room for it
+    pub fn for_qualifying(teams: Vec<u32>) -> Self {
+        let single_rounds = if teams.len().is_multiple_of(2) { teams.len().saturating_sub(1) } else { teams.len() };
+
+        Self::new(teams, single_rounds * 2 <= INTERNATIONAL_MATCHDAYS)
+    }
+
+    pub fn rounds(&self) -> usize {
+        self.fixtures.iter().map(|f| f.round + 1).max().unwrap_or(0)
+    }
+
+    pub fn fixtures_for(&self, round: usize) -> Vec<(u32, u32)> {
+        self.fixtures
+            .iter()
+            .filter(|f| f.round == round && f.result.is_none())
+            .map(|f| (f.home, f.away))
+            .collect()
+    }
+
+    pub fn record(&mut self, home: u32, away: u32, home_goals: u8, away_goals: u8) {
+        if let Some(fixture) = self
+            .fixtures
+            .iter_mut()
+            .find(|f| f.home == home && f.away == away && f.result.is_none())
+        {
+            fixture.result = Some((home_goals, away_goals));
+        }
+
+        for (team, goals_for, goals_against) in [(home, home_goals, away_goals), (away, away_goals, home_goals)] {
+            if let Some(row) = self.table.iter_mut().find(|row| row.team_id == team) {
+                row.played += 1;
+                row.goals_for += goals_for as u16;
+                row.goals_against += goals_against as u16;
+
+                match goals_for.cmp(&goals_against) {
+                    std::cmp::Ordering::Greater => {
+                        row.won += 1;
+                        row.points += 3;
+                    }
+                    std::cmp::Ordering::Equal => {
+                        row.drawn += 1;
+                        row.points += 1;
+                    }
+                    std::cmp::Ordering::Less => row.lost += 1,
+                }
+            }
+        }
+    }
+
+    /// Points, goal difference, goals scored, then seeding
+    pub fn standings(&self) -> Vec<u32> {
+        let mut rows: Vec<(usize, &GroupTableRow)> = self.table.iter().enumerate().collect();
+
+        rows.sort_by(|(a_seed, a), (b_seed, b)| {
+            let a_difference = a.goals_for as i32 - a.goals_against as i32;
+            let b_difference = b.goals_for as i32 - b.goals_against as i32;
+
+            b.points
+                .cmp(&a.points)
+                .then(b_difference.cmp(&a_difference))
+                .then(b.goals_for.cmp(&a.goals_for))
+                .then(a_seed.cmp(b_seed))
+        });
+
+        rows.into_iter().map(|(_, row)| row.team_id).collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KnockoutTie {
+    pub home: u32,
+    pub away: u32,
+    pub result: Option<KnockoutResult>,
+}
+
+impl KnockoutTie {
+    pub fn new(home: u32, away: u32) -> Self {
+        KnockoutTie { home, away, result: None }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KnockoutResult {
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub extra_time: Option<(u8, u8)>,
+    pub penalties: Option<(u8, u8)>,
+    pub winner: u32,
+}
+
+impl KnockoutResult {
+    /// Level after ninety minutes goes to extra time, then penalties.
+    /// `home_strength` is the home side's share of the combined quality
+    pub fn decide(
+        home: u32,
+        away: u32,
+        home_goals: u8,
+        away_goals: u8,
+        home_strength: f32,
+        mut random: impl FnMut() -> f32,
+    ) -> Self {
+        let mut result = KnockoutResult {
+            home_goals,
+            away_goals,
+            extra_time: None,
+            penalties: None,
+            winner: if home_goals >= away_goals { home } else { away },
+        };
+
+        if home_goals != away_goals {
+            return result;
+        }
+
+        let home_chance = EXTRA_TIME_HALF_GOAL_CHANCE * 2.0 * home_strength;
+        let away_chance = EXTRA_TIME_HALF_GOAL_CHANCE * 2.0 * (1.0 - home_strength);
+
+        let mut extra_time = (0u8, 0u8);
+        for _ in 0..EXTRA_TIME_HALVES {
+            if random() < home_chance {
+                extra_time.0 += 1;
+            }
+            if random() < away_chance {
+                extra_time.1 += 1;
+            }
+        }
+
+        result.extra_time = Some(extra_time);
+
+        if extra_time.0 != extra_time.1 {
+            result.winner = if extra_time.0 > extra_time.1 { home } else { away };
+            return result;
+        }
+
+        let penalties = Self::shootout(home_strength, &mut random);
+
+        result.winner = if penalties.0 > penalties.1 { home } else { away };
+        result.penalties = Some(penalties);
+
+        result
+    }
+
+    fn shootout(home_strength: f32, random: &mut impl FnMut() -> f32) -> (u8, u8) {
+        let home_conversion = PENALTY_CONVERSION + (home_strength - 0.5) * 0.2;
+        let away_conversion = PENALTY_CONVERSION - (home_strength - 0.5) * 0.2;
+
+        let mut score = (0u8, 0u8);
+
+        for round in 0..MAX_PENALTY_ROUNDS {
+            if random() < home_conversion {
+                score.0 += 1;
+            }
+            if random() < away_conversion {
+                score.1 += 1;
+            }
+
+            // Stop once one side can no longer catch up
+            let remaining = PENALTIES_PER_SIDE.saturating_sub(round + 1);
+            if score.0 > score.1 + remaining || score.1 > score.0 + remaining {
+                return score;
+            }
+        }
+
+        // Still level after every round, the home side is awarded the shootout
+        (score.0 + 1, score.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entrant(country_id: u32, continent_id: u32, reputation: u16) -> TournamentEntrant {
+        TournamentEntrant {
+            country_id,
+            continent_id,
+            reputation,
+        }
+    }
+
+    #[test]
+    fn world_cup_every_four_years() {
+        assert_eq!(NationalTournamentKind::WorldCup, NationalTournamentKind::for_year(2026));
+        assert_eq!(NationalTournamentKind::ContinentalChampionship, NationalTournamentKind::for_year(2028));
+    }
+
+    #[test]
+    fn draw_splits_places_by_entrants() {
+        let mut entrants: Vec<TournamentEntrant> = (0..30).map(|id| entrant(id, 1, id as u16)).collect();
+        entrants.extend((30..40).map(|id| entrant(id, 2, id as u16)));
+
+        let tournament = NationalTournament::draw(
+            "World Cup".to_string(),
+            NationalTournamentKind::WorldCup,
+            None,
+            2026,
+            &entrants,
+        )
+        .unwrap();
+
+        // 12 places for the first continent, 4 for the second
+        assert_eq!(16, tournament.qualifying_groups.len());
+        assert!(tournament.qualified.is_empty());
+        assert!(tournament
+            .qualifying_groups
+            .iter()
+            .all(|group| group.teams.len() <= MAX_QUALIFYING_GROUP_SIZE && group.rounds() <= INTERNATIONAL_MATCHDAYS));
+    }
+
+    #[test]
+    fn top_seeds_qualify_directly_in_small_fields() {
+        let entrants: Vec<TournamentEntrant> = (0..5).map(|id| entrant(id, 1, 100 - id as u16)).collect();
+
+        let tournament = NationalTournament::draw(
+            "Championship".to_string(),
+            NationalTournamentKind::ContinentalChampionship,
+            Some(1),
+            2025,
+            &entrants,
+        )
+        .unwrap();
+
+        assert_eq!(vec![0, 1, 2], tournament.qualified);
+        assert_eq!(1, tournament.qualifying_groups.len());
+        assert_eq!(vec![3, 4], tournament.qualifying_groups[0].teams);
+
+        assert!(NationalTournament::draw(
+            "Championship".to_string(),
+            NationalTournamentKind::ContinentalChampionship,
+            Some(1),
+            2025,
+            &entrants[..3],
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn group_standings_use_points_then_goal_difference() {
+        let mut group = TournamentGroup::new(vec![1, 2, 3, 4], false);
+
+        assert_eq!(3, group.rounds());
+
+        group.record(1, 2, 0, 1);
+        group.record(3, 4, 3, 0);
+        group.record(1, 3, 2, 2);
+
+        assert_eq!(vec![3, 2, 1, 4], group.standings());
+    }
+
+    /// Finals between countries without players, every match is decided by forfeit
+    fn play_finals(qualified: u32) -> NationalTournament {
+        let mut tournament = NationalTournament {
+            name: "World Cup".to_string(),
+            kind: NationalTournamentKind::WorldCup,
+            continent_id: None,
+            year: 2026,
+            stage: NationalTournamentStage::Qualification,
+            qualifying_groups: Vec::new(),
+            qualified: (1..=qualified).collect(),
+            finals_groups: Vec::new(),
+            knockout_rounds: Vec::new(),
+            winner: None,
+            reputations: HashMap::new(),
+        };
+
+        let mut date = NaiveDate::from_ymd_opt(2026, 4, 1).unwrap();
+        let mut data = SimulatorData::new(date.and_hms_opt(0, 0, 0).unwrap(), Vec::new());
+
+        while date.month() <= 7 {
+            tournament.simulate(date, &mut data);
+            date = date.succ_opt().unwrap();
+        }
+
+        tournament
+    }
+
+    #[test]
+    fn finals_bracket_halves_down_to_a_final() {
+        for (qualified, groups, rounds) in [(12, 4, 3), (24, 8, 4), (16, 4, 3), (5, 2, 2), (3, 1, 1)] {
+            let tournament = play_finals(qualified);
+
+            assert_eq!(groups, tournament.finals_groups.len(), "{} qualified", qualified);
+            assert!(tournament
+                .finals_groups
+                .iter()
+                .all(|group| group.teams.len() >= 2 && group.teams.len() <= FINALS_GROUP_SIZE));
+            assert_eq!(rounds, tournament.knockout_rounds.len(), "{} qualified", qualified);
+            assert_eq!(NationalTournamentStage::Finished, tournament.stage);
+            assert!(tournament.winner.is_some());
+        }
+    }
+
+    #[test]
+    fn lone_finalist_takes_the_title() {
+        let tournament = play_finals(1);
+
+        assert_eq!(Some(1), tournament.winner);
+        assert!(tournament.knockout_rounds.is_empty());
+
+        let tournament = play_finals(0);
+
+        assert_eq!(NationalTournamentStage::Finished, tournament.stage);
+        assert_eq!(None, tournament.winner);
+    }
+
+    #[test]
+    fn level_knockout_goes_to_penalties() {
+        // Nobody scores in extra time, the away side misses its first penalty
+        let mut kicks = [1.0, 1.0, 1.0, 1.0, 0.0, 1.0].into_iter().chain(std::iter::repeat(0.0));
+
+        let result = KnockoutResult::decide(1, 2, 1, 1, 0.5, || kicks.next().unwrap());
+
+        assert_eq!(Some((0, 0)), result.extra_time);
+        assert_eq!(Some((5, 4)), result.penalties);
+        assert_eq!(1, result.winner);
+    }
+}
@@ -1,9 +1,12 @@
 use crate::context::GlobalContext;
 use crate::league::{League, LeagueResult};
+use crate::utils::RandomSeed;
 use crate::{Club, Logging};
 use rayon::iter::IntoParallelRefMutIterator;
-use rayon::iter::ParallelIterator;
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize)]
 pub struct LeagueCollection {
     pub leagues: Vec<League>,
 }
@@ -20,9 +23,13 @@ impl LeagueCollection {
             .map(|c| (c.id, c.league_id))
             .collect();
 
+        // Seeded runs give every league its own seed so they repeat whatever thread plays them
+        let seeds: Vec<Option<u64>> = self.leagues.iter().map(|_| RandomSeed::fork()).collect();
+
         self.leagues
             .par_iter_mut()
-            .map(|league| {
+            .zip(seeds)
+            .map(|(league, seed)| {
                 let league_team_ids: Vec<u32> = teams_ids
                     .iter()
                     .filter(|(_, league_id)| *league_id == league.id)
@@ -35,10 +42,12 @@ impl LeagueCollection {
 
                     Logging::estimate_result(
                         || {
-                            league.simulate(
-                                clubs,
-                                ctx.with_league(league.id, league_slug, &league_team_ids),
-                            )
+                            RandomSeed::scoped(seed, || {
+                                league.simulate(
+                                    clubs,
+                                    ctx.with_league(league.id, league_slug, &league_team_ids),
+                                )
+                            })
                         },
                         message,
                     )
@@ -1,19 +1,26 @@
+use crate::awards::SeasonAwards;
 use crate::context::{GlobalContext, SimulationContext};
-use crate::league::{LeagueMatch, LeagueMatchResultResult, LeagueResult, LeagueTable, MatchStorage, Schedule, ScheduleItem};
+use crate::league::{LeagueMatch, LeagueMatchResultResult, LeagueResult, LeagueTable, LeagueTableResult, MatchStorage, Schedule, ScheduleItem};
 use crate::r#match::{Match, MatchResult};
-use crate::utils::Logging;
-use crate::{Club, Team};
+use crate::utils::{Logging, RandomSeed};
+use crate::{Club, InternationalBreak, MatchAttendanceFactors, Team, TeamType};
 use chrono::{Datelike, NaiveDate};
 use log::{debug, info, warn};
 use rayon::iter::IntoParallelRefMutIterator;
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+/// Share of the first team crowd that turns up for reserve and youth matches
+const RESERVE_ATTENDANCE_SHARE: f32 = 0.05;
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct League {
     pub id: u32,
     pub name: String,
     pub slug: String,
     pub country_id: u32,
+    /// Level in the country's pyramid, 1 is the top flight
+    pub tier: u8,
     pub schedule: Schedule,
     pub table: LeagueTable,
     pub settings: LeagueSettings,
@@ -25,6 +32,10 @@ pub struct League {
     pub regulations: LeagueRegulations,
     pub statistics: LeagueStatistics,
     pub milestones: LeagueMilestones,
+    pub awards: Vec<SeasonAwards>,
+    /// Team ids in the order the last finished season ended
+    #[serde(default)]
+    pub final_standings: Vec<u32>,
 }
 
 impl League {
@@ -41,6 +52,7 @@ impl League {
             name,
             slug,
             country_id,
+            tier: 1,
             schedule: Schedule::default(),
             table: LeagueTable::default(),
             matches: MatchStorage::new(),
@@ -50,9 +62,21 @@ impl League {
             regulations: LeagueRegulations::new(),
             statistics: LeagueStatistics::new(),
             milestones: LeagueMilestones::new(),
+            awards: Vec::new(),
+            final_standings: Vec::new(),
         }
     }
 
+    pub fn with_tier(mut self, tier: u8) -> Self {
+        self.tier = tier;
+        self
+    }
+
+    /// Standing of the league from 0 to 1
+    pub fn prestige(&self) -> f32 {
+        (self.reputation as f32 / 10000.0).min(1.0)
+    }
+
     pub fn simulate(&mut self, clubs: &[Club], ctx: GlobalContext<'_>) -> LeagueResult {
         let league_name = self.name.clone();
         let current_date = ctx.simulation.date.date();
@@ -76,6 +100,9 @@ impl League {
             ctx.with_league(self.id, String::from(&self.slug), &league_teams),
         );
 
+        // Clubs with players at a summer finals play once they are back
+        self.postpone_finals_clashes(&mut schedule_result.scheduled_matches, clubs, current_date);
+
         // Phase 4: Match execution with enhanced dynamics
         if schedule_result.is_match_scheduled() {
             let match_results = self.play_scheduled_matches(
@@ -84,9 +111,16 @@ impl League {
                 &ctx,
             );
 
-            self.process_match_day_results(&match_results, clubs, &ctx, current_date);
+            let milestones_before = self.milestones.season_milestones.len();
+
+            let managers_under_pressure =
+                self.process_match_day_results(&match_results, clubs, &ctx, current_date);
 
-            return LeagueResult::with_match_result(self.id, table_result, match_results);
+            let milestones = self.milestones.season_milestones[milestones_before..].to_vec();
+
+            return LeagueResult::with_match_result(self.id, table_result, match_results)
+                .with_managers_under_pressure(managers_under_pressure)
+                .with_milestones(milestones);
         }
 
         // Phase 5: Off-season or mid-season processing
@@ -97,12 +131,42 @@ impl League {
 
     // ========== MATCHDAY PREPARATION ==========
 
+    fn postpone_finals_clashes(
+        &mut self,
+        scheduled_matches: &mut Vec<LeagueMatch>,
+        clubs: &[Club],
+        current_date: NaiveDate,
+    ) {
+        let clashes: Vec<String> = scheduled_matches
+            .iter()
+            .filter(|scheduled_match| {
+                [scheduled_match.home_team_id, scheduled_match.away_team_id]
+                    .iter()
+                    .any(|&team_id| self.get_team(clubs, team_id).is_missing_players_to_finals(current_date))
+            })
+            .map(|scheduled_match| scheduled_match.id.clone())
+            .collect();
+
+        for id in &clashes {
+            if let Some(new_date) = self.schedule.postpone(id) {
+                info!("📅 {}: {} postponed to {}, players away at the finals", self.name, id, new_date.date());
+            }
+        }
+
+        scheduled_matches.retain(|scheduled_match| !clashes.contains(&scheduled_match.id));
+    }
+
     fn prepare_matchday(&mut self, ctx: &GlobalContext<'_>, clubs: &[Club]) {
         debug!("Preparing matchday for {}", self.name);
 
         let current_date = ctx.simulation.date.date();
         let day_of_week = current_date.weekday();
 
+        // Clubs from the same city play derbies, the league's teams are known from the first day of a season
+        if !self.dynamics.rivalries_registered {
+            self.dynamics.register_local_rivalries(clubs, self.id);
+        }
+
         // Update attendance predictions
         self.dynamics.update_attendance_predictions(
             &self.table,
@@ -139,19 +203,25 @@ impl League {
         clubs: &[Club],
         ctx: &GlobalContext<'_>,
     ) -> Vec<MatchResult> {
-        use rayon::iter::ParallelIterator;
+        use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+        let seeds: Vec<Option<u64>> = scheduled_matches.iter().map(|_| RandomSeed::fork()).collect();
 
         // Play all matches in parallel
         let match_results: Vec<MatchResult> = scheduled_matches
             .par_iter_mut()
-            .map(|scheduled_match| {
-                Self::play_single_match_static(
-                    scheduled_match,
-                    clubs,
-                    ctx,
-                    &self.dynamics,
-                    &self.table,
-                )
+            .zip(seeds)
+            .map(|(scheduled_match, seed)| {
+                RandomSeed::scoped(seed, || {
+                    Self::play_single_match_static(
+                        scheduled_match,
+                        clubs,
+                        ctx,
+                        &self.dynamics,
+                        &self.table,
+                        self.reputation,
+                    )
+                })
             })
             .collect();
 
@@ -173,7 +243,46 @@ impl League {
         ctx: &GlobalContext<'_>,
         dynamics: &LeagueDynamics,
         table: &LeagueTable,
+        league_reputation: u16,
     ) -> MatchResult {
+        let (match_to_play, home_team_type) = Self::make_match_static(
+            scheduled_match,
+            clubs,
+            ctx.simulation.date.date(),
+            dynamics,
+            table,
+            league_reputation,
+        );
+
+        let message = &format!(
+            "play match: {} vs {} (Momentum: {:.1} vs {:.1})",
+            &match_to_play.home_squad.team_name,
+            &match_to_play.away_squad.team_name,
+            dynamics.get_team_momentum(scheduled_match.home_team_id),
+            dynamics.get_team_momentum(scheduled_match.away_team_id)
+        );
+
+        let match_result = Logging::estimate_result(|| match_to_play.play(), message);
+
+        // Update match result in schedule
+        scheduled_match.result = Some(LeagueMatchResultResult::from_score(&match_result.score));
+
+        // Only first team matches are kept for replays
+        match home_team_type {
+            TeamType::Main => match_result,
+            _ => match_result.copy_without_data_positions(),
+        }
+    }
+
+    /// The match with both squads and the crowd, as the matchday sets it up
+    fn make_match_static(
+        scheduled_match: &LeagueMatch,
+        clubs: &[Club],
+        date: NaiveDate,
+        dynamics: &LeagueDynamics,
+        table: &LeagueTable,
+        league_reputation: u16,
+    ) -> (Match, TeamType) {
         let home_team = clubs
             .iter()
             .flat_map(|c| &c.teams.teams)
@@ -190,13 +299,8 @@ impl League {
         let home_momentum = dynamics.get_team_momentum(scheduled_match.home_team_id);
         let away_momentum = dynamics.get_team_momentum(scheduled_match.away_team_id);
 
-        let (home_pressure, away_pressure) = Self::calculate_match_pressures_static(
-            home_team,
-            away_team,
-            ctx.simulation.date.date(),
-            dynamics,
-            table,
-        );
+        let (home_pressure, away_pressure) =
+            Self::calculate_match_pressures_static(home_team, away_team, date, dynamics, table);
 
         // Prepare squads with psychological modifiers
         let mut home_squad = home_team.get_enhanced_match_squad();
@@ -205,29 +309,117 @@ impl League {
         Self::apply_psychological_factors_static(&mut home_squad, home_momentum, home_pressure);
         Self::apply_psychological_factors_static(&mut away_squad, away_momentum, away_pressure);
 
-        // Create and play match
+        // Crowd and home advantage
+        let home_club = clubs
+            .iter()
+            .find(|c| c.id == home_team.club_id)
+            .expect("Home club not found");
+
+        let stadium_attendance = home_club.stadium.attendance(&MatchAttendanceFactors {
+            home_reputation: home_team.reputation.home,
+            away_reputation: away_team.reputation.home,
+            home_momentum,
+            is_derby: dynamics.is_derby(home_team.id, away_team.id),
+            league_multiplier: dynamics.attendance_multiplier,
+            league_reputation,
+            date,
+        });
+
+        // Reserve and youth sides play at the training ground in front of a few fans
+        let attendance = match home_team.team_type {
+            TeamType::Main => stadium_attendance,
+            _ => (stadium_attendance as f32 * RESERVE_ATTENDANCE_SHARE) as u32,
+        };
+
+        home_squad.apply_home_advantage(home_club.stadium.home_advantage(attendance));
+
         let match_to_play = Match::make(
             scheduled_match.id.clone(),
             scheduled_match.league_id,
             &scheduled_match.league_slug,
             home_squad,
             away_squad,
+        )
+        .with_attendance(attendance);
+
+        (match_to_play, home_team.team_type)
+    }
+
+    /// A fixture still to be played, set up as its matchday would, for playing it outside the
+    /// daily simulation, e.g. live
+    pub fn fixture_match(&self, fixture_id: &str, clubs: &[Club], date: NaiveDate) -> Option<Match> {
+        let fixture = self
+            .schedule
+            .tours
+            .iter()
+            .flat_map(|tour| &tour.items)
+            .find(|item| item.id == fixture_id && item.result.is_none())?;
+
+        let scheduled_match = LeagueMatch {
+            id: fixture.id.clone(),
+            league_id: fixture.league_id,
+            league_slug: fixture.league_slug.clone(),
+            date: fixture.date,
+            home_team_id: fixture.home_team_id,
+            away_team_id: fixture.away_team_id,
+            result: None,
+        };
+
+        let (match_to_play, _) = Self::make_match_static(
+            &scheduled_match,
+            clubs,
+            date,
+            &self.dynamics,
+            &self.table,
+            self.reputation,
         );
 
-        let message = &format!(
-            "play match: {} vs {} (Momentum: {:.1} vs {:.1})",
-            &match_to_play.home_squad.team_name,
-            &match_to_play.away_squad.team_name,
-            home_momentum,
-            away_momentum
+        Some(match_to_play)
+    }
+
+    /// Counts a fixture played outside the daily simulation like a matchday result.
+    /// None when the fixture was played in the meantime
+    pub fn record_fixture_result(
+        &mut self,
+        match_result: MatchResult,
+        clubs: &[Club],
+        ctx: &GlobalContext<'_>,
+    ) -> Option<LeagueResult> {
+        let is_unplayed = self
+            .schedule
+            .tours
+            .iter()
+            .flat_map(|tour| &tour.items)
+            .any(|item| item.id == match_result.id && item.result.is_none());
+
+        if !is_unplayed {
+            return None;
+        }
+
+        self.dynamics.update_team_momentum_after_match(
+            match_result.home_team_id,
+            match_result.away_team_id,
+            &match_result,
         );
 
-        let match_result = Logging::estimate_result(|| match_to_play.play(), message);
+        let match_results = vec![match_result];
 
-        // Update match result in schedule
-        scheduled_match.result = Some(LeagueMatchResultResult::from_score(&match_result.score));
+        let milestones_before = self.milestones.season_milestones.len();
+
+        let managers_under_pressure = self.process_match_day_results(
+            &match_results,
+            clubs,
+            ctx,
+            ctx.simulation.date.date(),
+        );
+
+        let milestones = self.milestones.season_milestones[milestones_before..].to_vec();
 
-        match_result
+        Some(
+            LeagueResult::with_match_result(self.id, LeagueTableResult {}, match_results)
+                .with_managers_under_pressure(managers_under_pressure)
+                .with_milestones(milestones),
+        )
     }
 
     fn get_team_momentums(&self, home_id: u32, away_id: u32) -> (f32, f32) {
@@ -355,9 +547,9 @@ impl League {
         clubs: &[Club],
         ctx: &GlobalContext<'_>,
         current_date: NaiveDate,
-    ) {
+    ) -> Vec<u32> {
         // Update match results and statistics
-        self.process_match_results(match_results, clubs, ctx);
+        let managers_under_pressure = self.process_match_results(match_results, clubs, ctx);
 
         // Update table standings
         self.table.update_from_results(match_results);
@@ -375,6 +567,8 @@ impl League {
 
         // Apply regulatory actions
         self.apply_regulatory_actions(clubs, ctx);
+
+        managers_under_pressure
     }
 
     fn process_match_results(
@@ -382,7 +576,9 @@ impl League {
         results: &[MatchResult],
         clubs: &[Club],
         ctx: &GlobalContext<'_>,
-    ) {
+    ) -> Vec<u32> {
+        let mut managers_under_pressure = Vec::new();
+
         for result in results {
             // Update statistics
             self.statistics.process_match_result(result);
@@ -398,11 +594,17 @@ impl League {
             );
 
             // Check manager pressure
-            self.check_manager_pressure(result, clubs, ctx.simulation.date.date());
+            managers_under_pressure.extend(self.check_manager_pressure(
+                result,
+                clubs,
+                ctx.simulation.date.date(),
+            ));
         }
 
         // Update player rankings
         self.statistics.update_player_rankings(clubs);
+
+        managers_under_pressure
     }
 
     fn check_manager_pressure(
@@ -410,18 +612,25 @@ impl League {
         result: &MatchResult,
         _clubs: &[Club],
         _current_date: NaiveDate,
-    ) {
+    ) -> Vec<u32> {
+        let mut teams_under_pressure = Vec::new();
+
         // Check home team
         let home_losing_streak = self.dynamics.get_team_losing_streak(result.score.home_team.team_id);
         if home_losing_streak > 5 {
             warn!("🔴 Manager under severe pressure at team {}", result.score.home_team.team_id);
+            teams_under_pressure.push(result.score.home_team.team_id);
         }
 
         // Check away team
         let away_losing_streak = self.dynamics.get_team_losing_streak(result.score.away_team.team_id);
         if away_losing_streak > 5 {
             warn!("🔴 Manager under severe pressure at team {}", result.score.away_team.team_id);
+            teams_under_pressure.push(result.score.away_team.team_id);
         }
+
+        // Boards react to these in LeagueResult
+        teams_under_pressure
     }
 
     // ========== LEAGUE DYNAMICS ==========
@@ -538,7 +747,7 @@ impl League {
     }
 
     fn is_season_end(&self, date: NaiveDate) -> bool {
-        date.month() == 5 && date.day() >= 25
+        self.settings.is_season_end(date)
     }
 
     fn is_winter_break(&self, date: NaiveDate) -> bool {
@@ -546,10 +755,7 @@ impl League {
     }
 
     fn is_international_break(&self, date: NaiveDate) -> bool {
-        (date.month() == 9 && date.day() >= 4 && date.day() <= 12) ||
-            (date.month() == 10 && date.day() >= 9 && date.day() <= 17) ||
-            (date.month() == 11 && date.day() >= 13 && date.day() <= 21) ||
-            (date.month() == 3 && date.day() >= 20 && date.day() <= 28)
+        InternationalBreak::on(date).is_some()
     }
 
     fn process_season_end(&mut self, _clubs: &[Club]) {
@@ -561,8 +767,11 @@ impl League {
             self.milestones.record_champion(champion);
         }
 
+        self.final_standings = self.table.rows.iter().map(|r| r.team_id).collect();
+
         self.dynamics.reset_for_new_season();
         self.statistics.archive_season_stats();
+        self.milestones.season_milestones.clear();
     }
 
     fn process_winter_break(&mut self, _clubs: &[Club]) {
@@ -582,7 +791,7 @@ impl League {
 
 // Supporting structures for enhanced simulation
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LeagueDynamics {
     pub team_momentum: HashMap<u32, f32>,
     pub team_streaks: HashMap<u32, TeamStreak>,
@@ -590,6 +799,9 @@ pub struct LeagueDynamics {
     pub relegation_battle: RelegationBattle,
     pub european_race: EuropeanRace,
     pub rivalries: Vec<(u32, u32)>,
+    /// Rivalries are worked out once a season, a league without derbies included
+    #[serde(default)]
+    pub rivalries_registered: bool,
     pub attendance_multiplier: f32,
 }
 
@@ -602,6 +814,7 @@ impl LeagueDynamics {
             relegation_battle: RelegationBattle::default(),
             european_race: EuropeanRace::default(),
             rivalries: Vec::new(),
+            rivalries_registered: false,
             attendance_multiplier: 1.0,
         }
     }
@@ -720,6 +933,29 @@ impl LeagueDynamics {
             .collect();
     }
 
+    pub fn register_local_rivalries(&mut self, clubs: &[Club], league_id: u32) {
+        self.rivalries.clear();
+        self.rivalries_registered = true;
+
+        let league_teams: Vec<(u32, u32)> = clubs
+            .iter()
+            .flat_map(|club| {
+                club.teams
+                    .with_league(league_id)
+                    .into_iter()
+                    .map(move |team_id| (team_id, club.location.city_id))
+            })
+            .collect();
+
+        for (idx, (team_id, city_id)) in league_teams.iter().enumerate() {
+            for (other_team_id, other_city_id) in &league_teams[idx + 1..] {
+                if city_id == other_city_id {
+                    self.rivalries.push((*team_id, *other_team_id));
+                }
+            }
+        }
+    }
+
     pub fn is_derby(&self, team1: u32, team2: u32) -> bool {
         self.rivalries.iter().any(|(a, b)|
             (*a == team1 && *b == team2) || (*a == team2 && *b == team1)
@@ -761,34 +997,36 @@ impl LeagueDynamics {
         self.title_race = TitleRace::default();
         self.relegation_battle = RelegationBattle::default();
         self.european_race = EuropeanRace::default();
+        // Promoted and relegated teams change the derbies
+        self.rivalries_registered = false;
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct TeamStreak {
     pub winning_streak: u8,
     pub losing_streak: u8,
     pub unbeaten_streak: u8,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct TitleRace {
     pub leader_id: u32,
     pub gap_to_second: i8,
     pub contenders: Vec<u32>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct RelegationBattle {
     pub teams_in_danger: Vec<u32>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct EuropeanRace {
     pub teams_in_contention: Vec<u32>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LeagueRegulations {
     pub suspended_players: HashMap<u32, u8>, // player_id -> matches remaining
     pub yellow_card_accumulation: HashMap<u32, u8>, // player_id -> yellow cards
@@ -835,21 +1073,21 @@ impl LeagueRegulations {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FFPViolation {
     pub club_id: u32,
     pub violation_type: FFPViolationType,
     pub sanction: FFPSanction,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum FFPViolationType {
     ExcessiveDeficit,
     UnpaidDebts,
     FalseAccounting,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum FFPSanction {
     Warning,
     Fine(u32),
@@ -857,14 +1095,14 @@ pub enum FFPSanction {
     TransferBan,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct DisciplinaryCase {
     pub player_id: u32,
     pub incident_type: String,
     pub hearing_date: NaiveDate,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LeagueStatistics {
     pub total_goals: u32,
     pub total_matches: u32,
@@ -901,6 +1139,11 @@ impl LeagueStatistics {
         self.total_goals += (home_goals + away_goals) as u32;
         self.total_matches += 1;
 
+        // Running average over the season
+        self.average_attendance = ((self.average_attendance as u64 * (self.total_matches - 1) as u64
+            + result.attendance as u64)
+            / self.total_matches as u64) as u32;
+
         // Update highest scoring match
         let total_in_match = home_goals + away_goals;
         if let Some((_, _, _, current_high)) = self.highest_scoring_match {
@@ -961,8 +1204,7 @@ impl LeagueStatistics {
 
                     // Track clean sheets for goalkeepers
                     if player.positions.is_goalkeeper() && player.statistics.played > 0 {
-                        // Simplified clean sheet tracking
-                        self.clean_sheets.insert(player.id, 0);
+                        self.clean_sheets.insert(player.id, player.statistics.clean_sheets);
                     }
                 }
             }
@@ -1019,6 +1261,7 @@ impl LeagueStatistics {
         // Reset for new season
         self.total_goals = 0;
         self.total_matches = 0;
+        self.average_attendance = 0;
         self.top_scorer = None;
         self.top_assists = None;
         self.clean_sheets.clear();
@@ -1028,7 +1271,7 @@ impl LeagueStatistics {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LeagueMilestones {
     pub all_time_records: AllTimeRecords,
     pub season_milestones: Vec<Milestone>,
@@ -1080,7 +1323,11 @@ impl LeagueMilestones {
             let second = &table.rows[1];
             let max_possible_points_second = second.points + (matches_remaining * 3);
 
-            if leader.points > max_possible_points_second {
+            let already_won = self.season_milestones.iter().any(|m|
+                m.milestone_type == MilestoneType::TitleWon
+            );
+
+            if leader.points > max_possible_points_second && !already_won {
                 let milestone = Milestone {
                     milestone_type: MilestoneType::TitleWon,
                     team_id: leader.team_id,
@@ -1131,7 +1378,7 @@ impl LeagueMilestones {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct AllTimeRecords {
     pub most_points_in_season: (u32, u8), // team_id, points
     pub most_goals_in_season: (u32, i32), // team_id, goals
@@ -1141,7 +1388,7 @@ pub struct AllTimeRecords {
     pub longest_unbeaten_streak: (u32, u8), // team_id, matches
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Milestone {
     pub milestone_type: MilestoneType,
     pub team_id: u32,
@@ -1149,7 +1396,7 @@ pub struct Milestone {
     pub matches_played: u8,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum MilestoneType {
     TitleWon,
     RelegationConfirmed,
@@ -1189,7 +1436,7 @@ impl Schedule {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct DayMonthPeriod {
     pub from_day: u8,
     pub from_month: u8,
@@ -1209,7 +1456,7 @@ impl DayMonthPeriod {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LeagueSettings {
     pub season_starting_half: DayMonthPeriod,
     pub season_ending_half: DayMonthPeriod,
@@ -1224,4 +1471,48 @@ impl LeagueSettings {
         (NaiveDate::day(&date) as u8) == season_starting_date.from_day
             && (date.month() as u8) == season_starting_date.from_month
     }
-}
\ No newline at end of file
+
+    /// The season is over the day after the last day of its ending half
+    pub fn is_season_end(&self, date: NaiveDate) -> bool {
+        let season_ending_half = &self.season_ending_half;
+
+        date.pred_opt().is_some_and(|last_day| {
+            (last_day.day() as u8) == season_ending_half.to_day
+                && (last_day.month() as u8) == season_ending_half.to_month
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rivalries_are_registered_once_a_season() {
+        let mut dynamics = LeagueDynamics::new();
+
+        dynamics.register_local_rivalries(&[], 1);
+
+        assert!(dynamics.rivalries.is_empty());
+        assert!(dynamics.rivalries_registered);
+
+        dynamics.reset_for_new_season();
+
+        assert!(!dynamics.rivalries_registered);
+    }
+
+    #[test]
+    fn season_ends_the_day_after_its_ending_half() {
+        let settings = |to_day, to_month| LeagueSettings {
+            season_starting_half: DayMonthPeriod::new(1, 7, 5, 12),
+            season_ending_half: DayMonthPeriod::new(1, 3, to_day, to_month),
+        };
+        let date = |month, day| NaiveDate::from_ymd_opt(2025, month, day).unwrap();
+
+        assert!(settings(17, 5).is_season_end(date(5, 18)));
+        assert!(!settings(17, 5).is_season_end(date(5, 17)));
+        assert!(!settings(17, 5).is_season_end(date(5, 25)));
+        assert!(settings(31, 5).is_season_end(date(6, 1)));
+        assert!(settings(31, 12).is_season_end(date(1, 1)));
+    }
+}
@@ -1,13 +1,25 @@
-use crate::league::{LeagueTableResult, ScheduleItem};
-use crate::r#match::{GoalDetail, MatchResult, Score, TeamScore};
+use crate::league::{LeagueTableResult, Milestone, ScheduleItem};
+use crate::news::NewsReporter;
+use crate::r#match::player::statistics::MatchStatisticType;
+use crate::r#match::{FieldSquad, GoalDetail, MatchResult, Score, TeamScore};
 use crate::simulator::SimulatorData;
-use crate::{MatchHistoryItem, SimulationResult};
+use crate::{
+    Club, ClubExpenseType, ClubIncomeType, ContractBonusType, TeamType, ClubRevenueCalculator, MatchHistoryItem,
+    PlayerFieldPositionGroup, SimulationResult,
+};
 use chrono::NaiveDateTime;
+use std::cmp::Ordering;
+
+/// Match readiness a starter gains from ninety minutes of competitive football
+const MATCH_READINESS_GAIN: f32 = 4.0;
 
 pub struct LeagueResult {
     pub league_id: u32,
     pub table_result: LeagueTableResult,
     pub match_results: Option<Vec<MatchResult>>,
+    pub managers_under_pressure: Vec<u32>,
+    /// Milestones the league reached on the day
+    pub milestones: Vec<Milestone>,
 }
 
 impl LeagueResult {
@@ -16,6 +28,8 @@ impl LeagueResult {
             league_id,
             table_result,
             match_results: None,
+            managers_under_pressure: Vec::new(),
+            milestones: Vec::new(),
         }
     }
 
@@ -28,17 +42,53 @@ impl LeagueResult {
             league_id,
             table_result,
             match_results: Some(match_results),
+            managers_under_pressure: Vec::new(),
+            milestones: Vec::new(),
         }
     }
 
+    pub fn with_managers_under_pressure(mut self, team_ids: Vec<u32>) -> Self {
+        self.managers_under_pressure = team_ids;
+        self
+    }
+
+    pub fn with_milestones(mut self, milestones: Vec<Milestone>) -> Self {
+        self.milestones = milestones;
+        self
+    }
+
     pub fn process(self, data: &mut SimulatorData, result: &mut SimulationResult) {
         if let Some(match_results) = self.match_results {
             for match_result in match_results {
                 Self::process_match_results(&match_result, data);
 
+                for item in NewsReporter::match_report(&match_result, data) {
+                    data.news.publish(item);
+                }
+
                 result.match_results.push(match_result);
             }
         }
+
+        for milestone in &self.milestones {
+            if let Some(item) = NewsReporter::milestone(self.league_id, milestone, data) {
+                data.news.publish(item);
+            }
+        }
+
+        for team_id in self.managers_under_pressure {
+            if let Some(club) = Self::main_team_club_mut(team_id, data) {
+                club.board.apply_manager_pressure();
+            }
+        }
+    }
+
+    fn main_team_club_mut(team_id: u32, data: &mut SimulatorData) -> Option<&mut Club> {
+        // Boards only judge the first team
+        let team = data.team(team_id).filter(|team| team.team_type == TeamType::Main)?;
+        let club_id = team.club_id;
+
+        data.club_mut(club_id)
     }
 
     fn process_match_results(result: &MatchResult, data: &mut SimulatorData) {
@@ -71,6 +121,12 @@ impl LeagueResult {
             ),
         ));
 
+        Self::process_matchday_income(result, data);
+        Self::process_board_reaction(result, data);
+        Self::process_match_fitness(result, data);
+        Self::process_match_bonuses(result, data);
+        Self::process_player_ratings(result, data);
+
         // process_match_events(result, data);
         //
         // fn process_match_events(result: &MatchResult, data: &mut SimulatorData) {
@@ -98,6 +154,259 @@ impl LeagueResult {
         //     }
         // }
     }
+
+    /// First team sides of the match, reserve and youth games stay off the players' records
+    fn first_team_squads<'r>(result: &'r MatchResult, data: &SimulatorData) -> Vec<&'r FieldSquad> {
+        match &result.details {
+            Some(details) => [&details.left_team_players, &details.right_team_players]
+                .into_iter()
+                .filter(|squad| {
+                    data.team(squad.team_id)
+                        .is_some_and(|team| team.team_type == TeamType::Main)
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Playing is what brings back match fitness, in the first team or the reserves, only first team games count as appearances
+    fn process_match_fitness(result: &MatchResult, data: &mut SimulatorData) {
+        let starters: Vec<(u32, bool)> = match &result.details {
+            Some(details) => {
+                let first_team = Self::first_team_squads(result, data);
+
+                [&details.left_team_players, &details.right_team_players]
+                    .into_iter()
+                    .flat_map(|squad| {
+                        let counts = first_team.iter().any(|first| first.team_id == squad.team_id);
+                        squad.main.iter().map(move |&player_id| (player_id, counts))
+                    })
+                    .collect()
+            }
+            None => return,
+        };
+
+        for (player_id, counts) in starters {
+            if let Some(player) = data.player_mut(player_id) {
+                let readiness = &mut player.skills.physical.match_readiness;
+                *readiness = (*readiness + MATCH_READINESS_GAIN).min(20.0);
+
+                if counts {
+                    player.statistics.played += 1;
+                }
+            }
+        }
+    }
+
+    /// Appearance fees for the first team starters and goal fees for their scorers, paid by the player's club
+    fn process_match_bonuses(result: &MatchResult, data: &mut SimulatorData) {
+        let first_team = Self::first_team_squads(result, data);
+
+        if first_team.is_empty() {
+            return;
+        }
+
+        let mut bonuses: Vec<(u32, ContractBonusType)> = first_team
+            .iter()
+            .flat_map(|squad| &squad.main)
+            .map(|&player_id| (player_id, ContractBonusType::AppearanceFee))
+            .collect();
+
+        let in_first_team = |player_id: u32| {
+            first_team
+                .iter()
+                .any(|squad| squad.main.contains(&player_id) || squad.substitutes.contains(&player_id))
+        };
+
+        for detail in result
+            .score
+            .details
+            .iter()
+            .filter(|detail| !detail.is_auto_goal && in_first_team(detail.player_id))
+        {
+            let player = match data.player_mut(detail.player_id) {
+                Some(player) => player,
+                None => continue,
+            };
+
+            match detail.stat_type {
+                MatchStatisticType::Goal => {
+                    player.statistics.goals += 1;
+                    bonuses.push((detail.player_id, ContractBonusType::GoalFee));
+                }
+                MatchStatisticType::Assist => player.statistics.assists += 1,
+            }
+        }
+
+        for (player_id, bonus_type) in bonuses {
+            let amount = match data
+                .player(player_id)
+                .and_then(|player| player.contract.as_ref())
+                .and_then(|contract| contract.bonus(bonus_type))
+            {
+                Some(amount) if amount > 0 => amount,
+                _ => continue,
+            };
+
+            let club_id = match data
+                .indexes
+                .as_ref()
+                .and_then(|indexes| indexes.get_player_location(player_id))
+            {
+                Some((_, _, club_id, _)) => club_id,
+                None => continue,
+            };
+
+            if let Some(club) = data.club_mut(club_id) {
+                club.finance
+                    .balance
+                    .push_outcome_of(ClubExpenseType::PlayerWages, amount);
+            }
+        }
+    }
+
+    /// Rates every starter from the result, their goals and assists and, at the back, the goals let in
+    fn process_player_ratings(result: &MatchResult, data: &mut SimulatorData) {
+        let details = match &result.details {
+            Some(details) => details,
+            None => return,
+        };
+
+        let home_goals = result.score.home_team.get();
+        let away_goals = result.score.away_team.get();
+
+        let first_team = Self::first_team_squads(result, data);
+
+        // Awards only look at first team football
+        let sides: Vec<(&Vec<u32>, u8, u8)> = [
+            (&details.left_team_players, home_goals, away_goals),
+            (&details.right_team_players, away_goals, home_goals),
+        ]
+        .into_iter()
+        .filter(|(squad, _, _)| first_team.iter().any(|first| first.team_id == squad.team_id))
+        .map(|(squad, scored, conceded)| (&squad.main, scored, conceded))
+        .collect();
+
+        let count = |player_id: u32, stat_type: MatchStatisticType| {
+            result
+                .score
+                .details
+                .iter()
+                .filter(|detail| {
+                    detail.player_id == player_id
+                        && detail.stat_type == stat_type
+                        && !detail.is_auto_goal
+                })
+                .count() as u8
+        };
+
+        let mut ratings: Vec<(u32, f32, bool)> = Vec::new();
+
+        for (starters, scored, conceded) in sides {
+            for &player_id in starters {
+                let position = match data.player(player_id) {
+                    Some(player) => player.position().position_group(),
+                    None => continue,
+                };
+
+                let rating = Self::match_rating(
+                    position,
+                    scored,
+                    conceded,
+                    count(player_id, MatchStatisticType::Goal),
+                    count(player_id, MatchStatisticType::Assist),
+                );
+
+                let clean_sheet = conceded == 0
+                    && matches!(
+                        position,
+                        PlayerFieldPositionGroup::Goalkeeper | PlayerFieldPositionGroup::Defender
+                    );
+
+                ratings.push((player_id, rating, clean_sheet));
+            }
+        }
+
+        let best = ratings
+            .iter()
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+            .map(|(player_id, _, _)| *player_id);
+
+        for (player_id, rating, clean_sheet) in ratings {
+            if let Some(player) = data.player_mut(player_id) {
+                player.statistics.add_rating(rating);
+
+                if clean_sheet {
+                    player.statistics.clean_sheets += 1;
+                }
+
+                if best == Some(player_id) {
+                    player.statistics.player_of_the_match =
+                        player.statistics.player_of_the_match.saturating_add(1);
+                }
+            }
+        }
+    }
+
+    fn match_rating(
+        position: PlayerFieldPositionGroup,
+        scored: u8,
+        conceded: u8,
+        goals: u8,
+        assists: u8,
+    ) -> f32 {
+        let result = match scored.cmp(&conceded) {
+            Ordering::Greater => 0.5,
+            Ordering::Less => -0.5,
+            Ordering::Equal => 0.0,
+        };
+
+        let defending = match (position, conceded) {
+            (PlayerFieldPositionGroup::Goalkeeper, 0) => 1.0,
+            (PlayerFieldPositionGroup::Goalkeeper, _) => -0.3 * conceded as f32,
+            (PlayerFieldPositionGroup::Defender, 0) => 0.5,
+            (PlayerFieldPositionGroup::Defender, _) => -0.15 * conceded as f32,
+            _ => 0.0,
+        };
+
+        (6.0 + result + goals as f32 + assists as f32 * 0.5 + defending).clamp(1.0, 10.0)
+    }
+
+    fn process_board_reaction(result: &MatchResult, data: &mut SimulatorData) {
+        let home = &result.score.home_team;
+        let away = &result.score.away_team;
+
+        if let Some(club) = Self::main_team_club_mut(home.team_id, data) {
+            club.board.process_match_result(home.get(), away.get());
+        }
+
+        if let Some(club) = Self::main_team_club_mut(away.team_id, data) {
+            club.board.process_match_result(away.get(), home.get());
+        }
+    }
+
+    fn process_matchday_income(result: &MatchResult, data: &mut SimulatorData) {
+        let league_reputation = match data.league(result.league_id) {
+            Some(league) => league.reputation,
+            None => return,
+        };
+
+        let club_id = match data.team(result.score.home_team.team_id) {
+            Some(team) => team.club_id,
+            None => return,
+        };
+
+        if let Some(club) = data.club_mut(club_id) {
+            let revenue = ClubRevenueCalculator::matchday_revenue(
+                result.attendance,
+                club.stadium.ticket_price(league_reputation),
+            );
+
+            club.finance
+                .balance
+                .push_income_of(ClubIncomeType::Matchday, revenue);
+        }
+    }
 }
 
 pub struct LeagueMatch {
@@ -149,3 +458,19 @@ impl From<ScheduleItem> for LeagueMatch {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scorers_and_clean_sheets_rate_highest() {
+        let hat_trick = LeagueResult::match_rating(PlayerFieldPositionGroup::Forward, 3, 1, 3, 0);
+        let clean_sheet = LeagueResult::match_rating(PlayerFieldPositionGroup::Goalkeeper, 1, 0, 0, 0);
+        let thrashed = LeagueResult::match_rating(PlayerFieldPositionGroup::Goalkeeper, 0, 5, 0, 0);
+
+        assert_eq!(9.5, hat_trick);
+        assert_eq!(7.5, clean_sheet);
+        assert!(thrashed < 5.0);
+    }
+}
@@ -2,21 +2,22 @@ use crate::context::GlobalContext;
 use crate::league::round::RoundSchedule;
 use crate::league::{LeagueMatch, LeagueSettings, ScheduleGenerator, ScheduleResult, Season};
 use crate::r#match::Score;
-use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
 use log::error;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Schedule {
     pub tours: Vec<ScheduleTour>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduleTour {
     pub num: u8,
     pub items: Vec<ScheduleItem>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduleItem {
     pub id: String,
 
@@ -82,20 +83,15 @@ impl Schedule {
         result
     }
 
+    /// Fixtures of the date still to be played, one watched live may already have its result
     pub fn get_matches(&self, date: NaiveDateTime) -> Vec<ScheduleItem> {
         self.tours
             .iter()
             .flat_map(|t| &t.items)
-            .filter(|s| s.date == date)
-            .map(|s| {
-                ScheduleItem::new(
-                    s.league_id,
-                    String::from(&s.league_slug),
-                    s.home_team_id,
-                    s.away_team_id,
-                    s.date,
-                    None,
-                )
+            .filter(|s| s.date == date && s.result.is_none())
+            .map(|s| ScheduleItem {
+                result: None,
+                ..s.clone()
             })
             .collect()
     }
@@ -105,16 +101,7 @@ impl Schedule {
             .iter()
             .flat_map(|t| &t.items)
             .filter(|s| s.home_team_id == team_id || s.away_team_id == team_id)
-            .map(|s| {
-                ScheduleItem::new(
-                    s.league_id,
-                    String::from(&s.league_slug),
-                    s.home_team_id,
-                    s.away_team_id,
-                    s.date,
-                    s.result.clone(),
-                )
-            })
+            .cloned()
             .collect()
     }
 
@@ -128,6 +115,38 @@ impl Schedule {
             }
         }
     }
+
+    /// Moves a fixture to the next Wednesday both teams have free, it keeps its id
+    pub fn postpone(&mut self, id: &str) -> Option<NaiveDateTime> {
+        let (home_team_id, away_team_id, date) = self
+            .tours
+            .iter()
+            .flat_map(|t| &t.items)
+            .find(|i| i.id == id)
+            .map(|i| (i.home_team_id, i.away_team_id, i.date))?;
+
+        let is_busy = |date: NaiveDateTime| {
+            self.tours.iter().flat_map(|t| &t.items).any(|i| {
+                i.date.date() == date.date()
+                    && [i.home_team_id, i.away_team_id]
+                        .iter()
+                        .any(|team_id| *team_id == home_team_id || *team_id == away_team_id)
+            })
+        };
+
+        let mut new_date = date + Duration::days(1);
+        while new_date.weekday() != Weekday::Wed || is_busy(new_date) {
+            new_date += Duration::days(1);
+        }
+
+        for tour in &mut self.tours {
+            if let Some(item) = tour.items.iter_mut().find(|i| i.id == id) {
+                item.date = new_date;
+            }
+        }
+
+        Some(new_date)
+    }
 }
 
 
@@ -358,4 +377,58 @@ mod tests {
         };
         assert_eq!(schedule_tour.end_date(), item2.date.date());
     }
+
+    #[test]
+    fn test_schedule_postpone_to_free_wednesday() {
+        let at = |day| NaiveDate::from_ymd_opt(2024, 7, day).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+        let mut schedule = Schedule {
+            tours: vec![ScheduleTour {
+                num: 1,
+                items: vec![
+                    ScheduleItem::new(0, "slug".to_string(), 1, 2, at(6), None),
+                    ScheduleItem::new(0, "slug".to_string(), 3, 2, at(10), None),
+                ],
+            }],
+        };
+
+        let id = schedule.tours[0].items[0].id.clone();
+
+        assert_eq!(Some(at(17)), schedule.postpone(&id));
+        assert!(schedule.get_matches(at(6)).is_empty());
+
+        let postponed = schedule.get_matches(at(17));
+        assert_eq!(1, postponed.len());
+        assert_eq!(id, postponed[0].id);
+    }
+
+    #[test]
+    fn test_schedule_skips_fixtures_played_ahead_of_their_date() {
+        let at = |day| NaiveDate::from_ymd_opt(2024, 8, day).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+        let mut schedule = Schedule {
+            tours: vec![ScheduleTour {
+                num: 1,
+                items: vec![
+                    ScheduleItem::new(0, "slug".to_string(), 1, 2, at(10), None),
+                    ScheduleItem::new(0, "slug".to_string(), 3, 4, at(10), None),
+                ],
+            }],
+        };
+
+        let id = schedule.tours[0].items[0].id.clone();
+
+        schedule.update_match_result(
+            &id,
+            &Score {
+                home_team: TeamScore::new_with_score(1, 2),
+                away_team: TeamScore::new_with_score(2, 0),
+                details: Vec::new(),
+            },
+        );
+
+        let remaining = schedule.get_matches(at(10));
+        assert_eq!(1, remaining.len());
+        assert_ne!(id, remaining[0].id);
+    }
 }
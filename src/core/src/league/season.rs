@@ -1,5 +1,33 @@
-#[derive(Debug)]
+use chrono::{Datelike, NaiveDate};
+use std::fmt::{Display, Formatter, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Season {
     OneYear(u16),
     TwoYear(u16, u16),
 }
+
+impl Season {
+    /// July to June season the date falls in
+    pub fn from_date(date: NaiveDate) -> Self {
+        let year = date.year() as u16;
+
+        if date.month() >= 7 {
+            Season::TwoYear(year, year + 1)
+        } else {
+            Season::TwoYear(year - 1, year)
+        }
+    }
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Season::OneYear(year) => write!(f, "{}", year),
+            Season::TwoYear(start_year, end_year) => {
+                write!(f, "{}/{:02}", start_year, end_year % 100)
+            }
+        }
+    }
+}
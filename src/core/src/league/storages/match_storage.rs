@@ -1,7 +1,8 @@
 use crate::r#match::MatchResult;
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MatchStorage {
     results: HashMap<String, MatchResult>,
 }
@@ -73,6 +74,7 @@ mod tests {
             },
             home_team_id: 0,
             away_team_id: 0,
+            attendance: 0,
         };
         match_storage.push(match_result.clone());
         assert_eq!(match_storage.results.len(), 1);
@@ -97,6 +99,7 @@ mod tests {
             },
             home_team_id: 0,
             away_team_id: 0,
+            attendance: 0,
         };
 
         match_storage.push(match_result.clone());
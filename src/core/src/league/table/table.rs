@@ -2,8 +2,9 @@ use crate::context::GlobalContext;
 use crate::league::LeagueTableResult;
 use crate::r#match::MatchResult;
 use std::cmp::Ordering;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LeagueTable {
     pub rows: Vec<LeagueTableRow>,
 }
@@ -137,7 +138,7 @@ impl LeagueTable {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LeagueTableRow {
     pub team_id: u32,
     pub played: u8,
@@ -183,6 +184,7 @@ mod tests {
                 details: vec![],
             },
             details: None,
+            attendance: 0,
         }];
 
         table.update_from_results(&match_results);
@@ -234,6 +236,7 @@ mod tests {
                 details: vec![],
             },
             details: None,
+            attendance: 0,
         }];
 
         table.update_from_results(&match_results);
@@ -293,6 +296,7 @@ mod tests {
                 details: vec![],
             },
             details: None,
+            attendance: 0,
         }];
 
         table.update_from_results(&match_results);
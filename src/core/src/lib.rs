@@ -1,11 +1,13 @@
 pub mod simulator;
 pub use simulator::*;
 
+pub mod awards;
 pub mod club;
 pub mod context;
 pub mod continent;
 pub mod country;
 pub mod league;
+pub mod news;
 pub mod r#match;
 pub mod transfers;
 
@@ -15,34 +17,46 @@ pub mod utils;
 // Re-export club items
 pub use club::{
     // Modules
-    academy, board, mood, transfers as club_transfers,
+    academy, board, mood, scouting, transfers as club_transfers,
     // Person exports
     Person, PersonAttributes, PersonBehaviour, PersonBehaviourState,
     // Club itself
     Club, ClubBoard, ClubResult,
+    // Board exports
+    BoardMood, BoardMoodState, BoardDecision, BoardDecisionType,
+    BoardObjectiveType, BoardSeasonObjective, BoardObjectiveOutcome,
+    Stadium, StadiumOwnership, StadiumExpansion, MatchAttendanceFactors,
     ClubContext,
     // Finance exports
     ClubFinances, ClubFinancialBalance, ClubFinancialBalanceHistory,
     ClubSponsorship, ClubSponsorshipContract,
     ClubFinanceContext, ClubFinanceResult,
+    ClubFinancialBreakdown, ClubIncomeType, ClubExpenseType, ClubRevenueCalculator,
     // Relations exports
     Relations, PlayerRelation, StaffRelation, ChemistryFactors,
     ChangeType, MentorshipType, InfluenceLevel, ConflictType, ConflictSeverity,
     RelationshipChange, ConflictInfo,
     // Transfers exports
     ClubTransferStrategy,
+    // Scouting exports
+    ClubScouting, ScoutAssignment, ScoutJudgement, ScoutingRegion, ScoutingReport,
+    SCOUTING_PLAYERS_PER_WEEK,
     // Player exports
     Player, PlayerCollection, PlayerBuilder,
     PlayerAttributes, PlayerContext,
     PlayerPreferredFoot, PlayerPositionType, PlayerFieldPositionGroup, PlayerStatusType,
     PlayerSkills, Technical, Mental, Physical,
-    PlayerPositions, PlayerPosition, PlayerStatus, StatusData,
+    PlayerPositions, PlayerPosition, REQUIRED_POSITION_LEVEL, PlayerStatus, StatusData,
     PlayerStatistics, PlayerStatisticsHistory, PlayerStatisticsHistoryItem,
-    PlayerHappiness, PositiveHappiness, NegativeHappiness,
+    PlayerMorale, PlayerMoraleState, MoraleFactors, MoraleSituation,
+    PlayerInteractions, PlayerInteraction, PlayerInteractionType, PlayerPromise, PlayerPromiseType,
+    PlayerRequestType,
     PlayerClubContract, ContractType, PlayerSquadStatus, PlayerTransferStatus,
     ContractBonusType, ContractBonus, ContractClauseType, ContractClause,
-    PlayerMailbox, PlayerMessage, PlayerMessageType, PlayerContractProposal, PlayerMailboxResult,
-    AcceptContractHandler, ProcessContractHandler, handlers,
+    ContractNegotiation, ContractDemands, ContractOffer, ContractRenewal,
+    PlayerMailbox, PlayerMessage, PlayerMessageType, PlayerContractProposal, PlayerRequestDismissal,
+    PlayerMailboxResult,
+    AcceptContractHandler, ProcessContractHandler, PromiseHandler, DismissedRequestHandler, handlers,
     PlayerTraining, PlayerTrainingHistory, TrainingRecord, PlayerTrainingResult,
     PlayerResult, PlayerCollectionResult, PlayerContractResult,
     PlayerValueCalculator, PlayerGenerator, PlayerUtils,
@@ -86,6 +100,7 @@ pub use club::{
     TeamCompetitionType,
     // Status & mood
     ClubStatus, ClubMood,
+    ClubSeasonOutcome, SeasonOutcomeType,
 };
 
 // Re-export country items
@@ -93,7 +108,9 @@ pub use country::{
     Country, CountryResult, CountryContext,
     CountryEconomicFactors, InternationalCompetition, MediaCoverage,
     MediaStory, StoryType, CountryRegulations, CountryGeneratorData,
-    PeopleNameGeneratorData,
+    PeopleNameGeneratorData, NationalTeam, NationalTeamMatch, NationalTeamFixtureType,
+    InternationalBreak, NationalTournament, NationalTournamentKind, NationalTournamentStage,
+    TournamentGroup, GroupTableRow, KnockoutTie, KnockoutResult,
 };
 
 // Namespace conflicting CompetitionType enums
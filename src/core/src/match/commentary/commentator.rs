@@ -0,0 +1,478 @@
+use crate::r#match::commentary::Phrase;
+use crate::r#match::{
+    MatchCard, MatchTimelineEvent, MatchTimelineEventKind, SetPiece, ShotOutcome,
+};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// Shots at least this likely to go in are big chances
+const BIG_CHANCE_XG: f32 = 0.3;
+
+/// Shots this unlikely to go in are speculative efforts from range
+const LONG_SHOT_XG: f32 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentaryLanguage {
+    English,
+    Spanish,
+}
+
+impl CommentaryLanguage {
+    pub const ALL: [CommentaryLanguage; 2] =
+        [CommentaryLanguage::English, CommentaryLanguage::Spanish];
+
+    /// ISO 639-1 code
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommentaryLanguage::English => "en",
+            CommentaryLanguage::Spanish => "es",
+        }
+    }
+}
+
+/// How much of the match gets a line, each level includes the ones below it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CommentaryVerbosity {
+    /// Kick-offs, goals, cards and substitutions
+    Highlights,
+    /// Shots, fouls, set pieces, injuries and offsides too
+    Standard,
+    /// Every save and change of possession
+    Full,
+}
+
+impl CommentaryVerbosity {
+    pub const ALL: [CommentaryVerbosity; 3] = [
+        CommentaryVerbosity::Highlights,
+        CommentaryVerbosity::Standard,
+        CommentaryVerbosity::Full,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommentaryVerbosity::Highlights => "highlights",
+            CommentaryVerbosity::Standard => "standard",
+            CommentaryVerbosity::Full => "full",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CommentaryLine {
+    /// Match time in milliseconds
+    pub time: u64,
+    pub minute: u64,
+    pub text: String,
+}
+
+impl Display for CommentaryLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}' — {}", self.minute, self.text)
+    }
+}
+
+/// Names to use in the lines, events of ids without one are left out, goals are told by team
+#[derive(Default)]
+pub struct CommentaryNames {
+    teams: HashMap<u32, String>,
+    players: HashMap<u32, String>,
+}
+
+impl CommentaryNames {
+    pub fn new() -> Self {
+        CommentaryNames::default()
+    }
+
+    pub fn with_team(mut self, team_id: u32, name: impl Into<String>) -> Self {
+        self.teams.insert(team_id, name.into());
+        self
+    }
+
+    pub fn with_player(mut self, player_id: u32, name: impl Into<String>) -> Self {
+        self.players.insert(player_id, name.into());
+        self
+    }
+}
+
+/// Turns the event timeline of a match into commentary
+pub struct MatchCommentator<'n> {
+    language: CommentaryLanguage,
+    verbosity: CommentaryVerbosity,
+    names: &'n CommentaryNames,
+    home_team_id: u32,
+    away_team_id: u32,
+}
+
+impl<'n> MatchCommentator<'n> {
+    pub fn new(
+        home_team_id: u32,
+        away_team_id: u32,
+        names: &'n CommentaryNames,
+        language: CommentaryLanguage,
+        verbosity: CommentaryVerbosity,
+    ) -> Self {
+        MatchCommentator {
+            language,
+            verbosity,
+            names,
+            home_team_id,
+            away_team_id,
+        }
+    }
+
+    /// The same events always get the same lines
+    pub fn commentate(&self, events: &[MatchTimelineEvent]) -> Vec<CommentaryLine> {
+        let mut lines = Vec::new();
+
+        let mut home_goals = 0u8;
+        let mut away_goals = 0u8;
+        let mut periods = 0;
+
+        let mut last_wordings: HashMap<Phrase, usize> = HashMap::new();
+
+        for (idx, event) in events.iter().enumerate() {
+            let phrase = match event.kind {
+                MatchTimelineEventKind::KickOff => {
+                    // kick-offs after a goal are part of the goal
+                    let after_goal = idx > 0
+                        && matches!(events[idx - 1].kind, MatchTimelineEventKind::Goal { .. });
+
+                    if after_goal {
+                        continue;
+                    }
+
+                    periods += 1;
+
+                    match periods {
+                        1 => Phrase::KickOff,
+                        2 => Phrase::SecondHalf,
+                        _ => Phrase::ExtraTime,
+                    }
+                }
+                MatchTimelineEventKind::Goal { own_goal } => {
+                    let scored_by_home = (event.team_id == self.home_team_id) != own_goal;
+
+                    if scored_by_home {
+                        home_goals += 1;
+                    } else {
+                        away_goals += 1;
+                    }
+
+                    if own_goal {
+                        Phrase::OwnGoal
+                    } else {
+                        Phrase::Goal
+                    }
+                }
+                MatchTimelineEventKind::Shot { xg, outcome } => match outcome {
+                    // the goal gets its own line
+                    ShotOutcome::Goal => continue,
+                    ShotOutcome::Saved => Phrase::ShotSaved,
+                    ShotOutcome::Blocked => Phrase::ShotBlocked,
+                    ShotOutcome::OffTarget if xg >= BIG_CHANCE_XG => Phrase::BigChanceMissed,
+                    ShotOutcome::OffTarget if xg <= LONG_SHOT_XG => Phrase::LongShotWide,
+                    ShotOutcome::OffTarget => Phrase::ShotWide,
+                },
+                MatchTimelineEventKind::Save => Phrase::Save,
+                MatchTimelineEventKind::Foul => Phrase::Foul,
+                MatchTimelineEventKind::Card { card } => match card {
+                    MatchCard::Yellow => Phrase::YellowCard,
+                    MatchCard::Red => Phrase::RedCard,
+                },
+                MatchTimelineEventKind::SetPiece { set_piece } => match set_piece {
+                    SetPiece::Corner => Phrase::Corner,
+                    SetPiece::FreeKick => Phrase::FreeKick,
+                    SetPiece::Penalty => Phrase::Penalty,
+                    SetPiece::ThrowIn => Phrase::ThrowIn,
+                    SetPiece::GoalKick => Phrase::GoalKick,
+                },
+                MatchTimelineEventKind::Substitution { .. } => Phrase::Substitution,
+                MatchTimelineEventKind::Injury => Phrase::Injury,
+                MatchTimelineEventKind::Offside => Phrase::Offside,
+                MatchTimelineEventKind::PossessionChange => Phrase::PossessionChange,
+            };
+
+            if Self::verbosity(phrase) > self.verbosity {
+                continue;
+            }
+
+            let mut text = self.line(phrase, event, home_goals, away_goals, &mut last_wordings);
+
+            // a goal is told without the scorer rather than left out
+            if text.is_none() {
+                let team_only = match phrase {
+                    Phrase::Goal => Some(Phrase::TeamGoal),
+                    Phrase::OwnGoal => Some(Phrase::TeamOwnGoal),
+                    _ => None,
+                };
+
+                if let Some(team_only) = team_only {
+                    text = self.line(team_only, event, home_goals, away_goals, &mut last_wordings);
+                }
+            }
+
+            if let Some(text) = text {
+                lines.push(CommentaryLine {
+                    time: event.time,
+                    minute: event.time / 60_000 + 1,
+                    text,
+                });
+            }
+        }
+
+        lines
+    }
+
+    fn line(
+        &self,
+        phrase: Phrase,
+        event: &MatchTimelineEvent,
+        home_goals: u8,
+        away_goals: u8,
+        last_wordings: &mut HashMap<Phrase, usize>,
+    ) -> Option<String> {
+        let wordings = phrase.wordings(self.language);
+
+        let mut wording = Self::seed(event) % wordings.len();
+
+        // never the same wording twice in a row
+        if wordings.len() > 1 && last_wordings.get(&phrase) == Some(&wording) {
+            wording = (wording + 1) % wordings.len();
+        }
+
+        last_wordings.insert(phrase, wording);
+
+        self.fill(wordings[wording], event, home_goals, away_goals)
+    }
+
+    fn verbosity(phrase: Phrase) -> CommentaryVerbosity {
+        match phrase {
+            Phrase::KickOff
+            | Phrase::SecondHalf
+            | Phrase::ExtraTime
+            | Phrase::Goal
+            | Phrase::OwnGoal
+            | Phrase::TeamGoal
+            | Phrase::TeamOwnGoal
+            | Phrase::YellowCard
+            | Phrase::RedCard
+            | Phrase::Substitution => CommentaryVerbosity::Highlights,
+            Phrase::Save | Phrase::PossessionChange => CommentaryVerbosity::Full,
+            _ => CommentaryVerbosity::Standard,
+        }
+    }
+
+    fn seed(event: &MatchTimelineEvent) -> usize {
+        let seed = event
+            .time
+            .wrapping_mul(31)
+            .wrapping_add(event.player_id.unwrap_or(event.team_id) as u64);
+
+        (seed ^ (seed >> 7)) as usize
+    }
+
+    /// None when a name the wording needs is unknown
+    fn fill(
+        &self,
+        wording: &str,
+        event: &MatchTimelineEvent,
+        home_goals: u8,
+        away_goals: u8,
+    ) -> Option<String> {
+        let mut text = wording.to_string();
+
+        if text.contains("{player}") {
+            let player = self.names.players.get(&event.player_id?)?;
+            text = text.replace("{player}", player);
+        }
+
+        if text.contains("{other}") {
+            let MatchTimelineEventKind::Substitution { replaced_player_id } = event.kind else {
+                return None;
+            };
+            text = text.replace("{other}", self.names.players.get(&replaced_player_id)?);
+        }
+
+        if text.contains("{team}") {
+            text = text.replace("{team}", self.names.teams.get(&event.team_id)?);
+        }
+
+        if text.contains("{opponent}") {
+            let opponent_id = if event.team_id == self.home_team_id {
+                self.away_team_id
+            } else {
+                self.home_team_id
+            };
+            text = text.replace("{opponent}", self.names.teams.get(&opponent_id)?);
+        }
+
+        if text.contains("{score}") {
+            let home = self.names.teams.get(&self.home_team_id)?;
+            let away = self.names.teams.get(&self.away_team_id)?;
+            text = text.replace(
+                "{score}",
+                &format!("{} {}-{} {}", home, home_goals, away_goals, away),
+            );
+        }
+
+        Some(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOME: u32 = 1;
+    const AWAY: u32 = 2;
+
+    fn names() -> CommentaryNames {
+        CommentaryNames::new()
+            .with_team(HOME, "Milan")
+            .with_team(AWAY, "Inter")
+            .with_player(10, "Rossi")
+            .with_player(20, "Bianchi")
+    }
+
+    fn event(
+        time: u64,
+        team_id: u32,
+        player_id: Option<u32>,
+        kind: MatchTimelineEventKind,
+    ) -> MatchTimelineEvent {
+        MatchTimelineEvent {
+            time,
+            team_id,
+            player_id,
+            kind,
+        }
+    }
+
+    fn events() -> Vec<MatchTimelineEvent> {
+        vec![
+            event(0, HOME, None, MatchTimelineEventKind::KickOff),
+            event(
+                60_000,
+                AWAY,
+                Some(20),
+                MatchTimelineEventKind::PossessionChange,
+            ),
+            event(
+                22 * 60_000 + 500,
+                HOME,
+                Some(10),
+                MatchTimelineEventKind::Shot {
+                    xg: 0.1,
+                    outcome: ShotOutcome::OffTarget,
+                },
+            ),
+            event(
+                30 * 60_000,
+                HOME,
+                Some(10),
+                MatchTimelineEventKind::Shot {
+                    xg: 0.4,
+                    outcome: ShotOutcome::Goal,
+                },
+            ),
+            event(
+                30 * 60_000 + 700,
+                HOME,
+                Some(10),
+                MatchTimelineEventKind::Goal { own_goal: false },
+            ),
+            event(
+                30 * 60_000 + 700,
+                AWAY,
+                None,
+                MatchTimelineEventKind::KickOff,
+            ),
+            event(
+                40 * 60_000,
+                AWAY,
+                Some(20),
+                MatchTimelineEventKind::Goal { own_goal: true },
+            ),
+            event(40 * 60_000, HOME, None, MatchTimelineEventKind::KickOff),
+            event(45 * 60_000, AWAY, None, MatchTimelineEventKind::KickOff),
+        ]
+    }
+
+    fn commentate(verbosity: CommentaryVerbosity) -> Vec<CommentaryLine> {
+        let names = names();
+
+        MatchCommentator::new(HOME, AWAY, &names, CommentaryLanguage::English, verbosity)
+            .commentate(&events())
+    }
+
+    #[test]
+    fn highlights_follow_the_score() {
+        let lines = commentate(CommentaryVerbosity::Highlights);
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0].minute, 1);
+        assert!(lines[1].text.contains("Rossi"));
+        assert!(lines[1].text.ends_with("Milan 1-0 Inter"));
+        assert!(lines[2].text.ends_with("Milan 2-0 Inter"));
+        assert_eq!(lines[3].minute, 46);
+    }
+
+    #[test]
+    fn verbosity_adds_lines() {
+        let standard = commentate(CommentaryVerbosity::Standard);
+        let full = commentate(CommentaryVerbosity::Full);
+
+        assert_eq!(standard.len(), 5);
+        assert_eq!(standard[1].minute, 23);
+        assert!(standard[1].to_string().starts_with("23' — "));
+        assert_eq!(full.len(), 6);
+    }
+
+    #[test]
+    fn same_events_get_the_same_lines() {
+        let first: Vec<String> = commentate(CommentaryVerbosity::Full)
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        let second: Vec<String> = commentate(CommentaryVerbosity::Full)
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn unknown_players_are_left_out() {
+        let names = CommentaryNames::new()
+            .with_team(HOME, "Milan")
+            .with_team(AWAY, "Inter");
+
+        let mut events = events();
+        events.push(event(
+            50 * 60_000,
+            HOME,
+            Some(10),
+            MatchTimelineEventKind::Card {
+                card: MatchCard::Yellow,
+            },
+        ));
+
+        let lines = MatchCommentator::new(
+            HOME,
+            AWAY,
+            &names,
+            CommentaryLanguage::Spanish,
+            CommentaryVerbosity::Highlights,
+        )
+        .commentate(&events);
+
+        // the goals are still told, by team, the booking of an unnamed player is not
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].text.ends_with("Milan 1-0 Inter"));
+        assert!(lines[2].text.contains("Inter"));
+        assert!(lines[2].text.ends_with("Milan 2-0 Inter"));
+        assert!(lines.iter().all(|line| !line.text.contains('{')));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod commentator;
+pub mod phrases;
+
+pub use commentator::*;
+pub use phrases::*;
@@ -0,0 +1,305 @@
+use crate::r#match::CommentaryLanguage;
+
+/// What a commentary line says, each has a few wordings to pick from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phrase {
+    KickOff,
+    SecondHalf,
+    ExtraTime,
+    Goal,
+    OwnGoal,
+    /// A goal by a player without a name
+    TeamGoal,
+    TeamOwnGoal,
+    ShotSaved,
+    ShotBlocked,
+    ShotWide,
+    BigChanceMissed,
+    LongShotWide,
+    Save,
+    Foul,
+    YellowCard,
+    RedCard,
+    Corner,
+    FreeKick,
+    Penalty,
+    ThrowIn,
+    GoalKick,
+    Substitution,
+    Injury,
+    Offside,
+    PossessionChange,
+}
+
+impl Phrase {
+    /// Wordings with `{player}`, `{team}`, `{opponent}`, `{other}` and `{score}` placeholders
+    pub fn wordings(self, language: CommentaryLanguage) -> &'static [&'static str] {
+        match language {
+            CommentaryLanguage::English => english(self),
+            CommentaryLanguage::Spanish => spanish(self),
+        }
+    }
+}
+
+fn english(phrase: Phrase) -> &'static [&'static str] {
+    match phrase {
+        Phrase::KickOff => &[
+            "{team} get us under way.",
+            "We're off! {team} kick off.",
+            "The referee blows the whistle and {team} start things off.",
+        ],
+        Phrase::SecondHalf => &[
+            "{team} restart for the second half.",
+            "The second half is under way, {team} kick off.",
+        ],
+        Phrase::ExtraTime => &[
+            "Extra time begins, {team} kick off.",
+            "Thirty more minutes to settle it, {team} get extra time going.",
+        ],
+        Phrase::Goal => &[
+            "GOAL! {player} finds the net for {team}. {score}",
+            "GOAL! {player} buries it! {score}",
+            "GOAL! What a finish from {player}! {score}",
+            "GOAL! {player} makes no mistake. {score}",
+        ],
+        Phrase::OwnGoal => &[
+            "Own goal! {player} turns it into his own net. {score}",
+            "Disaster for {team}, {player} puts it past his own keeper. {score}",
+        ],
+        Phrase::TeamGoal => &[
+            "GOAL! {team} find the net. {score}",
+            "GOAL! {team} score! {score}",
+        ],
+        Phrase::TeamOwnGoal => &[
+            "Own goal! {team} put it into their own net. {score}",
+            "Disaster for {team}, an own goal. {score}",
+        ],
+        Phrase::ShotSaved => &[
+            "{player} lets fly, but the keeper is equal to it.",
+            "{player} tests the goalkeeper, who gets down well to save.",
+            "Good save! {player}'s effort is kept out.",
+        ],
+        Phrase::ShotBlocked => &[
+            "{player} shoots, but it's blocked.",
+            "{player} pulls the trigger and a {opponent} defender throws himself in the way.",
+            "Blocked! {player} can't find a way through.",
+        ],
+        Phrase::ShotWide => &[
+            "{player} cuts inside and curls one just wide.",
+            "{player} has a go, but it drifts past the post.",
+            "{player} snatches at it and it flies over the bar.",
+        ],
+        Phrase::BigChanceMissed => &[
+            "What a chance! {player} should have scored there.",
+            "{player} somehow misses from close range!",
+            "Big chance for {team}, but {player} can't convert it.",
+        ],
+        Phrase::LongShotWide => &[
+            "{player} tries his luck from distance, well off target.",
+            "Ambitious from {player}, that one was never troubling the keeper.",
+        ],
+        Phrase::Save => &[
+            "{player} gathers it safely.",
+            "{player} claims the ball for {team}.",
+        ],
+        Phrase::Foul => &[
+            "Foul by {player}.",
+            "{player} goes in late and the referee blows for a foul.",
+            "{player} brings his man down, free kick to {opponent}.",
+        ],
+        Phrase::YellowCard => &[
+            "{player} is shown a yellow card.",
+            "Into the book goes {player}.",
+        ],
+        Phrase::RedCard => &[
+            "Red card! {player} is sent off and {team} are down to ten.",
+            "{player} sees red!",
+        ],
+        Phrase::Corner => &["Corner to {team}.", "{team} win a corner."],
+        Phrase::FreeKick => &[
+            "Free kick to {team}.",
+            "{team} have a free kick in a promising position.",
+        ],
+        Phrase::Penalty => &[
+            "Penalty to {team}!",
+            "The referee points to the spot, penalty for {team}!",
+        ],
+        Phrase::ThrowIn => &["Throw-in to {team}."],
+        Phrase::GoalKick => &["Goal kick for {team}."],
+        Phrase::Substitution => &[
+            "Substitution for {team}: {player} comes on for {other}.",
+            "{team} make a change, {other} makes way for {player}.",
+        ],
+        Phrase::Injury => &[
+            "{player} is down and needs treatment.",
+            "Worry for {team}, {player} looks injured.",
+        ],
+        Phrase::Offside => &[
+            "{player} is flagged offside.",
+            "The flag goes up, {player} was too early.",
+        ],
+        Phrase::PossessionChange => &[
+            "{player} wins the ball for {team}.",
+            "{team} have it back through {player}.",
+            "{player} picks up the loose ball.",
+        ],
+    }
+}
+
+fn spanish(phrase: Phrase) -> &'static [&'static str] {
+    match phrase {
+        Phrase::KickOff => &[
+            "¡Arranca el partido! Saca {team}.",
+            "El árbitro pita el inicio y {team} mueve el balón.",
+        ],
+        Phrase::SecondHalf => &[
+            "Comienza la segunda parte, saca {team}.",
+            "Se reanuda el juego, {team} pone en marcha la segunda mitad.",
+        ],
+        Phrase::ExtraTime => &[
+            "Empieza la prórroga, saca {team}.",
+            "Treinta minutos más para decidirlo, arranca la prórroga {team}.",
+        ],
+        Phrase::Goal => &[
+            "¡GOL! {player} marca para {team}. {score}",
+            "¡GOL! ¡{player} la manda a la red! {score}",
+            "¡GOL! ¡Qué definición de {player}! {score}",
+        ],
+        Phrase::OwnGoal => &[
+            "¡Gol en propia puerta de {player}! {score}",
+            "Desgracia para {team}, {player} la mete en su propia portería. {score}",
+        ],
+        Phrase::TeamGoal => &[
+            "¡GOL de {team}! {score}",
+            "¡GOL! {team} marca. {score}",
+        ],
+        Phrase::TeamOwnGoal => &[
+            "¡Gol en propia puerta de {team}! {score}",
+            "Desgracia para {team}, gol en propia puerta. {score}",
+        ],
+        Phrase::ShotSaved => &[
+            "Dispara {player}, pero responde el portero.",
+            "¡Paradón! El disparo de {player} no entra.",
+        ],
+        Phrase::ShotBlocked => &[
+            "Chuta {player}, pero el disparo es bloqueado.",
+            "¡Bloqueado! {player} no encuentra hueco.",
+        ],
+        Phrase::ShotWide => &[
+            "{player} se perfila y la manda rozando el palo.",
+            "Lo intenta {player}, pero el balón se va fuera.",
+            "{player} dispara por encima del larguero.",
+        ],
+        Phrase::BigChanceMissed => &[
+            "¡Qué ocasión! {player} tenía que marcar ahí.",
+            "¡Increíble fallo de {player} a bocajarro!",
+        ],
+        Phrase::LongShotWide => &[
+            "{player} prueba desde lejos, muy desviado.",
+            "Disparo lejano de {player} que no inquieta al portero.",
+        ],
+        Phrase::Save => &[
+            "{player} atrapa el balón sin problemas.",
+            "{player} asegura el balón para {team}.",
+        ],
+        Phrase::Foul => &[
+            "Falta de {player}.",
+            "{player} llega tarde y el árbitro señala falta.",
+            "{player} derriba a su rival, falta a favor de {opponent}.",
+        ],
+        Phrase::YellowCard => &[
+            "Tarjeta amarilla para {player}.",
+            "{player} ve la amarilla.",
+        ],
+        Phrase::RedCard => &[
+            "¡Roja! {player} es expulsado y {team} se queda con diez.",
+            "¡Tarjeta roja para {player}!",
+        ],
+        Phrase::Corner => &["Córner para {team}.", "{team} gana un saque de esquina."],
+        Phrase::FreeKick => &[
+            "Falta a favor de {team}.",
+            "Tiro libre peligroso para {team}.",
+        ],
+        Phrase::Penalty => &[
+            "¡Penalti para {team}!",
+            "¡El árbitro señala el punto de penalti!",
+        ],
+        Phrase::ThrowIn => &["Saque de banda para {team}."],
+        Phrase::GoalKick => &["Saque de puerta para {team}."],
+        Phrase::Substitution => &[
+            "Cambio en {team}: entra {player} por {other}.",
+            "{team} mueve el banquillo, {other} deja su sitio a {player}.",
+        ],
+        Phrase::Injury => &[
+            "{player} está en el suelo y necesita atención.",
+            "Preocupación en {team}, {player} parece lesionado.",
+        ],
+        Phrase::Offside => &[
+            "{player} en fuera de juego.",
+            "Se levanta la bandera, {player} se adelantó.",
+        ],
+        Phrase::PossessionChange => &[
+            "{player} recupera el balón para {team}.",
+            "{team} recupera la posesión por medio de {player}.",
+            "{player} se hace con el balón suelto.",
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASES: [Phrase; 25] = [
+        Phrase::KickOff,
+        Phrase::SecondHalf,
+        Phrase::ExtraTime,
+        Phrase::Goal,
+        Phrase::OwnGoal,
+        Phrase::TeamGoal,
+        Phrase::TeamOwnGoal,
+        Phrase::ShotSaved,
+        Phrase::ShotBlocked,
+        Phrase::ShotWide,
+        Phrase::BigChanceMissed,
+        Phrase::LongShotWide,
+        Phrase::Save,
+        Phrase::Foul,
+        Phrase::YellowCard,
+        Phrase::RedCard,
+        Phrase::Corner,
+        Phrase::FreeKick,
+        Phrase::Penalty,
+        Phrase::ThrowIn,
+        Phrase::GoalKick,
+        Phrase::Substitution,
+        Phrase::Injury,
+        Phrase::Offside,
+        Phrase::PossessionChange,
+    ];
+
+    #[test]
+    fn every_phrase_has_wordings_in_every_language() {
+        for language in CommentaryLanguage::ALL {
+            for phrase in PHRASES {
+                assert!(
+                    !phrase.wordings(language).is_empty(),
+                    "{:?} has no {:?} wording",
+                    phrase,
+                    language
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn goal_wordings_show_the_score() {
+        for language in CommentaryLanguage::ALL {
+            for phrase in [Phrase::Goal, Phrase::OwnGoal, Phrase::TeamGoal, Phrase::TeamOwnGoal] {
+                for wording in phrase.wordings(language) {
+                    assert!(wording.contains("{score}"), "{}", wording);
+                }
+            }
+        }
+    }
+}
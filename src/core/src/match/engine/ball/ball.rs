@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use crate::r#match::ball::events::{BallEvent, BallGoalEventMetadata, GoalSide};
 use crate::r#match::events::EventCollection;
 use crate::r#match::{GameTickContext, MatchContext, MatchPlayer, PlayerSide};
@@ -71,6 +71,7 @@ impl Ball {
     ) {
         self.update_velocity();
         self.check_goal(context, events);
+        self.check_out_of_play(context, events);
         self.check_boundary_collision(context);
 
         self.try_intercept(players, events);
@@ -411,8 +412,8 @@ impl Ball {
         let ball_position = self.position;
         const NOTIFICATION_RADIUS: f32 = 500.0; // Cover entire field - all players can be notified
 
-        // Group players by team and find nearest from each team
-        let mut team_nearest: HashMap<u32, (&MatchPlayer, f32)> = HashMap::new();
+        // Group players by team and find nearest from each team, in team id order
+        let mut team_nearest: BTreeMap<u32, (&MatchPlayer, f32)> = BTreeMap::new();
 
         for player in players {
             let dx = player.position.x - ball_position.x;
@@ -443,6 +444,29 @@ impl Ball {
         notified_players
     }
 
+    /// A loose ball over a line is the referee's, against the player who touched it last
+    fn check_out_of_play(&self, context: &MatchContext, events: &mut EventCollection) {
+        if self.current_owner.is_some() {
+            return;
+        }
+
+        let field_width = context.field_size.width as f32;
+        let field_height = context.field_size.height as f32;
+
+        let is_out = self.position.x < 0.0
+            || self.position.x > field_width
+            || self.position.y < 0.0
+            || self.position.y > field_height;
+
+        if !is_out {
+            return;
+        }
+
+        if let Some(last_touch) = self.previous_owner {
+            events.add_ball_event(BallEvent::OutOfPlay(last_touch));
+        }
+    }
+
     fn check_boundary_collision(&mut self, context: &MatchContext) {
         let field_width = context.field_size.width as f32;
         let field_height = context.field_size.height as f32;
@@ -1,4 +1,4 @@
-use crate::r#match::events::Event;
+use crate::r#match::events::{Event, MatchReferee};
 use crate::r#match::player::events::PlayerEvent;
 use crate::r#match::{MatchContext, MatchField};
 use log::debug;
@@ -9,6 +9,8 @@ pub enum BallEvent {
     Claimed(u32),
     Gained(u32),
     TakeMe(u32),
+    /// Left the field, the player who touched it last
+    OutOfPlay(u32),
 }
 
 #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
@@ -30,7 +32,7 @@ impl BallEventDispatcher {
     pub fn dispatch(
         event: BallEvent,
         field: &mut MatchField,
-        context: &MatchContext,
+        context: &mut MatchContext,
     ) -> Vec<Event> {
         let mut remaining_events = Vec::new();
 
@@ -64,6 +66,9 @@ impl BallEventDispatcher {
             BallEvent::TakeMe(player_id) => {
                 remaining_events.push(Event::PlayerEvent(PlayerEvent::TakeBall(player_id)));
             }
+            BallEvent::OutOfPlay(player_id) => {
+                MatchReferee::out_of_play(player_id, field, context);
+            }
         }
 
         remaining_events
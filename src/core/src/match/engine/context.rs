@@ -1,5 +1,6 @@
 use nalgebra::Vector3;
-use crate::r#match::{GameState, GoalDetail, GoalPosition, MatchField, MatchFieldSize, MatchPlayerCollection, MatchState, MatchTime, Score, TeamsTactics, MATCH_HALF_TIME_MS};
+use crate::r#match::events::PlayerExit;
+use crate::r#match::{GameState, GoalDetail, GoalPosition, MatchField, MatchFieldSize, MatchPlayerCollection, MatchState, MatchTime, MatchTimeline, Score, TeamsTactics, MATCH_HALF_TIME_MS};
 
 const MATCH_TIME_INCREMENT_MS: u64 = 10;
 
@@ -20,6 +21,11 @@ pub struct MatchContext {
 
     // Track cumulative time across all match states
     pub total_match_time: u64,
+
+    pub timeline: MatchTimeline,
+
+    /// Players leaving the pitch at the end of the tick
+    pub exits: Vec<PlayerExit>,
 }
 
 impl MatchContext {
@@ -36,6 +42,8 @@ impl MatchContext {
             field_away_team_id: field.away_team_id,
             logging_enabled: false,
             total_match_time: 0,
+            timeline: MatchTimeline::new(),
+            exits: Vec::new(),
         }
     }
 
@@ -3,10 +3,10 @@ use crate::r#match::engine::events::dispatcher::EventCollection;
 use crate::r#match::events::EventDispatcher;
 use crate::r#match::field::MatchField;
 use crate::r#match::result::ResultMatchPositionData;
-use crate::r#match::{GameTickContext, MatchContext, MatchPlayer, MatchResultRaw, MatchSquad, Score, StateManager};
+use crate::r#match::{GameTickContext, LiveMatch, MatchContext, MatchPlayer, MatchResultRaw, MatchSquad};
 use crate::Tactics;
 use nalgebra::Vector3;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 pub struct FootballEngine<const W: usize, const H: usize> {}
 
@@ -22,66 +22,11 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
     }
 
     pub fn play(left_squad: MatchSquad, right_squad: MatchSquad) -> MatchResultRaw {
-        let score = Score::new(left_squad.team_id, right_squad.team_id);
+        let mut live_match = LiveMatch::<W, H>::new(left_squad, right_squad);
 
-        let players = MatchPlayerCollection::from_squads(&left_squad, &right_squad);
+        while live_match.tick() {}
 
-        let mut match_position_data = ResultMatchPositionData::new();
-
-        let mut field = MatchField::new(W, H, left_squad, right_squad);
-
-        let mut context = MatchContext::new(&field, players, score);
-
-        let mut state_manager = StateManager::new();
-
-        while let Some(state) = state_manager.next() {
-            context.state.set(state);
-
-            let play_state_result =
-                Self::play_inner(&mut field, &mut context, &mut match_position_data);
-
-            StateManager::handle_state_finish(&mut context, &mut field, play_state_result);
-        }
-
-        let mut result = MatchResultRaw::with_match_time(context.total_match_time);
-
-        context.fill_details();
-
-        result.score = Some(context.score.clone());
-
-        // Assign squads based on team IDs, not field positions
-        // left_team_players and right_team_players in result represent home and away teams
-        let left_side_squad = field.left_side_players.expect("left team players");
-        let right_side_squad = field.right_side_players.expect("right team players");
-
-        // Check which field side has the home team using FieldSquad's team_id
-        if left_side_squad.team_id == field.home_team_id {
-            // Home team is on the left side
-            result.left_team_players = left_side_squad;
-            result.right_team_players = right_side_squad;
-        } else {
-            // Home team is on the right side (after swap)
-            result.left_team_players = right_side_squad;
-            result.right_team_players = left_side_squad;
-        }
-
-        result.position_data = match_position_data;
-
-        result
-    }
-
-    fn play_inner(
-        field: &mut MatchField,
-        context: &mut MatchContext,
-        match_data: &mut ResultMatchPositionData,
-    ) -> PlayMatchStateResult {
-        let result = PlayMatchStateResult::default();
-
-        while context.increment_time() {
-            Self::game_tick(field, context, match_data);
-        }
-
-        result
+        live_match.finish()
     }
 
     pub fn game_tick(
@@ -251,13 +196,14 @@ impl MatchFieldSize {
     }
 }
 
+/// Players by id, iterated in id order so a seeded match plays out the same way
 pub struct MatchPlayerCollection {
-    pub players: HashMap<u32, MatchPlayer>,
+    pub players: BTreeMap<u32, MatchPlayer>,
 }
 
 impl MatchPlayerCollection {
     pub fn from_squads(home_squad: &MatchSquad, away_squad: &MatchSquad) -> Self {
-        let mut result = HashMap::new();
+        let mut result = BTreeMap::new();
 
         // home_main
         for hs_m in &home_squad.main_squad {
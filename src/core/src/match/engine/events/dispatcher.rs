@@ -1,6 +1,8 @@
 use crate::r#match::ball::events::{BallEvent, BallEventDispatcher};
+use crate::r#match::events::MatchSubstitutions;
 use crate::r#match::player::events::{PlayerEvent, PlayerEventDispatcher};
 use crate::r#match::{MatchContext, MatchField, ResultMatchPositionData};
+use crate::PlayerFieldPositionGroup;
 
 pub enum Event {
     BallEvent(BallEvent),
@@ -87,7 +89,30 @@ impl EventDispatcher {
         }
 
         if process_remaining_events {
-            Self::dispatch(remaining_events, field, context, match_data, false)
+            Self::dispatch(remaining_events, field, context, match_data, false);
+
+            Self::record_ball_owner(field, context);
+
+            MatchSubstitutions::exits(field, context);
+            MatchSubstitutions::planned(field, context);
+        }
+    }
+
+    fn record_ball_owner(field: &mut MatchField, context: &mut MatchContext) {
+        let Some(owner_id) = field.ball.current_owner else {
+            return;
+        };
+
+        if let Some(owner) = field.get_player(owner_id) {
+            let is_goalkeeper = owner.tactical_position.current_position.position_group()
+                == PlayerFieldPositionGroup::Goalkeeper;
+
+            context.timeline.ball_owner(
+                context.total_match_time,
+                owner.team_id,
+                owner.id,
+                is_goalkeeper,
+            );
         }
     }
 }
@@ -1,3 +1,7 @@
 pub mod dispatcher;
+pub mod referee;
+pub mod substitutions;
 
 pub use dispatcher::*;
+pub use referee::*;
+pub use substitutions::*;
@@ -0,0 +1,289 @@
+use crate::r#match::events::PlayerExit;
+use crate::r#match::{
+    MatchCard, MatchContext, MatchField, MatchPlayer, PlayerSide, SetPiece, FIELD_UNITS_PER_METRE,
+};
+use crate::utils::RandomSeed;
+use crate::PlayerFieldPositionGroup;
+use nalgebra::Vector3;
+
+/// Chance of a foul being booked, at an average aggression
+const BOOKING_CHANCE: f32 = 0.15;
+const SENDING_OFF_CHANCE: f32 = 0.01;
+const FOUL_INJURY_CHANCE: f32 = 0.03;
+
+const PENALTY_AREA_DEPTH_METRES: f32 = 16.5;
+const PENALTY_AREA_WIDTH_METRES: f32 = 40.3;
+const PENALTY_SPOT_METRES: f32 = 11.0;
+const GOAL_AREA_DEPTH_METRES: f32 = 5.5;
+
+/// Restarts are taken this far inside the line, so the ball isn't out again straight away
+const RESTART_MARGIN: f32 = 2.0;
+
+/// The decisions the players don't take themselves: cards, restarts and offsides
+pub struct MatchReferee;
+
+impl MatchReferee {
+    /// The fouled side gets a free kick, or a penalty in the box. The foul may be booked
+    /// and the fouled player may not be able to go on
+    pub fn foul(player_id: u32, field: &mut MatchField, context: &mut MatchContext) {
+        let Some(player) = field.players.iter().find(|player| player.id == player_id) else {
+            return;
+        };
+
+        let team_id = player.team_id;
+        let side = player.side;
+        let aggression = player.skills.mental.aggression;
+        let time = context.total_match_time;
+
+        context.timeline.foul(time, team_id, player_id);
+
+        let fouled = Self::fouled_player(player, field).map(|fouled| (fouled.id, fouled.team_id));
+
+        if let Some((fouled_id, fouled_team_id)) = fouled {
+            let ball_position = field.ball.position;
+
+            match side {
+                Some(side) if Self::in_own_penalty_area(side, ball_position, context) => {
+                    context
+                        .timeline
+                        .set_piece(time, fouled_team_id, Some(fouled_id), SetPiece::Penalty);
+
+                    Self::restart(fouled_id, Self::penalty_spot(side, context), field);
+                }
+                _ => {
+                    context
+                        .timeline
+                        .set_piece(time, fouled_team_id, Some(fouled_id), SetPiece::FreeKick);
+
+                    Self::restart(fouled_id, ball_position, field);
+                }
+            }
+
+            if RandomSeed::random::<f32>() < FOUL_INJURY_CHANCE {
+                context.timeline.injury(time, fouled_team_id, fouled_id);
+                context.exits.push(PlayerExit::Injured(fouled_id));
+            }
+        }
+
+        Self::book(player_id, team_id, aggression, context);
+    }
+
+    /// A pass to a team-mate beyond the second last defender, the ball and the halfway line
+    /// is stopped and the defending side takes a free kick. True when the pass is called back
+    pub fn offside(from_player_id: u32, to_player_id: u32, field: &mut MatchField, context: &mut MatchContext) -> bool {
+        let (Some(passer), Some(receiver)) = (
+            field.players.iter().find(|player| player.id == from_player_id),
+            field.players.iter().find(|player| player.id == to_player_id),
+        ) else {
+            return false;
+        };
+
+        let Some(side) = receiver.side else {
+            return false;
+        };
+
+        if passer.team_id != receiver.team_id {
+            return false;
+        }
+
+        // Distance towards the goal the receiver attacks
+        let width = context.field_size.width as f32;
+        let forward = |position: &Vector3<f32>| match side {
+            PlayerSide::Left => position.x,
+            PlayerSide::Right => width - position.x,
+        };
+
+        let mut defenders: Vec<f32> = field
+            .players
+            .iter()
+            .filter(|player| player.team_id != receiver.team_id)
+            .map(|player| forward(&player.position))
+            .collect();
+
+        defenders.sort_by(|a, b| b.total_cmp(a));
+
+        let receiver_line = forward(&receiver.position);
+
+        let is_offside = receiver_line > width / 2.0
+            && receiver_line > forward(&field.ball.position)
+            && defenders.get(1).is_some_and(|second_last| receiver_line > *second_last);
+
+        if !is_offside {
+            return false;
+        }
+
+        let receiver_team_id = receiver.team_id;
+        let receiver_position = receiver.position;
+        let time = context.total_match_time;
+
+        context.timeline.offside(time, receiver_team_id, to_player_id);
+
+        let taker = Self::nearest(receiver_position, field, |player| player.team_id != receiver_team_id)
+            .map(|player| (player.id, player.team_id, player.position));
+
+        if let Some((taker_id, taker_team_id, taker_position)) = taker {
+            context
+                .timeline
+                .set_piece(time, taker_team_id, Some(taker_id), SetPiece::FreeKick);
+
+            Self::restart(taker_id, taker_position, field);
+        }
+
+        true
+    }
+
+    /// A loose ball over a line: a throw-in, or a corner or goal kick over a goal line,
+    /// against the side that touched it last
+    pub fn out_of_play(last_touch_player_id: u32, field: &mut MatchField, context: &mut MatchContext) {
+        let Some(last_touch) = field.players.iter().find(|player| player.id == last_touch_player_id) else {
+            return;
+        };
+
+        let Some(last_touch_side) = last_touch.side else {
+            return;
+        };
+
+        let last_touch_team_id = last_touch.team_id;
+
+        let width = context.field_size.width as f32;
+        let height = context.field_size.height as f32;
+        let ball = field.ball.position;
+
+        let restarting = |player: &MatchPlayer| player.team_id != last_touch_team_id;
+
+        let (set_piece, taker, position) = if ball.x < 0.0 || ball.x > width {
+            // Left side players defend the goal at x = 0
+            let goal_side = if ball.x < 0.0 { PlayerSide::Left } else { PlayerSide::Right };
+            let goal_line = if ball.x < 0.0 { RESTART_MARGIN } else { width - RESTART_MARGIN };
+
+            if last_touch_side == goal_side {
+                let corner_y = if ball.y < height / 2.0 { RESTART_MARGIN } else { height - RESTART_MARGIN };
+                let corner = Vector3::new(goal_line, corner_y, 0.0);
+
+                (SetPiece::Corner, Self::nearest(corner, field, |player| restarting(player) && !Self::is_goalkeeper(player)), corner)
+            } else {
+                let goal_area = GOAL_AREA_DEPTH_METRES * FIELD_UNITS_PER_METRE;
+                let x = if ball.x < 0.0 { goal_area } else { width - goal_area };
+                let goal_kick = Vector3::new(x, height / 2.0, 0.0);
+
+                let goalkeeper = field
+                    .players
+                    .iter()
+                    .find(|player| restarting(player) && Self::is_goalkeeper(player));
+
+                (SetPiece::GoalKick, goalkeeper, goal_kick)
+            }
+        } else {
+            let position = Vector3::new(
+                ball.x.clamp(RESTART_MARGIN, width - RESTART_MARGIN),
+                ball.y.clamp(RESTART_MARGIN, height - RESTART_MARGIN),
+                0.0,
+            );
+
+            (SetPiece::ThrowIn, Self::nearest(position, field, |player| restarting(player) && !Self::is_goalkeeper(player)), position)
+        };
+
+        let Some((taker_id, taker_team_id)) = taker.map(|player| (player.id, player.team_id)) else {
+            return;
+        };
+
+        context
+            .timeline
+            .set_piece(context.total_match_time, taker_team_id, Some(taker_id), set_piece);
+
+        Self::restart(taker_id, position, field);
+    }
+
+    /// Yellow or straight red, a second yellow is a red too
+    fn book(player_id: u32, team_id: u32, aggression: f32, context: &mut MatchContext) {
+        let time = context.total_match_time;
+        let temper = 0.5 + aggression / 20.0;
+
+        let roll = RandomSeed::random::<f32>();
+
+        if roll < SENDING_OFF_CHANCE * temper {
+            context.timeline.card(time, team_id, player_id, MatchCard::Red);
+            context.exits.push(PlayerExit::SentOff(player_id));
+        } else if roll < BOOKING_CHANCE * temper {
+            context.timeline.card(time, team_id, player_id, MatchCard::Yellow);
+
+            if context.timeline.yellow_cards(player_id) >= 2 {
+                context.timeline.card(time, team_id, player_id, MatchCard::Red);
+                context.exits.push(PlayerExit::SentOff(player_id));
+            }
+        }
+    }
+
+    /// The player on the ball when it's an opponent, the nearest opponent otherwise
+    fn fouled_player<'p>(player: &MatchPlayer, field: &'p MatchField) -> Option<&'p MatchPlayer> {
+        let owner = field
+            .ball
+            .current_owner
+            .and_then(|owner_id| field.players.iter().find(|p| p.id == owner_id))
+            .filter(|owner| owner.team_id != player.team_id);
+
+        owner.or_else(|| Self::nearest(player.position, field, |p| p.team_id != player.team_id))
+    }
+
+    fn nearest(
+        position: Vector3<f32>,
+        field: &MatchField,
+        filter: impl Fn(&MatchPlayer) -> bool,
+    ) -> Option<&MatchPlayer> {
+        field
+            .players
+            .iter()
+            .filter(|player| filter(player))
+            .min_by(|a, b| {
+                (a.position - position)
+                    .norm()
+                    .total_cmp(&(b.position - position).norm())
+                    .then(a.id.cmp(&b.id))
+            })
+    }
+
+    /// The taker gets the ball standing still at `position`
+    fn restart(player_id: u32, position: Vector3<f32>, field: &mut MatchField) {
+        if let Some(player) = field.players.iter_mut().find(|player| player.id == player_id) {
+            player.position = Vector3::new(position.x, position.y, 0.0);
+            player.velocity = Vector3::zeros();
+        }
+
+        field.ball.position = Vector3::new(position.x, position.y, 0.0);
+        field.ball.velocity = Vector3::zeros();
+        field.ball.previous_owner = field.ball.current_owner;
+        field.ball.current_owner = Some(player_id);
+        field.ball.flags.in_flight_state = 0;
+    }
+
+    fn in_own_penalty_area(side: PlayerSide, position: Vector3<f32>, context: &MatchContext) -> bool {
+        let width = context.field_size.width as f32;
+        let height = context.field_size.height as f32;
+
+        let depth = PENALTY_AREA_DEPTH_METRES * FIELD_UNITS_PER_METRE;
+        let half_width = PENALTY_AREA_WIDTH_METRES * FIELD_UNITS_PER_METRE / 2.0;
+
+        let within_depth = match side {
+            PlayerSide::Left => position.x <= depth,
+            PlayerSide::Right => position.x >= width - depth,
+        };
+
+        within_depth && (position.y - height / 2.0).abs() <= half_width
+    }
+
+    /// In front of the goal the side defends
+    fn penalty_spot(side: PlayerSide, context: &MatchContext) -> Vector3<f32> {
+        let width = context.field_size.width as f32;
+        let height = context.field_size.height as f32;
+        let distance = PENALTY_SPOT_METRES * FIELD_UNITS_PER_METRE;
+
+        match side {
+            PlayerSide::Left => Vector3::new(distance, height / 2.0, 0.0),
+            PlayerSide::Right => Vector3::new(width - distance, height / 2.0, 0.0),
+        }
+    }
+
+    fn is_goalkeeper(player: &MatchPlayer) -> bool {
+        player.tactical_position.current_position.position_group() == PlayerFieldPositionGroup::Goalkeeper
+    }
+}
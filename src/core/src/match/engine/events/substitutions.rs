@@ -0,0 +1,181 @@
+use crate::r#match::{FieldSquad, MatchContext, MatchField, MatchState, MATCH_HALF_TIME_MS};
+use crate::PlayerFieldPositionGroup;
+use nalgebra::Vector3;
+
+/// Changes a side can make in a match
+pub const MAX_SUBSTITUTIONS: usize = 3;
+
+/// Second half times at which the tiredest players make way
+const SUBSTITUTION_WINDOWS: [u64; 2] = [MATCH_HALF_TIME_MS / 3, MATCH_HALF_TIME_MS * 2 / 3];
+
+/// Where players who left the pitch are kept
+const BENCH_POSITION: Vector3<f32> = Vector3::new(1.0, 1.0, 0.0);
+
+/// A player leaving the pitch during play, applied once the tick's events are dispatched
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlayerExit {
+    SentOff(u32),
+    Injured(u32),
+}
+
+/// Bench players coming on for tired, injured players
+pub struct MatchSubstitutions;
+
+impl MatchSubstitutions {
+    /// Sent off players leave their side a player short, injured ones are replaced while changes are left
+    pub fn exits(field: &mut MatchField, context: &mut MatchContext) {
+        for exit in std::mem::take(&mut context.exits) {
+            match exit {
+                PlayerExit::SentOff(player_id) => Self::send_off(player_id, field),
+                PlayerExit::Injured(player_id) => {
+                    Self::replace(player_id, field, context);
+                }
+            }
+        }
+    }
+
+    /// Each side changes its least fit outfield player at the second half windows
+    pub fn planned(field: &mut MatchField, context: &mut MatchContext) {
+        if context.state.match_state != MatchState::SecondHalf
+            || !SUBSTITUTION_WINDOWS.contains(&context.time.time)
+        {
+            return;
+        }
+
+        for team_id in [context.field_home_team_id, context.field_away_team_id] {
+            let tiredest = field
+                .players
+                .iter()
+                .filter(|player| player.team_id == team_id)
+                .filter(|player| {
+                    player.tactical_position.current_position.position_group()
+                        != PlayerFieldPositionGroup::Goalkeeper
+                })
+                .min_by(|a, b| {
+                    a.player_attributes
+                        .condition
+                        .cmp(&b.player_attributes.condition)
+                        .then(a.skills.physical.stamina.total_cmp(&b.skills.physical.stamina))
+                        .then(a.id.cmp(&b.id))
+                })
+                .map(|player| player.id);
+
+            if let Some(player_id) = tiredest {
+                Self::replace(player_id, field, context);
+            }
+        }
+    }
+
+    /// A bench player of the same position group, any outfield player otherwise, takes over the role
+    /// of `player_id`. False when the side has no changes or players left
+    pub fn replace(player_id: u32, field: &mut MatchField, context: &mut MatchContext) -> bool {
+        let Some(index) = field.players.iter().position(|player| player.id == player_id) else {
+            return false;
+        };
+
+        let team_id = field.players[index].team_id;
+        let group = field.players[index]
+            .tactical_position
+            .current_position
+            .position_group();
+
+        if context.timeline.substitutions(team_id) >= MAX_SUBSTITUTIONS {
+            return false;
+        }
+
+        let Some(bench) = Self::squad(field, team_id).map(|squad| squad.substitutes.clone()) else {
+            return false;
+        };
+
+        let available: Vec<(usize, PlayerFieldPositionGroup)> = field
+            .substitutes
+            .iter()
+            .enumerate()
+            .filter(|(_, sub)| sub.team_id == team_id && bench.contains(&sub.id))
+            .map(|(sub_index, sub)| (sub_index, sub.tactical_position.current_position.position_group()))
+            .collect();
+
+        let chosen = available
+            .iter()
+            .find(|(_, sub_group)| *sub_group == group)
+            .or_else(|| {
+                available.iter().find(|(_, sub_group)| {
+                    (*sub_group == PlayerFieldPositionGroup::Goalkeeper)
+                        == (group == PlayerFieldPositionGroup::Goalkeeper)
+                })
+            })
+            .map(|(sub_index, _)| *sub_index);
+
+        let Some(sub_index) = chosen else {
+            return false;
+        };
+
+        let mut sub = field.substitutes.remove(sub_index);
+        let replaced = &field.players[index];
+
+        sub.position = replaced.position;
+        sub.start_position = replaced.start_position;
+        sub.tactical_position = replaced.tactical_position.clone();
+        sub.side = replaced.side;
+        sub.velocity = Vector3::zeros();
+        sub.set_default_state();
+
+        let sub_id = sub.id;
+        let mut replaced = std::mem::replace(&mut field.players[index], sub);
+        replaced.position = BENCH_POSITION;
+        field.substitutes.push(replaced);
+
+        if field.ball.current_owner == Some(player_id) {
+            field.ball.current_owner = Some(sub_id);
+        }
+
+        if field.ball.previous_owner == Some(player_id) {
+            field.ball.previous_owner = None;
+        }
+
+        // The player who came on has played, the one who went off can't come back
+        if let Some(squad) = Self::squad_mut(field, team_id) {
+            squad.substitutes.retain(|id| *id != sub_id);
+            squad.main.push(sub_id);
+        }
+
+        context
+            .timeline
+            .substitution(context.total_match_time, team_id, sub_id, player_id);
+
+        true
+    }
+
+    /// The player leaves the pitch without anyone coming on
+    fn send_off(player_id: u32, field: &mut MatchField) {
+        let Some(index) = field.players.iter().position(|player| player.id == player_id) else {
+            return;
+        };
+
+        let mut player = field.players.remove(index);
+        player.position = BENCH_POSITION;
+        field.substitutes.push(player);
+
+        if field.ball.current_owner == Some(player_id) {
+            field.ball.current_owner = None;
+        }
+
+        if field.ball.previous_owner == Some(player_id) {
+            field.ball.previous_owner = None;
+        }
+    }
+
+    fn squad(field: &MatchField, team_id: u32) -> Option<&FieldSquad> {
+        [&field.left_side_players, &field.right_side_players]
+            .into_iter()
+            .flatten()
+            .find(|squad| squad.team_id == team_id)
+    }
+
+    fn squad_mut(field: &mut MatchField, team_id: u32) -> Option<&mut FieldSquad> {
+        [&mut field.left_side_players, &mut field.right_side_players]
+            .into_iter()
+            .flatten()
+            .find(|squad| squad.team_id == team_id)
+    }
+}
@@ -0,0 +1,247 @@
+use crate::r#match::{
+    FootballEngine, MatchContext, MatchField, MatchPlayerCollection, MatchResultRaw, MatchSquad,
+    MatchState, MatchTimelineEvent, PlayMatchStateResult, ResultMatchPositionData, Score,
+    StateManager,
+};
+use nalgebra::Vector3;
+use serde::Serialize;
+
+/// A match played one tick at a time, for watching it while it is simulated
+pub struct LiveMatch<const W: usize, const H: usize> {
+    field: MatchField,
+    context: MatchContext,
+    state_manager: StateManager,
+    position_data: ResultMatchPositionData,
+    /// A period is being played
+    playing: bool,
+    /// Timeline events already handed out in frames
+    framed_events: usize,
+}
+
+/// Where everything is at a moment of a live match
+#[derive(Debug, Clone, Serialize)]
+pub struct LiveMatchFrame {
+    /// Match time in milliseconds
+    pub time: u64,
+    pub state: MatchState,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub ball: Vector3<f32>,
+    pub players: Vec<LivePlayerPosition>,
+    /// Events since the previous frame
+    pub events: Vec<MatchTimelineEvent>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LivePlayerPosition {
+    pub id: u32,
+    pub position: Vector3<f32>,
+}
+
+impl<const W: usize, const H: usize> LiveMatch<W, H> {
+    pub fn new(left_squad: MatchSquad, right_squad: MatchSquad) -> Self {
+        let score = Score::new(left_squad.team_id, right_squad.team_id);
+
+        let players = MatchPlayerCollection::from_squads(&left_squad, &right_squad);
+
+        let field = MatchField::new(W, H, left_squad, right_squad);
+
+        let context = MatchContext::new(&field, players, score);
+
+        LiveMatch {
+            field,
+            context,
+            state_manager: StateManager::new(),
+            position_data: ResultMatchPositionData::new(),
+            playing: false,
+            framed_events: 0,
+        }
+    }
+
+    /// Plays the next tick, false once the match is over
+    pub fn tick(&mut self) -> bool {
+        loop {
+            if !self.playing {
+                let Some(state) = self.state_manager.next() else {
+                    return false;
+                };
+
+                self.context.state.set(state);
+
+                if let Some(team_id) = Self::kick_off_team(state, &self.context) {
+                    self.context
+                        .timeline
+                        .kick_off(self.context.total_match_time, team_id);
+                }
+
+                self.playing = true;
+            }
+
+            if self.context.increment_time() {
+                FootballEngine::<W, H>::game_tick(
+                    &mut self.field,
+                    &mut self.context,
+                    &mut self.position_data,
+                );
+
+                return true;
+            }
+
+            StateManager::handle_state_finish(
+                &mut self.context,
+                &mut self.field,
+                PlayMatchStateResult::default(),
+            );
+
+            self.playing = false;
+        }
+    }
+
+    /// Home kicks off the first half and extra time, away the second half
+    fn kick_off_team(state: MatchState, context: &MatchContext) -> Option<u32> {
+        match state {
+            MatchState::FirstHalf | MatchState::ExtraTime => Some(context.field_home_team_id),
+            MatchState::SecondHalf => Some(context.field_away_team_id),
+            _ => None,
+        }
+    }
+
+    pub fn frame(&mut self) -> LiveMatchFrame {
+        let events = self.context.timeline.events()[self.framed_events..].to_vec();
+
+        self.framed_events += events.len();
+
+        LiveMatchFrame {
+            time: self.context.total_match_time,
+            state: self.context.state.match_state,
+            home_goals: self.context.score.home_team.get(),
+            away_goals: self.context.score.away_team.get(),
+            ball: self.field.ball.position,
+            players: self
+                .field
+                .players
+                .iter()
+                .map(|player| LivePlayerPosition {
+                    id: player.id,
+                    position: player.position,
+                })
+                .collect(),
+            events,
+        }
+    }
+
+    pub fn finish(mut self) -> MatchResultRaw {
+        let mut result = MatchResultRaw::with_match_time(self.context.total_match_time);
+
+        self.context.fill_details();
+
+        result.score = Some(self.context.score.clone());
+
+        // Assign squads based on team IDs, not field positions
+        // left_team_players and right_team_players in result represent home and away teams
+        let left_side_squad = self.field.left_side_players.expect("left team players");
+        let right_side_squad = self.field.right_side_players.expect("right team players");
+
+        // Check which field side has the home team using FieldSquad's team_id
+        if left_side_squad.team_id == self.field.home_team_id {
+            // Home team is on the left side
+            result.left_team_players = left_side_squad;
+            result.right_team_players = right_side_squad;
+        } else {
+            // Home team is on the right side (after swap)
+            result.left_team_players = right_side_squad;
+            result.right_team_players = left_side_squad;
+        }
+
+        self.position_data.compress();
+
+        result.position_data = self.position_data;
+        result.events = self.context.timeline.into_events();
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r#match::MatchPlayer;
+    use crate::utils::RandomSeed;
+    use crate::{MatchTacticType, PlayerGenerator, PlayerPositionType, Tactics};
+    use chrono::NaiveDate;
+
+    const POSITIONS: [PlayerPositionType; 11] = [
+        PlayerPositionType::Goalkeeper,
+        PlayerPositionType::DefenderLeft,
+        PlayerPositionType::DefenderCenterLeft,
+        PlayerPositionType::DefenderCenterRight,
+        PlayerPositionType::DefenderRight,
+        PlayerPositionType::MidfielderLeft,
+        PlayerPositionType::MidfielderCenterLeft,
+        PlayerPositionType::MidfielderCenterRight,
+        PlayerPositionType::MidfielderRight,
+        PlayerPositionType::ForwardLeft,
+        PlayerPositionType::ForwardRight,
+    ];
+
+    fn squad(team_id: u32) -> MatchSquad {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 1).unwrap();
+
+        let main_squad = POSITIONS
+            .iter()
+            .enumerate()
+            .map(|(index, &position)| {
+                let mut player = PlayerGenerator::generate(1, now, position, 20);
+                player.id = team_id * 100 + index as u32;
+
+                MatchPlayer::from_player(team_id, &player, position, false)
+            })
+            .collect();
+
+        MatchSquad {
+            team_id,
+            team_name: format!("Team {}", team_id),
+            tactics: Tactics::new(MatchTacticType::T442),
+            main_squad,
+            substitutes: Vec::new(),
+            captain_id: None,
+            vice_captain_id: None,
+            penalty_taker_id: None,
+            free_kick_taker_id: None,
+        }
+    }
+
+    #[test]
+    fn watching_frames_plays_the_same_match() {
+        let home = squad(1);
+        let away = squad(2);
+
+        let played = RandomSeed::scoped(Some(7), || {
+            FootballEngine::<840, 545>::play(home.clone(), away.clone())
+        });
+
+        let (watched, framed_events) = RandomSeed::scoped(Some(7), || {
+            let mut live_match = LiveMatch::<840, 545>::new(home.clone(), away.clone());
+            let mut framed_events = Vec::new();
+
+            while live_match.tick() {
+                if live_match.context.total_match_time % 100 == 0 {
+                    framed_events.extend(live_match.frame().events);
+                }
+            }
+
+            framed_events.extend(live_match.frame().events);
+
+            (live_match.finish(), framed_events)
+        });
+
+        let played_score = played.score.as_ref().unwrap();
+        let watched_score = watched.score.as_ref().unwrap();
+
+        assert_eq!(played_score.home_team.get(), watched_score.home_team.get());
+        assert_eq!(played_score.away_team.get(), watched_score.away_team.get());
+        assert_eq!(played.match_time_ms, watched.match_time_ms);
+        assert_eq!(played.events, watched.events);
+        assert_eq!(watched.events, framed_events);
+    }
+}
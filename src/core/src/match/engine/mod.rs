@@ -2,19 +2,23 @@
 pub mod engine;
 pub mod events;
 pub mod field;
+pub mod live;
 pub mod player;
 pub mod raycast;
 pub mod result;
 pub mod state;
 pub mod tactics;
+pub mod timeline;
 pub mod context;
 
 pub use ball::*;
 pub use engine::*;
 pub use field::*;
+pub use live::*;
 pub use raycast::*;
 pub use result::*;
 pub use state::*;
+pub use timeline::*;
 pub use context::*;
 
 // Re-export player items except conflicting ones
@@ -1,7 +1,8 @@
-use crate::r#match::events::Event;
+use crate::r#match::events::{Event, MatchReferee};
 use crate::r#match::player::events::{PassingEventContext, ShootingEventContext};
 use crate::r#match::player::statistics::MatchStatisticType;
-use crate::r#match::{GoalDetail, MatchContext, MatchField, MatchPlayer};
+use crate::r#match::{GoalDetail, MatchContext, MatchField, MatchPlayer, MatchTimeline};
+use crate::utils::RandomSeed;
 use log::debug;
 use nalgebra::Vector3;
 use rand::Rng;
@@ -108,7 +109,7 @@ pub enum PlayerEvent {
     ClaimBall(u32),
     GainBall(u32),
     CaughtBall(u32),
-    CommitFoul,
+    CommitFoul(u32),
     RequestHeading(u32, Vector3<f32>),
     RequestShot(u32, Vector3<f32>),
     RequestBallReceive(u32),
@@ -150,6 +151,16 @@ impl PlayerEventDispatcher {
                 Self::handle_ball_owner_change_event(player_id, field);
             }
             PlayerEvent::PassTo(pass_event_model) => {
+                // A pass to a team-mate in an offside position is called back
+                if MatchReferee::offside(
+                    pass_event_model.from_player_id,
+                    pass_event_model.to_player_id,
+                    field,
+                    context,
+                ) {
+                    return remaining_events;
+                }
+
                 // Record the pass event (only if tracking is enabled)
                 if match_data.is_tracking_events() {
                     match_data.add_pass_event(
@@ -170,6 +181,7 @@ impl PlayerEventDispatcher {
                 Self::handle_gain_ball_event(player_id, field);
             }
             PlayerEvent::Shoot(shoot_event_model) => {
+                Self::record_shot(&shoot_event_model, field, context);
                 Self::handle_shoot_event(shoot_event_model, field);
             }
             PlayerEvent::CaughtBall(player_id) => {
@@ -184,6 +196,9 @@ impl PlayerEventDispatcher {
             PlayerEvent::ClearBall(velocity) => {
                 Self::handle_clear_ball_event(velocity, field);
             }
+            PlayerEvent::CommitFoul(player_id) => {
+                MatchReferee::foul(player_id, field, context);
+            }
             _ => {} // Ignore unsupported events
         }
 
@@ -202,6 +217,22 @@ impl PlayerEventDispatcher {
             time: context.total_match_time,
         });
 
+        let team_id = player.team_id;
+
+        context
+            .timeline
+            .goal(context.total_match_time, team_id, player_id, is_auto_goal);
+
+        // the team that conceded restarts the match
+        let conceding_team_id = match is_auto_goal {
+            true => team_id,
+            false => Self::opponent_team_id(team_id, context),
+        };
+
+        context
+            .timeline
+            .kick_off(context.total_match_time, conceding_team_id);
+
         field.ball.previous_owner = None;
         field.ball.current_owner = None;
 
@@ -241,7 +272,7 @@ impl PlayerEventDispatcher {
     }
 
     fn handle_pass_to_event(event_model: PassingEventContext, field: &mut MatchField) {
-        let mut rng = rand::rng();
+        let mut rng = RandomSeed::rng();
 
         // Extract player skills and condition
         let player = field.get_player(event_model.from_player_id).unwrap();
@@ -723,7 +754,7 @@ impl PlayerEventDispatcher {
         const MAX_SHOT_VELOCITY: f32 = 12.0; // Maximum realistic shot velocity per tick (~40 m/s at 60fps)
         const MIN_SHOT_DISTANCE: f32 = 1.0; // Minimum distance to prevent NaN from normalization
 
-        let mut rng = rand::rng();
+        let mut rng = RandomSeed::rng();
 
         // Get player skills for power and accuracy calculations
         let player = field.get_player(shoot_event_model.from_player_id).unwrap();
@@ -896,6 +927,39 @@ impl PlayerEventDispatcher {
         field.ball.flags.in_flight_state = 100;
     }
 
+    fn record_shot(
+        shoot_event_model: &ShootingEventContext,
+        field: &mut MatchField,
+        context: &mut MatchContext,
+    ) {
+        let Some(player) = field.get_player(shoot_event_model.from_player_id) else {
+            return;
+        };
+
+        // the goal nearest to where the shot is aimed
+        let goal = if (shoot_event_model.target.x - context.goal_positions.left.x).abs()
+            < (shoot_event_model.target.x - context.goal_positions.right.x).abs()
+        {
+            context.goal_positions.left
+        } else {
+            context.goal_positions.right
+        };
+
+        let xg = MatchTimeline::expected_goals(player.position, goal);
+
+        context
+            .timeline
+            .shot(context.total_match_time, player.team_id, player.id, xg);
+    }
+
+    fn opponent_team_id(team_id: u32, context: &MatchContext) -> u32 {
+        if team_id == context.field_home_team_id {
+            context.field_away_team_id
+        } else {
+            context.field_home_team_id
+        }
+    }
+
     fn handle_caught_ball_event(player_id: u32, field: &mut MatchField) {
         field.ball.previous_owner = field.ball.current_owner;
         field.ball.current_owner = Some(player_id);
@@ -51,6 +51,9 @@ impl MatchPlayer {
         position: PlayerPositionType,
         use_extended_state_logging: bool,
     ) -> Self {
+        let mut skills = player.skills;
+        skills.mental.apply_morale(player.morale.match_factor());
+
         MatchPlayer {
             id: player.id,
             position: Vector3::zeros(),
@@ -58,7 +61,7 @@ impl MatchPlayer {
             attributes: player.attributes,
             team_id,
             player_attributes: player.player_attributes,
-            skills: player.skills,
+            skills,
             velocity: Vector3::zeros(),
             tactical_position: TacticalPositions::new(position, None),
             side: None,
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug, Clone)]
 pub struct MatchPlayerStatistics {
     pub items: Vec<MatchPlayerStatisticsItem>,
@@ -44,7 +46,7 @@ pub struct MatchPlayerStatisticsItem {
     pub is_auto_goal: bool,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum MatchStatisticType {
     Goal,
     Assist,
@@ -7,6 +7,7 @@ use crate::{PlayerAttributes, PlayerSkills};
 use nalgebra::Vector3;
 use rand::Rng;
 use crate::r#match::player::strategies::players::{DefensiveOperationsImpl, MovementOperationsImpl, PassingOperationsImpl, PressureOperationsImpl, ShootingOperationsImpl, SkillOperationsImpl};
+use crate::utils::RandomSeed;
 
 pub struct PlayerOperationsImpl<'p> {
     ctx: &'p StateProcessingContext<'p>,
@@ -89,7 +90,7 @@ impl<'p> PlayerOperationsImpl<'p> {
         // Goal dimensions (using goal post standard size)
         let goal_width = 73.0; // Standard goal width in decimeters
 
-        let mut rng = rand::rng();
+        let mut rng = RandomSeed::rng();
 
         // Determine shot type based on distance and skills
         let is_placement_shot = distance_to_goal < 150.0 && finishing > 12.0;
@@ -198,7 +199,7 @@ impl<'p> PlayerOperationsImpl<'p> {
         let base_power = min_power + (max_power - min_power) * skill_factor * distance_factor;
 
         // Add slight randomization
-        let random_factor = rand::rng().random_range(0.9..1.1);
+        let random_factor = RandomSeed::rng().random_range(0.9..1.1);
 
         // Players with better skills have less randomization
         let final_random_factor = 1.0 + (random_factor - 1.0) * (1.0 - skill_factor * 0.5);
@@ -401,8 +402,8 @@ impl<'p> PlayerOperationsImpl<'p> {
         // Add minimal random jitter to separation for natural movement (reduced to prevent twitching)
         if separation.magnitude() > 0.1 {
             let jitter = Vector3::new(
-                (rand::random::<f32>() - 0.5) * 0.3, // Reduced from 0.8 to 0.3
-                (rand::random::<f32>() - 0.5) * 0.3, // Reduced from 0.8 to 0.3
+                (RandomSeed::random::<f32>() - 0.5) * 0.3, // Reduced from 0.8 to 0.3
+                (RandomSeed::random::<f32>() - 0.5) * 0.3, // Reduced from 0.8 to 0.3
                 0.0,
             );
             separation += jitter;
@@ -7,6 +7,7 @@ use crate::r#match::{
 use nalgebra::Vector3;
 
 use crate::r#match::events::Event;
+use crate::utils::RandomSeed;
 
 const HEADING_HEIGHT_THRESHOLD: f32 = 1.5; // Minimum height to consider heading (meters)
 const HEADING_DISTANCE_THRESHOLD: f32 = 1.5; // Maximum distance to the ball for heading (meters)
@@ -75,7 +76,7 @@ impl DefenderHeadingState {
         let overall_skill = (heading_skill + jumping_skill) / 2.0;
 
         // Simulate chance of success
-        let random_value: f32 = rand::random(); // Generates a random float between 0.0 and 1.0
+        let random_value: f32 = RandomSeed::random(); // Generates a random float between 0.0 and 1.0
 
         overall_skill > (random_value + HEADING_SUCCESS_THRESHOLD)
     }
@@ -1,6 +1,7 @@
 use crate::r#match::defenders::states::DefenderState;
 use crate::r#match::defenders::states::common::{DefenderCondition, ActivityIntensity};
 use crate::r#match::{ConditionContext, MatchPlayer, MatchPlayerLite, PlayerSide, StateChangeResult, StateProcessingContext, StateProcessingHandler};
+use crate::utils::RandomSeed;
 use nalgebra::Vector3;
 use rand::Rng;
 
@@ -194,7 +195,7 @@ impl DefenderOffsideTrapState {
         // Calculate the success probability based on teamwork and concentration
         let teamwork = ctx.player.skills.mental.teamwork as f32 / 20.0;
         let concentration = ctx.player.skills.mental.concentration as f32 / 20.0;
-        let mut rng = rand::rng();
+        let mut rng = RandomSeed::rng();
         let success_probability = (teamwork + concentration) / 2.0;
 
         // Determine the offside trap outcome
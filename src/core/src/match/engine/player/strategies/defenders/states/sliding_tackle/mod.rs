@@ -3,6 +3,7 @@ use crate::r#match::defenders::states::common::{DefenderCondition, ActivityInten
 use crate::r#match::events::Event;
 use crate::r#match::player::events::PlayerEvent;
 use crate::r#match::{ConditionContext, MatchPlayerLite, StateChangeResult, StateProcessingContext, StateProcessingHandler};
+use crate::utils::RandomSeed;
 use nalgebra::Vector3;
 use rand::Rng;
 
@@ -68,7 +69,7 @@ impl StateProcessingHandler for DefenderSlidingTackleState {
                 // Generate a foul event
                 state_change
                     .events
-                    .add(Event::PlayerEvent(PlayerEvent::CommitFoul));
+                    .add(Event::PlayerEvent(PlayerEvent::CommitFoul(ctx.player.id)));
 
                 // Transition to appropriate state (e.g., ReactingToFoul)
                 // You may need to define additional states for handling fouls
@@ -125,7 +126,7 @@ impl DefenderSlidingTackleState {
         ctx: &StateProcessingContext,
         _opponent: &MatchPlayerLite,
     ) -> (bool, bool) {
-        let mut rng = rand::rng();
+        let mut rng = RandomSeed::rng();
 
         // Get defender's tackling-related skills
         let tackling_skill = ctx.player.skills.technical.tackling  / 20.0; // Normalize to [0,1]
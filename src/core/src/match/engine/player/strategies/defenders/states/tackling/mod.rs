@@ -6,6 +6,7 @@ use crate::r#match::{
     ConditionContext, MatchPlayerLite, StateChangeResult,
     StateProcessingContext, StateProcessingHandler, SteeringBehavior,
 };
+use crate::utils::RandomSeed;
 use nalgebra::Vector3;
 use rand::Rng;
 
@@ -55,7 +56,7 @@ impl StateProcessingHandler for DefenderTacklingState {
             } else if committed_foul {
                 Some(StateChangeResult::with_defender_state_and_event(
                     DefenderState::Standing,
-                    Event::PlayerEvent(PlayerEvent::CommitFoul),
+                    Event::PlayerEvent(PlayerEvent::CommitFoul(ctx.player.id)),
                 ))
             } else {
                 None
@@ -162,7 +163,7 @@ impl DefenderTacklingState {
         ctx: &StateProcessingContext,
         opponent: &MatchPlayerLite,
     ) -> (bool, bool) {
-        let mut rng = rand::rng();
+        let mut rng = RandomSeed::rng();
 
         let tackling_skill = ctx.player.skills.technical.tackling / 20.0;
         let aggression = ctx.player.skills.mental.aggression / 20.0;
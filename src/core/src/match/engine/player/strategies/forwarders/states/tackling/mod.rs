@@ -6,6 +6,7 @@ use crate::r#match::{
     ConditionContext, MatchPlayerLite, StateChangeResult, StateProcessingContext,
     StateProcessingHandler, SteeringBehavior,
 };
+use crate::utils::RandomSeed;
 use nalgebra::Vector3;
 use rand::Rng;
 
@@ -38,7 +39,7 @@ impl StateProcessingHandler for ForwardTacklingState {
                 if committed_foul {
                     return Some(StateChangeResult::with_forward_state_and_event(
                         ForwardState::Standing,
-                        Event::PlayerEvent(PlayerEvent::CommitFoul),
+                        Event::PlayerEvent(PlayerEvent::CommitFoul(ctx.player.id)),
                     ));
                 }
 
@@ -62,7 +63,7 @@ impl StateProcessingHandler for ForwardTacklingState {
                     if committed_foul {
                         return Some(StateChangeResult::with_forward_state_and_event(
                             ForwardState::Standing,
-                            Event::PlayerEvent(PlayerEvent::CommitFoul),
+                            Event::PlayerEvent(PlayerEvent::CommitFoul(ctx.player.id)),
                         ));
                     }
 
@@ -185,7 +186,7 @@ impl ForwardTacklingState {
 
         // More likely to tackle if opponent is stationary or moving slowly
         if opponent_is_stationary {
-            return rand::random::<f32>() < tackle_eagerness * 1.2;
+            return RandomSeed::random::<f32>() < tackle_eagerness * 1.2;
         }
 
         // Check if opponent is moving toward our goal (more urgent to tackle)
@@ -195,11 +196,11 @@ impl ForwardTacklingState {
 
         if threat_level > 0.5 {
             // Opponent moving toward our goal - tackle more eagerly
-            return rand::random::<f32>() < tackle_eagerness * 1.4;
+            return RandomSeed::random::<f32>() < tackle_eagerness * 1.4;
         }
 
         // Standard tackle decision
-        rand::random::<f32>() < tackle_eagerness * 0.8
+        RandomSeed::random::<f32>() < tackle_eagerness * 0.8
     }
 
     /// Attempt a tackle with improved physics and skill-based calculation
@@ -208,7 +209,7 @@ impl ForwardTacklingState {
         ctx: &StateProcessingContext,
         opponent: &MatchPlayerLite,
     ) -> (bool, bool) {
-        let mut rng = rand::rng();
+        let mut rng = RandomSeed::rng();
 
         // Player skills
         let tackling_skill = ctx.player.skills.technical.tackling / 20.0;
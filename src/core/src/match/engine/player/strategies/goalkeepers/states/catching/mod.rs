@@ -2,6 +2,7 @@ use crate::r#match::goalkeepers::states::common::{ActivityIntensity, GoalkeeperC
 use crate::r#match::goalkeepers::states::state::GoalkeeperState;
 use crate::r#match::player::events::PlayerEvent;
 use crate::r#match::{ConditionContext, PlayerDistanceFromStartPosition, StateChangeResult, StateProcessingContext, StateProcessingHandler};
+use crate::utils::RandomSeed;
 use nalgebra::Vector3;
 
 #[derive(Default)]
@@ -147,7 +148,7 @@ impl GoalkeeperCatchingState {
         let clamped_catch_probability = catch_probability.clamp(0.10, 0.98);
 
         // Random number between 0 and 1
-        let random_factor = rand::random::<f32>();
+        let random_factor = RandomSeed::random::<f32>();
 
         clamped_catch_probability > random_factor
     }
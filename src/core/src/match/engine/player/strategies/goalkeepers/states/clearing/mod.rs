@@ -5,6 +5,7 @@ use crate::r#match::player::events::PlayerEvent;
 use crate::r#match::{
     ConditionContext, StateChangeResult, StateProcessingContext, StateProcessingHandler,
 };
+use crate::utils::RandomSeed;
 use nalgebra::Vector3;
 use rand::Rng;
 
@@ -59,7 +60,7 @@ impl GoalkeeperClearingState {
         let keeper_pos = ctx.player.position;
 
         // Determine which direction to clear based on position
-        let mut rng = rand::rng();
+        let mut rng = RandomSeed::rng();
         let random_factor: f32 = rng.random_range(-0.3..0.3);
 
         // Aim for a moderate distance upfield and toward sideline
@@ -5,6 +5,7 @@ use crate::r#match::player::events::PlayerEvent;
 use crate::r#match::{
     ConditionContext, StateChangeResult, StateProcessingContext, StateProcessingHandler,
 };
+use crate::utils::RandomSeed;
 use nalgebra::Vector3;
 
 const MAX_DIVE_TIME: f32 = 1.5; // Maximum time to stay in diving state (in seconds)
@@ -76,7 +77,7 @@ impl GoalkeeperDivingState {
         let mut dive_direction = to_future_ball.normalize();
 
         // Add some randomness to dive direction
-        let random_angle = (rand::random::<f32>() - 0.5) * std::f32::consts::PI / 6.0; // Random angle between -30 and 30 degrees
+        let random_angle = (RandomSeed::random::<f32>() - 0.5) * std::f32::consts::PI / 6.0; // Random angle between -30 and 30 degrees
         dive_direction = nalgebra::Rotation3::new(Vector3::z() * random_angle) * dive_direction;
 
         dive_direction
@@ -113,7 +114,7 @@ impl GoalkeeperDivingState {
         let goalkeeper_height = 1.9 + (ctx.player.player_attributes.height as f32 - 180.0) / 100.0; // Height in meters
         let catch_distance = goalkeeper_height * 0.5; // Adjust for goalkeeper height
 
-        ball_distance < catch_distance && rand::random::<f32>() < catch_probability
+        ball_distance < catch_distance && RandomSeed::random::<f32>() < catch_probability
     }
 
     fn is_ball_nearby(&self, ctx: &StateProcessingContext) -> bool {
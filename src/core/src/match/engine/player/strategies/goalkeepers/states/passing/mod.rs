@@ -4,6 +4,7 @@ use crate::r#match::goalkeepers::states::state::GoalkeeperState;
 use crate::r#match::player::events::{PlayerEvent, PassingEventContext};
 use crate::r#match::{ConditionContext, MatchPlayerLite, PassEvaluator, StateChangeResult, StateProcessingContext, StateProcessingHandler, VectorExtensions};
 use crate::PlayerFieldPositionGroup;
+use crate::utils::RandomSeed;
 use nalgebra::Vector3;
 use rand::Rng;
 
@@ -166,7 +167,7 @@ impl GoalkeeperPassingState {
         }
 
         // No immediate pressure - use vision and decision making
-        let mut rng = rand::rng();
+        let mut rng = RandomSeed::rng();
         let decision_random: f32 = rng.random();
 
         // Better vision = more likely to attempt long distribution
@@ -4,6 +4,7 @@ use crate::r#match::player::events::PlayerEvent;
 use crate::r#match::{
     ConditionContext, StateChangeResult, StateProcessingContext, StateProcessingHandler,
 };
+use crate::utils::RandomSeed;
 use nalgebra::Vector3;
 
 const PENALTY_SAVE_PROBABILITY: f32 = 0.3; // Probability of saving a penalty
@@ -24,7 +25,7 @@ impl StateProcessingHandler for GoalkeeperPenaltyState {
         }
 
         // 2. Attempt to save the penalty
-        let save_success = rand::random::<f32>() < PENALTY_SAVE_PROBABILITY;
+        let save_success = RandomSeed::random::<f32>() < PENALTY_SAVE_PROBABILITY;
         if save_success {
             // Penalty save is successful
             let mut state_change =
@@ -51,7 +52,7 @@ impl StateProcessingHandler for GoalkeeperPenaltyState {
 
     fn velocity(&self, ctx: &StateProcessingContext) -> Option<Vector3<f32>> {
         // Determine the velocity based on the penalty save attempt
-        let save_success = rand::random::<f32>() < PENALTY_SAVE_PROBABILITY;
+        let save_success = RandomSeed::random::<f32>() < PENALTY_SAVE_PROBABILITY;
         if save_success {
             // Move towards the predicted ball position
             let predicted_ball_position = Self::predict_ball_position(ctx);
@@ -4,6 +4,7 @@ use crate::r#match::player::events::PlayerEvent;
 use crate::r#match::{
     ConditionContext, StateChangeResult, StateProcessingContext, StateProcessingHandler,
 };
+use crate::utils::RandomSeed;
 use nalgebra::Vector3;
 
 const PICKUP_DISTANCE_THRESHOLD: f32 = 1.0; // Maximum distance to pick up the ball
@@ -37,7 +38,7 @@ impl StateProcessingHandler for GoalkeeperPickingUpState {
         }
 
         // 2. Attempt to pick up the ball
-        let pickup_success = rand::random::<f32>() < PICKUP_SUCCESS_PROBABILITY;
+        let pickup_success = RandomSeed::random::<f32>() < PICKUP_SUCCESS_PROBABILITY;
         if pickup_success {
             // Pickup is successful
             let mut state_change =
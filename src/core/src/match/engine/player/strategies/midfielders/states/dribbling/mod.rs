@@ -3,6 +3,7 @@ use crate::r#match::midfielders::states::MidfielderState;
 use crate::r#match::{
     ConditionContext, StateChangeResult, StateProcessingContext, StateProcessingHandler,
 };
+use crate::utils::RandomSeed;
 use nalgebra::Vector3;
 use rand::prelude::IteratorRandom;
 
@@ -82,7 +83,7 @@ impl MidfielderDribblingState {
         // Find an open teammate to pass to
         let teammates = ctx.players().teammates().nearby_ids(150.0);
 
-        if let Some((teammate_id, _)) = teammates.choose(&mut rand::rng()) {
+        if let Some((teammate_id, _)) = teammates.choose(&mut RandomSeed::rng()) {
             return Some(teammate_id);
         }
 
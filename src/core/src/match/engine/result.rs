@@ -1,12 +1,15 @@
 ﻿use crate::league::LeagueMatch;
 use crate::r#match::player::statistics::MatchStatisticType;
-use crate::r#match::{MatchSquad, ResultMatchPositionData};
+use crate::r#match::{MatchSquad, MatchTimelineEvent, ResultMatchPositionData};
 use std::sync::atomic::{AtomicU8, Ordering};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MatchResultRaw {
     pub score: Option<Score>,
 
+    /// Kept with the match files, not with the world
+    #[serde(skip, default = "ResultMatchPositionData::new")]
     pub position_data: ResultMatchPositionData,
 
     pub left_team_players: FieldSquad,
@@ -14,6 +17,9 @@ pub struct MatchResultRaw {
 
     pub match_time_ms: u64,
     pub additional_time_ms: u64,
+
+    #[serde(default)]
+    pub events: Vec<MatchTimelineEvent>,
 }
 
 impl Clone for MatchResultRaw {
@@ -25,6 +31,7 @@ impl Clone for MatchResultRaw {
             right_team_players: self.right_team_players.clone(),
             match_time_ms: self.match_time_ms,
             additional_time_ms: self.additional_time_ms,
+            events: self.events.clone(),
         }
     }
 }
@@ -38,6 +45,7 @@ impl MatchResultRaw {
             right_team_players: FieldSquad::new(),
             match_time_ms,
             additional_time_ms: 0,
+            events: Vec::new(),
         }
     }
 
@@ -49,6 +57,7 @@ impl MatchResultRaw {
             right_team_players: self.right_team_players.clone(),
             match_time_ms: self.match_time_ms,
             additional_time_ms: self.additional_time_ms,
+            events: self.events.clone(),
         }
     }
 
@@ -62,7 +71,7 @@ impl MatchResultRaw {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldSquad {
     pub team_id: u32,
     pub main: Vec<u32>,
@@ -99,7 +108,7 @@ impl FieldSquad {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Score {
     pub home_team: TeamScore,
     pub away_team: TeamScore,
@@ -107,7 +116,7 @@ pub struct Score {
     pub details: Vec<GoalDetail>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TeamScore {
     pub team_id: u32,
     score: AtomicU8,
@@ -162,7 +171,7 @@ impl PartialOrd for TeamScore {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GoalDetail {
     pub player_id: u32,
     pub stat_type: MatchStatisticType,
@@ -196,7 +205,7 @@ impl Score {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchResult {
     pub id: String,
     pub league_id: u32,
@@ -205,6 +214,7 @@ pub struct MatchResult {
     pub away_team_id: u32,
     pub details: Option<MatchResultRaw>,
     pub score: Score,
+    pub attendance: u32,
 }
 
 impl MatchResult {
@@ -221,6 +231,7 @@ impl MatchResult {
                 None
             },
             score: self.score.clone(),
+            attendance: self.attendance,
         }
     }
 }
@@ -235,6 +246,7 @@ impl From<&LeagueMatch> for MatchResult {
             away_team_id: m.away_team_id,
             score: Score::new(m.home_team_id, m.away_team_id),
             details: None,
+            attendance: 0,
         }
     }
 }
@@ -0,0 +1,365 @@
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// Field units in a metre, the 840 unit field is 105m long
+pub(crate) const FIELD_UNITS_PER_METRE: f32 = 8.0;
+
+/// Width of a real goal, xG is measured against it rather than the engine goal
+const GOAL_WIDTH_METRES: f32 = 7.32;
+
+const XG_INTERCEPT: f32 = 0.9;
+const XG_ANGLE_WEIGHT: f32 = 1.0;
+const XG_DISTANCE_WEIGHT: f32 = -0.2;
+
+/// A shot nobody reaches within this time went wide
+const SHOT_RESOLUTION_MS: u64 = 3_000;
+
+/// Something that happened in a match
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchTimelineEvent {
+    /// Match time in milliseconds
+    pub time: u64,
+    /// Team of the player, for an own goal the team that conceded it
+    pub team_id: u32,
+    pub player_id: Option<u32>,
+    #[serde(flatten)]
+    pub kind: MatchTimelineEventKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MatchTimelineEventKind {
+    KickOff,
+    Shot { xg: f32, outcome: ShotOutcome },
+    Save,
+    Goal { own_goal: bool },
+    Foul,
+    Card { card: MatchCard },
+    SetPiece { set_piece: SetPiece },
+    Substitution { replaced_player_id: u32 },
+    Injury,
+    Offside,
+    PossessionChange,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShotOutcome {
+    Goal,
+    Saved,
+    Blocked,
+    OffTarget,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchCard {
+    Yellow,
+    Red,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SetPiece {
+    Corner,
+    FreeKick,
+    Penalty,
+    ThrowIn,
+    GoalKick,
+}
+
+struct PendingShot {
+    index: usize,
+    team_id: u32,
+    time: u64,
+}
+
+/// Records the events of a match as they are dispatched
+#[derive(Default)]
+pub struct MatchTimeline {
+    events: Vec<MatchTimelineEvent>,
+    possession_team_id: Option<u32>,
+    pending_shot: Option<PendingShot>,
+}
+
+impl MatchTimeline {
+    pub fn new() -> Self {
+        MatchTimeline::default()
+    }
+
+    pub fn events(&self) -> &[MatchTimelineEvent] {
+        &self.events
+    }
+
+    pub fn into_events(self) -> Vec<MatchTimelineEvent> {
+        self.events
+    }
+
+    pub fn add(
+        &mut self,
+        time: u64,
+        team_id: u32,
+        player_id: Option<u32>,
+        kind: MatchTimelineEventKind,
+    ) {
+        self.events.push(MatchTimelineEvent {
+            time,
+            team_id,
+            player_id,
+            kind,
+        });
+    }
+
+    pub fn kick_off(&mut self, time: u64, team_id: u32) {
+        self.add(time, team_id, None, MatchTimelineEventKind::KickOff);
+    }
+
+    /// The outcome is settled by whoever gets the ball next
+    pub fn shot(&mut self, time: u64, team_id: u32, player_id: u32, xg: f32) {
+        self.pending_shot = Some(PendingShot {
+            index: self.events.len(),
+            team_id,
+            time,
+        });
+
+        self.add(
+            time,
+            team_id,
+            Some(player_id),
+            MatchTimelineEventKind::Shot {
+                xg,
+                outcome: ShotOutcome::OffTarget,
+            },
+        );
+    }
+
+    pub fn goal(&mut self, time: u64, team_id: u32, player_id: u32, own_goal: bool) {
+        if let Some(shot) = self.pending_shot.take() {
+            self.set_shot_outcome(shot.index, ShotOutcome::Goal);
+        }
+
+        self.add(
+            time,
+            team_id,
+            Some(player_id),
+            MatchTimelineEventKind::Goal { own_goal },
+        );
+    }
+
+    pub fn foul(&mut self, time: u64, team_id: u32, player_id: u32) {
+        self.add(time, team_id, Some(player_id), MatchTimelineEventKind::Foul);
+    }
+
+    pub fn card(&mut self, time: u64, team_id: u32, player_id: u32, card: MatchCard) {
+        self.add(time, team_id, Some(player_id), MatchTimelineEventKind::Card { card });
+    }
+
+    /// Restart awarded to `team_id`, with the player taking it when there is one
+    pub fn set_piece(&mut self, time: u64, team_id: u32, player_id: Option<u32>, set_piece: SetPiece) {
+        self.add(time, team_id, player_id, MatchTimelineEventKind::SetPiece { set_piece });
+    }
+
+    /// `player_id` comes on for `replaced_player_id`
+    pub fn substitution(&mut self, time: u64, team_id: u32, player_id: u32, replaced_player_id: u32) {
+        self.add(
+            time,
+            team_id,
+            Some(player_id),
+            MatchTimelineEventKind::Substitution { replaced_player_id },
+        );
+    }
+
+    pub fn injury(&mut self, time: u64, team_id: u32, player_id: u32) {
+        self.add(time, team_id, Some(player_id), MatchTimelineEventKind::Injury);
+    }
+
+    pub fn offside(&mut self, time: u64, team_id: u32, player_id: u32) {
+        self.add(time, team_id, Some(player_id), MatchTimelineEventKind::Offside);
+    }
+
+    pub fn yellow_cards(&self, player_id: u32) -> usize {
+        self.events
+            .iter()
+            .filter(|event| {
+                event.player_id == Some(player_id)
+                    && event.kind == MatchTimelineEventKind::Card { card: MatchCard::Yellow }
+            })
+            .count()
+    }
+
+    pub fn substitutions(&self, team_id: u32) -> usize {
+        self.events
+            .iter()
+            .filter(|event| {
+                event.team_id == team_id
+                    && matches!(event.kind, MatchTimelineEventKind::Substitution { .. })
+            })
+            .count()
+    }
+
+    /// Called every tick with the player on the ball
+    pub fn ball_owner(&mut self, time: u64, team_id: u32, player_id: u32, is_goalkeeper: bool) {
+        if let Some(shot) = self.pending_shot.take() {
+            if time.saturating_sub(shot.time) <= SHOT_RESOLUTION_MS && shot.team_id != team_id {
+                if is_goalkeeper {
+                    self.set_shot_outcome(shot.index, ShotOutcome::Saved);
+                    self.add(time, team_id, Some(player_id), MatchTimelineEventKind::Save);
+                } else {
+                    self.set_shot_outcome(shot.index, ShotOutcome::Blocked);
+                }
+            }
+        }
+
+        if self.possession_team_id != Some(team_id) {
+            // the first touch of the match is the kick-off, not a change
+            if self.possession_team_id.is_some() {
+                self.add(
+                    time,
+                    team_id,
+                    Some(player_id),
+                    MatchTimelineEventKind::PossessionChange,
+                );
+            }
+
+            self.possession_team_id = Some(team_id);
+        }
+    }
+
+    fn set_shot_outcome(&mut self, index: usize, shot_outcome: ShotOutcome) {
+        if let MatchTimelineEventKind::Shot { outcome, .. } = &mut self.events[index].kind {
+            *outcome = shot_outcome;
+        }
+    }
+
+    /// Chance of a shot from `position` going in, from its distance and the angle of the goal
+    pub fn expected_goals(position: Vector3<f32>, goal: Vector3<f32>) -> f32 {
+        let x = (goal.x - position.x).abs() / FIELD_UNITS_PER_METRE;
+        let y = (goal.y - position.y).abs() / FIELD_UNITS_PER_METRE;
+
+        let distance = (x * x + y * y).sqrt();
+
+        let half_width = GOAL_WIDTH_METRES / 2.0;
+
+        let angle = (GOAL_WIDTH_METRES * x).atan2(x * x + y * y - half_width * half_width);
+
+        let logit = XG_INTERCEPT + XG_ANGLE_WEIGHT * angle + XG_DISTANCE_WEIGHT * distance;
+
+        1.0 / (1.0 + (-logit).exp())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOME: u32 = 1;
+    const AWAY: u32 = 2;
+
+    fn goal() -> Vector3<f32> {
+        Vector3::new(840.0, 272.5, 0.0)
+    }
+
+    fn metres_out(x: f32, y: f32) -> Vector3<f32> {
+        Vector3::new(
+            840.0 - x * FIELD_UNITS_PER_METRE,
+            272.5 + y * FIELD_UNITS_PER_METRE,
+            0.0,
+        )
+    }
+
+    fn shot_outcome(timeline: &MatchTimeline) -> ShotOutcome {
+        timeline
+            .events()
+            .iter()
+            .find_map(|event| match event.kind {
+                MatchTimelineEventKind::Shot { outcome, .. } => Some(outcome),
+                _ => None,
+            })
+            .expect("shot")
+    }
+
+    #[test]
+    fn expected_goals_falls_with_distance_and_angle() {
+        let close = MatchTimeline::expected_goals(metres_out(6.0, 0.0), goal());
+        let penalty_spot = MatchTimeline::expected_goals(metres_out(11.0, 0.0), goal());
+        let long_range = MatchTimeline::expected_goals(metres_out(25.0, 0.0), goal());
+        let tight_angle = MatchTimeline::expected_goals(metres_out(3.0, 15.0), goal());
+
+        assert!(close > penalty_spot);
+        assert!(penalty_spot > long_range);
+        assert!(penalty_spot > tight_angle);
+        assert!(long_range > 0.0 && close < 1.0);
+    }
+
+    #[test]
+    fn shot_caught_by_goalkeeper_is_saved() {
+        let mut timeline = MatchTimeline::new();
+
+        timeline.ball_owner(0, HOME, 10, false);
+        timeline.shot(1_000, HOME, 10, 0.2);
+        timeline.ball_owner(1_500, AWAY, 20, true);
+
+        assert_eq!(shot_outcome(&timeline), ShotOutcome::Saved);
+        assert!(
+            timeline
+                .events()
+                .iter()
+                .any(|event| event.kind == MatchTimelineEventKind::Save && event.team_id == AWAY)
+        );
+    }
+
+    #[test]
+    fn shot_resolved_by_outfield_player_or_goal() {
+        let mut blocked = MatchTimeline::new();
+        blocked.shot(1_000, HOME, 10, 0.2);
+        blocked.ball_owner(1_100, AWAY, 21, false);
+        assert_eq!(shot_outcome(&blocked), ShotOutcome::Blocked);
+
+        let mut scored = MatchTimeline::new();
+        scored.shot(1_000, HOME, 10, 0.2);
+        scored.goal(1_200, HOME, 10, false);
+        assert_eq!(shot_outcome(&scored), ShotOutcome::Goal);
+
+        let mut wide = MatchTimeline::new();
+        wide.shot(1_000, HOME, 10, 0.2);
+        wide.ball_owner(1_000 + SHOT_RESOLUTION_MS + 10, AWAY, 20, true);
+        assert_eq!(shot_outcome(&wide), ShotOutcome::OffTarget);
+    }
+
+    #[test]
+    fn possession_change_recorded_only_when_team_changes() {
+        let mut timeline = MatchTimeline::new();
+
+        timeline.ball_owner(0, HOME, 10, false);
+        timeline.ball_owner(10, HOME, 11, false);
+        timeline.ball_owner(20, AWAY, 20, false);
+        timeline.ball_owner(30, AWAY, 21, false);
+
+        let changes: Vec<_> = timeline
+            .events()
+            .iter()
+            .filter(|event| event.kind == MatchTimelineEventKind::PossessionChange)
+            .collect();
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].team_id, AWAY);
+        assert_eq!(changes[0].time, 20);
+    }
+
+    #[test]
+    fn yellow_cards_and_substitutions_counted_per_player_and_team() {
+        let mut timeline = MatchTimeline::new();
+
+        timeline.card(100, HOME, 10, MatchCard::Yellow);
+        timeline.card(200, HOME, 11, MatchCard::Red);
+        timeline.card(300, HOME, 10, MatchCard::Yellow);
+        timeline.substitution(400, HOME, 15, 12);
+        timeline.substitution(500, AWAY, 25, 22);
+        timeline.substitution(600, HOME, 16, 13);
+
+        assert_eq!(timeline.yellow_cards(10), 2);
+        assert_eq!(timeline.yellow_cards(11), 0);
+        assert_eq!(timeline.substitutions(HOME), 2);
+        assert_eq!(timeline.substitutions(AWAY), 1);
+    }
+}
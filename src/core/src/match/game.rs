@@ -1,5 +1,5 @@
 use super::engine::FootballEngine;
-use crate::r#match::{MatchResult, MatchSquad};
+use crate::r#match::{LiveMatch, MatchResult, MatchResultRaw, MatchSquad};
 use log::debug;
 
 #[derive(Debug, Clone)]
@@ -9,6 +9,7 @@ pub struct Match {
     league_slug: String,
     pub home_squad: MatchSquad,
     pub away_squad: MatchSquad,
+    pub attendance: u32,
 }
 
 impl Match {
@@ -25,36 +26,47 @@ impl Match {
             league_slug: String::from(league_slug),
             home_squad,
             away_squad,
+            attendance: 0,
         }
     }
 
+    pub fn with_attendance(mut self, attendance: u32) -> Self {
+        self.attendance = attendance;
+        self
+    }
+
     pub fn play(self) -> MatchResult {
-        let home_team_id = self.home_squad.team_id;
-        let home_team_name = String::from(&self.home_squad.team_name);
+        let match_result =
+            FootballEngine::<840, 545>::play(self.home_squad.clone(), self.away_squad.clone());
 
-        let away_team_id = self.away_squad.team_id;
-        let away_team_name = String::from(&self.away_squad.team_name);
+        self.result(match_result)
+    }
 
-        let match_result = FootballEngine::<840, 545>::play(self.home_squad, self.away_squad);
+    /// The match to play a tick at a time, `result` turns the finished match into this match's result
+    pub fn live(&self) -> LiveMatch<840, 545> {
+        LiveMatch::new(self.home_squad.clone(), self.away_squad.clone())
+    }
 
+    pub fn result(self, match_result: MatchResultRaw) -> MatchResult {
         let score = match_result.score.as_ref().expect("no score");
 
         debug!(
             "match played: {} {}:{} {}",
-            home_team_name,
+            self.home_squad.team_name,
             score.home_team.get(),
-            away_team_name,
+            self.away_squad.team_name,
             score.away_team.get(),
         );
 
         MatchResult {
             id: self.id,
             league_id: self.league_id,
-            league_slug: String::from(&self.league_slug),
-            home_team_id,
-            away_team_id,
+            league_slug: self.league_slug,
+            home_team_id: self.home_squad.team_id,
+            away_team_id: self.away_squad.team_id,
             score: score.clone(),
             details: Some(match_result),
+            attendance: self.attendance,
         }
     }
 }
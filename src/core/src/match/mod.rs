@@ -1,15 +1,19 @@
+pub mod commentary;
 pub mod engine;
 
 pub mod game;
 
+pub mod replay;
 pub mod result;
 
 pub mod squad;
 pub mod state;
 
+pub use commentary::*;
 pub use engine::*;
 pub use game::*;
 
+pub use replay::*;
 pub use result::*;
 pub use squad::*;
 pub use state::*;
@@ -0,0 +1,137 @@
+use super::varint::VarintReader;
+use super::{FLAG_TRACK_EVENTS, REPLAY_FORMAT_VERSION, REPLAY_MAGIC, dequantize};
+use crate::r#match::{PassEventData, ResultMatchPositionData, ResultPositionDataItem};
+use nalgebra::Vector3;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum ReplayError {
+    /// Not a replay file
+    InvalidHeader,
+    /// Written by a newer build than this one
+    UnsupportedVersion(u8),
+    Truncated,
+    Corrupted,
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::InvalidHeader => write!(f, "not a replay"),
+            ReplayError::UnsupportedVersion(version) => write!(
+                f,
+                "replay version {} is newer than the supported version {}",
+                version, REPLAY_FORMAT_VERSION
+            ),
+            ReplayError::Truncated => write!(f, "replay is truncated"),
+            ReplayError::Corrupted => write!(f, "replay is corrupted"),
+        }
+    }
+}
+
+/// Reads replays written by `ReplayEncoder`
+pub struct ReplayDecoder;
+
+impl ReplayDecoder {
+    pub fn decode(data: &[u8]) -> Result<ResultMatchPositionData, ReplayError> {
+        let mut reader = VarintReader::new(data);
+
+        match reader.read_bytes(REPLAY_MAGIC.len()) {
+            Ok(magic) if magic == REPLAY_MAGIC => {}
+            _ => return Err(ReplayError::InvalidHeader),
+        }
+
+        let version = reader.read_u8()?;
+
+        if version > REPLAY_FORMAT_VERSION {
+            return Err(ReplayError::UnsupportedVersion(version));
+        }
+
+        let flags = reader.read_u8()?;
+
+        let scale = reader.read_varint()? as u32;
+        let keyframe_interval = reader.read_varint()? as usize;
+
+        if scale == 0 || keyframe_interval == 0 {
+            return Err(ReplayError::Corrupted);
+        }
+
+        let mut result = if flags & FLAG_TRACK_EVENTS != 0 {
+            ResultMatchPositionData::new_with_tracking()
+        } else {
+            ResultMatchPositionData::new()
+        };
+
+        result.ball = Self::read_track(&mut reader, scale, keyframe_interval)?;
+
+        let player_count = reader.read_varint()? as usize;
+
+        result.players = HashMap::with_capacity(player_count);
+
+        for _ in 0..player_count {
+            let player_id = reader.read_varint()? as u32;
+            let track = Self::read_track(&mut reader, scale, keyframe_interval)?;
+
+            result.players.insert(player_id, track);
+        }
+
+        let pass_count = reader.read_varint()? as usize;
+
+        let mut timestamp = 0;
+
+        for _ in 0..pass_count {
+            timestamp = reader.read_varint()?.saturating_add(timestamp);
+
+            let from_player_id = reader.read_varint()? as u32;
+            let to_player_id = reader.read_varint()? as u32;
+
+            result
+                .passes
+                .push(PassEventData::new(timestamp, from_player_id, to_player_id));
+        }
+
+        Ok(result)
+    }
+
+    fn read_track(
+        reader: &mut VarintReader,
+        scale: u32,
+        keyframe_interval: usize,
+    ) -> Result<Vec<ResultPositionDataItem>, ReplayError> {
+        let count = reader.read_varint()? as usize;
+
+        // Every sample takes at least four bytes, don't trust the count beyond that
+        let mut track = Vec::with_capacity(count.min(reader.remaining() / 4));
+
+        let mut timestamp = 0;
+        let mut position = [0i64; 3];
+
+        for idx in 0..count {
+            if idx % keyframe_interval == 0 {
+                timestamp = reader.read_varint()?;
+
+                for axis in position.iter_mut() {
+                    *axis = reader.read_signed_varint()?;
+                }
+            } else {
+                timestamp = reader.read_varint()?.saturating_add(timestamp);
+
+                for axis in position.iter_mut() {
+                    *axis = axis.wrapping_add(reader.read_signed_varint()?);
+                }
+            }
+
+            track.push(ResultPositionDataItem::new(
+                timestamp,
+                Vector3::new(
+                    dequantize(position[0], scale),
+                    dequantize(position[1], scale),
+                    dequantize(position[2], scale),
+                ),
+            ));
+        }
+
+        Ok(track)
+    }
+}
@@ -0,0 +1,84 @@
+use super::varint::{write_signed_varint, write_varint};
+use super::{
+    FLAG_TRACK_EVENTS, REPLAY_FORMAT_VERSION, REPLAY_KEYFRAME_INTERVAL, REPLAY_MAGIC,
+    REPLAY_POSITION_SCALE, quantize_steps,
+};
+use crate::r#match::{ResultMatchPositionData, ResultPositionDataItem};
+
+/// Writes recorded positions in the binary replay format
+pub struct ReplayEncoder;
+
+impl ReplayEncoder {
+    pub fn encode(data: &ResultMatchPositionData) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(data.ball.len() * 4);
+
+        buffer.extend_from_slice(REPLAY_MAGIC);
+        buffer.push(REPLAY_FORMAT_VERSION);
+        buffer.push(if data.is_tracking_events() {
+            FLAG_TRACK_EVENTS
+        } else {
+            0
+        });
+
+        write_varint(&mut buffer, REPLAY_POSITION_SCALE as u64);
+        write_varint(&mut buffer, REPLAY_KEYFRAME_INTERVAL as u64);
+
+        Self::write_track(&mut buffer, &data.ball);
+
+        let mut player_ids: Vec<u32> = data.players.keys().copied().collect();
+        player_ids.sort_unstable();
+
+        write_varint(&mut buffer, player_ids.len() as u64);
+
+        for player_id in player_ids {
+            write_varint(&mut buffer, player_id as u64);
+            Self::write_track(&mut buffer, &data.players[&player_id]);
+        }
+
+        write_varint(&mut buffer, data.passes.len() as u64);
+
+        let mut last_timestamp = 0;
+
+        for pass in &data.passes {
+            write_varint(&mut buffer, pass.timestamp.saturating_sub(last_timestamp));
+            write_varint(&mut buffer, pass.from_player_id as u64);
+            write_varint(&mut buffer, pass.to_player_id as u64);
+
+            last_timestamp = pass.timestamp;
+        }
+
+        buffer
+    }
+
+    fn write_track(buffer: &mut Vec<u8>, track: &[ResultPositionDataItem]) {
+        write_varint(buffer, track.len() as u64);
+
+        let mut last_timestamp = 0;
+        let mut last_position = [0i64; 3];
+
+        for (idx, item) in track.iter().enumerate() {
+            let position = [
+                quantize_steps(item.position.x, REPLAY_POSITION_SCALE),
+                quantize_steps(item.position.y, REPLAY_POSITION_SCALE),
+                quantize_steps(item.position.z, REPLAY_POSITION_SCALE),
+            ];
+
+            if idx % REPLAY_KEYFRAME_INTERVAL as usize == 0 {
+                write_varint(buffer, item.timestamp);
+
+                for axis in position {
+                    write_signed_varint(buffer, axis);
+                }
+            } else {
+                write_varint(buffer, item.timestamp.saturating_sub(last_timestamp));
+
+                for (axis, last_axis) in position.iter().zip(last_position) {
+                    write_signed_varint(buffer, axis - last_axis);
+                }
+            }
+
+            last_timestamp = item.timestamp;
+            last_position = position;
+        }
+    }
+}
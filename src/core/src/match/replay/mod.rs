@@ -0,0 +1,130 @@
+mod decoder;
+mod encoder;
+mod varint;
+
+pub use decoder::*;
+pub use encoder::*;
+
+/// Opens every replay, JSON exports start with `{`
+pub const REPLAY_MAGIC: &[u8; 4] = b"OFRP";
+
+/// Layout of version 1, after the magic:
+///
+/// - version, u8
+/// - flags, u8, bit 0 set when passes are tracked
+/// - position scale, varint, quantization steps per field unit
+/// - keyframe interval, varint, samples between absolute values
+/// - ball track
+/// - player count, varint, then a varint id and a track for each player
+/// - pass count, varint, then timestamp delta, from and to ids as varints
+///
+/// A track is a varint sample count followed by the samples. Keyframe samples
+/// hold the absolute timestamp and zigzag position, the samples between
+/// keyframes hold deltas from the previous one.
+pub const REPLAY_FORMAT_VERSION: u8 = 1;
+
+/// Positions are kept in hundredths of a field unit
+pub const REPLAY_POSITION_SCALE: u32 = 100;
+
+pub const REPLAY_KEYFRAME_INTERVAL: u32 = 256;
+
+const FLAG_TRACK_EVENTS: u8 = 1;
+
+/// Rounds a coordinate to the precision replays are stored with
+#[inline]
+pub fn quantize(value: f32) -> f32 {
+    dequantize(
+        quantize_steps(value, REPLAY_POSITION_SCALE),
+        REPLAY_POSITION_SCALE,
+    )
+}
+
+#[inline]
+fn quantize_steps(value: f32, scale: u32) -> i64 {
+    (value * scale as f32).round() as i64
+}
+
+#[inline]
+fn dequantize(steps: i64, scale: u32) -> f32 {
+    steps as f32 / scale as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r#match::ResultMatchPositionData;
+    use nalgebra::Vector3;
+
+    fn recorded_match() -> ResultMatchPositionData {
+        let mut data = ResultMatchPositionData::new_with_tracking();
+
+        for tick in 0..1000u64 {
+            let timestamp = tick * 10;
+            let t = tick as f32;
+
+            data.add_ball_positions(
+                timestamp,
+                Vector3::new(420.0 + t * 0.137, 272.5 - t * 0.05, (t * 0.01).sin().abs()),
+            );
+            data.add_player_positions(1, timestamp, Vector3::new(100.0 + t * 0.3, 200.0, 0.0));
+            data.add_player_positions(
+                2,
+                timestamp,
+                Vector3::new(700.0 - t * 0.21, 150.0 + (tick % 7) as f32, 0.0),
+            );
+            // on the bench all match
+            data.add_player_positions(3, timestamp, Vector3::new(-10.0, -10.0, 0.0));
+
+            if tick % 97 == 0 {
+                data.add_pass_event(timestamp, 1, 2);
+            }
+        }
+
+        data.compress();
+        data
+    }
+
+    #[test]
+    fn replay_round_trips_compressed_data() {
+        let data = recorded_match();
+
+        let encoded = ReplayEncoder::encode(&data);
+        let decoded = ReplayDecoder::decode(&encoded).unwrap();
+
+        assert_eq!(data.ball, decoded.ball);
+        assert_eq!(data.players, decoded.players);
+        assert_eq!(data.passes.len(), decoded.passes.len());
+        assert!(decoded.is_tracking_events());
+    }
+
+    #[test]
+    fn compress_drops_players_who_never_moved() {
+        let data = recorded_match();
+
+        assert!(data.players.contains_key(&1));
+        assert!(!data.players.contains_key(&3));
+    }
+
+    #[test]
+    fn unknown_replays_are_rejected() {
+        let mut encoded = ReplayEncoder::encode(&recorded_match());
+
+        assert!(matches!(
+            ReplayDecoder::decode(b"JSON"),
+            Err(ReplayError::InvalidHeader)
+        ));
+
+        encoded[4] = REPLAY_FORMAT_VERSION + 1;
+        assert!(matches!(
+            ReplayDecoder::decode(&encoded),
+            Err(ReplayError::UnsupportedVersion(_))
+        ));
+
+        encoded[4] = REPLAY_FORMAT_VERSION;
+        encoded.truncate(encoded.len() / 2);
+        assert!(matches!(
+            ReplayDecoder::decode(&encoded),
+            Err(ReplayError::Truncated)
+        ));
+    }
+}
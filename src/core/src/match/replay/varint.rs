@@ -0,0 +1,66 @@
+use crate::r#match::ReplayError;
+
+pub(super) fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+
+    buffer.push(value as u8);
+}
+
+pub(super) fn write_signed_varint(buffer: &mut Vec<u8>, value: i64) {
+    write_varint(buffer, ((value << 1) ^ (value >> 63)) as u64);
+}
+
+pub(super) struct VarintReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> VarintReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        VarintReader { data, position: 0 }
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.position)
+    }
+
+    pub fn read_bytes(&mut self, count: usize) -> Result<&'a [u8], ReplayError> {
+        let bytes = self
+            .data
+            .get(self.position..self.position + count)
+            .ok_or(ReplayError::Truncated)?;
+
+        self.position += count;
+
+        Ok(bytes)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, ReplayError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub fn read_varint(&mut self) -> Result<u64, ReplayError> {
+        let mut value = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_u8()?;
+
+            value |= ((byte & 0x7F) as u64) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(ReplayError::Corrupted)
+    }
+
+    pub fn read_signed_varint(&mut self) -> Result<i64, ReplayError> {
+        let value = self.read_varint()?;
+
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+}
@@ -1,4 +1,5 @@
-﻿use nalgebra::Vector3;
+﻿use crate::r#match::quantize;
+use nalgebra::Vector3;
 use serde::Serialize;
 use std::collections::HashMap;
 
@@ -42,9 +43,9 @@ impl PartialEq<ResultPositionDataItem> for ResultPositionDataItem {
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ResultMatchPositionData {
-    ball: Vec<ResultPositionDataItem>,
-    players: HashMap<u32, Vec<ResultPositionDataItem>>,
-    passes: Vec<PassEventData>,
+    pub(crate) ball: Vec<ResultPositionDataItem>,
+    pub(crate) players: HashMap<u32, Vec<ResultPositionDataItem>>,
+    pub(crate) passes: Vec<PassEventData>,
     #[serde(skip)]
     track_events: bool,
 }
@@ -68,7 +69,29 @@ impl ResultMatchPositionData {
         }
     }
 
-    pub fn compress(&mut self) {}
+    /// Rounds positions to the replay precision. Player samples that no longer change
+    /// anything are dropped, as are players who never moved, like unused substitutes.
+    /// The ball keeps every tick, it times the replay.
+    pub fn compress(&mut self) {
+        Self::quantize_track(&mut self.ball);
+
+        self.players.retain(|_, track| {
+            Self::quantize_track(track);
+            track.dedup_by(|item, previous| item.position == previous.position);
+
+            track.len() > 1
+        });
+    }
+
+    fn quantize_track(track: &mut [ResultPositionDataItem]) {
+        for item in track.iter_mut() {
+            item.position = Vector3::new(
+                quantize(item.position.x),
+                quantize(item.position.y),
+                quantize(item.position.z),
+            );
+        }
+    }
 
     /// Split the data into chunks based on time ranges
     /// Returns a vector of chunks, each containing data for a specific time window
@@ -100,10 +123,25 @@ impl ResultMatchPositionData {
 
             // Filter player positions for this time window
             for (player_id, positions) in &self.players {
-                let filtered_positions: Vec<ResultPositionDataItem> = positions.iter()
-                    .filter(|item| item.timestamp >= start_time && item.timestamp < end_time)
-                    .cloned()
-                    .collect();
+                let first = positions.partition_point(|item| item.timestamp < start_time);
+                let last = positions.partition_point(|item| item.timestamp < end_time);
+
+                let mut filtered_positions: Vec<ResultPositionDataItem> = Vec::with_capacity(last - first + 1);
+
+                // Samples are only kept when a player moves, one standing still at the start of
+                // the window is where their last sample before it put them
+                let starts_with_sample = positions
+                    .get(first)
+                    .is_some_and(|item| item.timestamp == start_time);
+
+                if first > 0 && !starts_with_sample {
+                    filtered_positions.push(ResultPositionDataItem::new(
+                        start_time,
+                        positions[first - 1].position,
+                    ));
+                }
+
+                filtered_positions.extend_from_slice(&positions[first..last]);
 
                 if !filtered_positions.is_empty() {
                     chunk.players.insert(*player_id, filtered_positions);
@@ -269,3 +307,41 @@ impl VectorExtensions for Vector3<f32> {
         diff.dot(&diff).sqrt()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stationary_player_keeps_their_position_in_the_next_chunk() {
+        let mut data = ResultMatchPositionData::new();
+
+        for timestamp in (0..=2_000).step_by(10) {
+            data.add_ball_positions(timestamp, Vector3::new(timestamp as f32, 0.0, 0.0));
+            data.add_player_positions(1, timestamp, Vector3::new(5.0, 5.0, 0.0));
+            data.add_player_positions(2, timestamp, Vector3::new(timestamp as f32, 1.0, 0.0));
+        }
+
+        // Player 1 moves once in the first chunk, then stands still
+        data.add_player_positions(1, 300, Vector3::new(7.0, 7.0, 0.0));
+
+        let chunks = data.split_into_chunks(1_000);
+
+        assert_eq!(chunks.len(), 2);
+
+        let second = &chunks[1];
+
+        assert_eq!(
+            second.players.get(&1).map(|track| track.as_slice()),
+            Some([ResultPositionDataItem::new(1_000, Vector3::new(7.0, 7.0, 0.0))].as_slice())
+        );
+        assert_eq!(
+            second.get_player_position_at(1, 1_500),
+            Some(Vector3::new(7.0, 7.0, 0.0))
+        );
+
+        // A player with a sample at the start of the window gets no extra keyframe
+        assert_eq!(second.players[&2][0].timestamp, 1_000);
+        assert_eq!(second.players[&2][1].timestamp, 1_010);
+    }
+}
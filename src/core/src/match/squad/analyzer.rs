@@ -2,7 +2,6 @@ use crate::club::{PlayerPositionType, Staff};
 use crate::r#match::MatchResult;
 use crate::{MatchTacticType, Player, TacticSelectionReason, TacticalStyle, Tactics, Team};
 use log::info;
-use std::collections::HashMap;
 
 /// Enhanced tactical analysis for squad selection
 pub struct TacticalSquadAnalyzer;
@@ -92,13 +91,14 @@ impl TacticalSquadAnalyzer {
         composition: SquadComposition,
         coach_prefs: CoachPreferences,
     ) -> Option<MatchTacticType> {
-        let mut formation_scores = HashMap::new();
-
         // Score each formation based on squad composition
-        for formation in MatchTacticType::all() {
-            let score = Self::score_formation_fit(&formation, &composition, &coach_prefs);
-            formation_scores.insert(formation, score);
-        }
+        let formation_scores: Vec<(MatchTacticType, f32)> = MatchTacticType::all()
+            .into_iter()
+            .map(|formation| {
+                let score = Self::score_formation_fit(&formation, &composition, &coach_prefs);
+                (formation, score)
+            })
+            .collect();
 
         // Return the highest scoring formation
         formation_scores
@@ -1,6 +1,6 @@
 use crate::club::{PlayerPositionType, Staff};
 use crate::r#match::player::MatchPlayer;
-use crate::{Player, Tactics, Team};
+use crate::{Player, PlayerPromiseType, Tactics, Team};
 use log::{debug, warn};
 use std::borrow::Borrow;
 
@@ -9,6 +9,9 @@ pub struct SquadSelector;
 const DEFAULT_SQUAD_SIZE: usize = 11;
 const DEFAULT_BENCH_SIZE: usize = 7;
 
+/// The coach keeps their word to the players promised football
+const PROMISED_PLAYING_TIME_BONUS: f32 = 3.0;
+
 pub struct PlayerSelectionResult {
     pub main_squad: Vec<MatchPlayer>,
     pub substitutes: Vec<MatchPlayer>,
@@ -26,12 +29,16 @@ impl SquadSelector {
     pub fn select(team: &Team, staff: &Staff) -> PlayerSelectionResult {
         let current_tactics = team.tactics();
 
-        // Filter available players (not injured, not banned)
+        // Filter available players (not injured, not banned, not away with the national team)
         let available_players: Vec<&Player> = team
             .players
             .players()
             .iter()
-            .filter(|&&p| !p.player_attributes.is_injured && !p.player_attributes.is_banned)
+            .filter(|&&p| {
+                !p.player_attributes.is_injured
+                    && !p.player_attributes.is_banned
+                    && !p.is_on_international_duty()
+            })
             .map(|p| *p)
             .collect();
 
@@ -303,6 +310,10 @@ impl SquadSelector {
             rating += 2.0;
         }
 
+        if player.interactions.has_promise(PlayerPromiseType::PlayingTime) {
+            rating += PROMISED_PLAYING_TIME_BONUS;
+        }
+
         // Reputation bonus (small factor)
         rating += (player.player_attributes.world_reputation as f32 / 10000.0) * 0.05; // 5% weight
 
@@ -411,7 +422,7 @@ impl SquadSelector {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{IntegerUtils, MatchTacticType, PlayerCollection, PlayerGenerator, StaffCollection, TeamBuilder, TeamReputation, TeamType, TrainingSchedule};
+    use crate::{IntegerUtils, MatchTacticType, PlayerCollection, PlayerGenerator, PlayerPromise, StaffCollection, TeamBuilder, TeamReputation, TeamType, TrainingSchedule};
     use chrono::{NaiveTime, Utc};
 
     #[test]
@@ -444,6 +455,27 @@ mod tests {
         assert!(fit > 10.0); // Should be above base score due to attacking bonuses
     }
 
+    #[test]
+    fn test_promised_player_rated_higher() {
+        let staff = generate_test_staff();
+        let tactics = Tactics::new(MatchTacticType::T442);
+        let now = Utc::now().date_naive();
+
+        let mut player = PlayerGenerator::generate(1, now, PlayerPositionType::Striker, 18);
+
+        let rating = |player: &Player| {
+            SquadSelector::calculate_player_rating_for_position(player, &staff, PlayerPositionType::Striker, &tactics)
+        };
+
+        let before = rating(&player);
+
+        player
+            .interactions
+            .promise(PlayerPromise::playing_time(staff.id, now));
+
+        assert!((rating(&player) - before - PROMISED_PLAYING_TIME_BONUS).abs() < 0.001);
+    }
+
     // Helper functions for tests
     fn generate_test_team() -> Team {
         let mut team = TeamBuilder::new()
@@ -12,4 +12,22 @@ pub struct MatchSquad {
     pub vice_captain_id: Option<MatchPlayer>,
     pub penalty_taker_id: Option<MatchPlayer>,
     pub free_kick_taker_id: Option<MatchPlayer>,
-}
\ No newline at end of file
+}
+
+impl MatchSquad {
+    /// Crowd support lifts the mental side of the home team's game
+    pub fn apply_home_advantage(&mut self, advantage: f32) {
+        let factor = 1.0 + advantage.max(0.0);
+
+        for player in self.main_squad.iter_mut().chain(self.substitutes.iter_mut()) {
+            let mental = &mut player.skills.mental;
+
+            mental.composure = (mental.composure * factor).min(20.0);
+            mental.concentration = (mental.concentration * factor).min(20.0);
+            mental.decisions = (mental.decisions * factor).min(20.0);
+            mental.determination = (mental.determination * factor).min(20.0);
+            mental.teamwork = (mental.teamwork * factor).min(20.0);
+            mental.work_rate = (mental.work_rate * factor).min(20.0);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::news::NewsItem;
+use chrono::NaiveDate;
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+/// Stories kept in the feed, older ones drop off
+const NEWS_FEED_CAPACITY: usize = 500;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct NewsFeed {
+    items: Vec<NewsItem>,
+    next_id: u32,
+}
+
+impl NewsFeed {
+    pub fn new() -> Self {
+        NewsFeed::default()
+    }
+
+    /// Runs the story and returns the id it was given
+    pub fn publish(&mut self, mut item: NewsItem) -> u32 {
+        self.next_id += 1;
+        item.id = self.next_id;
+
+        debug!("📰 {} ({:?})", item.headline, item.importance);
+
+        self.items.push(item);
+
+        if self.items.len() > NEWS_FEED_CAPACITY {
+            let overflow = self.items.len() - NEWS_FEED_CAPACITY;
+            self.items.drain(..overflow);
+        }
+
+        self.next_id
+    }
+
+    /// Newest first
+    pub fn latest(&self) -> impl Iterator<Item = &NewsItem> {
+        self.items.iter().rev()
+    }
+
+    /// Stories run on or after the date, newest first
+    pub fn since(&self, date: NaiveDate) -> impl Iterator<Item = &NewsItem> {
+        self.latest().take_while(move |item| item.date >= date)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::news::{NewsImportance, NewsType};
+
+    fn item(day: u32) -> NewsItem {
+        NewsItem::new(
+            NewsType::BigWin,
+            NaiveDate::from_ymd_opt(2024, 9, day).unwrap(),
+            NewsImportance::Notable,
+            format!("Story of day {}", day),
+        )
+    }
+
+    #[test]
+    fn feed_keeps_the_newest_stories() {
+        let mut feed = NewsFeed::new();
+
+        for day in 1..=30 {
+            for _ in 0..20 {
+                feed.publish(item(day));
+            }
+        }
+
+        assert_eq!(NEWS_FEED_CAPACITY, feed.len());
+        assert_eq!(Some(600), feed.latest().next().map(|item| item.id));
+
+        let since = NaiveDate::from_ymd_opt(2024, 9, 29).unwrap();
+        assert_eq!(40, feed.since(since).count());
+    }
+}
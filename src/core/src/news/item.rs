@@ -0,0 +1,126 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NewsType {
+    BigWin,
+    Upset,
+    WinningStreak,
+    LosingStreak,
+    LeagueMilestone,
+    ManagerSacked,
+    RecordTransfer,
+    Injury,
+}
+
+impl NewsType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NewsType::BigWin => "big_win",
+            NewsType::Upset => "upset",
+            NewsType::WinningStreak => "winning_streak",
+            NewsType::LosingStreak => "losing_streak",
+            NewsType::LeagueMilestone => "league_milestone",
+            NewsType::ManagerSacked => "manager_sacked",
+            NewsType::RecordTransfer => "record_transfer",
+            NewsType::Injury => "injury",
+        }
+    }
+}
+
+/// How prominently the story runs, from a footnote to the front page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum NewsImportance {
+    Minor,
+    Notable,
+    Major,
+    Sensational,
+}
+
+impl NewsImportance {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NewsImportance::Minor => "minor",
+            NewsImportance::Notable => "notable",
+            NewsImportance::Major => "major",
+            NewsImportance::Sensational => "sensational",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NewsEntityType {
+    League,
+    Club,
+    Team,
+    Player,
+    Staff,
+}
+
+impl NewsEntityType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NewsEntityType::League => "league",
+            NewsEntityType::Club => "club",
+            NewsEntityType::Team => "team",
+            NewsEntityType::Player => "player",
+            NewsEntityType::Staff => "staff",
+        }
+    }
+}
+
+/// Someone the story is about, named as they were when it ran
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewsEntity {
+    pub entity_type: NewsEntityType,
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewsItem {
+    pub id: u32,
+    pub date: NaiveDate,
+    pub news_type: NewsType,
+    pub headline: String,
+    pub importance: NewsImportance,
+    /// The subject of the story comes first
+    pub entities: Vec<NewsEntity>,
+}
+
+impl NewsItem {
+    pub fn new(
+        news_type: NewsType,
+        date: NaiveDate,
+        importance: NewsImportance,
+        headline: String,
+    ) -> Self {
+        NewsItem {
+            id: 0,
+            date,
+            news_type,
+            headline,
+            importance,
+            entities: Vec::new(),
+        }
+    }
+
+    pub fn with_entity(mut self, entity_type: NewsEntityType, id: u32, name: &str) -> Self {
+        self.entities.push(NewsEntity {
+            entity_type,
+            id,
+            name: String::from(name),
+        });
+        self
+    }
+
+    pub fn subject(&self) -> Option<&NewsEntity> {
+        self.entities.first()
+    }
+
+    pub fn involves(&self, entity_type: NewsEntityType, id: u32) -> bool {
+        self.entities
+            .iter()
+            .any(|entity| entity.entity_type == entity_type && entity.id == id)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod feed;
+pub mod item;
+pub mod reporter;
+
+pub use feed::*;
+pub use item::*;
+pub use reporter::*;
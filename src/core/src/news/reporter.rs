@@ -0,0 +1,361 @@
+use crate::league::{Milestone, MilestoneType, TeamStreak};
+use crate::news::{NewsEntityType, NewsImportance, NewsItem, NewsType};
+use crate::r#match::MatchResult;
+use crate::shared::FullName;
+use crate::simulator::SimulatorData;
+use crate::transfers::CompletedTransfer;
+use crate::{Player, Staff, Team, TeamType};
+use chrono::NaiveDate;
+
+/// Goal margin that makes a win a thrashing
+const BIG_WIN_MARGIN: u8 = 4;
+
+/// Reputation the loser has to have over the winner for the result to be an upset
+const UPSET_REPUTATION_GAP: u16 = 200;
+
+/// Streaks make the news every this many matches
+const STREAK_NEWS_LENGTH: u8 = 5;
+
+/// The best players in a first team, by ability, whose injuries make the news
+const KEY_PLAYERS: usize = 3;
+
+/// Turns what happened in the simulation into news stories
+pub struct NewsReporter;
+
+impl NewsReporter {
+    /// Big wins, upsets and streaks that came out of a league match
+    pub fn match_report(result: &MatchResult, data: &SimulatorData) -> Vec<NewsItem> {
+        let mut news = Vec::new();
+
+        let (league, home, away) = match (
+            data.league(result.league_id),
+            data.team(result.score.home_team.team_id),
+            data.team(result.score.away_team.team_id),
+        ) {
+            (Some(league), Some(home), Some(away)) => (league, home, away),
+            _ => return news,
+        };
+
+        let date = data.date.date();
+
+        let home_goals = result.score.home_team.get();
+        let away_goals = result.score.away_team.get();
+
+        let winner = match home_goals.cmp(&away_goals) {
+            std::cmp::Ordering::Greater => Some((home, away, home_goals, away_goals)),
+            std::cmp::Ordering::Less => Some((away, home, away_goals, home_goals)),
+            std::cmp::Ordering::Equal => None,
+        };
+
+        if let Some((winner, loser, scored, conceded)) = winner {
+            if let Some((news_type, importance)) = Self::result_story(
+                scored,
+                conceded,
+                winner.reputation.world,
+                loser.reputation.world,
+            ) {
+                let headline = match news_type {
+                    NewsType::Upset => format!(
+                        "{} stun {} with a {}-{} win",
+                        winner.name, loser.name, scored, conceded
+                    ),
+                    _ => format!(
+                        "{} thrash {} {}-{}",
+                        winner.name, loser.name, scored, conceded
+                    ),
+                };
+
+                news.push(
+                    NewsItem::new(news_type, date, importance, headline)
+                        .with_entity(NewsEntityType::Team, winner.id, &winner.name)
+                        .with_entity(NewsEntityType::Team, loser.id, &loser.name)
+                        .with_entity(NewsEntityType::League, league.id, &league.name),
+                );
+            }
+        }
+
+        for team in [home, away] {
+            let story = league
+                .dynamics
+                .team_streaks
+                .get(&team.id)
+                .and_then(Self::streak_story);
+
+            if let Some((news_type, length, importance)) = story {
+                let headline = match news_type {
+                    NewsType::WinningStreak => {
+                        format!("{} make it {} wins in a row", team.name, length)
+                    }
+                    _ => format!("{} slump to {} straight defeats", team.name, length),
+                };
+
+                news.push(
+                    NewsItem::new(news_type, date, importance, headline)
+                        .with_entity(NewsEntityType::Team, team.id, &team.name)
+                        .with_entity(NewsEntityType::League, league.id, &league.name),
+                );
+            }
+        }
+
+        // Below the top flight and in youth football only the specialist press takes notice
+        if league.tier > 1 || home.team_type != TeamType::Main {
+            for item in &mut news {
+                item.importance = NewsImportance::Minor;
+            }
+        }
+
+        news
+    }
+
+    /// A title clinched or an unbeaten run recorded by the league
+    pub fn milestone(
+        league_id: u32,
+        milestone: &Milestone,
+        data: &SimulatorData,
+    ) -> Option<NewsItem> {
+        let league = data.league(league_id)?;
+        let team = data.team(milestone.team_id)?;
+
+        let (headline, importance) = match milestone.milestone_type {
+            MilestoneType::TitleWon => (
+                format!(
+                    "{} crowned champions after {} matches",
+                    team.name, milestone.matches_played
+                ),
+                NewsImportance::Sensational,
+            ),
+            MilestoneType::UnbeatenRun => (
+                format!(
+                    "{} still unbeaten after {} matches",
+                    team.name, milestone.matches_played
+                ),
+                NewsImportance::Notable,
+            ),
+            _ => (
+                format!("{}: {}", team.name, milestone.description),
+                NewsImportance::Notable,
+            ),
+        };
+
+        let importance = if league.tier > 1 || team.team_type != TeamType::Main {
+            NewsImportance::Minor
+        } else {
+            importance
+        };
+
+        Some(
+            NewsItem::new(
+                NewsType::LeagueMilestone,
+                data.date.date(),
+                importance,
+                headline,
+            )
+            .with_entity(NewsEntityType::Team, team.id, &team.name)
+            .with_entity(NewsEntityType::League, league.id, &league.name),
+        )
+    }
+
+    pub fn manager_sacked(
+        club_id: u32,
+        club_name: &str,
+        manager: &Staff,
+        date: NaiveDate,
+    ) -> NewsItem {
+        let manager_name = Self::person_name(&manager.full_name);
+
+        NewsItem::new(
+            NewsType::ManagerSacked,
+            date,
+            NewsImportance::Major,
+            format!("{} sack manager {}", club_name, manager_name),
+        )
+        .with_entity(NewsEntityType::Club, club_id, club_name)
+        .with_entity(NewsEntityType::Staff, manager.id, &manager_name)
+    }
+
+    /// The most a club in the country has ever paid for a player
+    pub fn record_transfer(transfer: &CompletedTransfer, data: &SimulatorData) -> Option<NewsItem> {
+        let player = data.player(transfer.player_id)?;
+        let buyer = data.club(transfer.to_club_id)?;
+        let seller = data.club(transfer.from_club_id)?;
+
+        let player_name = Self::person_name(&player.full_name);
+
+        let headline = format!(
+            "{} break the transfer record to sign {} from {} for {}",
+            buyer.name,
+            player_name,
+            seller.name,
+            Self::fee(transfer.fee.amount)
+        );
+
+        Some(
+            NewsItem::new(
+                NewsType::RecordTransfer,
+                transfer.transfer_date,
+                NewsImportance::Major,
+                headline,
+            )
+            .with_entity(NewsEntityType::Player, player.id, &player_name)
+            .with_entity(NewsEntityType::Club, buyer.id, &buyer.name)
+            .with_entity(NewsEntityType::Club, seller.id, &seller.name),
+        )
+    }
+
+    /// Injuries to internationals and to the key players of a first team
+    pub fn injury(
+        player_id: u32,
+        international: bool,
+        date: NaiveDate,
+        data: &SimulatorData,
+    ) -> Option<NewsItem> {
+        let player = data.player(player_id)?;
+        let (_, _, _, team_id) = data.indexes.as_ref()?.get_player_location(player_id)?;
+        let team = data.team(team_id)?;
+
+        if !international && !Self::is_key_player(team, player) {
+            return None;
+        }
+
+        let player_name = Self::person_name(&player.full_name);
+
+        let (headline, importance) = if international {
+            (
+                format!("{} injured on international duty", player_name),
+                NewsImportance::Notable,
+            )
+        } else {
+            (
+                format!("{} injured in training", player_name),
+                NewsImportance::Minor,
+            )
+        };
+
+        Some(
+            NewsItem::new(NewsType::Injury, date, importance, headline)
+                .with_entity(NewsEntityType::Player, player.id, &player_name)
+                .with_entity(NewsEntityType::Team, team.id, &team.name),
+        )
+    }
+
+    /// Upsets go by the gap in reputation, thrashings by the goal margin
+    fn result_story(
+        scored: u8,
+        conceded: u8,
+        winner_reputation: u16,
+        loser_reputation: u16,
+    ) -> Option<(NewsType, NewsImportance)> {
+        let reputation_gap = loser_reputation.saturating_sub(winner_reputation);
+        let margin = scored.saturating_sub(conceded);
+
+        if reputation_gap >= UPSET_REPUTATION_GAP {
+            let importance = if reputation_gap >= UPSET_REPUTATION_GAP * 2 {
+                NewsImportance::Major
+            } else {
+                NewsImportance::Notable
+            };
+
+            return Some((NewsType::Upset, importance));
+        }
+
+        if margin >= BIG_WIN_MARGIN {
+            let importance = if margin >= BIG_WIN_MARGIN + 2 {
+                NewsImportance::Major
+            } else {
+                NewsImportance::Notable
+            };
+
+            return Some((NewsType::BigWin, importance));
+        }
+
+        None
+    }
+
+    /// Unbeaten runs are reported by the league milestones
+    fn streak_story(streak: &TeamStreak) -> Option<(NewsType, u8, NewsImportance)> {
+        let (news_type, length) = if streak.winning_streak > 0 {
+            (NewsType::WinningStreak, streak.winning_streak)
+        } else {
+            (NewsType::LosingStreak, streak.losing_streak)
+        };
+
+        if length < STREAK_NEWS_LENGTH || length % STREAK_NEWS_LENGTH != 0 {
+            return None;
+        }
+
+        let importance = if length >= STREAK_NEWS_LENGTH * 2 {
+            NewsImportance::Major
+        } else {
+            NewsImportance::Notable
+        };
+
+        Some((news_type, length, importance))
+    }
+
+    fn is_key_player(team: &Team, player: &Player) -> bool {
+        if team.team_type != TeamType::Main {
+            return false;
+        }
+
+        let better_players = team
+            .players
+            .players
+            .iter()
+            .filter(|other| {
+                other.player_attributes.current_ability > player.player_attributes.current_ability
+            })
+            .count();
+
+        better_players < KEY_PLAYERS
+    }
+
+    fn person_name(full_name: &FullName) -> String {
+        format!("{} {}", full_name.first_name, full_name.last_name)
+    }
+
+    fn fee(amount: f64) -> String {
+        if amount >= 1_000_000.0 {
+            format!("${:.1}M", amount / 1_000_000.0)
+        } else {
+            format!("${:.0}K", amount / 1_000.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upsets_and_thrashings_make_the_news() {
+        assert_eq!(
+            Some((NewsType::Upset, NewsImportance::Notable)),
+            NewsReporter::result_story(1, 0, 400, 700)
+        );
+        assert_eq!(
+            Some((NewsType::BigWin, NewsImportance::Major)),
+            NewsReporter::result_story(7, 1, 800, 500)
+        );
+        assert_eq!(None, NewsReporter::result_story(2, 1, 600, 650));
+    }
+
+    #[test]
+    fn streaks_are_reported_every_five_matches() {
+        let streak = |winning_streak, losing_streak| TeamStreak {
+            winning_streak,
+            losing_streak,
+            unbeaten_streak: winning_streak,
+        };
+
+        assert_eq!(
+            Some((NewsType::WinningStreak, 5, NewsImportance::Notable)),
+            NewsReporter::streak_story(&streak(5, 0))
+        );
+        assert_eq!(
+            Some((NewsType::LosingStreak, 10, NewsImportance::Major)),
+            NewsReporter::streak_story(&streak(0, 10))
+        );
+        assert_eq!(None, NewsReporter::streak_story(&streak(6, 0)));
+        assert_eq!(None, NewsReporter::streak_story(&streak(0, 0)));
+    }
+}
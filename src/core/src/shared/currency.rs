@@ -1,4 +1,6 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurrencyValue {
     pub amount: f64,
     pub currency: Currency,
@@ -10,7 +12,7 @@ impl CurrencyValue {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Currency {
     Usd,
 }
@@ -1,6 +1,7 @@
 use std::fmt::{Display, Formatter, Result};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FullName {
     pub first_name: String,
     pub last_name: String,
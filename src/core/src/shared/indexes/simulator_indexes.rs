@@ -67,6 +67,29 @@ impl SimulatorDataIndexes {
         }
     }
 
+    /// Players change teams and clubs while the game runs, everything else stays where it is
+    pub fn refresh_players(&mut self, data: &SimulatorData) {
+        self.player_indexes.clear();
+
+        for continent in &data.continents {
+            for country in &continent.countries {
+                for club in &country.clubs {
+                    for team in &club.teams.teams {
+                        for player in &team.players.players {
+                            self.add_player_location(
+                                player.id,
+                                continent.id,
+                                country.id,
+                                club.id,
+                                team.id,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     //league indexes
     pub fn add_league_location(&mut self, league_id: u32, continent_id: u32, country_id: u32) {
         self.league_indexes
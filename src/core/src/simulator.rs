@@ -1,12 +1,15 @@
 use crate::context::{GlobalContext, SimulationContext};
 use crate::continent::{Continent, ContinentResult};
+use crate::country::{InternationalTournaments, NationalTournament};
 use crate::league::League;
-use crate::r#match::MatchResult;
+use crate::news::NewsFeed;
+use crate::r#match::{Match, MatchResult};
 use crate::shared::{SimulatorDataIndexes, TeamData};
-use crate::transfers::TransferPool;
+use crate::transfers::{FreeAgentPool, ManagerMarket, TransferPool};
 use crate::utils::Logging;
 use crate::{Club, Country, Player, Team};
 use chrono::{Duration, NaiveDateTime};
+use serde::{Deserialize, Serialize};
 
 pub struct FootballSimulator;
 
@@ -26,10 +29,17 @@ impl FootballSimulator {
                     .map(|continent| continent.simulate(ctx.with_continent(continent.id)))
                     .collect();
 
+                // Clubs move players between their squads during the day
+                if results.iter().any(ContinentResult::squads_changed) {
+                    data.refresh_player_indexes();
+                }
+
                 for continent_result in results {
                     continent_result.process(data, &mut result);
                 }
 
+                InternationalTournaments::process(data);
+
                 data.next_date();
             },
             &format!("simulate date {}", current_data),
@@ -37,8 +47,50 @@ impl FootballSimulator {
 
         result
     }
+
+    /// A league fixture still to be played, set up as its matchday would, for playing it live
+    pub fn fixture(data: &SimulatorData, league_id: u32, fixture_id: &str) -> Option<Match> {
+        let country = data.country(data.league(league_id)?.country_id)?;
+
+        country
+            .leagues
+            .leagues
+            .iter()
+            .find(|league| league.id == league_id)?
+            .fixture_match(fixture_id, &country.clubs, data.date.date())
+    }
+
+    /// Counts a fixture played outside the daily simulation as if its matchday had played it.
+    /// Nothing is recorded when the fixture was played in the meantime
+    pub fn record_fixture(data: &mut SimulatorData, match_result: MatchResult) -> SimulationResult {
+        let mut result = SimulationResult::new();
+
+        let ctx = GlobalContext::new(SimulationContext::new(data.date));
+
+        let league_result = data
+            .league(match_result.league_id)
+            .map(|league| league.country_id)
+            .and_then(|country_id| data.country_mut(country_id))
+            .and_then(|country| {
+                let clubs = &country.clubs;
+
+                country
+                    .leagues
+                    .leagues
+                    .iter_mut()
+                    .find(|league| league.id == match_result.league_id)
+                    .and_then(|league| league.record_fixture_result(match_result, clubs, &ctx))
+            });
+
+        if let Some(league_result) = league_result {
+            league_result.process(data, &mut result);
+        }
+
+        result
+    }
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct SimulatorData {
     pub continents: Vec<Continent>,
 
@@ -46,6 +98,16 @@ pub struct SimulatorData {
 
     pub transfer_pool: TransferPool<Player>,
 
+    pub manager_market: ManagerMarket,
+
+    pub free_agents: FreeAgentPool,
+
+    pub national_tournaments: Vec<NationalTournament>,
+
+    pub news: NewsFeed,
+
+    /// Rebuilt from the world after loading
+    #[serde(skip)]
     pub indexes: Option<SimulatorDataIndexes>,
 
     pub match_played: bool
@@ -57,23 +119,39 @@ impl SimulatorData {
             continents,
             date,
             transfer_pool: TransferPool::new(),
+            manager_market: ManagerMarket::new(),
+            free_agents: FreeAgentPool::new(),
+            national_tournaments: Vec::new(),
+            news: NewsFeed::new(),
             indexes: None,
             match_played: false
         };
 
-        let mut indexes = SimulatorDataIndexes::new();
+        data.refresh_indexes();
 
-        indexes.refresh(&data);
+        data
+    }
 
-        data.indexes = Some(indexes);
+    /// Builds the lookup indexes from scratch, as after loading a save
+    pub fn refresh_indexes(&mut self) {
+        let mut indexes = SimulatorDataIndexes::new();
 
-        data
+        indexes.refresh(self);
+
+        self.indexes = Some(indexes);
     }
 
     pub fn next_date(&mut self) {
         self.date += Duration::days(1);
     }
 
+    pub fn refresh_player_indexes(&mut self) {
+        if let Some(mut indexes) = self.indexes.take() {
+            indexes.refresh_players(self);
+            self.indexes = Some(indexes);
+        }
+    }
+
     pub fn continent(&self, id: u32) -> Option<&Continent> {
         self.continents.iter().find(|c| c.id == id)
     }
@@ -0,0 +1,89 @@
+use crate::{Person, Player, PlayerFieldPositionGroup};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Players without a club, released by their clubs or out of contract
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FreeAgentPool {
+    pub players: Vec<Player>,
+}
+
+impl FreeAgentPool {
+    pub fn new() -> Self {
+        FreeAgentPool {
+            players: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, mut player: Player) {
+        player.contract = None;
+        self.players.push(player);
+    }
+
+    pub fn take(&mut self, player_id: u32) -> Option<Player> {
+        let index = self.players.iter().position(|player| player.id == player_id)?;
+        Some(self.players.remove(index))
+    }
+
+    pub fn get(&self, player_id: u32) -> Option<&Player> {
+        self.players.iter().find(|player| player.id == player_id)
+    }
+
+    /// The player of a country with the most potential in the position group, no older than `max_age`
+    pub fn best(
+        &self,
+        country_id: u32,
+        group: PlayerFieldPositionGroup,
+        max_age: Option<u8>,
+        date: NaiveDate,
+    ) -> Option<u32> {
+        self.players
+            .iter()
+            .filter(|player| player.country_id == country_id)
+            .filter(|player| player.position().position_group() == group)
+            .filter(|player| max_age.is_none_or(|max_age| player.age(date) <= max_age))
+            .max_by_key(|player| (player.player_attributes.potential_ability, player.id))
+            .map(|player| player.id)
+    }
+}
+
+impl Default for FreeAgentPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PlayerGenerator, PlayerPositionType};
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 7, 1).unwrap()
+    }
+
+    fn player(id: u32, country_id: u32, position: PlayerPositionType, born: i32, potential: u8) -> Player {
+        let mut player = PlayerGenerator::generate(country_id, date(), position, 20);
+        player.id = id;
+        player.birth_date = NaiveDate::from_ymd_opt(born, 1, 1).unwrap();
+        player.player_attributes.potential_ability = potential;
+        player
+    }
+
+    #[test]
+    fn best_free_agent_fits_the_country_position_and_age() {
+        let mut pool = FreeAgentPool::new();
+
+        pool.add(player(1, 1, PlayerPositionType::Striker, 2006, 90));
+        pool.add(player(2, 1, PlayerPositionType::Striker, 2000, 120));
+        pool.add(player(3, 2, PlayerPositionType::Striker, 2006, 130));
+        pool.add(player(4, 1, PlayerPositionType::Goalkeeper, 2006, 140));
+
+        let forward = PlayerFieldPositionGroup::Forward;
+
+        assert_eq!(pool.best(1, forward, None, date()), Some(2));
+        assert_eq!(pool.best(1, forward, Some(21), date()), Some(1));
+        assert_eq!(pool.best(3, forward, None, date()), None);
+        assert!(pool.get(1).is_some_and(|player| player.contract.is_none()));
+    }
+}
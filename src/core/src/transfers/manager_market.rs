@@ -0,0 +1,36 @@
+use crate::Staff;
+use serde::{Deserialize, Serialize};
+
+/// Coaches without a club, available to any club looking for a manager
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ManagerMarket {
+    pub unemployed: Vec<Staff>,
+}
+
+impl ManagerMarket {
+    pub fn new() -> Self {
+        ManagerMarket {
+            unemployed: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, mut staff: Staff) {
+        staff.contract = None;
+        self.unemployed.push(staff);
+    }
+
+    pub fn take(&mut self, staff_id: u32) -> Option<Staff> {
+        let index = self.unemployed.iter().position(|staff| staff.id == staff_id)?;
+        Some(self.unemployed.remove(index))
+    }
+
+    pub fn get(&self, staff_id: u32) -> Option<&Staff> {
+        self.unemployed.iter().find(|staff| staff.id == staff_id)
+    }
+}
+
+impl Default for ManagerMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -4,8 +4,9 @@ use crate::transfers::offer::TransferOffer;
 use crate::transfers::{CompletedTransfer, TransferType};
 use chrono::NaiveDate;
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferMarket {
     pub listings: Vec<TransferListing>,
     pub negotiations: HashMap<u32, TransferNegotiation>,
@@ -15,7 +16,7 @@ pub struct TransferMarket {
 }
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferListing {
     pub player_id: u32,
     pub club_id: u32,
@@ -26,14 +27,14 @@ pub struct TransferListing {
     pub status: TransferListingStatus,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TransferListingType {
     Transfer,
     Loan,
     EndOfContract,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TransferListingStatus {
     Available,
     InNegotiation,
@@ -131,6 +132,57 @@ impl TransferMarket {
         }
     }
 
+    /// The buying club pays the release clause, the selling club has no say in the deal
+    pub fn trigger_release_clause(
+        &mut self,
+        player_id: u32,
+        selling_club_id: u32,
+        selling_team_id: u32,
+        buying_club_id: u32,
+        fee: CurrencyValue,
+        current_date: NaiveDate,
+    ) -> Option<CompletedTransfer> {
+        // Whatever was on the table before is off
+        for listing in &mut self.listings {
+            if listing.player_id == player_id
+                && (listing.status == TransferListingStatus::Available
+                    || listing.status == TransferListingStatus::InNegotiation)
+            {
+                listing.status = TransferListingStatus::Cancelled;
+            }
+        }
+
+        let mut listing = TransferListing::new(
+            player_id,
+            selling_club_id,
+            selling_team_id,
+            fee.clone(),
+            current_date,
+            TransferListingType::Transfer,
+        );
+        listing.status = TransferListingStatus::InNegotiation;
+
+        self.listings.push(listing);
+
+        let negotiation_id = self.next_negotiation_id;
+        self.next_negotiation_id += 1;
+
+        let mut negotiation = TransferNegotiation::new(
+            negotiation_id,
+            player_id,
+            (self.listings.len() - 1) as u32,
+            selling_club_id,
+            buying_club_id,
+            TransferOffer::new(fee, buying_club_id, current_date),
+            current_date,
+        );
+        negotiation.accept();
+
+        self.negotiations.insert(negotiation_id, negotiation);
+
+        self.complete_transfer(negotiation_id, current_date)
+    }
+
     pub fn complete_transfer(&mut self, negotiation_id: u32, current_date: NaiveDate) -> Option<CompletedTransfer> {
         // Find the negotiation
         if let Some(negotiation) = self.negotiations.get(&negotiation_id) {
@@ -232,4 +284,30 @@ impl TransferMarket {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::Currency;
+
+    #[test]
+    fn release_clause_completes_without_negotiation() {
+        let date = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap();
+        let fee = CurrencyValue {
+            amount: 25_000_000.0,
+            currency: Currency::Usd,
+        };
+
+        let mut market = TransferMarket::new();
+        market.add_listing(TransferListing::new(7, 1, 10, fee.clone(), date, TransferListingType::Transfer));
+
+        let completed = market.trigger_release_clause(7, 1, 10, 2, fee, date).unwrap();
+
+        assert_eq!(2, completed.to_club_id);
+        assert_eq!(25_000_000.0, completed.fee.amount);
+        assert_eq!(TransferListingStatus::Cancelled, market.listings[0].status);
+        assert_eq!(TransferListingStatus::Completed, market.listings[1].status);
+        assert!(market.get_listing_by_player(7).is_none());
+    }
+}
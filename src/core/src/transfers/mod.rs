@@ -1,10 +1,14 @@
 pub mod pool;
 pub mod transfer;
 pub mod market;
+pub mod free_agents;
+pub mod manager_market;
 pub mod negotiation;
 pub mod offer;
 pub mod window;
 
+pub use free_agents::*;
+pub use manager_market::*;
 pub use market::*;
 pub use negotiation::*;
 pub use offer::*;
@@ -1,7 +1,8 @@
 use crate::transfers::offer::TransferOffer;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferNegotiation {
     pub id: u32,
     pub player_id: u32,
@@ -15,7 +16,7 @@ pub struct TransferNegotiation {
     pub created_date: NaiveDate,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum NegotiationStatus {
     Pending,
     Accepted,
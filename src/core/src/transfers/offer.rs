@@ -1,7 +1,8 @@
 use crate::shared::CurrencyValue;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferOffer {
     pub base_fee: CurrencyValue,
     pub clauses: Vec<TransferClause>,
@@ -11,7 +12,7 @@ pub struct TransferOffer {
     pub offered_date: NaiveDate,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransferClause {
     AppearanceFee(CurrencyValue, u32), // Money after X appearances
     GoalBonus(CurrencyValue, u32),     // Money after X goals
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize)]
 pub struct TransferPool<T> {
     pool: Mutex<HashMap<u32, Vec<T>>>,
 }
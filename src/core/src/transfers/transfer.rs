@@ -1,6 +1,7 @@
 use crate::shared::CurrencyValue;
 use crate::Player;
 use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
 
 pub struct PlayerTransfer {
     pub player: Player,
@@ -13,7 +14,7 @@ impl PlayerTransfer {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompletedTransfer {
     pub player_id: u32,
     pub from_club_id: u32,
@@ -24,7 +25,7 @@ pub struct CompletedTransfer {
     pub season_year: u16,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransferType {
     Permanent,
     Loan(NaiveDate), // End date
@@ -1,5 +1,7 @@
 pub mod floats;
 mod integers;
+mod seed;
 
 pub use floats::*;
 pub use integers::*;
+pub use seed::*;
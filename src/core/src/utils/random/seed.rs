@@ -0,0 +1,129 @@
+use rand::SeedableRng;
+use rand::distr::{Distribution, StandardUniform};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore};
+use std::cell::RefCell;
+
+thread_local! {
+    static SEEDED_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+/// Makes the random utils of the current thread repeatable, the same seed gives the same numbers
+pub struct RandomSeed;
+
+impl RandomSeed {
+    pub fn set(seed: u64) {
+        SEEDED_RNG.with(|rng| *rng.borrow_mut() = Some(StdRng::seed_from_u64(seed)));
+    }
+
+    /// Back to the thread's entropy seeded generator
+    pub fn clear() {
+        SEEDED_RNG.with(|rng| *rng.borrow_mut() = None);
+    }
+
+    #[inline]
+    pub fn random<T>() -> T
+    where
+        StandardUniform: Distribution<T>,
+    {
+        SEEDED_RNG.with(|rng| match rng.borrow_mut().as_mut() {
+            Some(rng) => rng.random(),
+            None => rand::random(),
+        })
+    }
+
+    /// Generator for ranges and choices, it follows the seed of the thread it is used on
+    pub fn rng() -> SeededRng {
+        SeededRng
+    }
+
+    /// A seed for work handed to another thread, None when this thread isn't seeded
+    pub fn fork() -> Option<u64> {
+        SEEDED_RNG.with(|rng| rng.borrow_mut().as_mut().map(|rng| rng.random()))
+    }
+
+    /// Runs `f` with the forked seed, the thread's own generator is put back afterwards
+    pub fn scoped<R>(seed: Option<u64>, f: impl FnOnce() -> R) -> R {
+        let Some(seed) = seed else {
+            return f();
+        };
+
+        let previous = SEEDED_RNG.with(|rng| rng.borrow_mut().replace(StdRng::seed_from_u64(seed)));
+
+        let result = f();
+
+        SEEDED_RNG.with(|rng| *rng.borrow_mut() = previous);
+
+        result
+    }
+}
+
+/// See `RandomSeed::rng`
+pub struct SeededRng;
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        RandomSeed::random()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        RandomSeed::random()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        SEEDED_RNG.with(|rng| match rng.borrow_mut().as_mut() {
+            Some(rng) => rng.fill_bytes(dest),
+            None => rand::rng().fill_bytes(dest),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_numbers() {
+        RandomSeed::set(42);
+        let first: Vec<u32> = (0..10).map(|_| RandomSeed::random()).collect();
+
+        RandomSeed::set(42);
+        let second: Vec<u32> = (0..10).map(|_| RandomSeed::random()).collect();
+
+        RandomSeed::set(43);
+        let other: Vec<u32> = (0..10).map(|_| RandomSeed::random()).collect();
+
+        RandomSeed::clear();
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn forked_seeds_repeat_on_any_thread() {
+        let run = || {
+            RandomSeed::set(7);
+            let seeds: Vec<Option<u64>> = (0..4).map(|_| RandomSeed::fork()).collect();
+
+            let numbers: Vec<u32> = std::thread::scope(|scope| {
+                seeds
+                    .into_iter()
+                    .map(|seed| {
+                        scope.spawn(move || RandomSeed::scoped(seed, || RandomSeed::rng().random_range(0..1000)))
+                    })
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .collect()
+            });
+
+            let after: u32 = RandomSeed::random();
+            RandomSeed::clear();
+
+            (numbers, after)
+        };
+
+        assert_eq!(run(), run());
+        assert_eq!(None, RandomSeed::fork());
+    }
+}
@@ -1,6 +1,4 @@
-extern crate rand;
-
-use rand::*;
+use crate::utils::RandomSeed;
 
 pub struct StringUtils;
 
@@ -10,9 +8,9 @@ impl StringUtils {
         (0..n)
             .map(|i| {
                 if i == 0 {
-                    (65 + random::<u8>() % 26) as char
+                    (65 + RandomSeed::random::<u8>() % 26) as char
                 } else {
-                    (97 + random::<u8>() % 26) as char
+                    (97 + RandomSeed::random::<u8>() % 26) as char
                 }
             })
             .collect()
@@ -1,21 +1,25 @@
 use crate::generators::{PlayerGenerator, PositionType, StaffGenerator};
-use crate::loaders::ContinentEntity;
+use crate::loaders::{ClubEntity, ContinentEntity};
 use crate::DatabaseEntity;
 use chrono::{NaiveDate, NaiveDateTime};
 use core::club::academy::ClubAcademy;
+use core::club::scouting::ClubScouting;
 use core::context::NaiveTime;
 use core::continent::Continent;
 use core::league::LeagueCollection;
 use core::league::{DayMonthPeriod, League, LeagueSettings};
 use core::shared::Location;
-use core::utils::IntegerUtils;
+use core::utils::{FloatUtils, IntegerUtils};
 use core::ClubStatus;
 use core::TeamCollection;
 use core::{
-    Club, ClubBoard, ClubFinances, Country, CountryGeneratorData, Player,
-    PlayerCollection, SimulatorData, Staff, StaffCollection, StaffPosition, Team,
+    Club, ClubBoard, ClubFinances, ClubSponsorshipContract, ContractClause, ContractClauseType,
+    Country, Stadium, StadiumExpansion,
+    StadiumOwnership, CountryGeneratorData, NationalTeam, Player,
+    PlayerCollection, PlayerSquadStatus, SimulatorData, Staff, StaffCollection, StaffPosition, Team,
     TeamReputation, TeamType, TrainingSchedule,
 };
+use std::cmp::Reverse;
 use std::str::FromStr;
 
 pub struct DatabaseGenerator;
@@ -27,35 +31,63 @@ impl DatabaseGenerator {
             NaiveTime::default(),
         );
 
+        if let Some(max_id) = data.players.iter().map(|p| p.id).max() {
+            PlayerGenerator::reserve_ids(max_id);
+        }
+
+        if let Some(max_id) = data.staff.iter().map(|s| s.id).max() {
+            StaffGenerator::reserve_ids(max_id);
+        }
+
         let continents = data
             .continents
             .iter()
             .map(|continent| Continent::new(                
                 continent.id,
                 continent.name.clone(),
-                DatabaseGenerator::generate_countries(continent, data)
+                DatabaseGenerator::generate_countries(continent, data, current_date.date())
             )).collect();
 
-        SimulatorData::new(current_date, continents)
+        let mut simulator_data = SimulatorData::new(current_date, continents);
+
+        for manager in Self::generate_unemployed_managers(data) {
+            simulator_data.manager_market.add(manager);
+        }
+
+        simulator_data
+    }
+
+    fn generate_unemployed_managers(data: &DatabaseEntity) -> Vec<Staff> {
+        let mut managers = Vec::new();
+
+        for country in &data.countries {
+            let clubs_count = data.clubs.iter().filter(|c| c.country_id == country.id).count();
+
+            if clubs_count == 0 {
+                continue;
+            }
+
+            let generator_data = Self::country_generator_data(country.id, data);
+
+            let mut staff_generator =
+                StaffGenerator::with_people_names(&generator_data.people_names);
+
+            // Roughly one coach out of work for every two clubs
+            for _ in 0..(clubs_count / 2).max(1) {
+                managers.push(staff_generator.generate(country.id, StaffPosition::Free));
+            }
+        }
+
+        managers
     }
 
-    fn generate_countries(continent: &ContinentEntity, data: &DatabaseEntity) -> Vec<Country> {
+    fn generate_countries(continent: &ContinentEntity, data: &DatabaseEntity, date: NaiveDate) -> Vec<Country> {
         data
             .countries
             .iter()
             .filter(|cn| cn.continent_id == continent.id)
             .map(|country| {
-                let generator_data = match data
-                    .names_by_country
-                    .iter()
-                    .find(|c| c.country_id == country.id)
-                {
-                    Some(names) => CountryGeneratorData::new(
-                        names.first_names.clone(),
-                        names.last_names.clone(),
-                    ),
-                    None => CountryGeneratorData::empty(),
-                };
+                let generator_data = Self::country_generator_data(country.id, data);
 
                 let mut player_generator =
                     PlayerGenerator::with_people_names(&generator_data.people_names);
@@ -68,12 +100,19 @@ impl DatabaseGenerator {
                     data,
                     &mut player_generator,
                     &mut staff_generator,
+                    date,
                 );
 
                 let leagues = LeagueCollection::new(
                     DatabaseGenerator::generate_leagues(country.id, data)
                 );
 
+                let national_team = NationalTeam::new(
+                    country.id,
+                    country.name.clone(),
+                    Some(staff_generator.generate(country.id, StaffPosition::Manager)),
+                );
+
                 Country::builder()
                     .id(country.id)
                     .code(country.code.clone())
@@ -84,11 +123,26 @@ impl DatabaseGenerator {
                     .clubs(clubs)
                     .reputation(country.reputation)
                     .generator_data(generator_data)
+                    .national_team(national_team)
                     .build()
                     .expect("Failed to build Country")
             }).collect()
     }
 
+    fn country_generator_data(country_id: u32, data: &DatabaseEntity) -> CountryGeneratorData {
+        match data
+            .names_by_country
+            .iter()
+            .find(|c| c.country_id == country_id)
+        {
+            Some(names) => CountryGeneratorData::new(
+                names.first_names.clone(),
+                names.last_names.clone(),
+            ),
+            None => CountryGeneratorData::empty(),
+        }
+    }
+
     fn generate_leagues(country_id: u32, data: &DatabaseEntity) -> Vec<League> {
         data
             .leagues
@@ -110,7 +164,8 @@ impl DatabaseGenerator {
                     },
                 };
                 
-                League::new(league.id, league.name.clone(), league.slug.clone(), league.country_id, 0, settings)                 
+                League::new(league.id, league.name.clone(), league.slug.clone(), league.country_id, league.reputation, settings)
+                    .with_tier(league.tier)
             })
             .collect()
     }
@@ -120,6 +175,7 @@ impl DatabaseGenerator {
         data: &DatabaseEntity,
         player_generator: &mut PlayerGenerator,
         staff_generator: &mut StaffGenerator,
+        date: NaiveDate,
     ) -> Vec<Club> {
         data
             .clubs
@@ -131,21 +187,43 @@ impl DatabaseGenerator {
                 location: Location {
                     city_id: club.location.city_id,
                 },
+                stadium: Self::generate_stadium(club),
                 board: ClubBoard::new(),
                 status: ClubStatus::Professional,
-                finance: ClubFinances::new(club.finance.balance, Vec::new()),
-                academy: ClubAcademy::new(100),
+                finance: ClubFinances::new(
+                    club.finance.balance,
+                    Self::generate_sponsorship_contracts(club),
+                ),
+                academy: ClubAcademy::new(Self::academy_level(club)),
+                scouting: ClubScouting::new(),
+                season_outcome: None,
                 teams: TeamCollection::new(
                     club.teams
                         .iter()
                         .map(|t| {
+                            let team_type = TeamType::from_str(&t.team_type).unwrap();
+
+                            let imported_players: Vec<Player> = data
+                                .players
+                                .iter()
+                                .filter(|p| p.team_id == t.id)
+                                .map(|p| player_generator.import(p))
+                                .collect();
+
+                            let imported_staffs: Vec<Staff> = data
+                                .staff
+                                .iter()
+                                .filter(|s| s.team_id == t.id)
+                                .map(|s| staff_generator.import(s))
+                                .collect();
+
                             Team::builder()
                                 .id(t.id)
                                 .league_id(t.league_id)
                                 .club_id(club.id)
                                 .name(t.name.clone())
                                 .slug(t.slug.clone())
-                                .team_type(TeamType::from_str(&t.team_type).unwrap())
+                                .team_type(team_type)
                                 .training_schedule(TrainingSchedule::new(
                                     NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
                                     NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
@@ -155,13 +233,26 @@ impl DatabaseGenerator {
                                     t.reputation.national,
                                     t.reputation.world,
                                 ))
-                                .players(PlayerCollection::new(Self::generate_players(
-                                    player_generator,
-                                    country_id,
+                                .players(PlayerCollection::new(Self::assign_contract_clauses(
+                                    Self::assign_squad_statuses(
+                                        match team_type {
+                                            TeamType::Main => Self::generate_players(
+                                                player_generator,
+                                                country_id,
+                                                Self::squad_size(data, t.league_id),
+                                                imported_players,
+                                            ),
+                                            _ => Self::generate_reserve_players(player_generator, country_id, team_type, imported_players),
+                                        },
+                                        team_type,
+                                    ),
+                                    team_type,
+                                    date,
                                 )))
-                                .staffs(StaffCollection::new(
-                                    Self::generate_staffs(staff_generator, country_id)
-                                ))
+                                .staffs(StaffCollection::new(match team_type {
+                                    TeamType::Main => Self::generate_staffs(staff_generator, country_id, imported_staffs),
+                                    _ => Self::generate_reserve_staffs(staff_generator, country_id, imported_staffs),
+                                }))
                                 .build()
                                 .expect("Failed to build Team")
                         })
@@ -171,48 +262,380 @@ impl DatabaseGenerator {
             .collect()
     }
 
-    fn generate_players(player_generator: &mut PlayerGenerator, country_id: u32) -> Vec<Player> {
+    /// Bigger clubs run better academies
+    fn academy_level(club: &ClubEntity) -> u8 {
+        let home_reputation = club
+            .teams
+            .iter()
+            .map(|t| t.reputation.home)
+            .max()
+            .unwrap_or(0);
+
+        (home_reputation / 1000).clamp(1, 10) as u8
+    }
+
+    fn generate_stadium(club: &ClubEntity) -> Stadium {
+        match &club.stadium {
+            Some(stadium) => {
+                let result = Stadium::new(
+                    stadium.name.clone(),
+                    stadium.capacity,
+                    stadium.quality,
+                    StadiumOwnership::from_str(&stadium.ownership).unwrap(),
+                );
+
+                match &stadium.expansion {
+                    Some(expansion) => result.with_expansion(StadiumExpansion::new(
+                        expansion.additional_capacity,
+                        expansion.cost,
+                        NaiveDate::parse_from_str(&expansion.completion_date, "%Y-%m-%d")
+                            .expect("invalid stadium expansion date"),
+                    )),
+                    None => result,
+                }
+            }
+            None => {
+                // Clubs without stadium data get a ground matching their reputation
+                let home_reputation = club
+                    .teams
+                    .iter()
+                    .map(|t| t.reputation.home)
+                    .max()
+                    .unwrap_or(0) as u32;
+
+                Stadium::new(
+                    format!("{} Stadium", club.name),
+                    5_000 + home_reputation * 4,
+                    IntegerUtils::random(5, 15) as u8,
+                    StadiumOwnership::Rented,
+                )
+            }
+        }
+    }
+
+    fn generate_sponsorship_contracts(club: &ClubEntity) -> Vec<ClubSponsorshipContract> {
+        let world_reputation = club
+            .teams
+            .iter()
+            .map(|t| t.reputation.world)
+            .max()
+            .unwrap_or(0) as i32;
+
+        let expiration = |years: i32| NaiveDate::from_ymd_opt(2024 + years, 6, 30).unwrap();
+
+        vec![
+            ClubSponsorshipContract::new(
+                String::from("Shirt sponsor"),
+                world_reputation * 300,
+                expiration(IntegerUtils::random(1, 5)),
+            ),
+            ClubSponsorshipContract::new(
+                String::from("Kit supplier"),
+                world_reputation * 150,
+                expiration(IntegerUtils::random(1, 5)),
+            ),
+        ]
+    }
+
+    fn squad_size(data: &DatabaseEntity, league_id: u32) -> Option<u8> {
+        data.leagues
+            .iter()
+            .find(|l| l.id == league_id)
+            .and_then(|l| l.squad_size)
+    }
+
+    fn generate_players(
+        player_generator: &mut PlayerGenerator,
+        country_id: u32,
+        squad_size: Option<u8>,
+        imported: Vec<Player>,
+    ) -> Vec<Player> {
+        let squad = match squad_size {
+            Some(size) => Self::squad_of(size),
+            None => [
+                (PositionType::Goalkeeper, IntegerUtils::random(3, 5)),
+                (PositionType::Defender, IntegerUtils::random(20, 40)),
+                (PositionType::Midfielder, IntegerUtils::random(25, 35)),
+                (PositionType::Striker, IntegerUtils::random(20, 24)),
+            ],
+        };
+
+        Self::fill_squad(imported, squad, |position| player_generator.generate(country_id, position))
+    }
+
+    /// A smaller squad of young players, youth sides stay within their age limit
+    fn generate_reserve_players(
+        player_generator: &mut PlayerGenerator,
+        country_id: u32,
+        team_type: TeamType,
+        imported: Vec<Player>,
+    ) -> Vec<Player> {
+        let max_age = team_type.max_age().unwrap_or(23);
+
+        let squad = [
+            (PositionType::Goalkeeper, IntegerUtils::random(2, 3)),
+            (PositionType::Defender, IntegerUtils::random(7, 9)),
+            (PositionType::Midfielder, IntegerUtils::random(7, 9)),
+            (PositionType::Striker, IntegerUtils::random(4, 5)),
+        ];
+
+        Self::fill_squad(imported, squad, |position| {
+            player_generator.generate_youth(country_id, position, 16, max_age)
+        })
+    }
+
+    /// A league's squad size split by position, an eighth of it in goal
+    fn squad_of(size: u8) -> [(PositionType, i32); 4] {
+        let size = size as i32;
+
+        let goalkeepers = (size / 8).max(2);
+        let defenders = size * 35 / 100;
+        let midfielders = size * 35 / 100;
+
+        [
+            (PositionType::Goalkeeper, goalkeepers),
+            (PositionType::Defender, defenders),
+            (PositionType::Midfielder, midfielders),
+            (PositionType::Striker, size - goalkeepers - defenders - midfielders),
+        ]
+    }
+
+    /// Imported players count towards the squad, only the rest of each position is generated
+    fn fill_squad(
+        imported: Vec<Player>,
+        squad: [(PositionType, i32); 4],
+        mut generate: impl FnMut(PositionType) -> Player,
+    ) -> Vec<Player> {
         let mut players = Vec::with_capacity(100);
 
-        let mut goalkeepers: Vec<Player> = (0..IntegerUtils::random(3, 5))
-            .map(|_| player_generator.generate(country_id, PositionType::Goalkeeper))
-            .collect();
+        for (position, count) in squad {
+            let imported_count = imported
+                .iter()
+                .filter(|player| PositionType::of(player.position()) == position)
+                .count() as i32;
 
-        let mut defenders: Vec<Player> = (0..IntegerUtils::random(20, 40))
-            .map(|_| player_generator.generate(country_id, PositionType::Defender))
-            .collect();
+            for _ in imported_count..count {
+                players.push(generate(position));
+            }
+        }
 
-        let mut midfielders: Vec<Player> = (0..IntegerUtils::random(25, 35))
-            .map(|_| player_generator.generate(country_id, PositionType::Midfielder))
-            .collect();
+        players.extend(imported);
 
-        let mut strikers: Vec<Player> = (0..IntegerUtils::random(20, 24))
-            .map(|_| player_generator.generate(country_id, PositionType::Striker))
-            .collect();
+        players
+    }
+
+    /// The best players in a squad are promised the most football
+    fn assign_squad_statuses(mut players: Vec<Player>, team_type: TeamType) -> Vec<Player> {
+        players.sort_by_key(|player| Reverse(player.player_attributes.current_ability));
+
+        for (rank, player) in players.iter_mut().enumerate() {
+            let status = match (team_type, rank) {
+                (TeamType::Main, 0..=2) => PlayerSquadStatus::KeyPlayer,
+                (TeamType::Main, 3..=10) => PlayerSquadStatus::FirstTeamRegular,
+                (TeamType::Main, 11..=17) => PlayerSquadStatus::FirstTeamSquadRotation,
+                (TeamType::Main, 18..=24) => PlayerSquadStatus::MainBackupPlayer,
+                (TeamType::Main, _) => PlayerSquadStatus::NotNeeded,
+                (_, 0..=2) => PlayerSquadStatus::HotProspectForTheFuture,
+                _ => PlayerSquadStatus::DecentYoungster,
+            };
+
+            if let Some(contract) = player.contract.as_mut() {
+                contract.squad_status = status;
+            }
+        }
+
+        players
+    }
 
-        players.append(&mut goalkeepers);
-        players.append(&mut defenders);
-        players.append(&mut midfielders);
-        players.append(&mut strikers);
+    /// Some first team contracts come with release clauses or a wage rise on promotion
+    fn assign_contract_clauses(mut players: Vec<Player>, team_type: TeamType, date: NaiveDate) -> Vec<Player> {
+        if team_type != TeamType::Main {
+            return players;
+        }
+
+        for player in &mut players {
+            let value = player.value(date);
+
+            let contract = match player.contract.as_mut() {
+                Some(contract) => contract,
+                None => continue,
+            };
+
+            let release_clause = match IntegerUtils::random(0, 100) {
+                0..=9 => Some((ContractClauseType::MinimumFeeRelease, FloatUtils::random(2.0, 3.0))),
+                10..=24 => Some((ContractClauseType::MinimumFeeReleaseToForeignClubs, FloatUtils::random(1.5, 2.5))),
+                25..=34 => Some((ContractClauseType::MinimumFeeReleaseToDomesticClubs, FloatUtils::random(2.5, 3.5))),
+                _ => None,
+            };
+
+            if let Some((clause_type, multiplier)) = release_clause {
+                contract.clauses.push(ContractClause::new(
+                    (value * multiplier as f64).min(i32::MAX as f64) as i32,
+                    clause_type,
+                ));
+            }
+
+            let regular = matches!(
+                contract.squad_status,
+                PlayerSquadStatus::KeyPlayer | PlayerSquadStatus::FirstTeamRegular
+            );
+
+            if regular && IntegerUtils::random(0, 100) < 20 {
+                contract.clauses.push(ContractClause::new(
+                    (value * FloatUtils::random(0.8, 1.2) as f64).min(i32::MAX as f64) as i32,
+                    ContractClauseType::RelegationFeeRelease,
+                ));
+            }
+
+            if IntegerUtils::random(0, 100) < 10 {
+                contract.clauses.push(ContractClause::new(
+                    IntegerUtils::random(10, 30),
+                    ContractClauseType::PromotionWageIncrease,
+                ));
+            }
+        }
 
         players
     }
 
-    fn generate_staffs(staff_generator: &mut StaffGenerator, country_id: u32) -> Vec<Staff> {
-        let mut staffs = Vec::with_capacity(30);
+    fn generate_staffs(staff_generator: &mut StaffGenerator, country_id: u32, imported: Vec<Staff>) -> Vec<Staff> {
+        let positions = [
+            StaffPosition::DirectorOfFootball,
+            StaffPosition::Director,
+            StaffPosition::Manager,
+            StaffPosition::AssistantManager,
+            StaffPosition::Coach,
+            StaffPosition::Coach,
+            StaffPosition::Coach,
+            StaffPosition::Physio,
+            StaffPosition::Physio,
+            StaffPosition::Physio,
+            StaffPosition::ChiefScout,
+            StaffPosition::Scout,
+            StaffPosition::Scout,
+        ];
 
-        staffs.push(staff_generator.generate(country_id, StaffPosition::DirectorOfFootball));
-        staffs.push(staff_generator.generate(country_id, StaffPosition::Director));
+        Self::fill_staffs(staff_generator, country_id, &positions, imported)
+    }
+
+    fn generate_reserve_staffs(staff_generator: &mut StaffGenerator, country_id: u32, imported: Vec<Staff>) -> Vec<Staff> {
+        let positions = [
+            StaffPosition::Manager,
+            StaffPosition::AssistantManager,
+            StaffPosition::Coach,
+            StaffPosition::Coach,
+            StaffPosition::Physio,
+        ];
+
+        Self::fill_staffs(staff_generator, country_id, &positions, imported)
+    }
+
+    /// An imported member of staff takes the place of a generated one in the same position
+    fn fill_staffs(
+        staff_generator: &mut StaffGenerator,
+        country_id: u32,
+        positions: &[StaffPosition],
+        mut imported: Vec<Staff>,
+    ) -> Vec<Staff> {
+        let mut staffs = Vec::with_capacity(positions.len() + imported.len());
 
-        staffs.push(staff_generator.generate(country_id, StaffPosition::AssistantManager));
-        staffs.push(staff_generator.generate(country_id, StaffPosition::Coach));
-        staffs.push(staff_generator.generate(country_id, StaffPosition::Coach));
-        staffs.push(staff_generator.generate(country_id, StaffPosition::Coach));
+        for &position in positions {
+            let holder = imported
+                .iter()
+                .position(|staff| staff.contract.as_ref().is_some_and(|c| c.position == position));
 
-        staffs.push(staff_generator.generate(country_id, StaffPosition::Physio));
-        staffs.push(staff_generator.generate(country_id, StaffPosition::Physio));
-        staffs.push(staff_generator.generate(country_id, StaffPosition::Physio));
+            match holder {
+                Some(index) => staffs.push(imported.remove(index)),
+                None => staffs.push(staff_generator.generate(country_id, position)),
+            }
+        }
+
+        staffs.append(&mut imported);
 
         staffs
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::PeopleNameGeneratorData;
+
+    fn people_names() -> PeopleNameGeneratorData {
+        PeopleNameGeneratorData {
+            first_names: vec!["Andrea".to_string()],
+            last_names: vec!["Rossi".to_string()],
+        }
+    }
+
+    #[test]
+    fn imported_players_are_not_generated_again() {
+        let mut player_generator = PlayerGenerator::with_people_names(&people_names());
+
+        let imported: Vec<Player> = [PositionType::Goalkeeper, PositionType::Striker, PositionType::Striker]
+            .into_iter()
+            .zip(9001..)
+            .map(|(position, id)| {
+                let mut player = player_generator.generate(776, position);
+                player.id = id;
+                player
+            })
+            .collect();
+
+        let mut generated = Vec::new();
+
+        let players = DatabaseGenerator::fill_squad(imported, DatabaseGenerator::squad_of(25), |position| {
+            generated.push(position);
+            player_generator.generate(776, position)
+        });
+
+        let generated_count = |position| generated.iter().filter(|generated| **generated == position).count();
+
+        assert_eq!(players.len(), 25);
+        assert_eq!(generated_count(PositionType::Goalkeeper), 2);
+        assert_eq!(generated_count(PositionType::Defender), 8);
+        assert_eq!(generated_count(PositionType::Midfielder), 8);
+        assert_eq!(generated_count(PositionType::Striker), 4);
+
+        for id in 9001..=9003 {
+            assert!(players.iter().any(|player| player.id == id));
+        }
+    }
+
+    #[test]
+    fn imported_staff_take_the_place_of_generated_staff() {
+        let mut staff_generator = StaffGenerator::with_people_names(&people_names());
+
+        let imported: Vec<Staff> = [StaffPosition::Manager, StaffPosition::Scout]
+            .into_iter()
+            .zip(9001..)
+            .map(|(position, id)| {
+                let mut staff = staff_generator.generate(776, position);
+                staff.id = id;
+                staff
+            })
+            .collect();
+
+        let positions = [StaffPosition::Manager, StaffPosition::AssistantManager, StaffPosition::Physio];
+
+        let staffs = DatabaseGenerator::fill_staffs(&mut staff_generator, 776, &positions, imported);
+
+        let ids: Vec<u32> = staffs.iter().map(|staff| staff.id).collect();
+
+        // The manager fills the manager's place, the scout has no place and joins as well
+        assert_eq!(staffs.len(), 4);
+        assert_eq!(ids[0], 9001);
+        assert_eq!(ids[3], 9002);
+        assert!(!ids[1..3].contains(&9001) && !ids[1..3].contains(&9002));
+    }
+
+    #[test]
+    fn squad_of_adds_up_to_the_squad_size() {
+        for size in [16, 25, 28, 30, 32] {
+            let squad = DatabaseGenerator::squad_of(size);
+
+            assert_eq!(size as i32, squad.iter().map(|(_, count)| count).sum::<i32>());
+            assert!(squad.iter().all(|(_, count)| *count >= 2));
+        }
+    }
+}
@@ -1,11 +1,13 @@
+use crate::loaders::{parse_date, PlayerEntity};
 use chrono::{Datelike, NaiveDate, Utc};
 use core::shared::FullName;
 use core::utils::{FloatUtils, IntegerUtils, StringUtils};
 use core::{
     Mental, PeopleNameGeneratorData, PersonAttributes, Physical, Player,
-    PlayerAttributes, PlayerClubContract, PlayerPosition, PlayerPositionType, PlayerPositions,
-    PlayerSkills, Technical,
+    PlayerAttributes, PlayerClubContract, PlayerFieldPositionGroup, PlayerPosition,
+    PlayerPositionType, PlayerPositions, PlayerSkills, Technical,
 };
+use std::cmp::Reverse;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{LazyLock};
 
@@ -26,6 +28,7 @@ impl PlayerGenerator {
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
 pub enum PositionType {
     Goalkeeper,
     Defender,
@@ -33,11 +36,104 @@ pub enum PositionType {
     Striker,
 }
 
+impl PositionType {
+    pub fn of(position: PlayerPositionType) -> Self {
+        match position.position_group() {
+            PlayerFieldPositionGroup::Goalkeeper => PositionType::Goalkeeper,
+            PlayerFieldPositionGroup::Defender => PositionType::Defender,
+            PlayerFieldPositionGroup::Midfielder => PositionType::Midfielder,
+            PlayerFieldPositionGroup::Forward => PositionType::Striker,
+        }
+    }
+}
+
 impl PlayerGenerator {
     pub fn generate(&mut self, country_id: u32, position: PositionType) -> Player {
+        self.generate_aged(country_id, position, 15, 35, 200000)
+    }
+
+    /// Generated ids start after the imported ones
+    pub fn reserve_ids(max_id: u32) {
+        PLAYER_ID_SEQUENCE.fetch_max(max_id + 1, Ordering::SeqCst);
+    }
+
+    /// A real player, generated where the entity leaves something out
+    pub fn import(&mut self, entity: &PlayerEntity) -> Player {
+        let mut positions: Vec<PlayerPosition> = entity
+            .positions
+            .iter()
+            .map(|position| PlayerPosition {
+                position: position.position_type().expect("validated position"),
+                level: position.level,
+            })
+            .collect();
+
+        // The best position decides what kind of player is generated and where the squad counts them
+        positions.sort_by_key(|position| Reverse(position.level));
+
+        let mut player = self.generate(entity.country_id, PositionType::of(positions[0].position));
+
+        player.id = entity.id;
+        player.full_name = FullName {
+            first_name: entity.first_name.clone(),
+            last_name: entity.last_name.clone(),
+            middle_name: entity.middle_name.clone(),
+        };
+        player.birth_date = parse_date(&entity.birth_date).expect("validated birth_date");
+        player.positions = PlayerPositions { positions };
+
+        for (name, value) in &entity.skills {
+            if let Some(skill) = player.skills.skill_mut(name) {
+                *skill = *value;
+            }
+        }
+
+        if let Some(contract) = &entity.contract {
+            player.contract = Some(PlayerClubContract::new(
+                contract.salary,
+                parse_date(&contract.expiration).expect("validated contract expiration"),
+            ));
+        }
+
+        let attributes = &mut player.player_attributes;
+
+        if let Some(value) = entity.value {
+            attributes.value = value;
+        }
+
+        // Without a given ability the skills tell it, 10 per point of the average
+        attributes.current_ability = entity.current_ability.unwrap_or_else(|| {
+            let skills = &player.skills;
+            let average =
+                (skills.technical.average() + skills.mental.average() + skills.physical.average()) / 3.0;
+
+            (average * 10.0).clamp(1.0, 200.0) as u8
+        });
+
+        attributes.potential_ability = entity
+            .potential_ability
+            .unwrap_or(attributes.potential_ability)
+            .max(attributes.current_ability);
+
+        player
+    }
+
+    /// Youth and reserve players, younger and on smaller contracts
+    pub fn generate_youth(&mut self, country_id: u32, position: PositionType, min_age: u8, max_age: u8) -> Player {
+        self.generate_aged(country_id, position, min_age, max_age, 5000)
+    }
+
+    fn generate_aged(
+        &mut self,
+        country_id: u32,
+        position: PositionType,
+        min_age: u8,
+        max_age: u8,
+        max_salary: i32,
+    ) -> Player {
         let now = Utc::now();
 
-        let year = IntegerUtils::random(now.year() - 35, now.year() - 15) as u32;
+        let year = IntegerUtils::random(now.year() - max_age as i32, now.year() - min_age as i32) as u32;
         let month = IntegerUtils::random(1, 12) as u32;
         let day = IntegerUtils::random(1, 29) as u32;
 
@@ -54,7 +150,7 @@ impl PlayerGenerator {
             .attributes(Self::generate_person_attributes())
             .player_attributes(Self::generate_player_attributes())
             .contract(Some(PlayerClubContract::new(
-                IntegerUtils::random(1000, 200000) as u32,
+                IntegerUtils::random(1000, max_salary) as u32,
                 NaiveDate::from_ymd_opt(now.year() + IntegerUtils::random(1, 5), 3, 14).unwrap(),
             )))
             .positions(Self::generate_positions(position))
@@ -285,3 +381,40 @@ impl PlayerGenerator {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn imported_players_keep_their_data_and_best_position() {
+        let entity: PlayerEntity = serde_json::from_str(
+            r#"{"id": 9001, "team_id": 1, "first_name": "Andrea", "last_name": "Rossi",
+            "birth_date": "2000-05-17", "country_id": 776,
+            "positions": [{"position": "DC", "level": 16}, {"position": "ST", "level": 20}],
+            "skills": {"finishing": 19, "pace": 4},
+            "contract": {"salary": 50000, "expiration": "2030-06-30"}}"#,
+        )
+        .unwrap();
+
+        let mut generator = PlayerGenerator::with_people_names(&PeopleNameGeneratorData {
+            first_names: vec!["Marco".to_string()],
+            last_names: vec!["Russo".to_string()],
+        });
+
+        let player = generator.import(&entity);
+
+        assert_eq!(player.id, 9001);
+        assert_eq!(player.full_name.first_name, "Andrea");
+        assert_eq!(player.birth_date, NaiveDate::from_ymd_opt(2000, 5, 17).unwrap());
+        assert_eq!(player.position(), PlayerPositionType::Striker);
+
+        assert_eq!(player.skills.technical.finishing, 19.0);
+        assert_eq!(player.skills.physical.pace, 4.0);
+
+        let contract = player.contract.expect("imported contract");
+
+        assert_eq!(contract.salary, 50000);
+        assert_eq!(contract.expiration, NaiveDate::from_ymd_opt(2030, 6, 30).unwrap());
+    }
+}
@@ -1,14 +1,14 @@
+use crate::loaders::{parse_date, StaffEntity};
 use chrono::{Datelike, NaiveDate, Utc};
 use core::shared::FullName;
 use core::utils::FloatUtils;
 use core::utils::{IntegerUtils, StringUtils};
 use core::{
-    CoachFocus, MentalFocusType, PeopleNameGeneratorData, PersonAttributes,
+    CoachFocus, CoachingStyle, MentalFocusType, PeopleNameGeneratorData, PersonAttributes,
     PhysicalFocusType, Staff, StaffAttributes, StaffClubContract, StaffCoaching, StaffDataAnalysis,
     StaffGoalkeeperCoaching, StaffKnowledge, StaffLicenseType, StaffMedical, StaffMental,
     StaffPosition, StaffStatus, TechnicalFocusType,
 };
-use rand::Rng;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{LazyLock};
 
@@ -37,7 +37,7 @@ impl StaffGenerator {
         let month = IntegerUtils::random(1, 12) as u32;
         let day = IntegerUtils::random(1, 29) as u32;
 
-        Staff::new(
+        let mut staff = Staff::new(
             STAFF_ID_SEQUENCE.fetch_add(1, Ordering::SeqCst),
             FullName::with_full(
                 self.generate_first_name(),
@@ -56,7 +56,62 @@ impl StaffGenerator {
             Self::generate_person_attributes(),
             Self::generate_staff_license_type(),
             Some(Self::generate_staff_focus()),
-        )
+        );
+
+        staff.coaching_style = Self::generate_coaching_style();
+
+        staff
+    }
+
+    /// Generated ids start after the imported ones
+    pub fn reserve_ids(max_id: u32) {
+        STAFF_ID_SEQUENCE.fetch_max(max_id + 1, Ordering::SeqCst);
+    }
+
+    /// A real member of staff, generated where the entity leaves something out
+    pub fn import(&mut self, entity: &StaffEntity) -> Staff {
+        let position = entity.staff_position().expect("validated staff position");
+
+        let mut staff = self.generate(entity.country_id, position);
+
+        staff.id = entity.id;
+        staff.full_name = FullName {
+            first_name: entity.first_name.clone(),
+            last_name: entity.last_name.clone(),
+            middle_name: entity.middle_name.clone(),
+        };
+        staff.birth_date = parse_date(&entity.birth_date).expect("validated birth_date");
+
+        if let Some(license) = entity.license_type() {
+            staff.license = license;
+        }
+
+        if let Some(contract) = &entity.contract {
+            staff.contract = Some(StaffClubContract::new(
+                contract.salary,
+                parse_date(&contract.expiration).expect("validated contract expiration"),
+                position,
+                StaffStatus::Active,
+            ));
+        }
+
+        for (name, value) in &entity.attributes {
+            if let Some(attribute) = staff.staff_attributes.attribute_mut(name) {
+                *attribute = *value;
+            }
+        }
+
+        staff
+    }
+
+    fn generate_coaching_style() -> CoachingStyle {
+        match IntegerUtils::random(0, 4) {
+            0 => CoachingStyle::Authoritarian,
+            1 => CoachingStyle::Democratic,
+            2 => CoachingStyle::LaissezFaire,
+            3 => CoachingStyle::Transformational,
+            _ => CoachingStyle::Tactical,
+        }
     }
 
     fn generate_person_attributes() -> PersonAttributes {
@@ -203,12 +258,10 @@ const PHYSICAL_FOCUSES: &[PhysicalFocusType] = &[
 ];
 
 fn get_random_technical(count: usize) -> Vec<TechnicalFocusType> {
-    let mut rng = rand::rng();
-
     let mut random_values = Vec::with_capacity(count);
 
     while random_values.len() < count {
-        let random_index = rng.random_range(0..TECHNICAL_FOCUSES.len() - 1);
+        let random_index = IntegerUtils::random(0, (TECHNICAL_FOCUSES.len() - 1) as i32) as usize;
         let random_value = TECHNICAL_FOCUSES[random_index];
 
         if !random_values.contains(&random_value) {
@@ -220,12 +273,10 @@ fn get_random_technical(count: usize) -> Vec<TechnicalFocusType> {
 }
 
 fn get_random_mental(count: usize) -> Vec<MentalFocusType> {
-    let mut rng = rand::rng();
-
     let mut random_values = Vec::with_capacity(count);
 
     while random_values.len() < count {
-        let random_index = rng.random_range(0..MENTAL_FOCUSES.len() - 1);
+        let random_index = IntegerUtils::random(0, (MENTAL_FOCUSES.len() - 1) as i32) as usize;
         let random_value = MENTAL_FOCUSES[random_index];
 
         if !random_values.contains(&random_value) {
@@ -237,12 +288,10 @@ fn get_random_mental(count: usize) -> Vec<MentalFocusType> {
 }
 
 fn get_random_physical(count: usize) -> Vec<PhysicalFocusType> {
-    let mut rng = rand::rng();
-
     let mut random_values = Vec::with_capacity(count);
 
     while random_values.len() < count {
-        let random_index = rng.random_range(0..PHYSICAL_FOCUSES.len() - 1);
+        let random_index = IntegerUtils::random(0, (PHYSICAL_FOCUSES.len() - 1) as i32) as usize;
         let random_value = PHYSICAL_FOCUSES[random_index];
 
         if !random_values.contains(&random_value) {
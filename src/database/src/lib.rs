@@ -1,12 +1,23 @@
 mod generators;
 mod loaders;
+mod saves;
 
 pub use loaders::{
     ClubEntity, ClubLoader, ContinentEntity, ContinentLoader, CountryEntity, CountryLoader,
-    LeagueEntity, LeagueLoader, NamesByCountryEntity, NamesByCountryLoader,
+    ContractEntity, DatabaseError, DatabaseValidator, LeagueEntity, LeagueLoader,
+    NamesByCountryEntity, NamesByCountryLoader, PeopleLoader, PlayerEntity, PlayerPositionEntity,
+    StaffEntity,
 };
 
 pub use generators::DatabaseGenerator;
+pub use saves::{SaveError, SaveGame, SAVE_DIRECTORY, SAVE_FORMAT_VERSION};
+
+use log::info;
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Directory with the database files, used when no directory is passed on the command line
+pub const DATABASE_PATH_ENV: &str = "DATABASE_PATH";
 
 pub struct DatabaseEntity {
     pub continents: Vec<ContinentEntity>,
@@ -15,6 +26,10 @@ pub struct DatabaseEntity {
     pub clubs: Vec<ClubEntity>,
 
     pub names_by_country: Vec<NamesByCountryEntity>,
+
+    /// Real people, only from a database directory
+    pub players: Vec<PlayerEntity>,
+    pub staff: Vec<StaffEntity>,
 }
 
 pub struct DatabaseLoader;
@@ -27,6 +42,46 @@ impl DatabaseLoader {
             leagues: LeagueLoader::load(),
             clubs: ClubLoader::load(),
             names_by_country: NamesByCountryLoader::load(),
+            players: Vec::new(),
+            staff: Vec::new(),
+        }
+    }
+
+    /// Database files from a directory, a file it doesn't have comes from the embedded database
+    pub fn load_from(directory: &Path) -> Result<DatabaseEntity, DatabaseError> {
+        if !directory.is_dir() {
+            return Err(DatabaseError::Io(
+                directory.to_path_buf(),
+                std::io::Error::new(std::io::ErrorKind::NotFound, "database directory not found"),
+            ));
+        }
+
+        info!("loading database from {}", directory.display());
+
+        let database = DatabaseEntity {
+            continents: ContinentLoader::load_from(directory)?,
+            countries: CountryLoader::load_from(directory)?,
+            leagues: LeagueLoader::load_from(directory)?,
+            clubs: ClubLoader::load_from(directory)?,
+            names_by_country: NamesByCountryLoader::load_from(directory)?,
+            players: PeopleLoader::load_players(directory)?,
+            staff: PeopleLoader::load_staff(directory)?,
+        };
+
+        DatabaseValidator::validate(&database)?;
+
+        Ok(database)
+    }
+
+    /// The given directory, else the `DATABASE_PATH` one, else the embedded database
+    pub fn load_configured(directory: Option<&Path>) -> Result<DatabaseEntity, DatabaseError> {
+        let directory = directory
+            .map(Path::to_path_buf)
+            .or_else(|| env::var(DATABASE_PATH_ENV).ok().map(PathBuf::from));
+
+        match directory {
+            Some(directory) => Self::load_from(&directory),
+            None => Ok(Self::load()),
         }
     }
 }
@@ -1,28 +1,55 @@
+use crate::loaders::{DatabaseError, DatabaseFile};
 use serde::Deserialize;
+use std::path::Path;
 
 const STATIC_CLUB_JSON: &'static str = include_str!("../data/clubs.json");
 
+pub const CLUBS_FILE: &str = "clubs.json";
+
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ClubEntity {
     pub id: u32,
     pub name: String,
     pub country_id: u32,
     pub location: ClubLocationEntity,
     pub finance: ClubFinanceEntity,
+    pub stadium: Option<ClubStadiumEntity>,
     pub teams: Vec<ClubTeamEntity>,
 }
 
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ClubLocationEntity {
     pub city_id: u32,
 }
 
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ClubFinanceEntity {
     pub balance: i32,
 }
 
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClubStadiumEntity {
+    pub name: String,
+    pub capacity: u32,
+    pub quality: u8,
+    pub ownership: String,
+    pub expansion: Option<ClubStadiumExpansionEntity>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClubStadiumExpansionEntity {
+    pub additional_capacity: u32,
+    pub cost: i32,
+    pub completion_date: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ClubReputationEntity {
     pub home: u16,
     pub national: u16,
@@ -30,6 +57,7 @@ pub struct ClubReputationEntity {
 }
 
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ClubTeamEntity {
     pub id: u32,
     pub name: String,
@@ -46,4 +74,8 @@ impl ClubLoader {
     pub fn load() -> Vec<ClubEntity> {
         serde_json::from_str(STATIC_CLUB_JSON).unwrap()
     }
+
+    pub fn load_from(directory: &Path) -> Result<Vec<ClubEntity>, DatabaseError> {
+        DatabaseFile::read(directory, CLUBS_FILE, STATIC_CLUB_JSON)
+    }
 }
@@ -1,8 +1,13 @@
+use crate::loaders::{DatabaseError, DatabaseFile};
 use serde::Deserialize;
+use std::path::Path;
 
 const STATIC_CONTINENTS_JSON: &str = include_str!("../data/continents.json");
 
+pub const CONTINENTS_FILE: &str = "continents.json";
+
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ContinentEntity {
     pub id: u32,
     pub name: String,
@@ -14,4 +19,8 @@ impl ContinentLoader {
     pub fn load() -> Vec<ContinentEntity> {
         serde_json::from_str(STATIC_CONTINENTS_JSON).unwrap()
     }
+
+    pub fn load_from(directory: &Path) -> Result<Vec<ContinentEntity>, DatabaseError> {
+        DatabaseFile::read(directory, CONTINENTS_FILE, STATIC_CONTINENTS_JSON)
+    }
 }
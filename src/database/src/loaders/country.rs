@@ -1,8 +1,13 @@
+use crate::loaders::{DatabaseError, DatabaseFile};
 use serde::Deserialize;
+use std::path::Path;
 
 const STATIC_COUNTRIES_JSON: &str = include_str!("../data/countries.json");
 
+pub const COUNTRIES_FILE: &str = "countries.json";
+
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct CountryEntity {
     pub id: u32,
     pub code: String,
@@ -18,4 +23,8 @@ impl CountryLoader {
     pub fn load() -> Vec<CountryEntity> {
         serde_json::from_str(STATIC_COUNTRIES_JSON).unwrap()
     }
+
+    pub fn load_from(directory: &Path) -> Result<Vec<CountryEntity>, DatabaseError> {
+        DatabaseFile::read(directory, COUNTRIES_FILE, STATIC_COUNTRIES_JSON)
+    }
 }
@@ -0,0 +1,104 @@
+use log::info;
+use serde::de::DeserializeOwned;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum DatabaseError {
+    Io(PathBuf, std::io::Error),
+    /// Not valid JSON or not matching the schema, with the line and column
+    Format(PathBuf, serde_json::Error),
+    /// A CSV line that can't be read, the message has the line
+    Csv(PathBuf, String),
+    /// Every broken reference and bad value that was found
+    Invalid(Vec<String>),
+}
+
+impl Display for DatabaseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DatabaseError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            DatabaseError::Format(path, err) => write!(f, "{}: {}", path.display(), err),
+            DatabaseError::Csv(path, message) => write!(f, "{}: {}", path.display(), message),
+            DatabaseError::Invalid(problems) => {
+                write!(f, "invalid database, {} problem(s):", problems.len())?;
+
+                for problem in problems {
+                    write!(f, "\n  {}", problem)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+pub(crate) struct DatabaseFile;
+
+impl DatabaseFile {
+    /// Reads `file_name` from the directory, the embedded copy stands in when the directory doesn't have it
+    pub(crate) fn read<T: DeserializeOwned>(
+        directory: &Path,
+        file_name: &str,
+        embedded: &str,
+    ) -> Result<T, DatabaseError> {
+        let path = directory.join(file_name);
+
+        match fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|err| DatabaseError::Format(path, err)),
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                info!(
+                    "{} not found, using the embedded {}",
+                    path.display(),
+                    file_name
+                );
+
+                serde_json::from_str(embedded)
+                    .map_err(|err| DatabaseError::Format(PathBuf::from(file_name), err))
+            }
+            Err(err) => Err(DatabaseError::Io(path, err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DatabaseLoader;
+    use crate::loaders::LEAGUES_FILE;
+
+    #[test]
+    fn falls_back_to_embedded_files_and_reports_schema_errors() {
+        let directory = std::env::temp_dir().join(format!("database-{}", std::process::id()));
+
+        fs::create_dir_all(&directory).unwrap();
+
+        let embedded = DatabaseLoader::load_from(&directory).map(|data| data.clubs.len());
+
+        fs::write(
+            directory.join(LEAGUES_FILE),
+            r#"[{"id": 2, "slug": "a", "name": "A", "country_id": 776, "reputation": 1, "setings": {}}]"#,
+        )
+        .unwrap();
+
+        let invalid = DatabaseLoader::load_from(&directory);
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(embedded.unwrap(), DatabaseLoader::load().clubs.len());
+
+        match invalid {
+            Err(DatabaseError::Format(path, err)) => {
+                assert!(path.ends_with(LEAGUES_FILE));
+                assert!(
+                    err.to_string().contains("unknown field `setings`"),
+                    "{}",
+                    err
+                );
+            }
+            _ => panic!("{} should fail on the unknown field", LEAGUES_FILE),
+        }
+    }
+}
@@ -1,8 +1,13 @@
+use crate::loaders::{DatabaseError, DatabaseFile};
 use serde::Deserialize;
+use std::path::Path;
 
 const STATIC_LEAGUES_JSON: &str = include_str!("../data/leagues.json");
 
+pub const LEAGUES_FILE: &str = "leagues.json";
+
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct LeagueEntity {
     pub id: u32,
     pub slug: String,
@@ -10,15 +15,26 @@ pub struct LeagueEntity {
     pub country_id: u32,
     pub settings: LeagueSettingsEntity,
     pub reputation: u16,
+    #[serde(default = "top_tier")]
+    pub tier: u8,
+    /// Players in a first team squad, left out for the generator's own mix
+    #[serde(default)]
+    pub squad_size: Option<u8>,
+}
+
+fn top_tier() -> u8 {
+    1
 }
 
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct LeagueSettingsEntity {
     pub season_starting_half: DayMonthPeriodEntity,
     pub season_ending_half: DayMonthPeriodEntity,
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct DayMonthPeriodEntity {
     pub from_day: u8,
     pub from_month: u8,
@@ -33,4 +49,8 @@ impl LeagueLoader {
     pub fn load() -> Vec<LeagueEntity> {
         serde_json::from_str(STATIC_LEAGUES_JSON).unwrap()
     }
+
+    pub fn load_from(directory: &Path) -> Result<Vec<LeagueEntity>, DatabaseError> {
+        DatabaseFile::read(directory, LEAGUES_FILE, STATIC_LEAGUES_JSON)
+    }
 }
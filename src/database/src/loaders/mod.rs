@@ -3,9 +3,15 @@ mod league;
 mod club;
 mod continent;
 mod names;
+mod file;
+mod people;
+mod validation;
 
 pub use country::*;
 pub use league::*;
 pub use club::*;
 pub use continent::*;
-pub use names::*;
\ No newline at end of file
+pub use names::*;
+pub use file::*;
+pub use people::*;
+pub use validation::*;
@@ -1,8 +1,13 @@
+use crate::loaders::{DatabaseError, DatabaseFile};
 use serde::Deserialize;
+use std::path::Path;
 
 const STATIC_NAME_BY_COUNTRY_JSON: &str = include_str!("../data/names/names_by_country.json");
 
+pub const NAMES_BY_COUNTRY_FILE: &str = "names/names_by_country.json";
+
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct NamesByCountryEntity {
     pub country_id: u32,
     pub first_names: Vec<String>,
@@ -15,4 +20,8 @@ impl NamesByCountryLoader {
     pub fn load() -> Vec<NamesByCountryEntity> {
         serde_json::from_str(STATIC_NAME_BY_COUNTRY_JSON).unwrap()
     }
+
+    pub fn load_from(directory: &Path) -> Result<Vec<NamesByCountryEntity>, DatabaseError> {
+        DatabaseFile::read(directory, NAMES_BY_COUNTRY_FILE, STATIC_NAME_BY_COUNTRY_JSON)
+    }
 }
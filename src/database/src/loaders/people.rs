@@ -0,0 +1,368 @@
+use crate::loaders::DatabaseError;
+use chrono::NaiveDate;
+use core::{PlayerPositionType, StaffLicenseType, StaffPosition};
+use log::info;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const PLAYERS_FILE: &str = "players.json";
+pub const PLAYERS_CSV_FILE: &str = "players.csv";
+
+pub const STAFF_FILE: &str = "staff.json";
+pub const STAFF_CSV_FILE: &str = "staff.csv";
+
+/// A real player, whatever is left out is generated
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PlayerEntity {
+    pub id: u32,
+    pub team_id: u32,
+    pub first_name: String,
+    pub last_name: String,
+    #[serde(default)]
+    pub middle_name: Option<String>,
+    /// YYYY-MM-DD
+    pub birth_date: String,
+    /// Nationality
+    pub country_id: u32,
+    /// Best position first
+    pub positions: Vec<PlayerPositionEntity>,
+    /// 1 to 20 by skill name, as in `finishing` or `work_rate`
+    #[serde(default)]
+    pub skills: HashMap<String, f32>,
+    #[serde(default)]
+    pub contract: Option<ContractEntity>,
+    #[serde(default)]
+    pub value: Option<u32>,
+    #[serde(default)]
+    pub current_ability: Option<u8>,
+    #[serde(default)]
+    pub potential_ability: Option<u8>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PlayerPositionEntity {
+    /// Short name, as in `GK` or `AMC`
+    pub position: String,
+    /// 1 to 20
+    pub level: u8,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContractEntity {
+    pub salary: u32,
+    /// YYYY-MM-DD
+    pub expiration: String,
+}
+
+/// A real member of staff, whatever is left out is generated
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StaffEntity {
+    pub id: u32,
+    pub team_id: u32,
+    pub first_name: String,
+    pub last_name: String,
+    #[serde(default)]
+    pub middle_name: Option<String>,
+    /// YYYY-MM-DD
+    pub birth_date: String,
+    pub country_id: u32,
+    /// As in `Manager` or `ChiefScout`
+    pub position: String,
+    /// As in `ContinentalPro`
+    #[serde(default)]
+    pub license: Option<String>,
+    #[serde(default)]
+    pub contract: Option<ContractEntity>,
+    /// 1 to 20 by attribute name, as in `tactical` or `man_management`
+    #[serde(default)]
+    pub attributes: HashMap<String, u8>,
+}
+
+impl PlayerPositionEntity {
+    pub fn position_type(&self) -> Option<PlayerPositionType> {
+        PlayerPositionType::from_short_name(&self.position)
+    }
+}
+
+impl StaffEntity {
+    pub fn staff_position(&self) -> Option<StaffPosition> {
+        variant(&self.position)
+    }
+
+    pub fn license_type(&self) -> Option<StaffLicenseType> {
+        self.license.as_deref().and_then(variant)
+    }
+}
+
+pub(crate) fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+/// Enum variant by its name
+fn variant<T: DeserializeOwned>(name: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(name.to_string())).ok()
+}
+
+/// Players and staff come only from a database directory, as JSON or CSV
+pub struct PeopleLoader;
+
+impl PeopleLoader {
+    pub fn load_players(directory: &Path) -> Result<Vec<PlayerEntity>, DatabaseError> {
+        Self::load(directory, PLAYERS_FILE, PLAYERS_CSV_FILE, player_from_row)
+    }
+
+    pub fn load_staff(directory: &Path) -> Result<Vec<StaffEntity>, DatabaseError> {
+        Self::load(directory, STAFF_FILE, STAFF_CSV_FILE, staff_from_row)
+    }
+
+    fn load<T: DeserializeOwned>(
+        directory: &Path,
+        json_file: &str,
+        csv_file: &str,
+        from_row: fn(&mut CsvRow) -> Result<T, String>,
+    ) -> Result<Vec<T>, DatabaseError> {
+        let json_path = directory.join(json_file);
+        let csv_path = directory.join(csv_file);
+
+        let entities = if json_path.is_file() {
+            let json = fs::read_to_string(&json_path)
+                .map_err(|err| DatabaseError::Io(json_path.clone(), err))?;
+
+            serde_json::from_str(&json).map_err(|err| DatabaseError::Format(json_path, err))?
+        } else if csv_path.is_file() {
+            read_csv(&csv_path, from_row)?
+        } else {
+            return Ok(Vec::new());
+        };
+
+        info!("{} imported from {}", entities.len(), directory.display());
+
+        Ok(entities)
+    }
+}
+
+fn read_csv<T>(
+    path: &Path,
+    from_row: fn(&mut CsvRow) -> Result<T, String>,
+) -> Result<Vec<T>, DatabaseError> {
+    let csv_error = |message: String| DatabaseError::Csv(PathBuf::from(path), message);
+
+    let mut reader = csv::Reader::from_path(path).map_err(|err| csv_error(err.to_string()))?;
+
+    let headers = reader
+        .headers()
+        .map_err(|err| csv_error(err.to_string()))?
+        .clone();
+
+    let mut entities = Vec::new();
+
+    for record in reader.records() {
+        let record = record.map_err(|err| csv_error(err.to_string()))?;
+
+        let line = record
+            .position()
+            .map(|position| position.line())
+            .unwrap_or(0);
+
+        let mut row = CsvRow {
+            cells: headers
+                .iter()
+                .zip(record.iter())
+                .filter(|(_, value)| !value.trim().is_empty())
+                .map(|(header, value)| (header.trim().to_string(), value.trim().to_string()))
+                .collect(),
+        };
+
+        let entity = from_row(&mut row)
+            .map_err(|message| csv_error(format!("line {}: {}", line, message)))?;
+
+        entities.push(entity);
+    }
+
+    Ok(entities)
+}
+
+/// The filled cells of a CSV line by column, taken out as they are read
+struct CsvRow {
+    cells: HashMap<String, String>,
+}
+
+impl CsvRow {
+    fn text(&mut self, column: &str) -> Result<String, String> {
+        self.optional_text(column)
+            .ok_or_else(|| format!("{} is required", column))
+    }
+
+    fn optional_text(&mut self, column: &str) -> Option<String> {
+        self.cells.remove(column)
+    }
+
+    fn number<N: std::str::FromStr>(&mut self, column: &str) -> Result<N, String> {
+        let value = self.text(column)?;
+
+        parse_number(column, &value)
+    }
+
+    fn optional_number<N: std::str::FromStr>(&mut self, column: &str) -> Result<Option<N>, String> {
+        self.optional_text(column)
+            .map(|value| parse_number(column, &value))
+            .transpose()
+    }
+
+    fn contract(&mut self) -> Result<Option<ContractEntity>, String> {
+        let salary = self.optional_number("salary")?;
+        let expiration = self.optional_text("contract_expiration");
+
+        match (salary, expiration) {
+            (Some(salary), Some(expiration)) => Ok(Some(ContractEntity { salary, expiration })),
+            (None, None) => Ok(None),
+            _ => Err("salary and contract_expiration go together".to_string()),
+        }
+    }
+
+    /// Whatever columns are left, by name
+    fn remaining<N: std::str::FromStr>(&mut self) -> Result<HashMap<String, N>, String> {
+        self.cells
+            .drain()
+            .map(|(column, value)| {
+                let number = parse_number(&column, &value)?;
+
+                Ok((column, number))
+            })
+            .collect()
+    }
+}
+
+fn parse_number<N: std::str::FromStr>(column: &str, value: &str) -> Result<N, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} '{}' is not a number", column, value))
+}
+
+/// Positions are `ST:20;AMC:15`, every other unknown column is a skill
+fn player_from_row(row: &mut CsvRow) -> Result<PlayerEntity, String> {
+    let positions = row
+        .text("positions")?
+        .split(';')
+        .map(|position| {
+            let (position, level) = position
+                .split_once(':')
+                .ok_or_else(|| format!("position '{}' is not POSITION:LEVEL", position))?;
+
+            Ok(PlayerPositionEntity {
+                position: position.trim().to_string(),
+                level: parse_number("position level", level.trim())?,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(PlayerEntity {
+        id: row.number("id")?,
+        team_id: row.number("team_id")?,
+        first_name: row.text("first_name")?,
+        last_name: row.text("last_name")?,
+        middle_name: row.optional_text("middle_name"),
+        birth_date: row.text("birth_date")?,
+        country_id: row.number("country_id")?,
+        positions,
+        contract: row.contract()?,
+        value: row.optional_number("value")?,
+        current_ability: row.optional_number("current_ability")?,
+        potential_ability: row.optional_number("potential_ability")?,
+        // Last, once the other columns are taken
+        skills: row.remaining()?,
+    })
+}
+
+/// Every unknown column is an attribute
+fn staff_from_row(row: &mut CsvRow) -> Result<StaffEntity, String> {
+    Ok(StaffEntity {
+        id: row.number("id")?,
+        team_id: row.number("team_id")?,
+        first_name: row.text("first_name")?,
+        last_name: row.text("last_name")?,
+        middle_name: row.optional_text("middle_name"),
+        birth_date: row.text("birth_date")?,
+        country_id: row.number("country_id")?,
+        position: row.text("position")?,
+        license: row.optional_text("license"),
+        contract: row.contract()?,
+        // Last, once the other columns are taken
+        attributes: row.remaining()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DatabaseLoader;
+
+    #[test]
+    fn imports_players_from_csv_and_reports_bad_rows() {
+        let data = DatabaseLoader::load();
+        let team = &data.clubs[0].teams[0];
+        let country_id = data.clubs[0].country_id;
+
+        let directory = std::env::temp_dir().join(format!("people-{}", std::process::id()));
+
+        fs::create_dir_all(&directory).unwrap();
+
+        let header = "id,team_id,first_name,last_name,birth_date,country_id,positions,salary,contract_expiration,finishing,pace";
+
+        fs::write(
+            directory.join(PLAYERS_CSV_FILE),
+            format!(
+                "{}\n900001,{},Marco,Rossi,1995-04-12,{},ST:20;AMC:15,50000,2027-06-30,18,16.5\n",
+                header, team.id, country_id
+            ),
+        )
+        .unwrap();
+
+        let loaded = DatabaseLoader::load_from(&directory);
+
+        fs::write(
+            directory.join(PLAYERS_CSV_FILE),
+            format!(
+                "{}\n900001,{},Marco,Rossi,1995-04-12,{},XX:20,,,18,16.5\n",
+                header, team.id, country_id
+            ),
+        )
+        .unwrap();
+
+        let invalid = DatabaseLoader::load_from(&directory);
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        let loaded = loaded.unwrap();
+        let player = &loaded.players[0];
+
+        assert_eq!(1, loaded.players.len());
+        assert_eq!(team.id, player.team_id);
+        assert_eq!(2, player.positions.len());
+        assert_eq!(
+            Some(PlayerPositionType::Striker),
+            player.positions[0].position_type()
+        );
+        assert_eq!(Some(&16.5), player.skills.get("pace"));
+        assert_eq!(50000, player.contract.as_ref().unwrap().salary);
+        assert!(loaded.staff.is_empty());
+
+        match invalid {
+            Err(DatabaseError::Invalid(problems)) => assert!(
+                problems
+                    .iter()
+                    .any(|p| p.starts_with(PLAYERS_FILE) && p.contains("unknown position 'XX'")),
+                "{:?}",
+                problems
+            ),
+            _ => panic!("the unknown position should fail validation"),
+        }
+    }
+}
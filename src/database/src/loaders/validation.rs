@@ -0,0 +1,582 @@
+use crate::DatabaseEntity;
+use crate::loaders::{
+    CLUBS_FILE, COUNTRIES_FILE, ContractEntity, DatabaseError, DayMonthPeriodEntity, LEAGUES_FILE,
+    NAMES_BY_COUNTRY_FILE, PLAYERS_FILE, STAFF_FILE, parse_date,
+};
+use chrono::NaiveDate;
+use core::{PlayerSkills, REQUIRED_POSITION_LEVEL, StadiumOwnership, StaffAttributes, TeamType};
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// Smallest first team squad that still fills a matchday side
+const MIN_SQUAD_SIZE: u8 = 16;
+
+/// Checks the references between the database files and the values the generator parses
+pub struct DatabaseValidator {
+    problems: Vec<String>,
+}
+
+impl DatabaseValidator {
+    pub fn validate(data: &DatabaseEntity) -> Result<(), DatabaseError> {
+        let mut validator = DatabaseValidator {
+            problems: Vec::new(),
+        };
+
+        validator.validate_countries(data);
+        validator.validate_leagues(data);
+        validator.validate_clubs(data);
+        validator.validate_names(data);
+        validator.validate_players(data);
+        validator.validate_staff(data);
+
+        if validator.problems.is_empty() {
+            Ok(())
+        } else {
+            Err(DatabaseError::Invalid(validator.problems))
+        }
+    }
+
+    fn problem(&mut self, file: &str, subject: String, message: String) {
+        self.problems
+            .push(format!("{}: {}: {}", file, subject, message));
+    }
+
+    fn validate_countries(&mut self, data: &DatabaseEntity) {
+        let continent_ids: HashSet<u32> = data.continents.iter().map(|c| c.id).collect();
+
+        let mut ids = HashSet::new();
+        let mut slugs = HashSet::new();
+
+        for country in &data.countries {
+            let subject = format!("country {} '{}'", country.id, country.name);
+
+            self.unique(COUNTRIES_FILE, &subject, "id", &mut ids, country.id);
+            self.unique(COUNTRIES_FILE, &subject, "slug", &mut slugs, &country.slug);
+
+            if !continent_ids.contains(&country.continent_id) {
+                self.problem(
+                    COUNTRIES_FILE,
+                    subject,
+                    format!("unknown continent_id {}", country.continent_id),
+                );
+            }
+        }
+    }
+
+    fn validate_leagues(&mut self, data: &DatabaseEntity) {
+        let country_ids: HashSet<u32> = data.countries.iter().map(|c| c.id).collect();
+
+        let mut ids = HashSet::new();
+        let mut slugs = HashSet::new();
+
+        for league in &data.leagues {
+            let subject = format!("league {} '{}'", league.id, league.name);
+
+            self.unique(LEAGUES_FILE, &subject, "id", &mut ids, league.id);
+            self.unique(LEAGUES_FILE, &subject, "slug", &mut slugs, &league.slug);
+
+            if !country_ids.contains(&league.country_id) {
+                self.problem(
+                    LEAGUES_FILE,
+                    subject.clone(),
+                    format!("unknown country_id {}", league.country_id),
+                );
+            }
+
+            if league.tier == 0 {
+                self.problem(
+                    LEAGUES_FILE,
+                    subject.clone(),
+                    "tier starts at 1 for the top flight".to_string(),
+                );
+            }
+
+            if league.squad_size.is_some_and(|size| size < MIN_SQUAD_SIZE) {
+                self.problem(
+                    LEAGUES_FILE,
+                    subject.clone(),
+                    format!("squad_size needs at least {} players", MIN_SQUAD_SIZE),
+                );
+            }
+
+            // Every team plays every round, so the fixtures need an even number of teams
+            let teams = data
+                .clubs
+                .iter()
+                .flat_map(|c| &c.teams)
+                .filter(|t| t.league_id == league.id)
+                .count();
+
+            if teams % 2 == 1 {
+                self.problem(
+                    LEAGUES_FILE,
+                    subject.clone(),
+                    format!("has an odd number of teams, {}", teams),
+                );
+            }
+
+            for (name, period) in [
+                (
+                    "season_starting_half",
+                    &league.settings.season_starting_half,
+                ),
+                ("season_ending_half", &league.settings.season_ending_half),
+            ] {
+                if let Some(message) = Self::period_problem(period) {
+                    self.problem(
+                        LEAGUES_FILE,
+                        subject.clone(),
+                        format!("{} {}", name, message),
+                    );
+                }
+            }
+        }
+    }
+
+    fn period_problem(period: &DayMonthPeriodEntity) -> Option<String> {
+        // A leap year, so 29 February is a valid day
+        let is_day =
+            |day: u8, month: u8| NaiveDate::from_ymd_opt(2024, month as u32, day as u32).is_some();
+
+        if !is_day(period.from_day, period.from_month) {
+            Some(format!(
+                "has an invalid start {}/{}",
+                period.from_day, period.from_month
+            ))
+        } else if !is_day(period.to_day, period.to_month) {
+            Some(format!(
+                "has an invalid end {}/{}",
+                period.to_day, period.to_month
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn validate_clubs(&mut self, data: &DatabaseEntity) {
+        let country_ids: HashSet<u32> = data.countries.iter().map(|c| c.id).collect();
+        let league_ids: HashSet<u32> = data.leagues.iter().map(|l| l.id).collect();
+
+        let mut ids = HashSet::new();
+        let mut team_ids = HashSet::new();
+        let mut team_slugs = HashSet::new();
+
+        for club in &data.clubs {
+            let subject = format!("club {} '{}'", club.id, club.name);
+
+            self.unique(CLUBS_FILE, &subject, "id", &mut ids, club.id);
+
+            if !country_ids.contains(&club.country_id) {
+                self.problem(
+                    CLUBS_FILE,
+                    subject.clone(),
+                    format!("unknown country_id {}", club.country_id),
+                );
+            }
+
+            if let Some(stadium) = &club.stadium {
+                if StadiumOwnership::from_str(&stadium.ownership).is_err() {
+                    self.problem(
+                        CLUBS_FILE,
+                        subject.clone(),
+                        format!(
+                            "unknown stadium ownership '{}', expected Owned, Rented or Shared",
+                            stadium.ownership
+                        ),
+                    );
+                }
+
+                if let Some(expansion) = &stadium.expansion {
+                    if NaiveDate::parse_from_str(&expansion.completion_date, "%Y-%m-%d").is_err() {
+                        self.problem(
+                            CLUBS_FILE,
+                            subject.clone(),
+                            format!(
+                                "stadium expansion completion_date '{}' is not YYYY-MM-DD",
+                                expansion.completion_date
+                            ),
+                        );
+                    }
+                }
+            }
+
+            let mut main_teams = 0;
+
+            for team in &club.teams {
+                let team_subject = format!("{}, team {} '{}'", subject, team.id, team.name);
+
+                self.unique(CLUBS_FILE, &team_subject, "team id", &mut team_ids, team.id);
+                self.unique(
+                    CLUBS_FILE,
+                    &team_subject,
+                    "team slug",
+                    &mut team_slugs,
+                    &team.slug,
+                );
+
+                match TeamType::from_str(&team.team_type) {
+                    Ok(TeamType::Main) => main_teams += 1,
+                    Ok(_) => {}
+                    Err(_) => self.problem(
+                        CLUBS_FILE,
+                        team_subject.clone(),
+                        format!(
+                            "unknown team_type '{}', expected Main, B, U18, U19, U21 or U23",
+                            team.team_type
+                        ),
+                    ),
+                }
+
+                if !league_ids.contains(&team.league_id) {
+                    self.problem(
+                        CLUBS_FILE,
+                        team_subject,
+                        format!("unknown league_id {}", team.league_id),
+                    );
+                }
+            }
+
+            if main_teams != 1 {
+                self.problem(
+                    CLUBS_FILE,
+                    subject,
+                    format!("needs exactly one Main team, has {}", main_teams),
+                );
+            }
+        }
+    }
+
+    fn validate_names(&mut self, data: &DatabaseEntity) {
+        let country_ids: HashSet<u32> = data.countries.iter().map(|c| c.id).collect();
+
+        let mut named_countries = HashSet::new();
+
+        for names in &data.names_by_country {
+            let subject = format!("names of country {}", names.country_id);
+
+            if !country_ids.contains(&names.country_id) {
+                self.problem(
+                    NAMES_BY_COUNTRY_FILE,
+                    subject.clone(),
+                    "unknown country_id".to_string(),
+                );
+            }
+
+            if names.first_names.is_empty() || names.last_names.is_empty() {
+                self.problem(
+                    NAMES_BY_COUNTRY_FILE,
+                    subject,
+                    "needs first_names and last_names".to_string(),
+                );
+            } else {
+                named_countries.insert(names.country_id);
+            }
+        }
+
+        // People at clubs are generated with the names of the club's country
+        let mut reported = HashSet::new();
+
+        for club in &data.clubs {
+            if country_ids.contains(&club.country_id)
+                && !named_countries.contains(&club.country_id)
+                && reported.insert(club.country_id)
+            {
+                self.problem(
+                    NAMES_BY_COUNTRY_FILE,
+                    format!("country {}", club.country_id),
+                    format!("has clubs but no names, '{}' is one of them", club.name),
+                );
+            }
+        }
+    }
+
+    fn validate_players(&mut self, data: &DatabaseEntity) {
+        let country_ids: HashSet<u32> = data.countries.iter().map(|c| c.id).collect();
+        let team_ids = Self::team_ids(data);
+
+        let mut ids = HashSet::new();
+        let mut skills = PlayerSkills::default();
+
+        for player in &data.players {
+            let subject = format!(
+                "player {} '{} {}'",
+                player.id, player.first_name, player.last_name
+            );
+
+            self.unique(PLAYERS_FILE, &subject, "id", &mut ids, player.id);
+            self.person(
+                PLAYERS_FILE,
+                &subject,
+                (player.team_id, player.country_id, &player.birth_date),
+                (&team_ids, &country_ids),
+            );
+            self.contract(PLAYERS_FILE, &subject, player.contract.as_ref());
+
+            if !player
+                .positions
+                .iter()
+                .any(|p| p.level >= REQUIRED_POSITION_LEVEL)
+            {
+                self.problem(
+                    PLAYERS_FILE,
+                    subject.clone(),
+                    format!(
+                        "needs a position at level {} or above",
+                        REQUIRED_POSITION_LEVEL
+                    ),
+                );
+            }
+
+            for position in &player.positions {
+                if position.position_type().is_none() {
+                    self.problem(
+                        PLAYERS_FILE,
+                        subject.clone(),
+                        format!(
+                            "unknown position '{}', expected a short name as GK or AMC",
+                            position.position
+                        ),
+                    );
+                }
+
+                if !(1..=20).contains(&position.level) {
+                    self.problem(
+                        PLAYERS_FILE,
+                        subject.clone(),
+                        format!(
+                            "position {} level {} is not 1 to 20",
+                            position.position, position.level
+                        ),
+                    );
+                }
+            }
+
+            for (name, value) in &player.skills {
+                if skills.skill_mut(name).is_none() {
+                    self.problem(
+                        PLAYERS_FILE,
+                        subject.clone(),
+                        format!("unknown skill '{}'", name),
+                    );
+                } else if !(1.0..=20.0).contains(value) {
+                    self.problem(
+                        PLAYERS_FILE,
+                        subject.clone(),
+                        format!("skill {} {} is not 1 to 20", name, value),
+                    );
+                }
+            }
+
+            for (name, ability) in [
+                ("current_ability", player.current_ability),
+                ("potential_ability", player.potential_ability),
+            ] {
+                if ability.is_some_and(|ability| ability > 200) {
+                    self.problem(
+                        PLAYERS_FILE,
+                        subject.clone(),
+                        format!("{} is over 200", name),
+                    );
+                }
+            }
+        }
+    }
+
+    fn validate_staff(&mut self, data: &DatabaseEntity) {
+        let country_ids: HashSet<u32> = data.countries.iter().map(|c| c.id).collect();
+        let team_ids = Self::team_ids(data);
+
+        let mut ids = HashSet::new();
+        let mut attributes = StaffAttributes::default();
+
+        for staff in &data.staff {
+            let subject = format!(
+                "staff {} '{} {}'",
+                staff.id, staff.first_name, staff.last_name
+            );
+
+            self.unique(STAFF_FILE, &subject, "id", &mut ids, staff.id);
+            self.person(
+                STAFF_FILE,
+                &subject,
+                (staff.team_id, staff.country_id, &staff.birth_date),
+                (&team_ids, &country_ids),
+            );
+            self.contract(STAFF_FILE, &subject, staff.contract.as_ref());
+
+            if staff.staff_position().is_none() {
+                self.problem(
+                    STAFF_FILE,
+                    subject.clone(),
+                    format!(
+                        "unknown position '{}', expected as Manager or Physio",
+                        staff.position
+                    ),
+                );
+            }
+
+            if staff.license.is_some() && staff.license_type().is_none() {
+                self.problem(
+                    STAFF_FILE,
+                    subject.clone(),
+                    format!(
+                        "unknown license '{}', expected as ContinentalPro or NationalA",
+                        staff.license.as_deref().unwrap_or_default()
+                    ),
+                );
+            }
+
+            for (name, value) in &staff.attributes {
+                if attributes.attribute_mut(name).is_none() {
+                    self.problem(
+                        STAFF_FILE,
+                        subject.clone(),
+                        format!("unknown attribute '{}'", name),
+                    );
+                } else if *value > 20 {
+                    self.problem(
+                        STAFF_FILE,
+                        subject.clone(),
+                        format!("attribute {} {} is over 20", name, value),
+                    );
+                }
+            }
+        }
+    }
+
+    fn team_ids(data: &DatabaseEntity) -> HashSet<u32> {
+        data.clubs
+            .iter()
+            .flat_map(|c| c.teams.iter().map(|t| t.id))
+            .collect()
+    }
+
+    /// Team, nationality and birth date, shared by players and staff
+    fn person(
+        &mut self,
+        file: &str,
+        subject: &str,
+        (team_id, country_id, birth_date): (u32, u32, &str),
+        (team_ids, country_ids): (&HashSet<u32>, &HashSet<u32>),
+    ) {
+        if !team_ids.contains(&team_id) {
+            self.problem(
+                file,
+                subject.to_string(),
+                format!("unknown team_id {}", team_id),
+            );
+        }
+
+        if !country_ids.contains(&country_id) {
+            self.problem(
+                file,
+                subject.to_string(),
+                format!("unknown country_id {}", country_id),
+            );
+        }
+
+        if parse_date(birth_date).is_none() {
+            self.problem(
+                file,
+                subject.to_string(),
+                format!("birth_date '{}' is not YYYY-MM-DD", birth_date),
+            );
+        }
+    }
+
+    fn contract(&mut self, file: &str, subject: &str, contract: Option<&ContractEntity>) {
+        if let Some(contract) = contract {
+            if parse_date(&contract.expiration).is_none() {
+                self.problem(
+                    file,
+                    subject.to_string(),
+                    format!(
+                        "contract expiration '{}' is not YYYY-MM-DD",
+                        contract.expiration
+                    ),
+                );
+            }
+        }
+    }
+
+    fn unique<T: Eq + Hash>(
+        &mut self,
+        file: &str,
+        subject: &str,
+        field: &str,
+        seen: &mut HashSet<T>,
+        value: T,
+    ) {
+        if !seen.insert(value) {
+            self.problem(file, subject.to_string(), format!("duplicate {}", field));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DatabaseLoader;
+
+    #[test]
+    fn embedded_database_is_valid() {
+        assert!(DatabaseValidator::validate(&DatabaseLoader::load()).is_ok());
+    }
+
+    #[test]
+    fn reports_leagues_with_an_odd_number_of_teams() {
+        let mut data = DatabaseLoader::load();
+
+        let league_id = data.clubs[0].teams[0].league_id;
+        let other_league_id = data.leagues.iter().find(|l| l.id != league_id).unwrap().id;
+
+        data.clubs[0].teams[0].league_id = other_league_id;
+
+        let Err(DatabaseError::Invalid(problems)) = DatabaseValidator::validate(&data) else {
+            panic!("the database should be invalid");
+        };
+
+        assert!(
+            problems
+                .iter()
+                .any(|p| p.contains(&format!("league {} ", league_id))
+                    && p.ends_with("has an odd number of teams, 19"))
+        );
+    }
+
+    #[test]
+    fn reports_every_broken_reference() {
+        let mut data = DatabaseLoader::load();
+
+        data.leagues[0].country_id = 999_999;
+        data.clubs[0].teams[0].league_id = 999_999;
+        data.clubs[1].teams[0].team_type = "Senior".to_string();
+        data.leagues[0].squad_size = Some(11);
+
+        let Err(DatabaseError::Invalid(problems)) = DatabaseValidator::validate(&data) else {
+            panic!("the database should be invalid");
+        };
+
+        assert!(
+            problems
+                .iter()
+                .any(|p| p.starts_with(LEAGUES_FILE) && p.ends_with("unknown country_id 999999"))
+        );
+        assert!(
+            problems
+                .iter()
+                .any(|p| p.starts_with(CLUBS_FILE) && p.ends_with("unknown league_id 999999"))
+        );
+        assert!(
+            problems
+                .iter()
+                .any(|p| p.contains("unknown team_type 'Senior'"))
+        );
+        assert!(
+            problems
+                .iter()
+                .any(|p| p.ends_with("squad_size needs at least 16 players"))
+        );
+    }
+}
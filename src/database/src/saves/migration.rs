@@ -0,0 +1,25 @@
+use serde_json::Value;
+
+/// Upgrades the world of a save by one format version
+pub type SaveMigration = fn(&mut Value);
+
+/// Migration at index N upgrades a save from version N + 1 to N + 2.
+/// Add one here whenever a change to the world model breaks older saves,
+/// and bump `SAVE_FORMAT_VERSION` with it.
+pub const SAVE_MIGRATIONS: &[SaveMigration] = &[];
+
+pub struct SaveMigrations;
+
+impl SaveMigrations {
+    /// Runs the migrations a save written in `version` needs, returns the version it ends up in
+    pub fn migrate(data: &mut Value, version: u32, migrations: &[SaveMigration]) -> u32 {
+        let mut version = version.max(1);
+
+        while let Some(migration) = migrations.get(version as usize - 1) {
+            migration(data);
+            version += 1;
+        }
+
+        version
+    }
+}
@@ -0,0 +1,5 @@
+mod migration;
+mod save;
+
+pub use migration::*;
+pub use save::*;
@@ -0,0 +1,242 @@
+use crate::saves::{SAVE_MIGRATIONS, SaveMigrations};
+use core::SimulatorData;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use log::info;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// Version of the world layout written by this build
+pub const SAVE_FORMAT_VERSION: u32 = 1;
+
+pub const SAVE_DIRECTORY: &str = "saves";
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(std::io::Error),
+    Format(serde_json::Error),
+    /// Written by a newer build than this one
+    UnsupportedVersion(u32),
+}
+
+impl Display for SaveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Io(err) => write!(f, "IO error: {}", err),
+            SaveError::Format(err) => write!(f, "invalid save: {}", err),
+            SaveError::UnsupportedVersion(version) => write!(
+                f,
+                "save version {} is newer than the supported version {}",
+                version, SAVE_FORMAT_VERSION
+            ),
+        }
+    }
+}
+
+impl From<std::io::Error> for SaveError {
+    fn from(err: std::io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(err: serde_json::Error) -> Self {
+        SaveError::Format(err)
+    }
+}
+
+#[derive(Serialize)]
+struct SaveFile<'a> {
+    version: u32,
+    game_date: String,
+    data: &'a SimulatorData,
+}
+
+/// Only the header is read up front, the world goes through the migrations first
+#[derive(Deserialize)]
+struct SaveFileHeader {
+    version: u32,
+    data: Value,
+}
+
+/// The whole world as a gzipped JSON file
+pub struct SaveGame;
+
+impl SaveGame {
+    pub fn save(data: &SimulatorData, path: &Path) -> Result<(), SaveError> {
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+
+        let save_file = SaveFile {
+            version: SAVE_FORMAT_VERSION,
+            game_date: data.date.format("%Y-%m-%d").to_string(),
+            data,
+        };
+
+        // The previous save stays intact until the new one is complete
+        let mut temp_file = path.as_os_str().to_owned();
+        temp_file.push(".tmp");
+
+        let mut encoder = GzEncoder::new(BufWriter::new(File::create(&temp_file)?), Compression::fast());
+
+        serde_json::to_writer(&mut encoder, &save_file)?;
+
+        encoder.finish()?.flush()?;
+
+        std::fs::rename(&temp_file, path)?;
+
+        info!("game saved to {}", path.display());
+
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<SimulatorData, SaveError> {
+        let decoder = GzDecoder::new(BufReader::new(File::open(path)?));
+
+        let header: SaveFileHeader = serde_json::from_reader(BufReader::new(decoder))?;
+
+        let mut data = Self::upgrade(header)?;
+
+        data.refresh_indexes();
+
+        info!("game loaded from {}", path.display());
+
+        Ok(data)
+    }
+
+    fn upgrade(header: SaveFileHeader) -> Result<SimulatorData, SaveError> {
+        if header.version > SAVE_FORMAT_VERSION {
+            return Err(SaveError::UnsupportedVersion(header.version));
+        }
+
+        let mut data = header.data;
+
+        SaveMigrations::migrate(&mut data, header.version, SAVE_MIGRATIONS);
+
+        Ok(serde_json::from_value(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::saves::SaveMigration;
+    use crate::{DatabaseGenerator, DatabaseLoader};
+    use core::league::LeagueTable;
+    use serde_json::json;
+
+    #[test]
+    fn migrations_run_from_the_save_version() {
+        let migrations: &[SaveMigration] = &[
+            |data| data["news"] = json!([]),
+            |data| data["match_played"] = json!(false),
+        ];
+
+        let mut data = json!({});
+        assert_eq!(3, SaveMigrations::migrate(&mut data, 1, migrations));
+        assert_eq!(json!({ "news": [], "match_played": false }), data);
+
+        let mut data = json!({});
+        assert_eq!(3, SaveMigrations::migrate(&mut data, 2, migrations));
+        assert_eq!(json!({ "match_played": false }), data);
+    }
+
+    /// Two Italian clubs in one league
+    fn generate_world(directory: &Path) -> SimulatorData {
+        let club = |id: u32| {
+            format!(
+                r#"{{"id": {id}, "name": "Club {id}", "country_id": 776, "location": {{"city_id": 1}},
+                "finance": {{"balance": 1000000}},
+                "stadium": {{"name": "Stadium {id}", "capacity": 10000, "quality": 10, "ownership": "Owned"}},
+                "teams": [{{"id": {id}, "name": "Club {id}", "slug": "club-{id}", "team_type": "Main", "league_id": 1,
+                "reputation": {{"home": 5000, "national": 5000, "world": 5000}}}}]}}"#
+            )
+        };
+
+        let files = [
+            ("continents.json", r#"[{"id": 1, "name": "Europe"}]"#.to_string()),
+            (
+                "countries.json",
+                r#"[{"id": 776, "code": "it", "slug": "italy", "name": "Italy", "continent_id": 1, "reputation": 0}]"#
+                    .to_string(),
+            ),
+            (
+                "leagues.json",
+                r#"[{"id": 1, "slug": "test-league", "name": "Test League", "country_id": 776, "reputation": 5000,
+                "settings": {"season_starting_half": {"from_day": 1, "from_month": 7, "to_day": 5, "to_month": 12},
+                "season_ending_half": {"from_day": 1, "from_month": 3, "to_day": 31, "to_month": 5}}}]"#
+                    .to_string(),
+            ),
+            ("clubs.json", format!("[{}, {}]", club(1), club(2))),
+            (
+                "names/names_by_country.json",
+                r#"[{"country_id": 776, "first_names": ["Andrea", "Marco"], "last_names": ["Rossi", "Russo"]}]"#
+                    .to_string(),
+            ),
+        ];
+
+        std::fs::create_dir_all(directory.join("names")).unwrap();
+
+        for (file, content) in files {
+            std::fs::write(directory.join(file), content).unwrap();
+        }
+
+        DatabaseGenerator::generate(&DatabaseLoader::load_from(directory).unwrap())
+    }
+
+    #[test]
+    fn saved_world_loads_back() {
+        let directory = std::env::temp_dir().join(format!("save-{}", std::process::id()));
+
+        let mut data = generate_world(&directory);
+
+        data.next_date();
+
+        let league = data.league_mut(1).unwrap();
+        league.table = LeagueTable::new(&[1, 2]);
+        league.table.rows[0].points = 3;
+
+        let player_id = data.club(1).unwrap().teams.teams[0].players.players[0].id;
+        data.player_mut(player_id).unwrap().statistics.goals = 7;
+
+        let path = directory.join(SAVE_DIRECTORY).join("world.save");
+
+        SaveGame::save(&data, &path).unwrap();
+
+        let loaded = SaveGame::load(&path).unwrap();
+        let temp_file_left = path.with_file_name("world.save.tmp").exists();
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert!(!temp_file_left);
+
+        // Lookups go through the indexes, which are not saved
+        assert!(loaded.indexes.is_some());
+        assert_eq!(loaded.date, data.date);
+        assert_eq!(loaded.league(1).unwrap().table.rows[0].points, 3);
+        assert_eq!(loaded.player(player_id).unwrap().statistics.goals, 7);
+        assert_eq!(
+            loaded.player(player_id).unwrap().full_name.to_string(),
+            data.player(player_id).unwrap().full_name.to_string()
+        );
+    }
+
+    #[test]
+    fn newer_saves_are_rejected() {
+        let header = SaveFileHeader {
+            version: SAVE_FORMAT_VERSION + 1,
+            data: json!({}),
+        };
+
+        assert!(matches!(
+            SaveGame::upgrade(header),
+            Err(SaveError::UnsupportedVersion(_))
+        ));
+    }
+}
@@ -11,6 +11,7 @@ use core::r#match::MatchField;
 use core::r#match::MatchPlayerCollection;
 use core::r#match::MatchSquad;
 use core::r#match::ResultMatchPositionData;
+use core::r#match::{ReplayDecoder, ReplayEncoder};
 use core::r#match::VectorExtensions;
 use core::Vector3;
 use env_logger::Env;
@@ -120,6 +121,8 @@ async fn main() {
     let mut replay_time: u64 = 0;
     let mut max_live_time: u64 = 0;  // Track maximum time reached in Live mode
     let mut pass_target: Option<PassTargetInfo> = None;  // Track current pass target
+    let mut replay_data: Option<ResultMatchPositionData> = None;  // Recorded data as stored for the UI
+    let mut replay_data_time: u64 = 0;
 
     loop {
         current_frame += 1;
@@ -171,6 +174,15 @@ async fn main() {
             max_live_time,
         );
 
+        // Replay from the stored binary format, so it plays back as it does in the UI
+        if play_mode == PlayMode::Replay && (replay_data.is_none() || replay_data_time != max_live_time) {
+            let mut recorded = match_data.clone();
+            recorded.compress();
+
+            replay_data = ReplayDecoder::decode(&ReplayEncoder::encode(&recorded)).ok();
+            replay_data_time = max_live_time;
+        }
+
         let start = Instant::now();
 
         // Execute game tick or update replay based on mode
@@ -213,13 +225,13 @@ async fn main() {
                 }
 
                 // Update field positions from recorded data
-                update_positions_from_replay(&mut field, &match_data, replay_time);
+                update_positions_from_replay(&mut field, replay_data.as_ref().unwrap_or(&match_data), replay_time);
             }
             _ => {
                 // Paused in replay mode
                 if play_mode == PlayMode::Replay {
                     // Make sure positions are set to current replay time
-                    update_positions_from_replay(&mut field, &match_data, replay_time);
+                    update_positions_from_replay(&mut field, replay_data.as_ref().unwrap_or(&match_data), replay_time);
                 }
             }
         }
@@ -1,9 +1,11 @@
 use std::env;
-use core::utils::TimeEstimation;
-use database::{DatabaseGenerator, DatabaseLoader};
+use batch::{BatchArgs, BatchRunner};
+use football_core::utils::TimeEstimation;
+use database::{DatabaseGenerator, DatabaseLoader, SaveGame};
 use env_logger::Env;
 use log::{info};
-use server::{FootballSimulatorServer, GameAppData};
+use server::{FootballSimulatorServer, GameAppData, LiveMatches, MatchStore};
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -11,13 +13,29 @@ use tokio::sync::RwLock;
 async fn main() {
     color_eyre::install().unwrap();
 
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.first().map(String::as_str) == Some("batch") {
+        env_logger::Builder::from_env(Env::default()
+            .default_filter_or("info")
+        ).init();
+
+        run_batch(&args[1..]);
+    }
+
     env_logger::Builder::from_env(Env::default()
         .default_filter_or("debug")
     ).init();
 
     let is_one_shot_game = env::var("MODE") == Ok(String::from("ONESHOT"));
 
-    let (database, estimated) = TimeEstimation::estimate(DatabaseLoader::load);
+    let database_directory = path_argument("--database");
+
+    let (database, estimated) = TimeEstimation::estimate(|| {
+        DatabaseLoader::load_configured(database_directory.as_deref())
+    });
+
+    let database = database.unwrap_or_else(|err| panic!("failed to load database: {}", err));
 
     info!("database loaded: {} ms", estimated);
 
@@ -25,13 +43,54 @@ async fn main() {
         info!("one shot game started");
     }
 
-    let game_data = DatabaseGenerator::generate(&database);
+    let game_data = match save_to_resume() {
+        Some(save_file) => SaveGame::load(&save_file).unwrap_or_else(|err| {
+            panic!("failed to load save {}: {}", save_file.display(), err)
+        }),
+        None => DatabaseGenerator::generate(&database),
+    };
 
     let data = GameAppData {
         database: Arc::new(database),
         data: Arc::new(RwLock::new(Some(game_data))),
+        match_store: MatchStore::from_env(),
+        live_matches: LiveMatches::new(),
         is_one_shot_game
     };
     
     FootballSimulatorServer::new(data).run().await;
 }
+
+/// `batch ...` simulates headless, writes the report and exits
+fn run_batch(args: &[String]) -> ! {
+    let result = BatchArgs::parse(args).and_then(BatchRunner::run);
+
+    match result {
+        Ok(report) => {
+            info!("batch finished: {} days, {} to {}", report.days, report.start_date, report.end_date);
+            std::process::exit(0)
+        }
+        Err(err) => {
+            eprintln!("batch failed: {}", err);
+            std::process::exit(1)
+        }
+    }
+}
+
+/// `--load <path>` resumes a saved game instead of generating a new world
+fn save_to_resume() -> Option<PathBuf> {
+    path_argument("--load")
+}
+
+/// Path following the argument `name` on the command line
+fn path_argument(name: &str) -> Option<PathBuf> {
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        if arg == name {
+            return args.next().map(PathBuf::from);
+        }
+    }
+
+    None
+}
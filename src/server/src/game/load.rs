@@ -0,0 +1,42 @@
+use crate::game::{SaveQuery, SaveResponse, save_name, save_path};
+use crate::{ApiError, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::{Query, State};
+use axum::response::{IntoResponse, Response};
+use database::{SaveError, SaveGame};
+
+/// POST /api/game/load?name={name}
+/// Replaces the running world with a saved one
+pub async fn game_load_action(
+    State(state): State<GameAppData>,
+    Query(params): Query<SaveQuery>,
+) -> ApiResult<Response> {
+    let name = save_name(params.name)?;
+    let path = save_path(&name);
+
+    if !path.exists() {
+        return Err(ApiError::NotFound(format!("Save '{}' not found", name)));
+    }
+
+    let save_file = path.clone();
+
+    let data = tokio::task::spawn_blocking(move || SaveGame::load(&save_file))
+        .await
+        .map_err(|err| ApiError::InternalError(format!("Load failed: {}", err)))?
+        .map_err(|err| match err {
+            SaveError::Io(_) => ApiError::InternalError(format!("Load failed: {}", err)),
+            _ => ApiError::BadRequest(format!("Load failed: {}", err)),
+        })?;
+
+    let game_date = data.date.format("%Y-%m-%d").to_string();
+
+    *state.data.write().await = Some(data);
+
+    let response = SaveResponse {
+        name,
+        path: path.display().to_string(),
+        game_date,
+    };
+
+    Ok(Json(response).into_response())
+}
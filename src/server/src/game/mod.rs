@@ -1,7 +1,11 @@
 mod create;
+mod load;
 mod process;
 pub mod routes;
+mod save;
 
 pub use create::*;
+pub use load::*;
 pub use process::*;
 pub use routes::*;
+pub use save::*;
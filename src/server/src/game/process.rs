@@ -4,6 +4,7 @@ use axum::extract::State;
 use axum::http::{StatusCode};
 use axum::response::IntoResponse;
 use axum::Json;
+use chrono::NaiveDate;
 use core::FootballSimulator;
 use core::SimulationResult;
 use log::{debug};
@@ -23,10 +24,14 @@ pub async fn game_process_action(State(state): State<GameAppData>) -> impl IntoR
             return;
         }
 
+        let match_date = simulator_data.date.date();
+
         let result = FootballSimulator::simulate(simulator_data);
         if result.has_match_results() {
-            tokio::task::spawn(async  {
-                write_match_results(result).await
+            let match_store = state.match_store.clone();
+
+            tokio::task::spawn(async move {
+                write_match_results(match_store, result, match_date).await
             });
 
             simulator_data.match_played = true;
@@ -41,11 +46,11 @@ pub async fn game_process_action(State(state): State<GameAppData>) -> impl IntoR
     }
 }
 
-async fn write_match_results(result: SimulationResult) {
+pub(crate) async fn write_match_results(match_store: MatchStore, result: SimulationResult, date: NaiveDate) {
     let mut tasks = JoinSet::new();
 
     for match_result in result.match_results {
-        tasks.spawn(MatchStore::store(match_result));
+        tasks.spawn(match_store.clone().store(match_result, date));
     }
 
     let now = Instant::now();
@@ -55,4 +60,6 @@ async fn write_match_results(result: SimulationResult) {
     let elapsed = now.elapsed().as_millis();
 
     debug!("match results stored in {} ms", elapsed);
+
+    match_store.remove_old_seasons(date).await;
 }
@@ -1,4 +1,4 @@
-use crate::game::{game_create_action, game_process_action};
+use crate::game::{game_create_action, game_load_action, game_process_action, game_save_action};
 use crate::GameAppData;
 use axum::routing::{get, post};
 use axum::Router;
@@ -7,4 +7,6 @@ pub fn game_routes() -> Router<GameAppData> {
     Router::new()
         .route("/api/game/create", get(game_create_action))
         .route("/api/game/process", post(game_process_action))
+        .route("/api/game/save", post(game_save_action))
+        .route("/api/game/load", post(game_load_action))
 }
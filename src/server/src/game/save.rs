@@ -0,0 +1,84 @@
+use crate::{ApiError, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::{Query, State};
+use axum::response::{IntoResponse, Response};
+use database::{SAVE_DIRECTORY, SaveGame};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const DEFAULT_SAVE_NAME: &str = "quicksave";
+
+#[derive(Deserialize)]
+pub struct SaveQuery {
+    /// Save slot name, letters, digits, '-' and '_' only (default: "quicksave")
+    pub name: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct SaveResponse {
+    pub name: String,
+    pub path: String,
+    /// Game date the save was taken at
+    pub game_date: String,
+}
+
+/// POST /api/game/save?name={name}
+/// Writes the whole world to the saves directory
+pub async fn game_save_action(
+    State(state): State<GameAppData>,
+    Query(params): Query<SaveQuery>,
+) -> ApiResult<Response> {
+    let name = save_name(params.name)?;
+    let path = save_path(&name);
+
+    let guard = Arc::clone(&state.data).read_owned().await;
+
+    if guard.is_none() {
+        return Err(ApiError::InternalError(
+            "Simulator data not loaded".to_string(),
+        ));
+    }
+
+    let save_file = path.clone();
+
+    let game_date = tokio::task::spawn_blocking(move || {
+        let simulator_data = guard.as_ref().unwrap();
+
+        SaveGame::save(simulator_data, &save_file)
+            .map(|_| simulator_data.date.format("%Y-%m-%d").to_string())
+    })
+    .await
+    .map_err(|err| ApiError::InternalError(format!("Save failed: {}", err)))?
+    .map_err(|err| ApiError::InternalError(format!("Save failed: {}", err)))?;
+
+    let response = SaveResponse {
+        name,
+        path: path.display().to_string(),
+        game_date,
+    };
+
+    Ok(Json(response).into_response())
+}
+
+pub(crate) fn save_name(name: Option<String>) -> ApiResult<String> {
+    let name = name.unwrap_or_else(|| DEFAULT_SAVE_NAME.to_string());
+
+    let is_valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if !is_valid {
+        return Err(ApiError::BadRequest(format!(
+            "Invalid save name '{}'",
+            name
+        )));
+    }
+
+    Ok(name)
+}
+
+pub(crate) fn save_path(name: &str) -> PathBuf {
+    PathBuf::from(SAVE_DIRECTORY).join(format!("{}.json.gz", name))
+}
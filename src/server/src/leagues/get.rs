@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 use core::r#match::Score;
 use core::r#match::player::statistics::MatchStatisticType;
 use core::r#match::GoalDetail;
+use core::awards::{AwardWinner, SeasonAwards};
+use core::SimulatorData;
 
 #[derive(Deserialize)]
 pub struct LeagueGetRequest {
@@ -24,6 +26,23 @@ pub struct LeagueGetViewModel<'l> {
     pub country_name: &'l str,
     pub table: LeagueTableDto<'l>,
     pub current_tour_schedule: Vec<TourSchedule<'l>>,
+    pub awards: Vec<LeagueSeasonAwards<'l>>,
+}
+
+#[derive(Serialize)]
+pub struct LeagueSeasonAwards<'a> {
+    pub season: String,
+    pub player_of_the_season: Option<LeagueAwardWinner<'a>>,
+    pub young_player_of_the_season: Option<LeagueAwardWinner<'a>>,
+    pub coach_of_the_season: Option<LeagueAwardWinner<'a>>,
+    pub team_of_the_season: Vec<LeagueAwardWinner<'a>>,
+}
+
+#[derive(Serialize)]
+pub struct LeagueAwardWinner<'a> {
+    pub id: u32,
+    pub name: String,
+    pub club_name: &'a str,
 }
 
 #[derive(Serialize)]
@@ -138,6 +157,12 @@ pub async fn league_get_action(
                 .collect(),
         },
         current_tour_schedule: Vec::new(),
+        awards: league
+            .awards
+            .iter()
+            .rev()
+            .map(|awards| get_season_awards(awards, simulator_data))
+            .collect(),
     };
 
     let now = simulator_data.date.date() + Duration::days(3);
@@ -248,3 +273,47 @@ pub async fn league_get_action(
     
     Ok(Json(model).into_response())
 }
+
+fn get_season_awards<'a>(awards: &SeasonAwards, data: &'a SimulatorData) -> LeagueSeasonAwards<'a> {
+    let player = |winner: &AwardWinner| {
+        let name = data
+            .player(winner.id)
+            .map(|player| player.full_name.to_string())
+            .unwrap_or_default();
+
+        award_winner(winner, name, data)
+    };
+
+    LeagueSeasonAwards {
+        season: awards.season.to_string(),
+        player_of_the_season: awards.player_of_the_season.as_ref().map(player),
+        young_player_of_the_season: awards.young_player_of_the_season.as_ref().map(player),
+        coach_of_the_season: awards.coach_of_the_season.as_ref().map(|winner| {
+            let name = data
+                .club(winner.club_id)
+                .and_then(|club| {
+                    club.teams
+                        .teams
+                        .iter()
+                        .flat_map(|team| team.staffs.staffs.iter())
+                        .find(|staff| staff.id == winner.id)
+                })
+                .map(|staff| staff.full_name.to_string())
+                .unwrap_or_default();
+
+            award_winner(winner, name, data)
+        }),
+        team_of_the_season: awards.team_of_the_season.iter().map(player).collect(),
+    }
+}
+
+fn award_winner<'a>(winner: &AwardWinner, name: String, data: &'a SimulatorData) -> LeagueAwardWinner<'a> {
+    LeagueAwardWinner {
+        id: winner.id,
+        name,
+        club_name: data
+            .club(winner.club_id)
+            .map(|club| club.name.as_str())
+            .unwrap_or_default(),
+    }
+}
@@ -5,11 +5,14 @@ mod events;
 mod game;
 mod leagues;
 mod r#match;
+mod news;
 mod player;
 mod routes;
 mod teams;
 
 pub use error::{ApiError, ApiResult};
+pub use r#match::live::LiveMatches;
+pub use r#match::stores::MatchStore;
 
 use crate::routes::ServerRoutes;
 use axum::response::IntoResponse;
@@ -70,6 +73,8 @@ impl FootballSimulatorServer {
 pub struct GameAppData {
     pub database: Arc<DatabaseEntity>,
     pub data: Arc<RwLock<Option<SimulatorData>>>,
+    pub match_store: MatchStore,
+    pub live_matches: LiveMatches,
     
     pub is_one_shot_game: bool,
 }
@@ -79,6 +84,8 @@ impl Clone for GameAppData {
         GameAppData {
             database: Arc::clone(&self.database),
             data: Arc::clone(&self.data),
+            match_store: self.match_store.clone(),
+            live_matches: self.live_matches.clone(),
             is_one_shot_game: self.is_one_shot_game
         }
     }
@@ -1,5 +1,4 @@
 use crate::{ApiError, ApiResult, GameAppData};
-use crate::r#match::stores::MatchStore;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
@@ -24,13 +23,15 @@ pub struct MatchMetadataResponse {
     pub chunk_count: usize,
     pub chunk_duration_ms: u64,
     pub total_duration_ms: u64,
+    /// Version of the binary replay the chunks are written in
+    pub replay_version: u8,
 }
 
 pub async fn match_chunk_action(
-    State(_): State<GameAppData>,
+    State(state): State<GameAppData>,
     Path(route_params): Path<MatchChunkRequest>,
 ) -> ApiResult<Response> {
-    let chunk_data = MatchStore::get_chunk(
+    let chunk_data = state.match_store.get_chunk(
         &route_params.league_slug,
         &route_params.match_id,
         route_params.chunk_number,
@@ -49,7 +50,7 @@ pub async fn match_chunk_action(
         .headers_mut()
         .append(
             "Content-Type",
-            "application/gzip"
+            "application/octet-stream"
                 .parse()
                 .map_err(|e| ApiError::InternalError(format!("Header parse error: {:?}", e)))?,
         );
@@ -66,10 +67,10 @@ pub async fn match_chunk_action(
 }
 
 pub async fn match_metadata_action(
-    State(_): State<GameAppData>,
+    State(state): State<GameAppData>,
     Path(route_params): Path<MatchMetadataRequest>,
 ) -> ApiResult<Response> {
-    let metadata_json = MatchStore::get_metadata(&route_params.league_slug, &route_params.match_id)
+    let metadata_json = state.match_store.get_metadata(&route_params.league_slug, &route_params.match_id)
         .await
         .ok_or_else(|| {
             ApiError::NotFound(format!(
@@ -82,6 +83,7 @@ pub async fn match_metadata_action(
         chunk_count: metadata_json["chunk_count"].as_u64().unwrap_or(1) as usize,
         chunk_duration_ms: metadata_json["chunk_duration_ms"].as_u64().unwrap_or(300_000),
         total_duration_ms: metadata_json["total_duration_ms"].as_u64().unwrap_or(0),
+        replay_version: metadata_json["replay_version"].as_u64().unwrap_or(0) as u8,
     };
 
     Ok(Json(metadata).into_response())
@@ -0,0 +1,142 @@
+use crate::{ApiError, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::response::{IntoResponse, Response};
+use core::SimulatorData;
+use core::r#match::{
+    CommentaryLanguage, CommentaryLine, CommentaryNames, CommentaryVerbosity, MatchCommentator,
+    MatchResultRaw,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct MatchCommentaryRequest {
+    pub league_slug: String,
+    pub match_id: String,
+}
+
+#[derive(Deserialize)]
+pub struct MatchCommentaryQuery {
+    /// Language code: "en" (default) or "es"
+    pub language: Option<String>,
+    /// How much gets a line: "highlights", "standard" (default) or "full"
+    pub verbosity: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct MatchCommentaryResponse {
+    pub language: &'static str,
+    pub verbosity: &'static str,
+    pub lines: Vec<CommentaryLine>,
+}
+
+/// GET /api/match/{league_slug}/{match_id}/commentary
+/// Returns the commentary of a played match, oldest line first
+pub async fn match_commentary_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<MatchCommentaryRequest>,
+    Query(params): Query<MatchCommentaryQuery>,
+) -> ApiResult<Response> {
+    let language = match &params.language {
+        Some(code) => CommentaryLanguage::ALL
+            .iter()
+            .copied()
+            .find(|language| language.as_str() == code)
+            .ok_or_else(|| ApiError::BadRequest(format!("Unknown language '{}'", code)))?,
+        None => CommentaryLanguage::English,
+    };
+
+    let verbosity = match &params.verbosity {
+        Some(verbosity) => CommentaryVerbosity::ALL
+            .iter()
+            .copied()
+            .find(|level| level.as_str() == verbosity)
+            .ok_or_else(|| ApiError::BadRequest(format!("Unknown verbosity '{}'", verbosity)))?,
+        None => CommentaryVerbosity::Standard,
+    };
+
+    let guard = state.data.read().await;
+
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let league_id = simulator_data
+        .indexes
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Indexes not available".to_string()))?
+        .slug_indexes
+        .get_league_by_slug(&route_params.league_slug)
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("League '{}' not found", route_params.league_slug))
+        })?;
+
+    let league = simulator_data
+        .league(league_id)
+        .ok_or_else(|| ApiError::NotFound(format!("League with ID {} not found", league_id)))?;
+
+    let match_result = league.matches.get(&route_params.match_id).ok_or_else(|| {
+        ApiError::NotFound(format!("Match '{}' not found", route_params.match_id))
+    })?;
+
+    let result_details = match_result
+        .details
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Match details not available".to_string()))?;
+
+    let names = commentary_names(
+        match_result.home_team_id,
+        match_result.away_team_id,
+        result_details,
+        simulator_data,
+    );
+
+    let lines = MatchCommentator::new(
+        match_result.home_team_id,
+        match_result.away_team_id,
+        &names,
+        language,
+        verbosity,
+    )
+    .commentate(&result_details.events);
+
+    let result = MatchCommentaryResponse {
+        language: language.as_str(),
+        verbosity: verbosity.as_str(),
+        lines,
+    };
+
+    Ok(Json(result).into_response())
+}
+
+/// Teams by name, players by surname as commentators call them
+fn commentary_names(
+    home_team_id: u32,
+    away_team_id: u32,
+    result_details: &MatchResultRaw,
+    simulator_data: &SimulatorData,
+) -> CommentaryNames {
+    let mut names = CommentaryNames::new();
+
+    for team_id in [home_team_id, away_team_id] {
+        if let Some(team) = simulator_data.team(team_id) {
+            names = names.with_team(team_id, &team.name);
+        }
+    }
+
+    let squads = [
+        &result_details.left_team_players,
+        &result_details.right_team_players,
+    ];
+
+    for player_id in squads
+        .iter()
+        .flat_map(|squad| squad.main.iter().chain(squad.substitutes.iter()))
+    {
+        if let Some(player) = simulator_data.player(*player_id) {
+            names = names.with_player(*player_id, &player.full_name.last_name);
+        }
+    }
+
+    names
+}
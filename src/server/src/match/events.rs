@@ -0,0 +1,97 @@
+use crate::{ApiError, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Response};
+use core::SimulatorData;
+use core::r#match::MatchTimelineEventKind;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct MatchEventsRequest {
+    pub league_slug: String,
+    pub match_id: String,
+}
+
+pub async fn match_events_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<MatchEventsRequest>,
+) -> ApiResult<Response> {
+    let guard = state.data.read().await;
+
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let league_id = simulator_data
+        .indexes
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Indexes not available".to_string()))?
+        .slug_indexes
+        .get_league_by_slug(&route_params.league_slug)
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("League '{}' not found", route_params.league_slug))
+        })?;
+
+    let league = simulator_data
+        .league(league_id)
+        .ok_or_else(|| ApiError::NotFound(format!("League with ID {} not found", league_id)))?;
+
+    let match_result = league.matches.get(&route_params.match_id).ok_or_else(|| {
+        ApiError::NotFound(format!("Match '{}' not found", route_params.match_id))
+    })?;
+
+    let result_details = match_result
+        .details
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Match details not available".to_string()))?;
+
+    let events = result_details
+        .events
+        .iter()
+        .map(|event| MatchEventDto {
+            time: event.time,
+            team_id: event.team_id,
+            is_home: event.team_id == match_result.home_team_id,
+            player_id: event.player_id,
+            player_name: event
+                .player_id
+                .and_then(|player_id| player_name(player_id, simulator_data)),
+            kind: event.kind,
+        })
+        .collect();
+
+    let result = MatchEventsResponse {
+        home_team_id: match_result.home_team_id,
+        away_team_id: match_result.away_team_id,
+        events,
+    };
+
+    Ok(Json(result).into_response())
+}
+
+fn player_name(player_id: u32, simulator_data: &SimulatorData) -> Option<String> {
+    let player = simulator_data.player(player_id)?;
+
+    Some(format!(
+        "{} {}",
+        player.full_name.first_name, player.full_name.last_name
+    ))
+}
+
+#[derive(Serialize)]
+pub struct MatchEventsResponse {
+    pub home_team_id: u32,
+    pub away_team_id: u32,
+    pub events: Vec<MatchEventDto>,
+}
+
+#[derive(Serialize)]
+pub struct MatchEventDto {
+    pub time: u64,
+    pub team_id: u32,
+    pub is_home: bool,
+    pub player_id: Option<u32>,
+    pub player_name: Option<String>,
+    #[serde(flatten)]
+    pub kind: MatchTimelineEventKind,
+}
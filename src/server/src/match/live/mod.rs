@@ -0,0 +1,222 @@
+mod start;
+mod stream;
+
+pub use start::*;
+pub use stream::*;
+
+use core::r#match::{LiveMatchFrame, MatchTimelineEvent};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+
+/// Frames a slow viewer can fall behind by before it catches up from the replay
+const LIVE_CHANNEL_CAPACITY: usize = 64;
+
+#[derive(Debug, Clone)]
+pub enum LiveMatchMessage {
+    Frame(Arc<LiveMatchFrame>),
+    End { home_goals: u8, away_goals: u8 },
+}
+
+/// A live match with what viewers joining late need to catch up
+struct LiveMatchChannel {
+    sender: broadcast::Sender<LiveMatchMessage>,
+    /// Every event of the match so far
+    events: Vec<MatchTimelineEvent>,
+    latest_frame: Option<Arc<LiveMatchFrame>>,
+    end: Option<LiveMatchMessage>,
+}
+
+impl LiveMatchChannel {
+    /// The latest frame with the events after the first `seen_events`, then the end once it is over
+    fn replay(&self, seen_events: usize) -> Vec<LiveMatchMessage> {
+        let mut messages = Vec::new();
+
+        if let Some(latest_frame) = &self.latest_frame {
+            let mut frame = LiveMatchFrame::clone(latest_frame);
+            frame.events = self.events.get(seen_events..).unwrap_or_default().to_vec();
+
+            messages.push(LiveMatchMessage::Frame(Arc::new(frame)));
+        }
+
+        messages.extend(self.end.clone());
+
+        messages
+    }
+}
+
+/// Matches being played for viewers, by live id
+#[derive(Clone, Default)]
+pub struct LiveMatches {
+    channels: Arc<Mutex<HashMap<u32, LiveMatchChannel>>>,
+    next_id: Arc<AtomicU32>,
+}
+
+impl LiveMatches {
+    pub fn new() -> Self {
+        LiveMatches::default()
+    }
+
+    fn open(&self) -> u32 {
+        let live_id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+
+        let (sender, _) = broadcast::channel(LIVE_CHANNEL_CAPACITY);
+
+        self.channels.lock().unwrap().insert(
+            live_id,
+            LiveMatchChannel {
+                sender,
+                events: Vec::new(),
+                latest_frame: None,
+                end: None,
+            },
+        );
+
+        live_id
+    }
+
+    /// Sends to the viewers and keeps it for the ones joining later
+    fn publish(&self, live_id: u32, message: LiveMatchMessage) {
+        let mut channels = self.channels.lock().unwrap();
+
+        let Some(channel) = channels.get_mut(&live_id) else {
+            return;
+        };
+
+        match &message {
+            LiveMatchMessage::Frame(frame) => {
+                channel.events.extend(frame.events.iter().cloned());
+                channel.latest_frame = Some(Arc::clone(frame));
+            }
+            LiveMatchMessage::End { .. } => channel.end = Some(message.clone()),
+        }
+
+        let _ = channel.sender.send(message);
+    }
+
+    fn viewers(&self, live_id: u32) -> usize {
+        self.channels
+            .lock()
+            .unwrap()
+            .get(&live_id)
+            .map_or(0, |channel| channel.sender.receiver_count())
+    }
+
+    /// What the viewer missed after the first `seen_events` events, and the messages from then on
+    fn subscribe(
+        &self,
+        live_id: u32,
+        seen_events: usize,
+    ) -> Option<(Vec<LiveMatchMessage>, broadcast::Receiver<LiveMatchMessage>)> {
+        self.channels
+            .lock()
+            .unwrap()
+            .get(&live_id)
+            .map(|channel| (channel.replay(seen_events), channel.sender.subscribe()))
+    }
+
+    fn close(&self, live_id: u32) {
+        self.channels.lock().unwrap().remove(&live_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::Vector3;
+    use core::r#match::{MatchState, MatchTimelineEventKind};
+
+    fn frame(time: u64, events: usize) -> LiveMatchMessage {
+        LiveMatchMessage::Frame(Arc::new(LiveMatchFrame {
+            time,
+            state: MatchState::FirstHalf,
+            home_goals: 0,
+            away_goals: 0,
+            ball: Vector3::zeros(),
+            players: Vec::new(),
+            events: (0..events)
+                .map(|_| MatchTimelineEvent {
+                    time,
+                    team_id: 1,
+                    player_id: None,
+                    kind: MatchTimelineEventKind::Foul,
+                })
+                .collect(),
+        }))
+    }
+
+    #[test]
+    fn viewers_get_messages_until_the_match_closes() {
+        let live_matches = LiveMatches::new();
+
+        let live_id = live_matches.open();
+        let other_id = live_matches.open();
+
+        assert_ne!(live_id, other_id);
+
+        let (replay, mut receiver) = live_matches.subscribe(live_id, 0).expect("live match");
+
+        assert!(replay.is_empty());
+        assert_eq!(live_matches.viewers(live_id), 1);
+
+        live_matches.publish(
+            live_id,
+            LiveMatchMessage::End {
+                home_goals: 2,
+                away_goals: 1,
+            },
+        );
+
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(LiveMatchMessage::End {
+                home_goals: 2,
+                away_goals: 1
+            })
+        ));
+
+        live_matches.close(live_id);
+
+        assert!(live_matches.subscribe(live_id, 0).is_none());
+    }
+
+    #[test]
+    fn late_viewers_get_the_latest_frame_with_the_missed_events() {
+        let live_matches = LiveMatches::new();
+
+        let live_id = live_matches.open();
+
+        live_matches.publish(live_id, frame(100, 2));
+        live_matches.publish(live_id, frame(200, 0));
+        live_matches.publish(live_id, frame(300, 1));
+        live_matches.publish(
+            live_id,
+            LiveMatchMessage::End {
+                home_goals: 0,
+                away_goals: 0,
+            },
+        );
+
+        let (replay, _) = live_matches.subscribe(live_id, 0).expect("live match");
+
+        assert_eq!(replay.len(), 2);
+
+        match &replay[0] {
+            LiveMatchMessage::Frame(frame) => {
+                assert_eq!(frame.time, 300);
+                assert_eq!(frame.events.len(), 3);
+            }
+            _ => panic!("frame first"),
+        }
+
+        assert!(matches!(replay[1], LiveMatchMessage::End { .. }));
+
+        let (caught_up, _) = live_matches.subscribe(live_id, 2).expect("live match");
+
+        match &caught_up[0] {
+            LiveMatchMessage::Frame(frame) => assert_eq!(frame.events.len(), 1),
+            _ => panic!("frame first"),
+        }
+    }
+}
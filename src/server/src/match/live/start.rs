@@ -0,0 +1,228 @@
+use crate::game::write_match_results;
+use crate::r#match::live::{LiveMatchMessage, LiveMatches};
+use crate::{ApiError, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::{Query, State};
+use axum::response::{IntoResponse, Response};
+use core::FootballSimulator;
+use core::r#match::{LiveMatch, MatchResult};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Match time between two frames
+const FRAME_INTERVAL_MS: u64 = 100;
+
+/// Engine ticks are 10ms of match time
+const TICKS_PER_FRAME: u64 = FRAME_INTERVAL_MS / 10;
+
+const MAX_SPEED: f64 = 600.0;
+
+/// A match nobody watches for this long is stopped, one nobody came to watch is not started
+const IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+const VIEWER_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Viewers arriving after the final whistle still get the replay and the result for this long
+const CLOSE_DELAY: Duration = Duration::from_secs(60);
+
+#[derive(Deserialize)]
+pub struct LiveMatchQuery {
+    pub league_slug: String,
+    /// A fixture of the league's schedule still to be played
+    pub fixture_id: String,
+    /// Match seconds per real second: 1 is real time (default), 0 plays as fast as possible
+    pub speed: Option<f64>,
+}
+
+#[derive(Serialize)]
+pub struct LiveMatchResponse {
+    pub live_id: u32,
+    pub stream_url: String,
+}
+
+/// POST /api/match/live?league_slug={slug}&fixture_id={id}&speed={speed}
+/// Plays a league fixture in the background from when the first viewer joins at the returned
+/// stream url. The result counts like the fixture had been played on its matchday
+pub async fn live_match_start_action(
+    State(state): State<GameAppData>,
+    Query(params): Query<LiveMatchQuery>,
+) -> ApiResult<Response> {
+    let speed = params.speed.unwrap_or(1.0);
+
+    if !(0.0..=MAX_SPEED).contains(&speed) {
+        return Err(ApiError::BadRequest(format!(
+            "Speed must be between 0 and {}",
+            MAX_SPEED
+        )));
+    }
+
+    let fixture = {
+        let guard = state.data.read().await;
+
+        let simulator_data = guard
+            .as_ref()
+            .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+        let league_id = simulator_data
+            .indexes
+            .as_ref()
+            .ok_or_else(|| ApiError::InternalError("Indexes not available".to_string()))?
+            .slug_indexes
+            .get_league_by_slug(&params.league_slug)
+            .ok_or_else(|| {
+                ApiError::NotFound(format!("League '{}' not found", params.league_slug))
+            })?;
+
+        FootballSimulator::fixture(simulator_data, league_id, &params.fixture_id).ok_or_else(
+            || {
+                ApiError::NotFound(format!(
+                    "Fixture '{}' not found or already played",
+                    params.fixture_id
+                ))
+            },
+        )?
+    };
+
+    let live_matches = state.live_matches.clone();
+
+    let live_id = live_matches.open();
+
+    info!(
+        "live match {} opened: {} {} - {} at speed {}",
+        live_id,
+        params.fixture_id,
+        fixture.home_squad.team_name,
+        fixture.away_squad.team_name,
+        speed
+    );
+
+    tokio::task::spawn_blocking(move || {
+        if !wait_for_viewer(&live_matches, live_id) {
+            info!("live match {} not started, nobody came to watch", live_id);
+            live_matches.close(live_id);
+            return;
+        }
+
+        let mut live_match = fixture.live();
+
+        if !play_live(&mut live_match, &live_matches, live_id, speed) {
+            info!("live match {} stopped, nobody is watching", live_id);
+            live_matches.close(live_id);
+            return;
+        }
+
+        let result = fixture.result(live_match.finish());
+
+        live_matches.publish(
+            live_id,
+            LiveMatchMessage::End {
+                home_goals: result.score.home_team.get(),
+                away_goals: result.score.away_team.get(),
+            },
+        );
+
+        info!("live match {} finished", live_id);
+
+        record_result(&state, result);
+
+        tokio::spawn(async move {
+            tokio::time::sleep(CLOSE_DELAY).await;
+            live_matches.close(live_id);
+        });
+    });
+
+    let response = LiveMatchResponse {
+        live_id,
+        stream_url: format!("/api/match/live/{}/stream", live_id),
+    };
+
+    Ok(Json(response).into_response())
+}
+
+/// Goes through the league result path and is stored for replays like a simulated match
+fn record_result(state: &GameAppData, match_result: MatchResult) {
+    let fixture_id = match_result.id.clone();
+
+    let mut guard = state.data.blocking_write();
+
+    let Some(simulator_data) = guard.as_mut() else {
+        return;
+    };
+
+    let match_date = simulator_data.date.date();
+
+    let result = FootballSimulator::record_fixture(simulator_data, match_result);
+
+    if result.has_match_results() {
+        let match_store = state.match_store.clone();
+
+        tokio::task::spawn(async move {
+            write_match_results(match_store, result, match_date).await
+        });
+
+        simulator_data.match_played = true;
+    } else {
+        info!(
+            "live fixture {} was played in the meantime, its result is dropped",
+            fixture_id
+        );
+    }
+}
+
+/// False when nobody subscribed in time
+fn wait_for_viewer(live_matches: &LiveMatches, live_id: u32) -> bool {
+    let started = Instant::now();
+
+    while live_matches.viewers(live_id) == 0 {
+        if started.elapsed() > IDLE_TIMEOUT {
+            return false;
+        }
+
+        std::thread::sleep(VIEWER_POLL_INTERVAL);
+    }
+
+    true
+}
+
+/// Plays the match a frame at a time, sleeping between frames to keep to the speed.
+/// False when it was stopped before the end
+fn play_live(
+    live_match: &mut LiveMatch<840, 545>,
+    live_matches: &LiveMatches,
+    live_id: u32,
+    speed: f64,
+) -> bool {
+    let started = Instant::now();
+    let mut last_watched = Instant::now();
+
+    let mut match_time_ms = 0u64;
+
+    loop {
+        for _ in 0..TICKS_PER_FRAME {
+            if !live_match.tick() {
+                live_matches.publish(live_id, LiveMatchMessage::Frame(Arc::new(live_match.frame())));
+                return true;
+            }
+        }
+
+        match_time_ms += FRAME_INTERVAL_MS;
+
+        if live_matches.viewers(live_id) > 0 {
+            last_watched = Instant::now();
+        } else if last_watched.elapsed() > IDLE_TIMEOUT {
+            return false;
+        }
+
+        live_matches.publish(live_id, LiveMatchMessage::Frame(Arc::new(live_match.frame())));
+
+        if speed > 0.0 {
+            let due = Duration::from_secs_f64(match_time_ms as f64 / 1000.0 / speed);
+
+            if let Some(wait) = due.checked_sub(started.elapsed()) {
+                std::thread::sleep(wait);
+            }
+        }
+    }
+}
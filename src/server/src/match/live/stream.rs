@@ -0,0 +1,109 @@
+use crate::r#match::live::{LiveMatchMessage, LiveMatches};
+use crate::{ApiError, ApiResult, GameAppData};
+use axum::extract::{Path, State};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+#[derive(Deserialize)]
+pub struct LiveMatchStreamRequest {
+    pub live_id: u32,
+}
+
+/// A viewer's place in the match
+struct LiveMatchViewer {
+    live_matches: LiveMatches,
+    live_id: u32,
+    /// Replayed messages still to send before the live ones
+    pending: VecDeque<LiveMatchMessage>,
+    receiver: broadcast::Receiver<LiveMatchMessage>,
+    seen_events: usize,
+    ended: bool,
+}
+
+impl LiveMatchViewer {
+    /// The next message, a viewer that fell behind catches up from the replay so no event is lost
+    async fn next(&mut self) -> Option<LiveMatchMessage> {
+        loop {
+            if let Some(message) = self.pending.pop_front() {
+                return Some(message);
+            }
+
+            match self.receiver.recv().await {
+                Ok(message) => return Some(message),
+                Err(RecvError::Lagged(_)) => {
+                    let (replay, receiver) =
+                        self.live_matches.subscribe(self.live_id, self.seen_events)?;
+
+                    self.pending = replay.into();
+                    self.receiver = receiver;
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+/// GET /api/match/live/{live_id}/stream
+/// Server-sent "frame" events with positions, score and new events, then an "end" event.
+/// The first frame brings a late viewer up to date with every event so far
+pub async fn live_match_stream_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<LiveMatchStreamRequest>,
+) -> ApiResult<Response> {
+    let live_matches = state.live_matches.clone();
+
+    let (replay, receiver) = live_matches
+        .subscribe(route_params.live_id, 0)
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("Live match {} not found", route_params.live_id))
+        })?;
+
+    let viewer = LiveMatchViewer {
+        live_matches,
+        live_id: route_params.live_id,
+        pending: replay.into(),
+        receiver,
+        seen_events: 0,
+        ended: false,
+    };
+
+    let stream = futures::stream::unfold(viewer, |mut viewer| async move {
+        loop {
+            if viewer.ended {
+                return None;
+            }
+
+            let event = match viewer.next().await? {
+                LiveMatchMessage::Frame(frame) => {
+                    viewer.seen_events += frame.events.len();
+                    Event::default().event("frame").json_data(&*frame)
+                }
+                LiveMatchMessage::End {
+                    home_goals,
+                    away_goals,
+                } => {
+                    viewer.ended = true;
+                    Event::default().event("end").json_data(json!({
+                        "home_goals": home_goals,
+                        "away_goals": away_goals
+                    }))
+                }
+            };
+
+            match event {
+                Ok(event) => return Some((Ok::<_, Infallible>(event), viewer)),
+                Err(_) => continue,
+            }
+        }
+    });
+
+    Ok(Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response())
+}
@@ -1,5 +1,8 @@
 ﻿pub mod chunk;
+mod commentary;
+mod events;
 mod get;
+pub mod live;
 pub mod routes;
 pub mod stores;
 
@@ -1,12 +1,19 @@
 use crate::r#match::chunk::{match_chunk_action, match_metadata_action};
 use crate::GameAppData;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::Router;
+use crate::r#match::commentary::match_commentary_action;
+use crate::r#match::events::match_events_action;
 use crate::r#match::get::match_get_action;
+use crate::r#match::live::{live_match_start_action, live_match_stream_action};
 
 pub fn match_routes() -> Router<GameAppData> {
     Router::new()
+        .route("/api/match/live", post(live_match_start_action))
+        .route("/api/match/live/{live_id}/stream", get(live_match_stream_action))
         .route("/api/match/{league_slug}/{match_id}", get(match_get_action))
         .route("/api/match/{league_slug}/{match_id}/metadata", get(match_metadata_action))
+        .route("/api/match/{league_slug}/{match_id}/events", get(match_events_action))
+        .route("/api/match/{league_slug}/{match_id}/commentary", get(match_commentary_action))
         .route("/api/match/{league_slug}/{match_id}/chunk/{chunk_number}", get(match_chunk_action))
 }
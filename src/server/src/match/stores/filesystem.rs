@@ -0,0 +1,116 @@
+use crate::r#match::stores::{MatchFile, MatchStorage};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Match files in a directory per season and league, `{root}/{season}/{league_slug}/`
+pub struct FileSystemMatchStorage {
+    root: PathBuf,
+}
+
+impl FileSystemMatchStorage {
+    pub fn new(root: PathBuf) -> Self {
+        FileSystemMatchStorage { root }
+    }
+
+    /// Season directories, the latest first
+    fn seasons(&self) -> io::Result<Vec<(u16, PathBuf)>> {
+        let entries = match fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+
+        let mut seasons = Vec::new();
+
+        for entry in entries {
+            let entry = entry?;
+
+            if let Some(season) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u16>().ok())
+            {
+                seasons.push((season, entry.path()));
+            }
+        }
+
+        seasons.sort_by(|a, b| b.0.cmp(&a.0));
+
+        Ok(seasons)
+    }
+
+    fn count_files(directory: &PathBuf) -> io::Result<usize> {
+        let mut count = 0;
+
+        for entry in fs::read_dir(directory)? {
+            let entry = entry?;
+
+            if entry.file_type()?.is_dir() {
+                count += Self::count_files(&entry.path())?;
+            } else {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+impl MatchStorage for FileSystemMatchStorage {
+    fn write(
+        &self,
+        season: u16,
+        league_slug: &str,
+        match_id: &str,
+        file: MatchFile,
+        data: &[u8],
+    ) -> io::Result<()> {
+        let directory = self.root.join(season.to_string()).join(league_slug);
+
+        fs::create_dir_all(&directory)?;
+
+        let file_name = file.name(match_id);
+
+        // Readers never see a half written file
+        let temp_file = directory.join(format!("{}.tmp", file_name));
+
+        fs::write(&temp_file, data)?;
+        fs::rename(&temp_file, directory.join(file_name))
+    }
+
+    fn read(
+        &self,
+        league_slug: &str,
+        match_id: &str,
+        file: MatchFile,
+    ) -> io::Result<Option<Vec<u8>>> {
+        let file_name = file.name(match_id);
+
+        for (_, directory) in self.seasons()? {
+            match fs::read(directory.join(league_slug).join(&file_name)) {
+                Ok(data) => return Ok(Some(data)),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn remove_seasons_before(&self, season: u16) -> io::Result<usize> {
+        let mut removed = 0;
+
+        for (_, directory) in self
+            .seasons()?
+            .into_iter()
+            .filter(|(directory_season, _)| *directory_season < season)
+        {
+            removed += Self::count_files(&directory)?;
+
+            fs::remove_dir_all(&directory)?;
+        }
+
+        Ok(removed)
+    }
+}
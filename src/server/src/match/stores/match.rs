@@ -1,168 +1,262 @@
-use async_compression::tokio::write::GzipEncoder;
-use core::r#match::{MatchResult, ResultMatchPositionData};
-use log::{debug, info};
+use crate::r#match::stores::{FileSystemMatchStorage, MatchFile, MatchStorage, SqliteMatchStorage};
+use chrono::NaiveDate;
+use core::league::Season;
+use core::r#match::{MatchResult, REPLAY_FORMAT_VERSION, ReplayEncoder, ResultMatchPositionData};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use log::{debug, error, info};
+use std::env;
+use std::io;
+use std::io::Write;
 use std::path::PathBuf;
-use tokio::fs::File;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use std::sync::Arc;
 
 const MATCH_DIRECTORY: &str = "matches";
+const MATCH_DATABASE: &str = "matches.db";
 const CHUNK_DURATION_MS: u64 = 300_000; // 5 minutes per chunk
 
-pub struct MatchStore;
+/// Seasons of matches kept, the current one included
+const RETAINED_SEASONS: u16 = 2;
+
+/// Writes played matches to the configured storage and reads them back for the UI
+#[derive(Clone)]
+pub struct MatchStore {
+    storage: Arc<dyn MatchStorage>,
+    /// 0 keeps every season
+    retained_seasons: u16,
+}
 
 impl MatchStore {
-    pub async fn get(league_slug: &str, match_id: &str) -> Vec<u8> {
-        let match_file = PathBuf::from(MATCH_DIRECTORY)
-            .join(league_slug)
-            .join(format!("{}.json.gz", match_id));
+    pub fn new(storage: Arc<dyn MatchStorage>, retained_seasons: u16) -> Self {
+        MatchStore {
+            storage,
+            retained_seasons,
+        }
+    }
 
-        let mut file = File::options().read(true).open(&match_file).await.unwrap();
+    /// `MATCH_STORAGE` picks the backend, "fs" (default) or "sqlite".
+    /// `MATCH_STORAGE_PATH` is the directory or database file, so it can live outside the working directory.
+    /// `MATCH_RETAINED_SEASONS` is how many seasons to keep, 0 keeps them all.
+    pub fn from_env() -> Self {
+        let path = env::var("MATCH_STORAGE_PATH").ok();
 
-        let mut result = Vec::new();
+        let storage: Arc<dyn MatchStorage> = match env::var("MATCH_STORAGE").as_deref() {
+            Ok("sqlite") => {
+                let path = PathBuf::from(path.unwrap_or_else(|| MATCH_DATABASE.to_string()));
 
-        file.read_to_end(&mut result)
-            .await
-            .expect(format!("failed to read match {}", match_id).as_str());
+                let storage = SqliteMatchStorage::open(&path).unwrap_or_else(|err| {
+                    panic!("failed to open match database {}: {}", path.display(), err)
+                });
 
-        result
-    }
+                info!("matches stored in database {}", path.display());
 
-    pub async fn get_chunk(league_slug: &str, match_id: &str, chunk_number: usize) -> Option<Vec<u8>> {
-        let chunk_file = PathBuf::from(MATCH_DIRECTORY)
-            .join(league_slug)
-            .join(format!("{}_chunk_{}.json.gz", match_id, chunk_number));
+                Arc::new(storage)
+            }
+            _ => {
+                let path = PathBuf::from(path.unwrap_or_else(|| MATCH_DIRECTORY.to_string()));
+
+                info!("matches stored in directory {}", path.display());
 
-        let mut file = match File::options().read(true).open(&chunk_file).await {
-            Ok(f) => f,
-            Err(_) => {
-                debug!("Chunk file not found: {}", chunk_file.display());
-                return None;
+                Arc::new(FileSystemMatchStorage::new(path))
             }
         };
 
-        let mut result = Vec::new();
-        file.read_to_end(&mut result)
-            .await
-            .expect(&format!("failed to read chunk {} for match {}", chunk_number, match_id));
+        let retained_seasons = env::var("MATCH_RETAINED_SEASONS")
+            .ok()
+            .and_then(|seasons| seasons.parse().ok())
+            .unwrap_or(RETAINED_SEASONS);
 
-        Some(result)
+        MatchStore::new(storage, retained_seasons)
     }
 
-    pub async fn get_metadata(league_slug: &str, match_id: &str) -> Option<serde_json::Value> {
-        let metadata_file = PathBuf::from(MATCH_DIRECTORY)
-            .join(league_slug)
-            .join(format!("{}_metadata.json", match_id));
+    pub async fn get(&self, league_slug: &str, match_id: &str) -> Option<Vec<u8>> {
+        self.read(league_slug, match_id, MatchFile::Export).await
+    }
 
-        let mut file = match File::options().read(true).open(&metadata_file).await {
-            Ok(f) => f,
-            Err(_) => {
-                debug!("Metadata file not found: {}", metadata_file.display());
-                return None; // No metadata means no chunks available
+    pub async fn get_chunk(
+        &self,
+        league_slug: &str,
+        match_id: &str,
+        chunk_number: usize,
+    ) -> Option<Vec<u8>> {
+        self.read(league_slug, match_id, MatchFile::Chunk(chunk_number))
+            .await
+    }
+
+    pub async fn get_metadata(
+        &self,
+        league_slug: &str,
+        match_id: &str,
+    ) -> Option<serde_json::Value> {
+        let metadata = self
+            .read(league_slug, match_id, MatchFile::Metadata)
+            .await?;
+
+        match serde_json::from_slice(&metadata) {
+            Ok(metadata) => Some(metadata),
+            Err(err) => {
+                error!(
+                    "invalid metadata for match {}/{}: {}",
+                    league_slug, match_id, err
+                );
+                None
             }
-        };
+        }
+    }
 
-        let mut contents = String::new();
-        file.read_to_string(&mut contents)
-            .await
-            .expect(&format!("failed to read metadata for match {}", match_id));
+    async fn read(&self, league_slug: &str, match_id: &str, file: MatchFile) -> Option<Vec<u8>> {
+        let storage = Arc::clone(&self.storage);
 
-        let metadata: serde_json::Value = serde_json::from_str(&contents)
-            .expect("failed to parse metadata");
+        let league_slug = league_slug.to_string();
+        let match_id = match_id.to_string();
 
-        Some(metadata)
-    }
+        let result =
+            tokio::task::spawn_blocking(move || storage.read(&league_slug, &match_id, file)).await;
 
-    pub async fn store(result: MatchResult) {
-        let out_dir = PathBuf::from(MATCH_DIRECTORY).join(&result.league_slug);
+        match result {
+            Ok(Ok(data)) => {
+                if data.is_none() {
+                    debug!("match file not found: {:?}", file);
+                }
+                data
+            }
+            Ok(Err(err)) => {
+                error!("failed to read match file {:?}: {}", file, err);
+                None
+            }
+            Err(err) => {
+                error!("failed to read match file {:?}: {}", file, err);
+                None
+            }
+        }
+    }
 
-        if let Ok(_) = tokio::fs::create_dir_all(&out_dir).await{}
+    /// Stores the position data of a match played on `date`
+    pub async fn store(self, result: MatchResult, date: NaiveDate) {
+        let match_id = result.id.clone();
 
-        let out_file = out_dir.join(format!("{}.json.gz", result.id));
+        let stored = tokio::task::spawn_blocking(move || {
+            Self::store_files(self.storage.as_ref(), &result, Self::season(date))
+        })
+        .await;
 
-        let file = File::options()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&out_file)
-            .await
-            .expect(&format!("failed to create file {}", out_file.display()));
-
-        let mut compressed_file = GzipEncoder::with_quality(file, async_compression::Level::Best);
-
-        if let Some(res) = result.details {
-            //serialize and write compressed data
-            let file_data =
-                serde_json::to_vec(&res.position_data).expect("failed to serialize data");
-
-            compressed_file
-                .write_all(&file_data)
-                .await
-                .expect("failed to write data");
-            compressed_file
-                .write_all(b"\n")
-                .await
-                .expect("failed to write newline");
-
-            compressed_file
-                .shutdown()
-                .await
-                .expect("failed to shutdown file");
-
-            // Store chunks
-            Self::store_chunks(&result.league_slug, &result.id, &res.position_data).await;
+        match stored {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => error!("failed to store match {}: {}", match_id, err),
+            Err(err) => error!("failed to store match {}: {}", match_id, err),
         }
     }
 
-    async fn store_chunks(league_slug: &str, match_id: &str, data: &ResultMatchPositionData) {
+    fn store_files(
+        storage: &dyn MatchStorage,
+        result: &MatchResult,
+        season: u16,
+    ) -> io::Result<()> {
+        let Some(details) = &result.details else {
+            return Ok(());
+        };
+
+        let league_slug = &result.league_slug;
+        let match_id = &result.id;
+
+        // full match as JSON, for debugging, the UI plays the replay chunks
+        let mut export = GzEncoder::new(Vec::new(), Compression::best());
+
+        serde_json::to_writer(&mut export, &details.position_data)?;
+        export.write_all(b"\n")?;
+
+        storage.write(
+            season,
+            league_slug,
+            match_id,
+            MatchFile::Export,
+            &export.finish()?,
+        )?;
+
+        Self::store_chunks(
+            storage,
+            season,
+            league_slug,
+            match_id,
+            &details.position_data,
+        )
+    }
+
+    fn store_chunks(
+        storage: &dyn MatchStorage,
+        season: u16,
+        league_slug: &str,
+        match_id: &str,
+        data: &ResultMatchPositionData,
+    ) -> io::Result<()> {
         let chunks = data.split_into_chunks(CHUNK_DURATION_MS);
         let chunk_count = chunks.len();
 
         info!("Storing {} chunks for match {}", chunk_count, match_id);
 
-        // Store each chunk
         for (idx, chunk) in chunks.iter().enumerate() {
-            let chunk_file = PathBuf::from(MATCH_DIRECTORY)
-                .join(league_slug)
-                .join(format!("{}_chunk_{}.json.gz", match_id, idx));
-
-            let file = File::options()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(&chunk_file)
-                .await
-                .expect(&format!("failed to create chunk file {}", chunk_file.display()));
-
-            let mut compressed_file = GzipEncoder::with_quality(file, async_compression::Level::Best);
-
-            let chunk_data = serde_json::to_vec(&chunk).expect("failed to serialize chunk");
+            let chunk_data = ReplayEncoder::encode(chunk);
 
             debug!("Chunk {} uncompressed size = {}", idx, chunk_data.len());
 
-            compressed_file
-                .write_all(&chunk_data)
-                .await
-                .expect("failed to write chunk data");
+            let mut compressed_chunk = GzEncoder::new(Vec::new(), Compression::best());
 
-            compressed_file
-                .shutdown()
-                .await
-                .expect("failed to shutdown chunk file");
-        }
+            compressed_chunk.write_all(&chunk_data)?;
 
-        // Store metadata
-        let metadata_file = PathBuf::from(MATCH_DIRECTORY)
-            .join(league_slug)
-            .join(format!("{}_metadata.json", match_id));
+            storage.write(
+                season,
+                league_slug,
+                match_id,
+                MatchFile::Chunk(idx),
+                &compressed_chunk.finish()?,
+            )?;
+        }
 
+        // Metadata goes last, a match without it has no chunks to play yet
         let metadata = serde_json::json!({
             "chunk_count": chunk_count,
             "chunk_duration_ms": CHUNK_DURATION_MS,
-            "total_duration_ms": data.max_timestamp()
+            "total_duration_ms": data.max_timestamp(),
+            "replay_version": REPLAY_FORMAT_VERSION
         });
 
-        tokio::fs::write(&metadata_file, serde_json::to_string_pretty(&metadata).unwrap())
-            .await
-            .expect(&format!("failed to write metadata file {}", metadata_file.display()));
+        storage.write(
+            season,
+            league_slug,
+            match_id,
+            MatchFile::Metadata,
+            &serde_json::to_vec_pretty(&metadata)?,
+        )
+    }
+
+    /// Drops the matches of the seasons that are no longer retained
+    pub async fn remove_old_seasons(&self, date: NaiveDate) {
+        if self.retained_seasons == 0 {
+            return;
+        }
+
+        let oldest_season = Self::season(date).saturating_sub(self.retained_seasons - 1);
+
+        let storage = Arc::clone(&self.storage);
+
+        let removed =
+            tokio::task::spawn_blocking(move || storage.remove_seasons_before(oldest_season)).await;
+
+        match removed {
+            Ok(Ok(0)) => {}
+            Ok(Ok(removed)) => info!(
+                "removed {} match files from before {}",
+                removed, oldest_season
+            ),
+            Ok(Err(err)) => error!("failed to remove old matches: {}", err),
+            Err(err) => error!("failed to remove old matches: {}", err),
+        }
+    }
+
+    /// Year the season of the date started in
+    fn season(date: NaiveDate) -> u16 {
+        match Season::from_date(date) {
+            Season::OneYear(year) | Season::TwoYear(year, _) => year,
+        }
     }
 }
@@ -1,3 +1,9 @@
+mod filesystem;
 pub mod r#match;
+mod sqlite;
+mod storage;
 
-pub use r#match::*;
\ No newline at end of file
+pub use filesystem::*;
+pub use r#match::*;
+pub use sqlite::*;
+pub use storage::*;
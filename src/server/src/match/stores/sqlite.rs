@@ -0,0 +1,97 @@
+use crate::r#match::stores::{MatchFile, MatchStorage};
+use rusqlite::{Connection, OptionalExtension, params};
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Match files as rows of a single SQLite database
+pub struct SqliteMatchStorage {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteMatchStorage {
+    pub fn open(path: &Path) -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    #[cfg(test)]
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> rusqlite::Result<Self> {
+        connection.pragma_update(None, "journal_mode", "WAL")?;
+
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS match_files (
+                league_slug TEXT NOT NULL,
+                match_id TEXT NOT NULL,
+                file TEXT NOT NULL,
+                season INTEGER NOT NULL,
+                data BLOB NOT NULL,
+                PRIMARY KEY (league_slug, match_id, file)
+            );
+            CREATE INDEX IF NOT EXISTS match_files_season ON match_files (season);",
+        )?;
+
+        Ok(SqliteMatchStorage {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    fn execute<T>(
+        &self,
+        statement: impl FnOnce(&Connection) -> rusqlite::Result<T>,
+    ) -> io::Result<T> {
+        let connection = self
+            .connection
+            .lock()
+            .map_err(|_| io::Error::other("match database lock poisoned"))?;
+
+        statement(&connection).map_err(io::Error::other)
+    }
+}
+
+impl MatchStorage for SqliteMatchStorage {
+    fn write(
+        &self,
+        season: u16,
+        league_slug: &str,
+        match_id: &str,
+        file: MatchFile,
+        data: &[u8],
+    ) -> io::Result<()> {
+        self.execute(|connection| {
+            connection.execute(
+                "INSERT OR REPLACE INTO match_files (league_slug, match_id, file, season, data)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![league_slug, match_id, file.name(match_id), season, data],
+            )
+        })?;
+
+        Ok(())
+    }
+
+    fn read(
+        &self,
+        league_slug: &str,
+        match_id: &str,
+        file: MatchFile,
+    ) -> io::Result<Option<Vec<u8>>> {
+        self.execute(|connection| {
+            connection
+                .query_row(
+                    "SELECT data FROM match_files WHERE league_slug = ?1 AND match_id = ?2 AND file = ?3",
+                    params![league_slug, match_id, file.name(match_id)],
+                    |row| row.get(0),
+                )
+                .optional()
+        })
+    }
+
+    fn remove_seasons_before(&self, season: u16) -> io::Result<usize> {
+        self.execute(|connection| {
+            connection.execute("DELETE FROM match_files WHERE season < ?1", params![season])
+        })
+    }
+}
@@ -0,0 +1,130 @@
+use std::io;
+
+/// Files kept for a played match
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchFile {
+    /// Full position data as gzipped JSON, for debugging
+    Export,
+    /// Gzipped binary replay of a part of the match, played by the UI
+    Chunk(usize),
+    Metadata,
+}
+
+impl MatchFile {
+    /// Name of the file, unique within a match
+    pub fn name(&self, match_id: &str) -> String {
+        match self {
+            MatchFile::Export => format!("{}.json.gz", match_id),
+            MatchFile::Chunk(chunk_number) => {
+                format!("{}_chunk_{}.replay.gz", match_id, chunk_number)
+            }
+            MatchFile::Metadata => format!("{}_metadata.json", match_id),
+        }
+    }
+}
+
+/// Where the files of played matches are kept.
+/// Calls block, run them off the async runtime.
+pub trait MatchStorage: Send + Sync {
+    /// `season` is the year the season started in
+    fn write(
+        &self,
+        season: u16,
+        league_slug: &str,
+        match_id: &str,
+        file: MatchFile,
+        data: &[u8],
+    ) -> io::Result<()>;
+
+    /// `None` when there is no such match or file
+    fn read(
+        &self,
+        league_slug: &str,
+        match_id: &str,
+        file: MatchFile,
+    ) -> io::Result<Option<Vec<u8>>>;
+
+    /// Removes the matches of the seasons started before `season`, returns how many files went
+    fn remove_seasons_before(&self, season: u16) -> io::Result<usize>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r#match::stores::{FileSystemMatchStorage, SqliteMatchStorage};
+    use std::path::PathBuf;
+
+    fn check_storage(storage: &dyn MatchStorage) {
+        storage
+            .write(
+                2024,
+                "serie-a",
+                "2024-08-18_1_2",
+                MatchFile::Chunk(0),
+                b"first",
+            )
+            .unwrap();
+        storage
+            .write(
+                2025,
+                "serie-a",
+                "2025-08-17_1_2",
+                MatchFile::Metadata,
+                b"{}",
+            )
+            .unwrap();
+
+        // written again, the last one wins
+        storage
+            .write(
+                2024,
+                "serie-a",
+                "2024-08-18_1_2",
+                MatchFile::Chunk(0),
+                b"replay",
+            )
+            .unwrap();
+
+        assert_eq!(
+            Some(b"replay".to_vec()),
+            storage
+                .read("serie-a", "2024-08-18_1_2", MatchFile::Chunk(0))
+                .unwrap()
+        );
+        assert_eq!(
+            None,
+            storage
+                .read("serie-a", "2024-08-18_1_2", MatchFile::Chunk(1))
+                .unwrap()
+        );
+
+        assert_eq!(1, storage.remove_seasons_before(2025).unwrap());
+
+        assert_eq!(
+            None,
+            storage
+                .read("serie-a", "2024-08-18_1_2", MatchFile::Chunk(0))
+                .unwrap()
+        );
+        assert!(
+            storage
+                .read("serie-a", "2025-08-17_1_2", MatchFile::Metadata)
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn file_system_storage_keeps_and_removes_matches() {
+        let root = std::env::temp_dir().join(format!("match_storage_{}", std::process::id()));
+
+        check_storage(&FileSystemMatchStorage::new(PathBuf::from(&root)));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn sqlite_storage_keeps_and_removes_matches() {
+        check_storage(&SqliteMatchStorage::open_in_memory().unwrap());
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{ApiError, ApiResult, GameAppData};
+use axum::extract::{Query, State};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use core::news::{NewsEntity, NewsEntityType, NewsImportance, NewsItem};
+use core::SimulatorData;
+use serde::{Deserialize, Serialize};
+
+const IMPORTANCE_LEVELS: [NewsImportance; 4] = [
+    NewsImportance::Minor,
+    NewsImportance::Notable,
+    NewsImportance::Major,
+    NewsImportance::Sensational,
+];
+
+#[derive(Deserialize)]
+pub struct NewsQuery {
+    /// Maximum number of stories to return (default: 50)
+    pub limit: Option<usize>,
+    /// Only stories about this team or its club
+    pub team_slug: Option<String>,
+    /// Only stories from this league
+    pub league_slug: Option<String>,
+    /// Story type: "big_win", "upset", "manager_sacked", etc.
+    pub news_type: Option<String>,
+    /// Least important stories to include: "minor", "notable", "major" or "sensational"
+    pub min_importance: Option<String>,
+}
+
+/// News feed, newest stories first
+#[derive(Serialize)]
+pub struct NewsResponse {
+    /// Current game date
+    pub game_date: String,
+    pub news: Vec<NewsItemDto>,
+}
+
+#[derive(Serialize)]
+pub struct NewsItemDto {
+    pub id: u32,
+    pub date: String,
+    pub news_type: &'static str,
+    pub importance: &'static str,
+    pub headline: String,
+    /// The subject of the story comes first
+    pub entities: Vec<NewsEntityDto>,
+}
+
+#[derive(Serialize)]
+pub struct NewsEntityDto {
+    /// Entity type: "league", "club", "team", "player" or "staff"
+    pub entity_type: &'static str,
+    pub id: u32,
+    pub name: String,
+    /// Slug of teams and leagues, for links
+    pub slug: Option<String>,
+}
+
+/// GET /api/news
+/// Returns the stories the simulation produced, for the UI and AI context
+pub async fn news_list_action(
+    State(state): State<GameAppData>,
+    Query(params): Query<NewsQuery>,
+) -> ApiResult<Response> {
+    let guard = state.data.read().await;
+
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let indexes = simulator_data
+        .indexes
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Indexes not available".to_string()))?;
+
+    let team_filter = match &params.team_slug {
+        Some(team_slug) => {
+            let team_id = indexes
+                .slug_indexes
+                .get_team_by_slug(team_slug)
+                .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", team_slug)))?;
+
+            let club_id = simulator_data
+                .team(team_id)
+                .map(|team| team.club_id)
+                .ok_or_else(|| ApiError::NotFound(format!("Team with ID {} not found", team_id)))?;
+
+            Some((team_id, club_id))
+        }
+        None => None,
+    };
+
+    let league_filter = match &params.league_slug {
+        Some(league_slug) => Some(
+            indexes
+                .slug_indexes
+                .get_league_by_slug(league_slug)
+                .ok_or_else(|| ApiError::NotFound(format!("League '{}' not found", league_slug)))?,
+        ),
+        None => None,
+    };
+
+    let min_importance = match &params.min_importance {
+        Some(importance) => IMPORTANCE_LEVELS
+            .iter()
+            .copied()
+            .find(|level| level.as_str() == importance)
+            .ok_or_else(|| ApiError::BadRequest(format!("Unknown importance '{}'", importance)))?,
+        None => NewsImportance::Minor,
+    };
+
+    let limit = params.limit.unwrap_or(50);
+
+    let news = simulator_data
+        .news
+        .latest()
+        .filter(|item| item.importance >= min_importance)
+        .filter(|item| match &params.news_type {
+            Some(news_type) => item.news_type.as_str() == news_type,
+            None => true,
+        })
+        .filter(|item| match team_filter {
+            Some((team_id, club_id)) => {
+                item.involves(NewsEntityType::Team, team_id)
+                    || item.involves(NewsEntityType::Club, club_id)
+            }
+            None => true,
+        })
+        .filter(|item| match league_filter {
+            Some(league_id) => item.involves(NewsEntityType::League, league_id),
+            None => true,
+        })
+        .take(limit)
+        .map(|item| news_item_dto(item, simulator_data))
+        .collect();
+
+    let response = NewsResponse {
+        game_date: simulator_data.date.format("%Y-%m-%d").to_string(),
+        news,
+    };
+
+    Ok(Json(response).into_response())
+}
+
+fn news_item_dto(item: &NewsItem, simulator_data: &SimulatorData) -> NewsItemDto {
+    NewsItemDto {
+        id: item.id,
+        date: item.date.format("%Y-%m-%d").to_string(),
+        news_type: item.news_type.as_str(),
+        importance: item.importance.as_str(),
+        headline: item.headline.clone(),
+        entities: item
+            .entities
+            .iter()
+            .map(|entity| news_entity_dto(entity, simulator_data))
+            .collect(),
+    }
+}
+
+fn news_entity_dto(entity: &NewsEntity, simulator_data: &SimulatorData) -> NewsEntityDto {
+    let slug = match entity.entity_type {
+        NewsEntityType::Team => simulator_data.team(entity.id).map(|team| team.slug.clone()),
+        NewsEntityType::League => simulator_data
+            .league(entity.id)
+            .map(|league| league.slug.clone()),
+        _ => None,
+    };
+
+    NewsEntityDto {
+        entity_type: entity.entity_type.as_str(),
+        id: entity.id,
+        name: entity.name.clone(),
+        slug,
+    }
+}
@@ -0,0 +1,5 @@
+mod list;
+pub mod routes;
+
+pub use list::*;
+pub use routes::*;
@@ -0,0 +1,8 @@
+use crate::news::news_list_action;
+use crate::GameAppData;
+use axum::routing::get;
+use axum::Router;
+
+pub fn news_routes() -> Router<GameAppData> {
+    Router::new().route("/api/news", get(news_list_action))
+}
@@ -10,6 +10,7 @@ use core::Team;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use core::Person;
+use core::awards::AwardScope;
 
 #[derive(Deserialize)]
 pub struct PlayerGetRequest {
@@ -48,6 +49,16 @@ pub struct PlayerGetViewModel<'p> {
     pub statistics: PlayerStatistics,
 
     pub status: PlayerStatusDto,
+
+    pub awards: Vec<PlayerAwardDto<'p>>,
+}
+
+#[derive(Serialize)]
+pub struct PlayerAwardDto<'a> {
+    pub award: &'static str,
+    pub season: String,
+    pub awarded_by: &'a str,
+    pub club_name: &'a str,
 }
 
 #[derive(Serialize)]
@@ -61,6 +72,7 @@ pub struct PlayerStatistics {
     pub player_of_the_match: u8,
     pub yellow_cards: u8,
     pub red_cards: u8,
+    pub clean_sheets: u16,
 
     pub shots_on_target: f32,
     pub tackling: f32,
@@ -227,6 +239,7 @@ pub async fn player_get_action(
         neighbor_teams,
         statistics: get_statistics(player),
         status: PlayerStatusDto::new(player.statuses.get()),
+        awards: get_awards(player, simulator_data),
     };
 
     if let Some(contract) = &player.contract {
@@ -240,6 +253,27 @@ pub async fn player_get_action(
     Ok(Json(model).into_response())
 }
 
+fn get_awards<'a>(player: &Player, data: &'a SimulatorData) -> Vec<PlayerAwardDto<'a>> {
+    player
+        .awards
+        .iter()
+        .rev()
+        .map(|award| PlayerAwardDto {
+            award: award.award_type.as_str(),
+            season: award.season.to_string(),
+            awarded_by: match award.scope {
+                AwardScope::League(league_id) => data.league(league_id).map(|league| league.name.as_str()),
+                AwardScope::Continent(continent_id) => data.continent(continent_id).map(|continent| continent.name.as_str()),
+            }
+            .unwrap_or_default(),
+            club_name: data
+                .club(award.club_id)
+                .map(|club| club.name.as_str())
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
 fn get_attributes(player: &Player) -> PlayerAttributesDto {
     PlayerAttributesDto {
         international_apps: player.player_attributes.international_apps,
@@ -327,6 +361,7 @@ fn get_statistics(player: &Player) -> PlayerStatistics {
         player_of_the_match: player.statistics.player_of_the_match,
         yellow_cards: player.statistics.yellow_cards,
         red_cards: player.statistics.red_cards,
+        clean_sheets: player.statistics.clean_sheets,
         shots_on_target: player.statistics.shots_on_target,
         tackling: player.statistics.tackling,
         passes: player.statistics.passes,
@@ -22,7 +22,7 @@ pub struct PlayerStateResponse<'p> {
     pub position: &'p str,
     pub age: u8,
 
-    // Mood & happiness
+    // Mood & morale
     pub mood: MoodDto,
 
     // Form & condition
@@ -34,18 +34,65 @@ pub struct PlayerStateResponse<'p> {
     // Current statuses/concerns
     pub concerns: Vec<&'static str>,
 
+    // Requests, promises and the club's answers
+    pub interactions: InteractionsDto,
+
     // Attributes relevant for AI
     pub personality: PersonalityDto,
 }
 
 #[derive(Serialize)]
 pub struct MoodDto {
-    /// 0-100 scale derived from happiness
-    pub happiness: u8,
+    /// Morale 0-100
+    pub morale: u8,
+    /// Morale state: "Very Poor", "Poor", "Okay", "Good" or "Superb"
+    pub morale_state: &'static str,
     /// Is the player generally happy?
     pub is_happy: bool,
     /// Behaviour state: "Poor", "Normal", or "Good"
     pub behaviour: &'static str,
+    /// What lifts or drags down the player's morale
+    pub factors: MoraleFactorsDto,
+    /// Has the player asked to leave
+    pub transfer_requested: bool,
+}
+
+#[derive(Serialize)]
+pub struct MoraleFactorsDto {
+    pub playing_time: f32,
+    pub wage: f32,
+    pub team_results: f32,
+    pub coach: f32,
+    pub transfer_request: f32,
+    pub personality: f32,
+}
+
+#[derive(Serialize)]
+pub struct InteractionsDto {
+    /// Promises still to be kept
+    pub open_promises: Vec<PromiseDto>,
+    /// Most recent first
+    pub history: Vec<InteractionDto>,
+}
+
+#[derive(Serialize)]
+pub struct PromiseDto {
+    /// "playing_time" or "new_contract"
+    pub promise_type: &'static str,
+    pub staff_id: u32,
+    pub made: String,
+    pub deadline: String,
+}
+
+#[derive(Serialize)]
+pub struct InteractionDto {
+    pub date: String,
+    /// "requested", "promised", "dismissed", "transfer_listed", "contract_renewed", "contract_talks_failed",
+    /// "promise_kept" or "promise_broken"
+    pub interaction_type: &'static str,
+    /// "playing_time", "new_contract" or "transfer"
+    pub subject: &'static str,
+    pub staff_id: Option<u32>,
 }
 
 #[derive(Serialize)]
@@ -118,9 +165,19 @@ pub async fn player_state_action(
         age: player.age(now.date()),
 
         mood: MoodDto {
-            happiness: calculate_happiness_score(player),
-            is_happy: player.happiness.is_happy(),
+            morale: player.morale.value.round() as u8,
+            morale_state: player.morale.state.as_str(),
+            is_happy: player.morale.is_happy(),
             behaviour: player.behaviour.as_str(),
+            factors: MoraleFactorsDto {
+                playing_time: player.morale.factors.playing_time,
+                wage: player.morale.factors.wage,
+                team_results: player.morale.factors.team_results,
+                coach: player.morale.factors.coach,
+                transfer_request: player.morale.factors.transfer_request,
+                personality: player.morale.factors.personality,
+            },
+            transfer_requested: player.morale.transfer_requested,
         },
 
         form: FormDto {
@@ -145,6 +202,32 @@ pub async fn player_state_action(
 
         concerns,
 
+        interactions: InteractionsDto {
+            open_promises: player
+                .interactions
+                .promises
+                .iter()
+                .map(|promise| PromiseDto {
+                    promise_type: promise.promise_type.as_str(),
+                    staff_id: promise.staff_id,
+                    made: promise.made.format("%Y-%m-%d").to_string(),
+                    deadline: promise.deadline.format("%Y-%m-%d").to_string(),
+                })
+                .collect(),
+            history: player
+                .interactions
+                .history
+                .iter()
+                .rev()
+                .map(|interaction| InteractionDto {
+                    date: interaction.date.format("%Y-%m-%d").to_string(),
+                    interaction_type: interaction.interaction_type.as_str(),
+                    subject: interaction.interaction_type.subject(),
+                    staff_id: interaction.staff_id,
+                })
+                .collect(),
+        },
+
         personality: PersonalityDto {
             ambition: (player.attributes.ambition * 20.0).floor() as u8,
             loyalty: (player.attributes.loyalty * 20.0).floor() as u8,
@@ -156,24 +239,6 @@ pub async fn player_state_action(
     Ok(Json(model).into_response())
 }
 
-/// Calculate a happiness score 0-100 based on player state
-fn calculate_happiness_score(player: &Player) -> u8 {
-    // Base score from behaviour state
-    let base = match player.behaviour.as_str() {
-        "Good" => 80,
-        "Normal" => 50,
-        "Poor" => 20,
-        _ => 50,
-    };
-
-    // Adjust based on is_happy
-    if player.happiness.is_happy() {
-        std::cmp::min(100, base + 20)
-    } else {
-        std::cmp::max(0, base - 20)
-    }
-}
-
 /// Convert player statuses to human-readable concern strings
 fn get_player_concerns(player: &Player) -> Vec<&'static str> {
     player.statuses.get().iter().filter_map(|status| {
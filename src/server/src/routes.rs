@@ -3,6 +3,7 @@ use crate::date::current_date_routes;
 use crate::events::event_routes;
 use crate::game::game_routes;
 use crate::leagues::league_routes;
+use crate::news::news_routes;
 use crate::player::player_routes;
 use crate::r#match::routes::match_routes;
 use crate::teams::team_routes;
@@ -23,6 +24,7 @@ impl ServerRoutes {
             .merge(player_routes())
             .merge(match_routes())
             .merge(event_routes())
+            .merge(news_routes())
             .merge(current_date_routes());
 
         #[cfg(debug_assertions)]
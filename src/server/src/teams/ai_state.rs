@@ -2,7 +2,7 @@ use crate::{ApiError, ApiResult, GameAppData};
 use axum::extract::{Path, State};
 use axum::response::{IntoResponse, Response};
 use axum::Json;
-use core::{Person, Team};
+use core::{Person, PlayerMoraleState, Team};
 use serde::{Deserialize, Serialize};
 
 use super::get::{get_conditions, get_current_ability_stars};
@@ -70,9 +70,9 @@ pub struct SquadSummaryDto {
 pub struct MoraleDto {
     /// Overall team behaviour state
     pub team_behaviour: &'static str,
-    /// Number of players with "Poor" behaviour
+    /// Number of players with poor morale
     pub players_poor_mood: usize,
-    /// Number of players with "Good" behaviour
+    /// Number of players with good morale
     pub players_good_mood: usize,
 }
 
@@ -154,11 +154,14 @@ pub async fn team_ai_state_action(
 
     let injured_count = players.iter().filter(|p| p.player_attributes.is_injured).count();
     let suspended_count = players.iter().filter(|p| p.player_attributes.is_banned).count();
-    let unhappy_count = players.iter().filter(|p| !p.happiness.is_happy()).count();
+    let unhappy_count = players.iter().filter(|p| !p.morale.is_happy()).count();
 
     // Calculate morale stats
-    let players_poor_mood = players.iter().filter(|p| p.behaviour.as_str() == "Poor").count();
-    let players_good_mood = players.iter().filter(|p| p.behaviour.as_str() == "Good").count();
+    let players_poor_mood = players
+        .iter()
+        .filter(|p| matches!(p.morale.state, PlayerMoraleState::VeryPoor | PlayerMoraleState::Poor))
+        .count();
+    let players_good_mood = players.iter().filter(|p| p.morale.is_happy()).count();
 
     // Determine team's overall behaviour based on majority
     let team_behaviour = if players_good_mood > players_poor_mood {
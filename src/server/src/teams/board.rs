@@ -0,0 +1,125 @@
+use crate::{ApiError, ApiResult, GameAppData};
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use core::{BoardDecision, BoardDecisionType, BoardSeasonObjective};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct TeamBoardGetRequest {
+    pub team_slug: String,
+}
+
+#[derive(Serialize)]
+pub struct TeamBoardViewModel<'c> {
+    pub club_name: &'c str,
+    pub mood: String,
+    pub confidence: f32,
+    pub manager_appointed_on: Option<String>,
+    pub objective: Option<BoardObjectiveDto>,
+    pub objectives_history: Vec<BoardObjectiveDto>,
+    pub decisions: Vec<BoardDecisionDto>,
+}
+
+#[derive(Serialize)]
+pub struct BoardObjectiveDto {
+    pub objective: String,
+    pub league_id: u32,
+    pub target_position: u8,
+    pub set_on: String,
+    pub final_position: Option<u8>,
+    pub achieved: Option<bool>,
+}
+
+impl From<&BoardSeasonObjective> for BoardObjectiveDto {
+    fn from(objective: &BoardSeasonObjective) -> Self {
+        BoardObjectiveDto {
+            objective: format!("{:?}", objective.objective_type),
+            league_id: objective.league_id,
+            target_position: objective.target_position,
+            set_on: objective.set_on.format("%Y-%m-%d").to_string(),
+            final_position: objective.outcome.as_ref().map(|o| o.final_position),
+            achieved: objective.outcome.as_ref().map(|o| o.achieved),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct BoardDecisionDto {
+    pub date: String,
+    pub decision: String,
+    pub staff_id: Option<u32>,
+    pub confidence: f32,
+}
+
+impl From<&BoardDecision> for BoardDecisionDto {
+    fn from(decision: &BoardDecision) -> Self {
+        let (name, staff_id) = match &decision.decision_type {
+            BoardDecisionType::ObjectiveSet(objective) => (format!("ObjectiveSet: {:?}", objective), None),
+            BoardDecisionType::ObjectiveAchieved(objective) => (format!("ObjectiveAchieved: {:?}", objective), None),
+            BoardDecisionType::ObjectiveFailed(objective) => (format!("ObjectiveFailed: {:?}", objective), None),
+            BoardDecisionType::ManagerSacked(staff_id) => ("ManagerSacked".to_string(), Some(*staff_id)),
+            BoardDecisionType::ManagerLeft(staff_id) => ("ManagerLeft".to_string(), Some(*staff_id)),
+            BoardDecisionType::CaretakerAppointed(staff_id) => ("CaretakerAppointed".to_string(), Some(*staff_id)),
+            BoardDecisionType::ManagerAppointed(staff_id) => ("ManagerAppointed".to_string(), Some(*staff_id)),
+            BoardDecisionType::DirectorAppointed => ("DirectorAppointed".to_string(), None),
+            BoardDecisionType::SportDirectorAppointed => ("SportDirectorAppointed".to_string(), None),
+        };
+
+        BoardDecisionDto {
+            date: decision.date.format("%Y-%m-%d").to_string(),
+            decision: name,
+            staff_id,
+            confidence: decision.confidence,
+        }
+    }
+}
+
+pub async fn team_board_get_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<TeamBoardGetRequest>,
+) -> ApiResult<Response> {
+    let guard = state.data.read().await;
+
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let indexes = simulator_data
+        .indexes
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Indexes not available".to_string()))?;
+
+    let team_id = indexes
+        .slug_indexes
+        .get_team_by_slug(&route_params.team_slug)
+        .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", route_params.team_slug)))?;
+
+    let team = simulator_data
+        .team(team_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Team with ID {} not found", team_id)))?;
+
+    let club = simulator_data
+        .club(team.club_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Club with ID {} not found", team.club_id)))?;
+
+    let board = &club.board;
+
+    let model = TeamBoardViewModel {
+        club_name: &club.name,
+        mood: format!("{:?}", board.mood.state),
+        confidence: board.mood.confidence,
+        manager_appointed_on: board
+            .manager_appointed_on
+            .map(|date| date.format("%Y-%m-%d").to_string()),
+        objective: board.objective.as_ref().map(BoardObjectiveDto::from),
+        objectives_history: board
+            .objectives_history
+            .iter()
+            .map(BoardObjectiveDto::from)
+            .collect(),
+        decisions: board.decisions.iter().map(BoardDecisionDto::from).collect(),
+    };
+
+    Ok(Json(model).into_response())
+}
@@ -0,0 +1,131 @@
+use crate::{ApiError, ApiResult, GameAppData};
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use chrono::Duration;
+use core::{ClubFinancialBalance, ClubFinancialBreakdown};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct TeamFinancesGetRequest {
+    pub team_slug: String,
+}
+
+#[derive(Serialize)]
+pub struct TeamFinancesViewModel<'c> {
+    pub club_name: &'c str,
+    pub balance: i32,
+    pub transfer_budget: Option<f64>,
+    pub wage_budget: Option<f64>,
+    pub current_month: FinancialMonthDto,
+    pub history: Vec<FinancialMonthDto>,
+}
+
+#[derive(Serialize)]
+pub struct FinancialMonthDto {
+    pub month: String,
+    pub balance: i32,
+    pub income: i32,
+    pub outcome: i32,
+    pub breakdown: FinancialBreakdownDto,
+}
+
+#[derive(Serialize)]
+pub struct FinancialBreakdownDto {
+    pub matchday: i32,
+    pub tv_rights: i32,
+    pub sponsorship: i32,
+    pub prize_money: i32,
+    pub transfer_income: i32,
+    pub other_income: i32,
+    pub player_wages: i32,
+    pub staff_wages: i32,
+    pub transfer_expenses: i32,
+    pub stadium_expenses: i32,
+    pub other_expenses: i32,
+}
+
+impl From<&ClubFinancialBreakdown> for FinancialBreakdownDto {
+    fn from(breakdown: &ClubFinancialBreakdown) -> Self {
+        FinancialBreakdownDto {
+            matchday: breakdown.matchday,
+            tv_rights: breakdown.tv_rights,
+            sponsorship: breakdown.sponsorship,
+            prize_money: breakdown.prize_money,
+            transfer_income: breakdown.transfer_income,
+            other_income: breakdown.other_income,
+            player_wages: breakdown.player_wages,
+            staff_wages: breakdown.staff_wages,
+            transfer_expenses: breakdown.transfer_expenses,
+            stadium_expenses: breakdown.stadium_expenses,
+            other_expenses: breakdown.other_expenses,
+        }
+    }
+}
+
+impl FinancialMonthDto {
+    fn new(month: String, balance: &ClubFinancialBalance) -> Self {
+        FinancialMonthDto {
+            month,
+            balance: balance.balance,
+            income: balance.income,
+            outcome: balance.outcome,
+            breakdown: FinancialBreakdownDto::from(&balance.breakdown),
+        }
+    }
+}
+
+pub async fn team_finances_get_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<TeamFinancesGetRequest>,
+) -> ApiResult<Response> {
+    let guard = state.data.read().await;
+
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let indexes = simulator_data
+        .indexes
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Indexes not available".to_string()))?;
+
+    let team_id = indexes
+        .slug_indexes
+        .get_team_by_slug(&route_params.team_slug)
+        .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", route_params.team_slug)))?;
+
+    let team = simulator_data
+        .team(team_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Team with ID {} not found", team_id)))?;
+
+    let club = simulator_data
+        .club(team.club_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Club with ID {} not found", team.club_id)))?;
+
+    let finance = &club.finance;
+
+    // History records are stored on the first day of the following month
+    let history = finance
+        .history
+        .iter()
+        .map(|(date, balance)| {
+            let month = (*date - Duration::days(1)).format("%Y-%m").to_string();
+            FinancialMonthDto::new(month, balance)
+        })
+        .collect();
+
+    let model = TeamFinancesViewModel {
+        club_name: &club.name,
+        balance: finance.balance.balance,
+        transfer_budget: finance.transfer_budget.as_ref().map(|b| b.amount),
+        wage_budget: finance.wage_budget.as_ref().map(|b| b.amount),
+        current_month: FinancialMonthDto::new(
+            simulator_data.date.format("%Y-%m").to_string(),
+            &finance.balance,
+        ),
+        history,
+    };
+
+    Ok(Json(model).into_response())
+}
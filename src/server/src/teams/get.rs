@@ -131,15 +131,21 @@ pub async fn team_get_action(
 
     let neighbor_teams = get_neighbor_teams(team.club_id, simulator_data)?;
 
+    let club_balance = &simulator_data
+        .club(team.club_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Club with ID {} not found", team.club_id)))?
+        .finance
+        .balance;
+
     let model = TeamGetViewModel {
         slug: &team.slug,
         name: &team.name,
         league_slug: &league.slug,
         league_name: &league.name,
         balance: TeamBalance {
-            amount: 0,
-            income: 0,
-            outcome: 0,
+            amount: club_balance.balance,
+            income: club_balance.income,
+            outcome: club_balance.outcome,
         },
         players,
         neighbor_teams,
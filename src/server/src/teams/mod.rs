@@ -1,10 +1,14 @@
 mod get;
+mod board;
+mod finances;
 mod ai_state;
 mod squad_state;
 pub mod routes;
 mod schedule;
 
 pub use get::*;
+pub use board::*;
+pub use finances::*;
 pub use ai_state::*;
 pub use squad_state::*;
 pub use routes::*;
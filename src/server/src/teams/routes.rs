@@ -1,4 +1,4 @@
-use crate::teams::{team_get_action, team_schedule_get_action, team_ai_state_action, squad_state_action};
+use crate::teams::{team_get_action, team_schedule_get_action, team_ai_state_action, squad_state_action, team_finances_get_action, team_board_get_action};
 use crate::GameAppData;
 use axum::routing::get;
 use axum::Router;
@@ -18,4 +18,12 @@ pub fn team_routes() -> Router<GameAppData> {
             "/api/teams/{team_slug}/squad-state",
             get(squad_state_action),
         )
+        .route(
+            "/api/teams/{team_slug}/finances",
+            get(team_finances_get_action),
+        )
+        .route(
+            "/api/teams/{team_slug}/board",
+            get(team_board_get_action),
+        )
 }
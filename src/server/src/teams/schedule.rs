@@ -22,6 +22,8 @@ pub struct TeamScheduleViewModel<'t> {
 
 #[derive(Serialize)]
 pub struct TeamScheduleItem<'t> {
+    /// Picks the fixture to watch live
+    pub fixture_id: &'t str,
     pub date: String,
     pub time: String,
     pub opponent_slug: &'t str,
@@ -85,6 +87,7 @@ pub async fn team_schedule_get_action(
                 let away_team_data = simulator_data.team_data(schedule.away_team_id).unwrap();
 
                 TeamScheduleItem {
+                    fixture_id: &schedule.id,
                     date: schedule.date.format("%d.%m.%Y").to_string(),
                     time: schedule.date.format("%H:%M").to_string(),
                     opponent_slug: if is_home {
@@ -34,8 +34,8 @@ pub struct PlayerSquadState {
     pub age: u8,
 
     // State indicators
-    /// Happiness score 0-100
-    pub happiness: u8,
+    /// Morale 0-100
+    pub morale: u8,
     /// Is player currently happy
     pub is_happy: bool,
     /// Behaviour: "Poor", "Normal", "Good"
@@ -107,10 +107,10 @@ pub async fn squad_state_action(
         .map(|p| build_player_squad_state(p, now))
         .collect();
 
-    // Calculate team morale as average happiness
-    let total_happiness: u32 = players.iter().map(|p| p.happiness as u32).sum();
+    // Calculate team morale as average player morale
+    let total_morale: u32 = players.iter().map(|p| p.morale as u32).sum();
     let team_morale = if !players.is_empty() {
-        (total_happiness / players.len() as u32) as u8
+        (total_morale / players.len() as u32) as u8
     } else {
         50
     };
@@ -127,7 +127,6 @@ pub async fn squad_state_action(
 }
 
 fn build_player_squad_state(player: &Player, now: chrono::NaiveDateTime) -> PlayerSquadState {
-    let happiness = calculate_happiness_score(player);
     let concerns = get_player_concerns(player);
 
     let (contract_days, expiring_soon, squad_status) = player.contract.as_ref().map_or(
@@ -148,8 +147,8 @@ fn build_player_squad_state(player: &Player, now: chrono::NaiveDateTime) -> Play
         position: player.positions.display_positions().join(", "),
         age: player.age(now.date()),
 
-        happiness,
-        is_happy: player.happiness.is_happy(),
+        morale: player.morale.value.round() as u8,
+        is_happy: player.morale.is_happy(),
         behaviour: player.behaviour.as_str(),
 
         condition: get_conditions(player),
@@ -173,22 +172,6 @@ fn build_player_squad_state(player: &Player, now: chrono::NaiveDateTime) -> Play
     }
 }
 
-/// Calculate a happiness score 0-100 based on player state
-fn calculate_happiness_score(player: &Player) -> u8 {
-    let base = match player.behaviour.as_str() {
-        "Good" => 80,
-        "Normal" => 50,
-        "Poor" => 20,
-        _ => 50,
-    };
-
-    if player.happiness.is_happy() {
-        std::cmp::min(100, base + 20)
-    } else {
-        std::cmp::max(0, base - 20)
-    }
-}
-
 /// Convert player statuses to human-readable concern strings
 fn get_player_concerns(player: &Player) -> Vec<&'static str> {
     player.statuses.get().iter().filter_map(|status| {
@@ -0,0 +1,136 @@
+import {MatchDataDto, ObjectPositionDto, PassEventDto} from "./match.service";
+
+const REPLAY_MAGIC = "OFRP";
+const REPLAY_FORMAT_VERSION = 1;
+const FLAG_TRACK_EVENTS = 1;
+
+/**
+ * Reads the binary replay chunks written by the Rust `ReplayEncoder`,
+ * see `core::r#match::replay` for the layout
+ */
+export class MatchReplayDecoder {
+    private bytes: Uint8Array;
+    private offset: number = 0;
+
+    private constructor(buffer: ArrayBuffer) {
+        this.bytes = new Uint8Array(buffer);
+    }
+
+    static decode(buffer: ArrayBuffer): MatchDataDto {
+        return new MatchReplayDecoder(buffer).read();
+    }
+
+    private read(): MatchDataDto {
+        const magic = String.fromCharCode(...this.readBytes(REPLAY_MAGIC.length));
+        if (magic !== REPLAY_MAGIC) {
+            throw new Error('Not a match replay');
+        }
+
+        const version = this.readByte();
+        if (version > REPLAY_FORMAT_VERSION) {
+            throw new Error(`Replay version ${version} is not supported`);
+        }
+
+        const flags = this.readByte();
+        const scale = this.readVarint();
+        const keyframeInterval = this.readVarint();
+
+        const ball = this.readTrack(scale, keyframeInterval);
+
+        const players: { [key: number]: ObjectPositionDto[] } = {};
+        const playerCount = this.readVarint();
+
+        for (let i = 0; i < playerCount; i++) {
+            const playerId = this.readVarint();
+            players[playerId] = this.readTrack(scale, keyframeInterval);
+        }
+
+        const passes: PassEventDto[] = [];
+        const passCount = this.readVarint();
+
+        let timestamp = 0;
+        for (let i = 0; i < passCount; i++) {
+            timestamp += this.readVarint();
+            passes.push({
+                timestamp,
+                from_player_id: this.readVarint(),
+                to_player_id: this.readVarint()
+            });
+        }
+
+        return {
+            ball,
+            players,
+            passes: flags & FLAG_TRACK_EVENTS ? passes : undefined
+        };
+    }
+
+    private readTrack(scale: number, keyframeInterval: number): ObjectPositionDto[] {
+        const count = this.readVarint();
+        const track: ObjectPositionDto[] = new Array(count);
+
+        let timestamp = 0;
+        let x = 0, y = 0, z = 0;
+
+        for (let i = 0; i < count; i++) {
+            if (i % keyframeInterval === 0) {
+                timestamp = this.readVarint();
+                x = this.readSignedVarint();
+                y = this.readSignedVarint();
+                z = this.readSignedVarint();
+            } else {
+                timestamp += this.readVarint();
+                x += this.readSignedVarint();
+                y += this.readSignedVarint();
+                z += this.readSignedVarint();
+            }
+
+            track[i] = new ObjectPositionDto(timestamp, [x / scale, y / scale, z / scale]);
+        }
+
+        return track;
+    }
+
+    private readBytes(count: number): Uint8Array {
+        if (this.offset + count > this.bytes.length) {
+            throw new Error('Replay is truncated');
+        }
+
+        const bytes = this.bytes.subarray(this.offset, this.offset + count);
+        this.offset += count;
+
+        return bytes;
+    }
+
+    private readByte(): number {
+        if (this.offset >= this.bytes.length) {
+            throw new Error('Replay is truncated');
+        }
+
+        return this.bytes[this.offset++];
+    }
+
+    // Plain arithmetic instead of bit operators, timestamps can outgrow 32 bits
+    private readVarint(): number {
+        let value = 0;
+        let multiplier = 1;
+
+        for (;;) {
+            const byte = this.readByte();
+
+            value += (byte & 0x7F) * multiplier;
+
+            if ((byte & 0x80) === 0) {
+                return value;
+            }
+
+            multiplier *= 128;
+        }
+    }
+
+    private readSignedVarint(): number {
+        const value = this.readVarint();
+
+        return value % 2 === 0 ? value / 2 : -(value + 1) / 2;
+    }
+}
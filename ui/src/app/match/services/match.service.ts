@@ -1,7 +1,8 @@
 import {HttpClient} from "@angular/common/http";
 import {Injectable} from "@angular/core";
-import {Observable} from "rxjs";
+import {Observable, map} from "rxjs";
 import {Container} from "pixi.js";
+import {MatchReplayDecoder} from "./match.replay.decoder";
 
 @Injectable({
     providedIn: 'root',
@@ -22,20 +23,113 @@ export class MatchService {
         return this.http.get<MatchMetadataDto>(`/api/match/${league_slug}/${match_id}/metadata`);
     }
 
+    events(league_slug: string, match_id: string): Observable<MatchEventsDto> {
+        return this.http.get<MatchEventsDto>(`/api/match/${league_slug}/${match_id}/events`);
+    }
+
+    commentary(league_slug: string, match_id: string, language: string = 'en', verbosity: string = 'standard'): Observable<MatchCommentaryDto> {
+        return this.http.get<MatchCommentaryDto>(`/api/match/${league_slug}/${match_id}/commentary`, {
+            params: {language, verbosity}
+        });
+    }
+
+    startLive(league_slug: string, fixture_id: string, speed: number = 1): Observable<LiveMatchDto> {
+        return this.http.post<LiveMatchDto>(`/api/match/live`, null, {
+            params: {league_slug, fixture_id, speed}
+        });
+    }
+
+    liveFrames(live: LiveMatchDto): Observable<LiveMatchFrameDto> {
+        return new Observable<LiveMatchFrameDto>(subscriber => {
+            const source = new EventSource(live.stream_url);
+
+            source.addEventListener('frame', event => subscriber.next(JSON.parse((event as MessageEvent).data)));
+            source.addEventListener('end', () => {
+                source.close();
+                subscriber.complete();
+            });
+            source.onerror = () => {
+                source.close();
+                subscriber.error('live match stream closed');
+            };
+
+            return () => source.close();
+        });
+    }
+
     chunk(league_slug: string, match_id: string, chunk_number: number): Observable<MatchDataDto> {
-        return this.http.get<MatchDataDto>(`/api/match/${league_slug}/${match_id}/chunk/${chunk_number}`);
+        return this.http
+            .get(`/api/match/${league_slug}/${match_id}/chunk/${chunk_number}`, {responseType: 'arraybuffer'})
+            .pipe(map(buffer => MatchReplayDecoder.decode(buffer)));
     }
 }
 
 export interface MatchDataDto {
     players: { [key: number]: ObjectPositionDto[] },
-    ball: ObjectPositionDto[]
+    ball: ObjectPositionDto[],
+    passes?: PassEventDto[]
+}
+
+export interface PassEventDto {
+    timestamp: number,
+    from_player_id: number,
+    to_player_id: number
 }
 
 export interface MatchMetadataDto {
     chunk_count: number,
     chunk_duration_ms: number,
-    total_duration_ms: number
+    total_duration_ms: number,
+    replay_version: number
+}
+
+export interface MatchEventsDto {
+    home_team_id: number,
+    away_team_id: number,
+    events: MatchEventDto[]
+}
+
+export interface MatchEventDto {
+    time: number,
+    team_id: number,
+    is_home: boolean,
+    player_id?: number,
+    player_name?: string,
+    type: 'kick_off' | 'shot' | 'save' | 'goal' | 'foul' | 'card' | 'set_piece'
+        | 'substitution' | 'injury' | 'offside' | 'possession_change',
+    xg?: number,
+    outcome?: 'goal' | 'saved' | 'blocked' | 'off_target',
+    own_goal?: boolean,
+    card?: 'yellow' | 'red',
+    set_piece?: 'corner' | 'free_kick' | 'penalty' | 'throw_in' | 'goal_kick',
+    replaced_player_id?: number
+}
+
+export interface MatchCommentaryDto {
+    language: string,
+    verbosity: string,
+    lines: CommentaryLineDto[]
+}
+
+export interface CommentaryLineDto {
+    time: number,
+    minute: number,
+    text: string
+}
+
+export interface LiveMatchDto {
+    live_id: number,
+    stream_url: string
+}
+
+export interface LiveMatchFrameDto {
+    time: number,
+    state: string,
+    home_goals: number,
+    away_goals: number,
+    ball: number[],
+    players: { id: number, position: number[] }[],
+    events: MatchEventDto[]
 }
 
 export class ObjectPositionDto {
@@ -75,6 +75,7 @@ export interface TeamScheduleDto {
 }
 
 export interface TeamScheduleItemDto {
+   fixture_id: string,
    date: String,
    time: String,
    opponent_slug: String,